@@ -0,0 +1,77 @@
+//! Compares the sequential and parallel memory scanners on a synthetic full-memory dump.
+//!
+//! Run with `cargo bench --bench parallel --features rayon`. The size of the dump, 1 GiB
+//! by default, is read in MiB from `USERDMP_BENCH_MIB`.
+
+use std::{hint::black_box, time::Instant};
+use userdmp::{UserDump, search::Pattern};
+
+/// Size of each captured region.
+const REGION_SIZE: usize = 0x10_0000;
+
+/// Builds a minidump with a single `Memory64ListStream` holding `regions` regions of
+/// pseudo-random bytes, sprinkled with strings and instances of the searched pattern.
+fn synthetic_dump(regions: usize) -> Vec<u8> {
+    let list_size = 16 + regions * 16;
+    let base_rva = (32 + 12 + list_size) as u64;
+
+    let mut data = Vec::with_capacity(base_rva as usize + regions * REGION_SIZE);
+    data.extend_from_slice(b"MDMP");
+    data.extend_from_slice(&0xA793u32.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&32u32.to_le_bytes());
+    data.resize(32, 0);
+
+    // Memory64ListStream
+    data.extend_from_slice(&9u32.to_le_bytes());
+    data.extend_from_slice(&(list_size as u32).to_le_bytes());
+    data.extend_from_slice(&44u32.to_le_bytes());
+    data.extend_from_slice(&(regions as u64).to_le_bytes());
+    data.extend_from_slice(&base_rva.to_le_bytes());
+    for index in 0..regions as u64 {
+        data.extend_from_slice(&(0x1_0000_0000 + index * 2 * REGION_SIZE as u64).to_le_bytes());
+        data.extend_from_slice(&(REGION_SIZE as u64).to_le_bytes());
+    }
+
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for _ in 0..regions * REGION_SIZE / 8 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        data.extend_from_slice(&state.to_le_bytes());
+    }
+
+    for offset in (base_rva as usize..data.len() - 0x40).step_by(0x1_0000) {
+        data[offset..offset + 10].copy_from_slice(&[0x48, 0x8B, 0x05, 0x10, 0x20, 0x30, 0x40, 0x48, 0x85, 0xC0]);
+        data[offset + 0x20..offset + 0x34].copy_from_slice(b"password=hunter2;   ");
+    }
+
+    data
+}
+
+/// Runs `scan` and prints its duration alongside the number of results.
+fn time(name: &str, scan: impl FnOnce() -> usize) {
+    let start = Instant::now();
+    let found = black_box(scan());
+    println!("{name:<20} {found:>8} results in {:?}", start.elapsed());
+}
+
+fn main() {
+    let mib = std::env::var("USERDMP_BENCH_MIB")
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(1024);
+
+    let data = synthetic_dump(mib.max(1) * 0x10_0000 / REGION_SIZE);
+    let dmp = UserDump::from_bytes(&data).expect("synthetic dump parses");
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    println!("Captured: {mib} MiB on {threads} threads");
+
+    let pattern = Pattern::parse("48 8B 05 ?? ?? ?? ?? 48 85 C0").expect("pattern parses");
+    time("search_pattern", || dmp.search_pattern(&pattern).count());
+    time("par_search_pattern", || dmp.par_search_pattern(&pattern).len());
+    time("search_str", || dmp.search_str("password").count());
+    time("par_search_str", || dmp.par_search_str("password").len());
+    time("strings", || dmp.strings(8).count());
+    time("par_strings", || dmp.par_strings(8).len());
+}
@@ -3,7 +3,7 @@ use userdmp::{error::UserDmpError, UserDump};
 fn main() -> Result<(), UserDmpError> {
     let dmp = UserDump::new("C:\\Examples.dmp")?;
 
-    for (_, handle) in dmp.handles() {
+    for handle in dmp.handles().values() {
         println!("Handle: {}", handle.handle());
         println!("Access: {}", handle.granted_access);
         println!("Type Name: {:?}", handle.type_name().unwrap_or(""));
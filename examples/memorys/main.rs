@@ -1,14 +1,23 @@
-use userdmp::{error::UserDmpError, UserDump};
+use userdmp::{error::UserDmpError, memory::MemoryFilter, UserDump};
 
 fn main() -> Result<(), UserDmpError> {
     let dmp = UserDump::new("C:\\Examples.dmp")?;
 
-    for (_, memory) in dmp.memorys() {
+    for memory in dmp.memorys().values() {
         println!("Start: {}", memory.start_addr());
         println!("End: {}", memory.end_addr());
         println!("Data: {:?}", memory.data);
         // Access the other members ...
     }
 
+    for memory in dmp
+        .memorys()
+        .executable()
+        .writable()
+        .private()
+    {
+        println!("RWX private region: {:#x}", memory.start_addr());
+    }
+
     Ok(())
 }
@@ -3,7 +3,7 @@ use userdmp::{error::UserDmpError, UserDump};
 fn main() -> Result<(), UserDmpError> {
     let dmp = UserDump::new("C:\\Examples.dmp")?;
 
-    for (_, module) in dmp.modules().iter() {
+    for module in dmp.modules().values() {
         println!("[*] Path: {:?}", module.path);
         println!("[*] Range Address: {:?}", module.range);
         println!("[*] Checksum: {:?}", module.checksum);
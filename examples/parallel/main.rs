@@ -0,0 +1,44 @@
+use std::time::Instant;
+use userdmp::{error::UserDmpError, search::Pattern, UserDump};
+
+fn main() -> Result<(), UserDmpError> {
+    // Pass a large dump to compare the sequential and parallel scanners.
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "C:\\Examples.dmp".to_string());
+    let dmp = UserDump::new(path)?;
+    let captured = dmp
+        .memorys()
+        .values()
+        .map(|memory| memory.data.len())
+        .sum::<usize>();
+    println!("Captured: {} MiB", captured >> 20);
+
+    let pattern = Pattern::parse("48 8B 05 ?? ?? ?? ?? 48 85 C0")?;
+
+    let start = Instant::now();
+    let found = dmp.search_pattern(&pattern).count();
+    println!("search_pattern:     {found} matches in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let found = dmp.par_search_pattern(&pattern).len();
+    println!("par_search_pattern: {found} matches in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let found = dmp.search_str("password").count();
+    println!("search_str:         {found} matches in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let found = dmp.par_search_str("password").len();
+    println!("par_search_str:     {found} matches in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let found = dmp.strings(8).count();
+    println!("strings:            {found} strings in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let found = dmp.par_strings(8).len();
+    println!("par_strings:        {found} strings in {:?}", start.elapsed());
+
+    Ok(())
+}
@@ -2,11 +2,13 @@ use userdmp::{error::UserDmpError, UserDump};
 
 fn main() -> Result<(), UserDmpError> {
     let dmp = UserDump::new("C:\\Examples.dmp")?;
-    let system = dmp.system;
+    let system = &dmp.system;
 
     println!("Number Of Processors: {}", system.number_of_processors);
     println!("Arch: {:?}", system.processor_architecture);
     println!("BuildNumber: {:?}", system.build_number);
+    println!("OS: {}", system.os_description());
+    println!("CPU: {:?} {:?}", system.cpu_vendor(), system.cpu_features());
     // Access the other members ...
 
     Ok(())
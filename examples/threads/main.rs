@@ -5,7 +5,14 @@ fn main() -> Result<(), UserDmpError> {
 
     for (tid, thread) in dmp.threads().iter() {
         println!("[*] TID: {:?}", tid);
+        println!("[*] Name: {:?}", thread.name().unwrap_or(""));
         println!("[*] TEB: {:?}", thread.teb);
+        println!(
+            "[*] Start: {}",
+            thread
+                .start_symbol(&dmp)
+                .unwrap_or_default()
+        );
         println!("[*] CONTEXT: {:#x?}", thread.context());
         // Access the other members ...
     }
@@ -0,0 +1,505 @@
+use core::fmt;
+use std::{collections::HashMap, ops::Range};
+use crate::parse::{Memory, MemoryOwner, Module, UserDump};
+
+/// An entry of an [`Intervals`] index.
+#[derive(Debug, Clone)]
+struct Interval<T> {
+    /// The range covered by the entry.
+    range: Range<u64>,
+
+    /// The highest end of this entry and all the entries before it.
+    max_end: u64,
+
+    /// The indexed value.
+    value: T,
+}
+
+/// A static interval index: ranges sorted by start, with the running maximum of their ends.
+///
+/// Lookups binary-search the last range starting at or before the address, then walk back
+/// only while an earlier range may still reach it. Without overlaps this stops after one
+/// step, so lookups run in logarithmic time; overlapping ranges from malformed dumps are
+/// still found instead of being hidden by their neighbours.
+#[derive(Debug, Clone)]
+struct Intervals<T> {
+    /// The entries, ordered by start.
+    entries: Vec<Interval<T>>,
+}
+
+impl<T: Copy> Intervals<T> {
+    /// Builds the index from ranges ordered by start. Empty ranges are left out.
+    fn new(ranges: impl IntoIterator<Item = (Range<u64>, T)>) -> Self {
+        let mut max_end = 0;
+        let entries = ranges
+            .into_iter()
+            .filter(|(range, _)| range.start < range.end)
+            .map(|(range, value)| {
+                max_end = max_end.max(range.end);
+                Interval { range, max_end, value }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns the value of the range containing `va` that starts the closest to it.
+    fn find(&self, va: u64) -> Option<T> {
+        let end = self
+            .entries
+            .partition_point(|entry| entry.range.start <= va);
+        self.entries[..end]
+            .iter()
+            .rev()
+            .take_while(|entry| entry.max_end > va)
+            .find(|entry| entry.range.contains(&va))
+            .map(|entry| entry.value)
+    }
+
+    /// Returns the values of the ranges overlapping `range`, ordered by start.
+    fn overlapping(&self, range: Range<u64>) -> impl Iterator<Item = T> + '_ {
+        // Entries before the first one whose running maximum reaches `range.start` all end before it.
+        let first = self
+            .entries
+            .partition_point(|entry| entry.max_end <= range.start);
+        self.entries[first..]
+            .iter()
+            .take_while(move |entry| entry.range.start < range.end)
+            .filter(move |entry| entry.range.end > range.start)
+            .map(|entry| entry.value)
+    }
+}
+
+/// Describes what a range of the address space is used for, like the `Usage` column of
+/// the `!address` WinDbg command.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Usage {
+    /// A free region (`MEM_FREE`).
+    Free,
+
+    /// The image of the module loaded at the given base address.
+    Image(u64),
+
+    /// The stack of the thread with the given ID, guard and reserved pages included.
+    Stack(u32),
+
+    /// The Thread Environment Block of the thread with the given ID.
+    Teb(u32),
+
+    /// The Process Environment Block.
+    Peb,
+
+    /// A segment of the heap at the given address.
+    Heap(u64),
+
+    /// A region in use that could not be attributed.
+    Other,
+
+    /// A range described by no region of the dump.
+    Unknown,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Usage::Free => write!(f, "Free"),
+            Usage::Image(base) => write!(f, "Image {base:#x}"),
+            Usage::Stack(thread_id) => write!(f, "Stack (thread {thread_id})"),
+            Usage::Teb(thread_id) => write!(f, "TEB (thread {thread_id})"),
+            Usage::Peb => write!(f, "PEB"),
+            Usage::Heap(heap) => write!(f, "Heap {heap:#x}"),
+            Usage::Other => write!(f, "Other"),
+            Usage::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Represents a range of the address space map, see [`AddressSpace::entries`].
+#[derive(Debug, Clone)]
+pub struct AddressRange<'d, 'a> {
+    /// The addresses covered by the range.
+    pub range: Range<u64>,
+
+    /// The region describing the range, or `None` for ranges described by no region.
+    pub region: Option<&'d Memory<'a>>,
+
+    /// What the range is used for.
+    pub usage: Usage,
+}
+
+/// A prebuilt index over the regions and modules of a dump, for attributing many addresses.
+///
+/// [`UserDump::memory_at`] and [`UserDump::module_at`] already run in logarithmic time,
+/// but assume ranges never overlap. The index keeps its lookups logarithmic while
+/// tolerating overlaps, answers range and gap queries, and is meant to be built once and
+/// reused when attributing a large number of addresses (e.g., every pointer of a heap).
+///
+/// Gaps are the parts of the address space not covered by any region, or covered only
+/// by free regions.
+///
+/// The index also holds a normalized map of the whole address space, with each region
+/// attributed to the module, stack, TEB, PEB or heap it belongs to (see
+/// [`AddressSpace::entries`]). Its `Display` implementation lists it like `!address`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::UserDump;
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let space = dump.address_space();
+/// for va in [0x7ffa_1234_5678, 0x1000] {
+///     match (space.module_at(va), space.region_at(va), space.gap_at(va)) {
+///         (Some(module), ..) => println!("{va:#x} in {}", module.name().unwrap_or("???")),
+///         (_, Some(memory), _) => println!("{va:#x} in region {:#x}", memory.start_addr()),
+///         (.., Some(gap)) => println!("{va:#x} in gap {:#x}-{:#x}", gap.start, gap.end),
+///         _ => println!("{va:#x} unknown"),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AddressSpace<'d, 'a> {
+    /// The regions, including free ones.
+    regions: Intervals<&'d Memory<'a>>,
+
+    /// The modules.
+    modules: Intervals<&'d Module<'a>>,
+
+    /// The gaps between the regions in use, ordered by address.
+    gaps: Vec<Range<u64>>,
+
+    /// The map of the whole address space, ordered by address.
+    entries: Vec<AddressRange<'d, 'a>>,
+}
+
+/// Returns the key grouping the regions of one allocation, the region itself when the
+/// dump does not record allocation bases.
+fn allocation(memory: &Memory) -> u64 {
+    if memory.allocation_base != 0 {
+        memory.allocation_base
+    } else {
+        memory.range.start
+    }
+}
+
+impl<'d, 'a> AddressSpace<'d, 'a> {
+    /// Builds the index over the regions and modules of a dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump to index.
+    pub fn new(dump: &'d UserDump<'a>) -> Self {
+        let regions = Intervals::new(
+            dump.memorys()
+                .values()
+                .map(|memory| (memory.range.clone(), memory)),
+        );
+        let modules = Intervals::new(
+            dump.modules()
+                .values()
+                .map(|module| (module.range.clone(), module)),
+        );
+
+        // Walks the regions in use in address order, recording where none reaches.
+        let mut gaps = Vec::new();
+        let mut covered = 0;
+        for memory in dump
+            .memorys()
+            .values()
+            .filter(|memory| !memory.state.is_free())
+        {
+            if memory.range.start > covered {
+                gaps.push(covered..memory.range.start);
+            }
+
+            covered = covered.max(memory.range.end);
+        }
+
+        if covered < u64::MAX {
+            gaps.push(covered..u64::MAX);
+        }
+
+        let mut space = Self {
+            regions,
+            modules,
+            gaps,
+            entries: Vec::new(),
+        };
+        space.entries = space.map(dump);
+        space
+    }
+
+    /// Builds the map of the address space, attributing each region to its owner.
+    fn map(&self, dump: &'d UserDump<'a>) -> Vec<AddressRange<'d, 'a>> {
+        // Stacks and heaps own whole allocations, TEBs and the PEB only their own region.
+        let mut owners = HashMap::new();
+        for heap in dump.heaps() {
+            for address in std::iter::once(heap.base).chain(
+                heap.segments
+                    .iter()
+                    .map(|segment| segment.start),
+            ) {
+                if let Some(memory) = self.region_at(address) {
+                    owners.insert(allocation(memory), Usage::Heap(heap.base));
+                }
+            }
+        }
+
+        let mut tebs = HashMap::new();
+        for thread in dump.threads().values() {
+            if let Some(memory) = self.region_at(thread.stack.start) {
+                owners.insert(allocation(memory), Usage::Stack(thread.thread_id));
+            }
+
+            if let Some(memory) = self.region_at(thread.teb) {
+                tebs.insert(memory.range.start, Usage::Teb(thread.thread_id));
+            }
+        }
+
+        let peb = dump
+            .peb_address()
+            .and_then(|peb| self.region_at(peb))
+            .map(|memory| memory.range.start);
+
+        let usage = |memory: &Memory| {
+            if memory.state.is_free() {
+                Usage::Free
+            } else if let Some(module) = self.module_at(memory.range.start) {
+                Usage::Image(module.start_addr())
+            } else if peb == Some(memory.range.start) {
+                Usage::Peb
+            } else if let Some(usage) = tebs.get(&memory.range.start) {
+                *usage
+            } else if let Some(MemoryOwner::ThreadStack(thread_id)) = memory.owner() {
+                Usage::Stack(thread_id)
+            } else {
+                owners
+                    .get(&allocation(memory))
+                    .copied()
+                    .unwrap_or(Usage::Other)
+            }
+        };
+
+        // Fills the holes between the regions with unknown ranges.
+        let mut entries = Vec::new();
+        let mut covered = 0;
+        for memory in dump.memorys().values() {
+            if memory.range.start > covered {
+                entries.push(AddressRange {
+                    range: covered..memory.range.start,
+                    region: None,
+                    usage: Usage::Unknown,
+                });
+            }
+
+            covered = covered.max(memory.range.end);
+            entries.push(AddressRange {
+                range: memory.range.clone(),
+                region: Some(memory),
+                usage: usage(memory),
+            });
+        }
+
+        if covered < u64::MAX {
+            entries.push(AddressRange {
+                range: covered..u64::MAX,
+                region: None,
+                usage: Usage::Unknown,
+            });
+        }
+
+        entries
+    }
+
+    /// Returns the region containing a virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Memory)` - The region containing `va`, the innermost one if regions overlap.
+    /// * `None` - If no region of the dump contains the address.
+    pub fn region_at(&self, va: u64) -> Option<&'d Memory<'a>> {
+        self.regions.find(va)
+    }
+
+    /// Returns the module containing a virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Module)` - The module whose image contains `va`, the innermost one if images overlap.
+    /// * `None` - If the address does not belong to any loaded module.
+    pub fn module_at(&self, va: u64) -> Option<&'d Module<'a>> {
+        self.modules.find(va)
+    }
+
+    /// Returns the regions overlapping a range of addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The virtual addresses to look up.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the regions overlapping `range`, ordered by address.
+    pub fn regions_in(&self, range: Range<u64>) -> impl Iterator<Item = &'d Memory<'a>> + '_ {
+        self.regions.overlapping(range)
+    }
+
+    /// Returns the modules overlapping a range of addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The virtual addresses to look up.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the modules overlapping `range`, ordered by base address.
+    pub fn modules_in(&self, range: Range<u64>) -> impl Iterator<Item = &'d Module<'a>> + '_ {
+        self.modules.overlapping(range)
+    }
+
+    /// Returns the gap containing a virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Range<u64>)` - The gap containing `va`.
+    /// * `None` - If the address belongs to a region in use.
+    pub fn gap_at(&self, va: u64) -> Option<Range<u64>> {
+        let index = self
+            .gaps
+            .partition_point(|gap| gap.start <= va)
+            .checked_sub(1)?;
+        let gap = &self.gaps[index];
+        gap.contains(&va).then(|| gap.clone())
+    }
+
+    /// Returns the gaps of the address space, ordered by address.
+    ///
+    /// The last gap extends from the end of the highest region in use to `u64::MAX`.
+    pub fn gaps(&self) -> &[Range<u64>] {
+        &self.gaps
+    }
+
+    /// Returns the map of the whole address space, from 0 to `u64::MAX`.
+    ///
+    /// Each region of the dump yields one entry, and the ranges described by no region
+    /// yield [`Usage::Unknown`] entries, so the entries are contiguous unless regions overlap.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, address_space::Usage};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let space = dump.address_space();
+    /// let stacks = space
+    ///     .entries()
+    ///     .iter()
+    ///     .filter(|entry| matches!(entry.usage, Usage::Stack(_)))
+    ///     .map(|entry| entry.range.end - entry.range.start)
+    ///     .sum::<u64>();
+    /// println!("{stacks} bytes of stacks");
+    /// ```
+    pub fn entries(&self) -> &[AddressRange<'d, 'a>] {
+        &self.entries
+    }
+
+    /// Returns the entry of the map containing a virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&AddressRange)` - The entry containing `va`.
+    /// * `None` - Only for `u64::MAX`, which no range includes.
+    pub fn entry_at(&self, va: u64) -> Option<&AddressRange<'d, 'a>> {
+        let index = self
+            .entries
+            .partition_point(|entry| entry.range.start <= va)
+            .checked_sub(1)?;
+        self.entries[..=index]
+            .iter()
+            .rev()
+            .find(|entry| entry.range.contains(&va))
+    }
+
+    /// Returns what a virtual address is used for.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    pub fn usage_at(&self, va: u64) -> Usage {
+        self.entry_at(va)
+            .map_or(Usage::Unknown, |entry| entry.usage)
+    }
+}
+
+impl fmt::Display for AddressSpace<'_, '_> {
+    /// Lists the map like `!address`: range, size, type, state, protection and usage.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            write!(
+                f,
+                "{:#018x} {:#018x} {:#14x}",
+                entry.range.start,
+                entry.range.end,
+                entry.range.end - entry.range.start
+            )?;
+            match entry.region {
+                Some(memory) => {
+                    // The flag types write their names directly, so they are rendered first to be padded.
+                    let (type_, state, protect) = (memory.type_.to_string(), memory.state.to_string(), memory.protect.to_string());
+                    write!(f, " {type_:<12} {state:<12} {protect:<24}")?
+                }
+                None => write!(f, " {:<12} {:<12} {:<24}", "-", "-", "-")?,
+            }
+
+            match entry.usage {
+                Usage::Image(base) => {
+                    let name = self
+                        .modules
+                        .find(base)
+                        .and_then(|module| module.name())
+                        .unwrap_or("???");
+                    writeln!(f, " Image {name}")?
+                }
+                usage => writeln!(f, " {usage}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Builds an [`AddressSpace`] index and map over the regions and modules of the dump.
+    ///
+    /// Building the index walks every region once and locates the heaps; keep it around
+    /// to attribute many addresses.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// print!("{}", dump.address_space());
+    /// ```
+    pub fn address_space(&self) -> AddressSpace<'_, 'a> {
+        AddressSpace::new(self)
+    }
+}
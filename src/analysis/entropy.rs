@@ -0,0 +1,160 @@
+use crate::memory::MemoryFilter;
+use crate::parse::{Memory, Module, UserDump};
+
+/// Entropy from which executable memory is considered packed or encrypted, in bits per byte.
+///
+/// Compiled code usually stays between 5.5 and 6.5, while compressed or encrypted data
+/// approaches the maximum of 8.
+pub const HIGH_ENTROPY: f64 = 7.2;
+
+/// Minimum number of captured bytes for the entropy of a region to be meaningful.
+const MIN_ENTROPY_LEN: usize = 0x100;
+
+/// Accumulates byte frequencies to compute the Shannon entropy of discontiguous data.
+struct Histogram {
+    /// The number of occurrences of each byte value.
+    counts: [u64; 256],
+
+    /// The number of bytes counted.
+    total: u64,
+}
+
+impl Histogram {
+    /// Creates an empty histogram.
+    fn new() -> Self {
+        Self { counts: [0; 256], total: 0 }
+    }
+
+    /// Counts the bytes of a buffer.
+    fn add(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.counts[*byte as usize] += 1;
+        }
+
+        self.total += bytes.len() as u64;
+    }
+
+    /// Returns the Shannon entropy of the counted bytes, in bits per byte.
+    fn entropy(&self) -> Option<f64> {
+        if self.total == 0 {
+            return None;
+        }
+
+        let total = self.total as f64;
+        let entropy = self
+            .counts
+            .iter()
+            .filter(|count| **count != 0)
+            .map(|count| {
+                let p = *count as f64 / total;
+                -p * p.log2()
+            })
+            .sum();
+
+        Some(entropy)
+    }
+}
+
+impl Memory<'_> {
+    /// Computes the Shannon entropy of the captured bytes of the region.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The entropy, from 0 (constant bytes) to 8 (uniformly random bytes).
+    /// * `None` - If the content of the region was not captured.
+    pub fn entropy(&self) -> Option<f64> {
+        let mut histogram = Histogram::new();
+        histogram.add(self.data);
+        histogram.entropy()
+    }
+}
+
+impl Module<'_> {
+    /// Computes the Shannon entropy of each section of the module, over its captured bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the module belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * The name and entropy of the sections with captured bytes, in section table order.
+    ///   Empty if the headers of the module were not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     for (section, entropy) in module.section_entropy(&dump) {
+    ///         println!("{} {section} {entropy:.2}", module.name().unwrap_or("???"));
+    ///     }
+    /// }
+    /// ```
+    pub fn section_entropy(&self, dump: &UserDump) -> Vec<(String, f64)> {
+        let Ok(headers) = dump.pe_headers(self.start_addr()) else {
+            return Vec::new();
+        };
+
+        headers
+            .sections
+            .iter()
+            .filter_map(|section| {
+                let range = section.range(self.start_addr());
+                let mut histogram = Histogram::new();
+
+                // Counts the captured bytes of every region overlapping the section.
+                for memory in dump
+                    .memorys()
+                    .range(..range.end)
+                    .map(|(_, memory)| memory)
+                    .rev()
+                    .take_while(|memory| memory.range.end > range.start)
+                {
+                    let start = range.start.max(memory.range.start) - memory.range.start;
+                    let end = range.end.min(memory.range.end) - memory.range.start;
+                    let data = memory.data;
+                    histogram.add(&data[(start as usize).min(data.len())..(end as usize).min(data.len())]);
+                }
+
+                Some((section.name.clone(), histogram.entropy()?))
+            })
+            .collect()
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Finds the executable regions whose content looks packed or encrypted.
+    ///
+    /// Regions with fewer than 256 captured bytes are ignored, since their entropy is not significant.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The entropy from which a region is reported, in bits per byte
+    ///   (e.g., [`HIGH_ENTROPY`]).
+    ///
+    /// # Returns
+    ///
+    /// * The regions and their entropy, ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, analysis::entropy::HIGH_ENTROPY};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (memory, entropy) in dump.high_entropy_regions(HIGH_ENTROPY) {
+    ///     println!("{:#x} {entropy:.2} {}", memory.start_addr(), memory.protect);
+    /// }
+    /// ```
+    pub fn high_entropy_regions(&self, threshold: f64) -> Vec<(&Memory<'a>, f64)> {
+        self.memorys()
+            .executable()
+            .filter(|memory| memory.data.len() >= MIN_ENTROPY_LEN)
+            .filter_map(|memory| Some((memory, memory.entropy()?)))
+            .filter(|(_, entropy)| *entropy >= threshold)
+            .collect()
+    }
+}
@@ -0,0 +1,241 @@
+use core::fmt;
+use std::collections::BTreeMap;
+use crate::parse::{Module, UserDump};
+use crate::pe::Export;
+use super::decode_jump;
+
+/// Identifies where a hook was installed.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum HookKind {
+    /// An import address table slot was redirected.
+    Iat,
+
+    /// An export address table entry points outside its module.
+    Eat,
+
+    /// An exported function starts with a jump leaving its module.
+    Inline,
+}
+
+impl fmt::Display for HookKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HookKind::Iat => write!(f, "IAT hook"),
+            HookKind::Eat => write!(f, "EAT hook"),
+            HookKind::Inline => write!(f, "inline hook"),
+        }
+    }
+}
+
+/// Represents a probable hook, see [`UserDump::hooks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Hook {
+    /// Where the hook was installed.
+    pub kind: HookKind,
+
+    /// The base address of the module whose import table, export table or code is hooked.
+    pub module_base: u64,
+
+    /// The hooked function (e.g., `KERNEL32.dll!CreateFileW` for imports, `CreateFileW` for exports).
+    pub function: String,
+
+    /// The address the hook was found at: the import address table slot, or the exported function.
+    pub address: u64,
+
+    /// The address the hook leads to.
+    pub target: u64,
+
+    /// The base address of the module containing the target, or `None` if the target is unbacked.
+    pub target_module: Option<u64>,
+}
+
+impl Hook {
+    /// Returns true if the hook leads to memory that does not belong to any module.
+    pub fn is_unbacked(&self) -> bool {
+        self.target_module.is_none()
+    }
+}
+
+impl fmt::Display for Hook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {} at {:#x} -> {:#x}", self.kind, self.function, self.address, self.target)?;
+        if self.is_unbacked() {
+            write!(f, " (unbacked)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Names an export after its name, or its ordinal for exports without a name.
+fn export_name(export: &Export) -> String {
+    match &export.name {
+        Some(name) => name.clone(),
+        None => format!("#{}", export.ordinal),
+    }
+}
+
+impl UserDump<'_> {
+    /// Finds the import table entries, export table entries and exported functions that were hooked.
+    ///
+    /// The checks are performed on the tables and code of every module:
+    /// - An import is hooked when it resolves to unbacked memory, or to a module other than
+    ///   the DLL it is imported from. Imports from API sets or from DLLs that are not loaded
+    ///   are only checked for unbacked targets, and forwarded exports are accepted.
+    /// - An export is hooked when its address lies outside its module and it is not a forwarder.
+    /// - An exported function is hooked when it starts with a jump leaving its module. Jumps
+    ///   through the import address table of the module itself are ordinary import thunks.
+    ///
+    /// # Returns
+    ///
+    /// * The probable hooks, grouped by module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for hook in dump.hooks() {
+    ///     println!("{hook} ({})", dump.symbolize(hook.target).unwrap_or_default());
+    /// }
+    /// ```
+    pub fn hooks(&self) -> Vec<Hook> {
+        let mut exports = BTreeMap::new();
+        let mut hooks = Vec::new();
+        for module in self.modules().values() {
+            self.import_hooks(module, &mut exports, &mut hooks);
+            self.export_hooks(module, &mut exports, &mut hooks);
+        }
+
+        hooks
+    }
+
+    /// Checks the import address table of a module.
+    fn import_hooks(&self, module: &Module, exports: &mut BTreeMap<u64, Vec<Export>>, hooks: &mut Vec<Hook>) {
+        let Ok(imports) = self.imports(module.start_addr()) else {
+            return;
+        };
+
+        for import in imports {
+            if import.address == 0 {
+                continue;
+            }
+
+            let target_module = self.module_at(import.address);
+            let expected = self.modules().values().find(|module| {
+                module
+                    .name()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(&import.module))
+            });
+
+            let hooked = match (target_module, expected) {
+                // Slots not resolved yet hold the lookup entry, which is not mapped memory.
+                (None, _) => self
+                    .memory_at(import.address)
+                    .is_some_and(|memory| memory.state.is_committed() || !memory.data.is_empty()),
+                (Some(_), None) => false,
+                (Some(target), Some(expected)) if target.start_addr() == expected.start_addr() => false,
+                (Some(_), Some(expected)) => {
+                    // A forwarded export legitimately resolves into another module, and an
+                    // export missing from the dump cannot be judged.
+                    let explained = exports
+                        .entry(expected.start_addr())
+                        .or_insert_with(|| {
+                            self.exports(expected.start_addr())
+                                .unwrap_or_default()
+                        })
+                        .iter()
+                        .find(|export| match (&import.name, import.ordinal) {
+                            (Some(name), _) => export.name.as_ref() == Some(name),
+                            (None, Some(ordinal)) => export.ordinal == ordinal as u32,
+                            (None, None) => false,
+                        })
+                        .is_none_or(|export| export.forwarder.is_some());
+                    !explained
+                }
+            };
+
+            if hooked {
+                let function = match (&import.name, import.ordinal) {
+                    (Some(name), _) => format!("{}!{name}", import.module),
+                    (None, Some(ordinal)) => format!("{}!#{ordinal}", import.module),
+                    (None, None) => format!("{}!<unknown>", import.module),
+                };
+
+                hooks.push(Hook {
+                    kind: HookKind::Iat,
+                    module_base: module.start_addr(),
+                    function,
+                    address: import.slot,
+                    target: import.address,
+                    target_module: target_module.map(|module| module.start_addr()),
+                });
+            }
+        }
+    }
+
+    /// Checks the export address table and the prologue of the exported functions of a module.
+    fn export_hooks(&self, module: &Module, exports: &mut BTreeMap<u64, Vec<Export>>, hooks: &mut Vec<Hook>) {
+        let base = module.start_addr();
+        let Ok(headers) = self.pe_headers(base) else {
+            return;
+        };
+
+        let exports = exports
+            .entry(base)
+            .or_insert_with(|| self.exports(base).unwrap_or_default());
+
+        for export in exports
+            .iter()
+            .filter(|export| export.forwarder.is_none())
+        {
+            if !module.range.contains(&export.address) {
+                hooks.push(Hook {
+                    kind: HookKind::Eat,
+                    module_base: base,
+                    function: export_name(export),
+                    address: export.address,
+                    target: export.address,
+                    target_module: self
+                        .module_at(export.address)
+                        .map(|module| module.start_addr()),
+                });
+
+                continue;
+            }
+
+            // Exported variables live in data sections and are not decoded.
+            let code = headers
+                .section_at((export.address - base) as u32)
+                .is_some_and(|section| section.is_executable());
+            if !code {
+                continue;
+            }
+
+            let Some(jump) = decode_jump(self, export.address) else {
+                continue;
+            };
+
+            let thunk = jump
+                .slot
+                .is_some_and(|slot| module.range.contains(&slot));
+            if module.range.contains(&jump.target) || thunk {
+                continue;
+            }
+
+            hooks.push(Hook {
+                kind: HookKind::Inline,
+                module_base: base,
+                function: export_name(export),
+                address: export.address,
+                target: jump.target,
+                target_module: self
+                    .module_at(jump.target)
+                    .map(|module| module.start_addr()),
+            });
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use core::fmt;
+use crate::data::IMAGE_DOS_SIGNATURE;
+use crate::memory::MemoryProtection;
+use crate::parse::{Memory, UserDump};
+
+/// Number of captured bytes kept as the preview of an injected region.
+const PREVIEW_LEN: usize = 64;
+
+/// Describes why a memory region looks injected.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum InjectionKind {
+    /// Private memory that can be executed but not written, typically a payload
+    /// whose protection was flipped after it was copied.
+    PrivateExecutable,
+
+    /// Private memory that can be both written and executed, typically shellcode
+    /// or an unpacking stub.
+    PrivateWritableExecutable,
+
+    /// Memory starting with an `MZ` header that is absent from the module list,
+    /// typically a reflectively loaded or manually mapped image.
+    UnlistedImage,
+}
+
+impl fmt::Display for InjectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InjectionKind::PrivateExecutable => write!(f, "executable private memory"),
+            InjectionKind::PrivateWritableExecutable => write!(f, "writable and executable private memory"),
+            InjectionKind::UnlistedImage => write!(f, "PE image missing from the module list"),
+        }
+    }
+}
+
+/// Represents a memory region that looks injected, see [`UserDump::injected_regions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InjectedRegion<'a> {
+    /// Why the region looks injected.
+    pub kind: InjectionKind,
+
+    /// The start address of the region.
+    pub address: u64,
+
+    /// The size of the region, in bytes.
+    pub size: u64,
+
+    /// The base address of the allocation containing the region.
+    pub allocation_base: u64,
+
+    /// The protection of the region.
+    pub protect: MemoryProtection,
+
+    /// The first captured bytes of the region, empty if its content was not captured.
+    pub preview: &'a [u8],
+}
+
+impl fmt::Display for InjectedRegion<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:#x} ({:#x} bytes, {})", self.kind, self.address, self.size, self.protect)
+    }
+}
+
+impl<'a> InjectedRegion<'a> {
+    /// Creates a new [`InjectedRegion`] from the region it describes.
+    fn new(kind: InjectionKind, memory: &Memory<'a>) -> Self {
+        Self {
+            kind,
+            address: memory.range.start,
+            size: memory.len(),
+            allocation_base: memory.allocation_base,
+            protect: memory.protect,
+            preview: &memory.data[..memory.data.len().min(PREVIEW_LEN)],
+        }
+    }
+}
+
+/// Returns true if the captured content of a region starts with an `MZ` header.
+fn starts_with_mz(memory: &Memory) -> bool {
+    memory
+        .data
+        .first_chunk::<2>()
+        .is_some_and(|magic| u16::from_le_bytes(*magic) == IMAGE_DOS_SIGNATURE)
+}
+
+impl<'a> UserDump<'a> {
+    /// Finds the memory regions holding code that does not belong to any loaded module.
+    ///
+    /// Two kinds of regions are reported:
+    /// - Private regions whose protection allows execution (`RX` or `RWX`).
+    /// - Regions whose captured content starts with an `MZ` header while no module
+    ///   is listed at their address, regardless of their type or protection.
+    ///
+    /// A region matching both is reported once, as an [`InjectionKind::UnlistedImage`].
+    ///
+    /// # Returns
+    ///
+    /// * The suspicious regions, ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for region in dump.injected_regions() {
+    ///     println!("{region}");
+    ///     println!("  {:02x?}", region.preview);
+    /// }
+    /// ```
+    pub fn injected_regions(&self) -> Vec<InjectedRegion<'a>> {
+        self.memorys()
+            .values()
+            .filter(|memory| {
+                self.module_at(memory.range.start)
+                    .is_none()
+            })
+            .filter_map(|memory| {
+                let kind = if starts_with_mz(memory) {
+                    InjectionKind::UnlistedImage
+                } else if !memory.type_.is_private() || !memory.protect.is_executable() {
+                    return None;
+                } else if memory.protect.is_writable() {
+                    InjectionKind::PrivateWritableExecutable
+                } else {
+                    InjectionKind::PrivateExecutable
+                };
+
+                Some(InjectedRegion::new(kind, memory))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,575 @@
+use std::{collections::BTreeMap, fmt};
+use crate::options::UnmappedReadPolicy;
+use crate::parse::{Memory, Module, Thread, ThreadContext, UserDump};
+
+/// The `passes` module runs pluggable analysis passes that report findings with severities.
+pub mod passes;
+
+/// The `injections` module finds executable memory and PE images that do not belong to any loaded module.
+pub mod injections;
+
+/// The `tampering` module compares the in-memory image of each module against its headers and the loader.
+pub mod tampering;
+
+/// The `hooks` module finds hooked import and export table entries and inline hooks on exported functions.
+pub mod hooks;
+
+/// The `entropy` module measures the entropy of memory regions and module sections to spot packed payloads.
+pub mod entropy;
+
+/// Modules hosting the thread start thunks, skipped when inferring a start address from the stack.
+const THREAD_START_MODULES: [&str; 3] = ["ntdll.dll", "kernel32.dll", "kernelbase.dll"];
+
+/// `LoadLibrary` exports abused as thread start routines to load a DLL remotely.
+const LOAD_LIBRARY_EXPORTS: [&str; 4] = ["LoadLibraryA", "LoadLibraryW", "LoadLibraryExA", "LoadLibraryExW"];
+
+/// Ordinary relative thread priorities, from `THREAD_PRIORITY_LOWEST` to `THREAD_PRIORITY_HIGHEST`.
+const NORMAL_PRIORITIES: std::ops::RangeInclusive<i32> = -2..=2;
+
+/// Directories holding operating system binaries, relative to the root of the system volume.
+const SYSTEM_DIRECTORIES: [&str; 4] = [
+    "\\windows\\system32\\",
+    "\\windows\\syswow64\\",
+    "\\windows\\sysnative\\",
+    "\\windows\\winsxs\\",
+];
+
+/// Directories holding installed applications, relative to the root of the system volume.
+const PROGRAM_DIRECTORIES: [&str; 2] = ["\\program files\\", "\\program files (x86)\\"];
+
+/// Directories writable by unprivileged users, matched anywhere in the path.
+const USER_WRITABLE_DIRECTORIES: [&str; 7] = [
+    "\\appdata\\",
+    "\\temp\\",
+    "\\tmp\\",
+    "\\downloads\\",
+    "\\desktop\\",
+    "\\users\\public\\",
+    "\\programdata\\",
+];
+
+/// Classifies the directory a module was loaded from.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModuleOrigin {
+    /// An operating system directory (`System32`, `SysWOW64`, `WinSxS`).
+    System,
+
+    /// An application installed under `Program Files`.
+    ProgramFiles,
+
+    /// A directory writable by unprivileged users (`AppData`, `Temp`, `Downloads`, ...).
+    UserWritable,
+
+    /// A network share (UNC path).
+    Network,
+
+    /// Any other location.
+    Unknown,
+}
+
+impl fmt::Display for ModuleOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModuleOrigin::System => write!(f, "system"),
+            ModuleOrigin::ProgramFiles => write!(f, "program files"),
+            ModuleOrigin::UserWritable => write!(f, "user-writable"),
+            ModuleOrigin::Network => write!(f, "network share"),
+            ModuleOrigin::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Module<'_> {
+    /// Classifies the directory the module was loaded from.
+    ///
+    /// DOS paths (`C:\...`), NT device paths (`\Device\HarddiskVolume3\...`) and their
+    /// `\\?\` and `\??\` prefixed forms are recognized, case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, analysis::ModuleOrigin};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     if module.origin() == ModuleOrigin::UserWritable {
+    ///         println!("{} loaded from a user-writable directory", module.path.display());
+    ///     }
+    /// }
+    /// ```
+    pub fn origin(&self) -> ModuleOrigin {
+        let path = self
+            .path
+            .to_string_lossy()
+            .to_lowercase()
+            .replace('/', "\\");
+        let path = ["\\\\?\\", "\\??\\"]
+            .iter()
+            .find_map(|prefix| path.strip_prefix(prefix))
+            .unwrap_or(&path);
+
+        // UNC paths, in DOS, extended or NT form.
+        if let Some(share) = path
+            .strip_prefix("unc\\")
+            .or_else(|| path.strip_prefix("\\device\\mup\\"))
+        {
+            return if share.is_empty() {
+                ModuleOrigin::Unknown
+            } else {
+                ModuleOrigin::Network
+            };
+        }
+
+        if path.starts_with("\\\\") {
+            return ModuleOrigin::Network;
+        }
+
+        // Strips the volume, keeping the path rooted at its top-level directory.
+        let rooted = if path.as_bytes().get(1) == Some(&b':') {
+            &path[2..]
+        } else if let Some(rest) = path.strip_prefix("\\device\\") {
+            rest.find('\\')
+                .map_or("", |index| &rest[index..])
+        } else if let Some(rest) = path.strip_prefix("\\systemroot\\") {
+            return if SYSTEM_DIRECTORIES
+                .iter()
+                .any(|directory| format!("\\windows\\{rest}").starts_with(directory))
+            {
+                ModuleOrigin::System
+            } else {
+                ModuleOrigin::Unknown
+            };
+        } else {
+            return ModuleOrigin::Unknown;
+        };
+
+        if SYSTEM_DIRECTORIES
+            .iter()
+            .any(|directory| rooted.starts_with(directory))
+        {
+            ModuleOrigin::System
+        } else if PROGRAM_DIRECTORIES
+            .iter()
+            .any(|directory| rooted.starts_with(directory))
+        {
+            ModuleOrigin::ProgramFiles
+        } else if USER_WRITABLE_DIRECTORIES
+            .iter()
+            .any(|directory| rooted.contains(directory))
+        {
+            ModuleOrigin::UserWritable
+        } else {
+            ModuleOrigin::Unknown
+        }
+    }
+}
+
+/// Describes an unusual scheduling state of a thread.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum SchedulingAnomaly {
+    /// The thread was suspended when the dump was written.
+    Suspended {
+        /// The suspend count of the thread.
+        count: u32,
+    },
+
+    /// The thread runs at a priority outside the normal range (e.g., time-critical or idle).
+    AbnormalPriority {
+        /// The priority of the thread.
+        priority: i32,
+    },
+
+    /// The thread runs in a different priority class than most threads of the process.
+    PriorityClassMismatch {
+        /// The priority class of the thread.
+        priority_class: u32,
+
+        /// The priority class shared by most threads of the process.
+        process_class: u32,
+    },
+}
+
+impl fmt::Display for SchedulingAnomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulingAnomaly::Suspended { count } => write!(f, "suspended (count {count})"),
+            SchedulingAnomaly::AbnormalPriority { priority } => write!(f, "abnormal priority {priority}"),
+            SchedulingAnomaly::PriorityClassMismatch {
+                priority_class,
+                process_class,
+            } => {
+                write!(f, "priority class {priority_class:#x} differs from process class {process_class:#x}")
+            }
+        }
+    }
+}
+
+/// Identifies where the start address of a thread was taken from.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StartSource {
+    /// The start address recorded in the `ThreadInfoListStream`.
+    ThreadInfo,
+
+    /// The first frame of the stack that does not belong to the thread start thunks.
+    ///
+    /// This is an address inside the start routine, not necessarily its first instruction.
+    StackEvidence,
+}
+
+/// Describes why the start of a thread looks suspicious.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum StartIndicator {
+    /// The thread starts in executable memory that is not backed by any loaded module.
+    UnbackedExecutable,
+
+    /// The thread starts outside any loaded module and no memory region describes the address.
+    UnknownMemory,
+
+    /// The thread starts directly at a `LoadLibrary` export, the classic remote DLL injection.
+    ///
+    /// Holds the library path argument when it is still present in the thread context.
+    LoadLibraryStart {
+        /// The address of the library path passed to `LoadLibrary`, when known.
+        argument: Option<u64>,
+    },
+
+    /// The thread has not run yet and `RtlUserThreadStart` is about to jump into private memory.
+    ThunkToPrivate {
+        /// The routine `RtlUserThreadStart` will call.
+        target: u64,
+    },
+}
+
+impl fmt::Display for StartIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartIndicator::UnbackedExecutable => write!(f, "starts in unbacked executable memory"),
+            StartIndicator::UnknownMemory => write!(f, "starts outside any known memory"),
+            StartIndicator::LoadLibraryStart { argument: Some(argument) } => write!(f, "starts at LoadLibrary({argument:#x})"),
+            StartIndicator::LoadLibraryStart { argument: None } => write!(f, "starts at LoadLibrary"),
+            StartIndicator::ThunkToPrivate { target } => write!(f, "RtlUserThreadStart targets private memory at {target:#x}"),
+        }
+    }
+}
+
+/// Represents the resolved start of a thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadStart {
+    /// The ID of the thread.
+    pub thread_id: u32,
+
+    /// The start address of the thread.
+    pub address: u64,
+
+    /// Where the start address was taken from.
+    pub source: StartSource,
+
+    /// The base address of the module containing the start address, if any.
+    pub module_base: Option<u64>,
+
+    /// The reasons the start of the thread looks suspicious. Empty for ordinary threads.
+    pub indicators: Vec<StartIndicator>,
+}
+
+impl ThreadStart {
+    /// Returns the module containing the start address.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the thread was resolved from.
+    pub fn module<'d, 'a>(&self, dump: &'d UserDump<'a>) -> Option<&'d Module<'a>> {
+        self.module_base
+            .and_then(|base| dump.modules().get(&base))
+    }
+
+    /// Returns the offset of the start address from the base of its module.
+    pub fn offset(&self) -> Option<u64> {
+        self.module_base
+            .map(|base| self.address - base)
+    }
+
+    /// Returns true if at least one suspicious indicator was found.
+    pub fn is_suspicious(&self) -> bool {
+        !self.indicators.is_empty()
+    }
+}
+
+impl Thread {
+    /// Names the start address of the thread after its module and closest export
+    /// (e.g., `kernel32!BaseThreadInitThunk`), see [`UserDump::symbolize`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the thread belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The name of the start address.
+    /// * `None` - If no start address was recorded, or it lies outside every module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     println!("{tid}: started in {}", thread.start_symbol(&dump).unwrap_or_default());
+    /// }
+    /// ```
+    pub fn start_symbol(&self, dump: &UserDump) -> Option<String> {
+        dump.symbolize(self.start_address()?)
+    }
+}
+
+/// Returns the instruction pointer and the start routine and parameter registers of a thread.
+///
+/// A new thread enters user mode at `RtlUserThreadStart`, with the start routine
+/// and its parameter held in `rcx`/`rdx` on x64 and `eax`/`ebx` on x86.
+fn start_arguments(thread: &Thread) -> Option<(u64, u64, u64)> {
+    match thread.context() {
+        ThreadContext::X64(context) => Some((context.Rip, context.Rcx, context.Rdx)),
+        ThreadContext::X86(context) => Some((context.Eip.into(), context.Eax.into(), context.Ebx.into())),
+        ThreadContext::Unsupported(_) => None,
+    }
+}
+
+/// An unconditional jump decoded by [`decode_jump`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Jump {
+    /// The destination of the jump.
+    pub target: u64,
+
+    /// The address of the pointer holding the destination, for indirect jumps.
+    pub slot: Option<u64>,
+}
+
+/// Decodes the unconditional jump at an address.
+///
+/// Recognizes the trampolines used by hooks and patched entry points: `jmp rel32`,
+/// `jmp rel8`, `jmp [mem]`, `push imm32; ret` and `mov rax/r11, imm64; jmp rax/r11`.
+pub(crate) fn decode_jump(dump: &UserDump, address: u64) -> Option<Jump> {
+    let code = dump
+        .read_range_with(address, 13, UnmappedReadPolicy::Partial)
+        .ok()?
+        .data;
+
+    let direct = |target: u64| Some(Jump { target, slot: None });
+    let imm64 = |bytes: &[u8]| {
+        bytes
+            .first_chunk::<8>()
+            .and_then(|imm| direct(u64::from_le_bytes(*imm)))
+    };
+    match *code.as_slice() {
+        [0xE9, a, b, c, d, ..] => direct(
+            address
+                .wrapping_add(5)
+                .wrapping_add(i32::from_le_bytes([a, b, c, d]) as u64),
+        ),
+        [0xEB, rel8, ..] => direct(
+            address
+                .wrapping_add(2)
+                .wrapping_add(rel8 as i8 as u64),
+        ),
+        [0xFF, 0x25, a, b, c, d, ..] => {
+            let slot = match dump.pointer_size() {
+                8 => address
+                    .wrapping_add(6)
+                    .wrapping_add(i32::from_le_bytes([a, b, c, d]) as u64),
+                _ => u32::from_le_bytes([a, b, c, d]).into(),
+            };
+
+            Some(Jump {
+                target: dump.read_ptr(slot).ok()?,
+                slot: Some(slot),
+            })
+        }
+        [0x68, a, b, c, d, 0xC3, ..] => direct(u32::from_le_bytes([a, b, c, d]).into()),
+        [0x48, 0xB8, ref rest @ ..] if rest.get(8..10) == Some(&[0xFF, 0xE0]) => imm64(rest),
+        [0x49, 0xBB, ref rest @ ..] if rest.get(8..11) == Some(&[0x41, 0xFF, 0xE3]) => imm64(rest),
+        _ => None,
+    }
+}
+
+/// Returns true if an address lies outside any module, in memory that is not part of an image.
+fn is_private(memory: Option<&Memory>, module: Option<&Module>) -> bool {
+    module.is_none() && memory.is_none_or(|memory| !memory.type_.is_image())
+}
+
+impl UserDump<'_> {
+    /// Resolves the start address of every thread and flags suspicious starts.
+    ///
+    /// The start address comes from the `ThreadInfoListStream` when available, and
+    /// otherwise from the outermost stack frame that is not part of the thread start
+    /// thunks in `ntdll.dll` and `kernel32.dll`. A thread is flagged when it starts in
+    /// executable memory not backed by a module, directly at a `LoadLibrary` export,
+    /// or when `RtlUserThreadStart` is about to jump into private memory; all common
+    /// signs of injected threads.
+    ///
+    /// # Returns
+    ///
+    /// * A `Vec<ThreadStart>` ordered by thread ID. Threads whose start cannot be
+    ///   determined are omitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for start in dump.thread_starts().iter().filter(|start| start.is_suspicious()) {
+    ///     for indicator in &start.indicators {
+    ///         println!("Thread {}: {indicator}", start.thread_id);
+    ///     }
+    /// }
+    /// ```
+    pub fn thread_starts(&self) -> Vec<ThreadStart> {
+        let load_library = self.thunk_exports(&["kernel32.dll", "kernelbase.dll"], &LOAD_LIBRARY_EXPORTS);
+        let user_thread_start = self.thunk_exports(&["ntdll.dll"], &["RtlUserThreadStart"]);
+
+        self.threads()
+            .values()
+            .filter_map(|thread| {
+                let (address, source) = match thread.start_address() {
+                    Some(address) => (address, StartSource::ThreadInfo),
+                    None => (self.stack_start(thread)?, StartSource::StackEvidence),
+                };
+
+                let module = self.module_at(address);
+                let memory = self.memory_at(address);
+                let mut indicators = Vec::new();
+
+                if module.is_none() {
+                    match memory {
+                        Some(memory) if memory.protect.is_executable() => indicators.push(StartIndicator::UnbackedExecutable),
+                        Some(_) => {}
+                        None => indicators.push(StartIndicator::UnknownMemory),
+                    }
+                }
+
+                let arguments = start_arguments(thread);
+                let not_started = arguments.filter(|(ip, _, _)| user_thread_start.contains(ip));
+
+                if load_library.contains(&address) {
+                    let argument = not_started.map(|(_, _, parameter)| parameter);
+                    indicators.push(StartIndicator::LoadLibraryStart { argument });
+                }
+
+                if let Some((_, target, _)) = not_started
+                    && is_private(self.memory_at(target), self.module_at(target))
+                {
+                    indicators.push(StartIndicator::ThunkToPrivate { target });
+                }
+
+                Some(ThreadStart {
+                    thread_id: thread.thread_id,
+                    address,
+                    source,
+                    module_base: module.map(|module| module.start_addr()),
+                    indicators,
+                })
+            })
+            .collect()
+    }
+
+    /// Flags threads whose scheduling state is unusual.
+    ///
+    /// A thread is flagged when it is suspended, when its relative priority is outside
+    /// `-2..=2`, or when its priority class differs from the one shared by most threads,
+    /// which is taken as the process default. Frozen or starved threads are a frequent
+    /// cause of the hangs that lead to a dump being taken.
+    ///
+    /// Note that dumps written by some tools record the suspension applied by the
+    /// tool itself, in which case every thread is reported as suspended.
+    ///
+    /// # Returns
+    ///
+    /// * A `Vec<(u32, SchedulingAnomaly)>` with the thread ID and the anomaly, ordered by thread ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (thread_id, anomaly) in dump.scheduling_anomalies() {
+    ///     println!("Thread {thread_id}: {anomaly}");
+    /// }
+    /// ```
+    pub fn scheduling_anomalies(&self) -> Vec<(u32, SchedulingAnomaly)> {
+        // The most common priority class is taken as the process default, ties going to the lowest value.
+        let mut classes = BTreeMap::new();
+        for thread in self.threads().values() {
+            *classes
+                .entry(thread.priority_class)
+                .or_insert(0usize) += 1;
+        }
+
+        let process_class = classes
+            .iter()
+            .max_by_key(|(class, count)| (**count, std::cmp::Reverse(**class)))
+            .map(|(class, _)| *class);
+
+        let mut anomalies = Vec::new();
+        for thread in self.threads().values() {
+            if thread.suspend_count != 0 {
+                anomalies.push((thread.thread_id, SchedulingAnomaly::Suspended { count: thread.suspend_count }));
+            }
+
+            let priority = thread.priority as i32;
+            if !NORMAL_PRIORITIES.contains(&priority) {
+                anomalies.push((thread.thread_id, SchedulingAnomaly::AbnormalPriority { priority }));
+            }
+
+            if let Some(process_class) = process_class
+                && thread.priority_class != process_class
+            {
+                anomalies.push((
+                    thread.thread_id,
+                    SchedulingAnomaly::PriorityClassMismatch {
+                        priority_class: thread.priority_class,
+                        process_class,
+                    },
+                ));
+            }
+        }
+
+        anomalies
+    }
+
+    /// Infers the start routine of a thread from its outermost stack frames.
+    fn stack_start(&self, thread: &Thread) -> Option<u64> {
+        let frames = self.stack_trace(thread).ok()?;
+        frames
+            .iter()
+            .rev()
+            .find(|frame| {
+                frame
+                    .module(self)
+                    .and_then(|module| module.name())
+                    .is_none_or(|name| {
+                        !THREAD_START_MODULES
+                            .iter()
+                            .any(|thunk| name.eq_ignore_ascii_case(thunk))
+                    })
+            })
+            .map(|frame| frame.instruction_pointer)
+    }
+
+    /// Returns the addresses of the given exports in the given modules.
+    fn thunk_exports(&self, modules: &[&str], exports: &[&str]) -> Vec<u64> {
+        modules
+            .iter()
+            .filter_map(|name| self.module_by_name(name))
+            .filter_map(|module| self.exports(module.start_addr()).ok())
+            .flatten()
+            .filter(|export| {
+                export
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| exports.contains(&name))
+            })
+            .map(|export| export.address)
+            .collect()
+    }
+}
@@ -0,0 +1,500 @@
+use std::{collections::BTreeMap, fmt};
+use crate::data::*;
+use crate::parse::UserDump;
+use crate::peb::ModuleListDiff;
+use super::injections::InjectionKind;
+use super::tampering::TamperingIndicator;
+
+/// `STATUS_POSSIBLE_DEADLOCK`, raised when a wait on a critical section times out.
+const STATUS_POSSIBLE_DEADLOCK: u32 = 0xC000_0194;
+
+/// System calls a blocked thread is usually sitting in.
+const WAIT_SYSCALLS: [&str; 6] = [
+    "NtWaitForSingleObject",
+    "NtWaitForMultipleObjects",
+    "NtWaitForAlertByThreadId",
+    "NtWaitForKeyedEvent",
+    "NtDelayExecution",
+    "NtRemoveIoCompletion",
+];
+
+/// Size of a system call stub in `ntdll.dll`; an instruction pointer within it is blocked in the call.
+const SYSCALL_STUB_SIZE: u64 = 0x20;
+
+/// The severity of a [`Finding`], from the least to the most severe.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Informational, not a problem by itself.
+    Info,
+
+    /// Worth a look, rarely a problem.
+    Low,
+
+    /// Likely a problem or a sign of tampering.
+    Medium,
+
+    /// Almost certainly a problem or a sign of tampering.
+    High,
+
+    /// Explains the crash or hang on its own.
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Low => write!(f, "low"),
+            Severity::Medium => write!(f, "medium"),
+            Severity::High => write!(f, "high"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// Represents something an [`Analysis`] pass noticed in a dump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The name of the pass that produced the finding.
+    pub pass: &'static str,
+
+    /// How severe the finding is.
+    pub severity: Severity,
+
+    /// A one-line description of the finding.
+    pub message: String,
+
+    /// The thread the finding is about, if any.
+    pub thread_id: Option<u32>,
+
+    /// The address the finding is about, if any.
+    pub address: Option<u64>,
+
+    /// The registered label covering the address, if any (see [`UserDump::register_labels`]).
+    pub label: Option<String>,
+}
+
+impl Finding {
+    /// Creates a new [`Finding`] that is not tied to a thread or an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `pass` - The name of the pass producing the finding.
+    /// * `severity` - How severe the finding is.
+    /// * `message` - A one-line description of the finding.
+    pub fn new(pass: &'static str, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            pass,
+            severity,
+            message: message.into(),
+            thread_id: None,
+            address: None,
+            label: None,
+        }
+    }
+
+    /// Ties the finding to a thread.
+    pub fn thread(mut self, thread_id: u32) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+
+    /// Ties the finding to an address.
+    pub fn address(mut self, address: u64) -> Self {
+        self.address = Some(address);
+        self
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.severity, self.pass, self.message)?;
+        if let Some(thread_id) = self.thread_id {
+            write!(f, " (thread {thread_id})")?;
+        }
+
+        if let Some(address) = self.address {
+            write!(f, " (at {address:#x})")?;
+        }
+
+        if let Some(label) = &self.label {
+            write!(f, " [{label}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A pass that inspects a dump and reports [`Finding`]s.
+///
+/// Implement it to plug custom checks into an [`AnalysisRegistry`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, analysis::passes::{Analysis, AnalysisRegistry, Finding, Severity}};
+///
+/// struct NoThreads;
+///
+/// impl Analysis for NoThreads {
+///     fn name(&self) -> &'static str {
+///         "no-threads"
+///     }
+///
+///     fn run(&self, dump: &UserDump) -> Vec<Finding> {
+///         match dump.threads().is_empty() {
+///             true => vec![Finding::new(self.name(), Severity::Medium, "the dump holds no thread")],
+///             false => Vec::new(),
+///         }
+///     }
+/// }
+///
+/// let registry = AnalysisRegistry::builtin().register(NoThreads);
+/// ```
+pub trait Analysis: Send + Sync {
+    /// Returns the stable name of the pass, used in [`Finding::pass`].
+    fn name(&self) -> &'static str;
+
+    /// Inspects the dump and returns the findings, in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump to inspect.
+    fn run(&self, dump: &UserDump) -> Vec<Finding>;
+}
+
+/// An ordered set of [`Analysis`] passes run together over a dump.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, analysis::passes::AnalysisRegistry};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// for finding in dump.analyze(&AnalysisRegistry::builtin()) {
+///     println!("{finding}");
+/// }
+/// ```
+#[derive(Default)]
+pub struct AnalysisRegistry {
+    /// The registered passes, in registration order.
+    passes: Vec<Box<dyn Analysis>>,
+}
+
+impl AnalysisRegistry {
+    /// Creates a new [`AnalysisRegistry`] with no pass.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`AnalysisRegistry`] holding every built-in pass with its default settings.
+    pub fn builtin() -> Self {
+        Self::new()
+            .register(InjectionDetection)
+            .register(HandleLeaks::default())
+            .register(DeadlockDetection)
+            .register(MitigationSummary)
+            .register(TamperingDetection)
+            .register(HookDetection)
+    }
+
+    /// Adds a pass to the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `pass` - The pass to add. It runs after the passes already registered.
+    pub fn register(mut self, pass: impl Analysis + 'static) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Returns the names of the registered passes, in registration order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.passes
+            .iter()
+            .map(|pass| pass.name())
+            .collect()
+    }
+
+    /// Runs every pass over a dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump to inspect.
+    ///
+    /// # Returns
+    ///
+    /// * The findings of every pass, the most severe first. Findings of equal severity
+    ///   keep the registration order of their pass.
+    pub fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut findings = self
+            .passes
+            .iter()
+            .flat_map(|pass| pass.run(dump))
+            .collect::<Vec<Finding>>();
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity));
+        findings
+    }
+}
+
+impl fmt::Debug for AnalysisRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnalysisRegistry")
+            .field("passes", &self.names())
+            .finish()
+    }
+}
+
+/// Flags injected threads, executable memory that does not belong to any module and
+/// modules unlinked from the loader list.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct InjectionDetection;
+
+impl Analysis for InjectionDetection {
+    fn name(&self) -> &'static str {
+        "injection"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for start in dump.thread_starts() {
+            for indicator in &start.indicators {
+                findings.push(
+                    Finding::new(self.name(), Severity::High, indicator.to_string())
+                        .thread(start.thread_id)
+                        .address(start.address),
+                );
+            }
+        }
+
+        for region in dump.injected_regions() {
+            let severity = match region.kind {
+                InjectionKind::PrivateExecutable => Severity::Medium,
+                InjectionKind::PrivateWritableExecutable | InjectionKind::UnlistedImage => Severity::High,
+            };
+
+            let message = format!("{} of {:#x} bytes", region.kind, region.size);
+            findings.push(Finding::new(self.name(), severity, message).address(region.address));
+        }
+
+        for diff in dump.diff_module_lists() {
+            if let ModuleListDiff::NotInLoader { base, .. } = diff {
+                findings.push(Finding::new(self.name(), Severity::High, diff.to_string()).address(base));
+            }
+        }
+
+        findings
+    }
+}
+
+/// Flags object types holding an unusually large number of handles.
+#[derive(Copy, Debug, Clone)]
+pub struct HandleLeaks {
+    /// The number of handles of a single type from which a leak is reported.
+    pub threshold: usize,
+}
+
+impl Default for HandleLeaks {
+    fn default() -> Self {
+        Self { threshold: 5000 }
+    }
+}
+
+impl Analysis for HandleLeaks {
+    fn name(&self) -> &'static str {
+        "handle-leaks"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut counts = BTreeMap::new();
+        for handle in dump.handles().values() {
+            *counts
+                .entry(handle.type_name().unwrap_or("Unknown"))
+                .or_insert(0usize) += 1;
+        }
+
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count >= self.threshold)
+            .map(|(type_name, count)| {
+                let severity = if count >= self.threshold * 4 { Severity::High } else { Severity::Medium };
+                Finding::new(self.name(), severity, format!("{count} open {type_name} handles"))
+            })
+            .collect()
+    }
+}
+
+/// Flags dumps where the threads are blocked on each other.
+///
+/// A deadlock is reported when the exception is `STATUS_POSSIBLE_DEADLOCK`, or when
+/// every thread of the process sits in a wait system call.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct DeadlockDetection;
+
+impl Analysis for DeadlockDetection {
+    fn name(&self) -> &'static str {
+        "deadlock"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        if let Some(exception) = dump.exception()
+            && exception.code == STATUS_POSSIBLE_DEADLOCK
+        {
+            let message = "a critical section wait timed out (STATUS_POSSIBLE_DEADLOCK)";
+            findings.push(Finding::new(self.name(), Severity::Critical, message).thread(exception.thread_id));
+        }
+
+        let waits = dump.thunk_exports(&["ntdll.dll"], &WAIT_SYSCALLS);
+        if waits.is_empty() || dump.threads().is_empty() {
+            return findings;
+        }
+
+        let blocked = dump
+            .threads()
+            .values()
+            .filter(|thread| {
+                thread
+                    .context()
+                    .instruction_pointer()
+                    .is_some_and(|ip| {
+                        waits
+                            .iter()
+                            .any(|&stub| (stub..stub + SYSCALL_STUB_SIZE).contains(&ip))
+                    })
+            })
+            .count();
+
+        if blocked == dump.threads().len() && blocked > 1 {
+            let message = format!("all {blocked} threads are blocked in wait calls");
+            findings.push(Finding::new(self.name(), Severity::High, message));
+        }
+
+        findings
+    }
+}
+
+/// Reports the modules built without ASLR, DEP or Control Flow Guard.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct MitigationSummary;
+
+impl Analysis for MitigationSummary {
+    fn name(&self) -> &'static str {
+        "mitigations"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for module in dump.modules().values() {
+            let Ok(headers) = dump.pe_headers(module.start_addr()) else {
+                continue;
+            };
+
+            let flags = headers.dll_characteristics;
+            let missing = [
+                (IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE, "ASLR"),
+                (IMAGE_DLLCHARACTERISTICS_NX_COMPAT, "DEP"),
+                (IMAGE_DLLCHARACTERISTICS_GUARD_CF, "CFG"),
+            ]
+            .into_iter()
+            .filter(|(flag, _)| flags & flag == 0)
+            .map(|(_, name)| name)
+            .collect::<Vec<&str>>();
+
+            if missing.is_empty() {
+                continue;
+            }
+
+            // Missing CFG alone is common in third-party code; missing ASLR or DEP is not.
+            let severity = if missing == ["CFG"] { Severity::Info } else { Severity::Low };
+            let name = module.name().unwrap_or("???");
+            let message = format!("{name} is built without {}", missing.join(", "));
+            findings.push(Finding::new(self.name(), severity, message).address(module.start_addr()));
+        }
+
+        findings
+    }
+}
+
+/// Flags modules that look hollowed or patched, see [`UserDump::tampered_modules`].
+#[derive(Copy, Debug, Clone, Default)]
+pub struct TamperingDetection;
+
+impl Analysis for TamperingDetection {
+    fn name(&self) -> &'static str {
+        "tampering"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for tampered in dump.tampered_modules() {
+            let name = tampered
+                .module(dump)
+                .and_then(|module| module.name())
+                .unwrap_or("???");
+
+            for indicator in &tampered.indicators {
+                let severity = match indicator {
+                    TamperingIndicator::UnexpectedProtection { .. } | TamperingIndicator::EntryPointOutsideCode { .. } => Severity::Medium,
+                    _ => Severity::High,
+                };
+
+                let message = format!("{name}: {indicator}");
+                findings.push(Finding::new(self.name(), severity, message).address(tampered.module_base));
+            }
+        }
+
+        findings
+    }
+}
+
+/// Flags hooked import and export table entries and inline hooks, see [`UserDump::hooks`].
+#[derive(Copy, Debug, Clone, Default)]
+pub struct HookDetection;
+
+impl Analysis for HookDetection {
+    fn name(&self) -> &'static str {
+        "hooks"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        dump.hooks()
+            .into_iter()
+            .map(|hook| {
+                let severity = if hook.is_unbacked() { Severity::High } else { Severity::Medium };
+                let name = dump
+                    .modules()
+                    .get(&hook.module_base)
+                    .and_then(|module| module.name())
+                    .unwrap_or("???");
+
+                let message = format!("{name}: {hook}");
+                Finding::new(self.name(), severity, message).address(hook.address)
+            })
+            .collect()
+    }
+}
+
+impl UserDump<'_> {
+    /// Runs the passes of a registry over the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The passes to run (e.g., [`AnalysisRegistry::builtin`]).
+    ///
+    /// # Returns
+    ///
+    /// * The findings of every pass, the most severe first, labelled with the
+    ///   registered label covering their address.
+    pub fn analyze(&self, registry: &AnalysisRegistry) -> Vec<Finding> {
+        let mut findings = registry.run(self);
+        for finding in &mut findings {
+            finding.label = finding
+                .address
+                .and_then(|address| self.label_at(address))
+                .map(str::to_string);
+        }
+
+        findings
+    }
+}
@@ -0,0 +1,263 @@
+use core::fmt;
+use crate::memory::{MemoryProtection, MemoryType};
+use crate::parse::{Module, UserDump};
+use super::decode_jump;
+
+/// Describes why a loaded module looks hollowed or patched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TamperingIndicator {
+    /// The first page of the module was captured but does not hold valid PE headers,
+    /// as when the headers were erased after loading.
+    InvalidHeaders,
+
+    /// The `SizeOfImage` of the in-memory headers differs from the size recorded by the loader.
+    SizeOfImageMismatch {
+        /// The `SizeOfImage` of the in-memory headers.
+        headers: u32,
+
+        /// The size of the module in the module list.
+        module: u64,
+    },
+
+    /// The link timestamp of the in-memory headers differs from the one recorded in the module list.
+    TimestampMismatch {
+        /// The `TimeDateStamp` of the in-memory headers.
+        headers: u32,
+
+        /// The timestamp of the module in the module list.
+        module: u32,
+    },
+
+    /// Part of the module is not backed by its image file, the hallmark of process hollowing.
+    NotImageBacked {
+        /// The start address of the region.
+        address: u64,
+
+        /// The type of the region.
+        type_: MemoryType,
+    },
+
+    /// A region of the module is executable or writable while its section is not.
+    UnexpectedProtection {
+        /// The start address of the region.
+        address: u64,
+
+        /// The section holding the region, or `headers` for the PE headers.
+        section: String,
+
+        /// The protection of the region.
+        protect: MemoryProtection,
+    },
+
+    /// The entry point lies outside the executable sections of the image.
+    EntryPointOutsideCode {
+        /// The address of the entry point.
+        entry_point: u64,
+    },
+
+    /// The entry point starts with a jump leaving the module.
+    EntryPointRedirected {
+        /// The address of the entry point.
+        entry_point: u64,
+
+        /// The destination of the jump.
+        target: u64,
+    },
+}
+
+impl fmt::Display for TamperingIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TamperingIndicator::InvalidHeaders => write!(f, "PE headers are missing or corrupt"),
+            TamperingIndicator::SizeOfImageMismatch { headers, module } => {
+                write!(f, "SizeOfImage {headers:#x} differs from module size {module:#x}")
+            }
+            TamperingIndicator::TimestampMismatch { headers, module } => {
+                write!(f, "header timestamp {headers:#x} differs from module timestamp {module:#x}")
+            }
+            TamperingIndicator::NotImageBacked { address, type_ } => write!(f, "{type_} region at {address:#x}"),
+            TamperingIndicator::UnexpectedProtection { address, section, protect } => {
+                write!(f, "{section} region at {address:#x} is {protect}")
+            }
+            TamperingIndicator::EntryPointOutsideCode { entry_point } => {
+                write!(f, "entry point {entry_point:#x} is outside the code sections")
+            }
+            TamperingIndicator::EntryPointRedirected { entry_point, target } => {
+                write!(f, "entry point {entry_point:#x} jumps to {target:#x}")
+            }
+        }
+    }
+}
+
+/// Represents a loaded module whose in-memory image disagrees with its headers or the loader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TamperedModule {
+    /// The base address of the module.
+    pub module_base: u64,
+
+    /// The reasons the module looks tampered with.
+    pub indicators: Vec<TamperingIndicator>,
+}
+
+impl TamperedModule {
+    /// Returns the module the indicators were found in.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the module was inspected in.
+    pub fn module<'d, 'a>(&self, dump: &'d UserDump<'a>) -> Option<&'d Module<'a>> {
+        dump.modules().get(&self.module_base)
+    }
+}
+
+impl UserDump<'_> {
+    /// Compares the in-memory image of every module against its headers and the loader.
+    ///
+    /// The following checks are performed on each module:
+    /// - The PE headers are present and well formed when the first page was captured.
+    /// - `SizeOfImage` and the link timestamp match the module list.
+    /// - Every region of the module is `MEM_IMAGE`.
+    /// - No region is executable or writable unless its section is.
+    /// - The entry point lies in an executable section and does not jump out of the module.
+    ///
+    /// # Returns
+    ///
+    /// * The modules with at least one indicator, ordered by base address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for tampered in dump.tampered_modules() {
+    ///     let name = tampered.module(&dump).and_then(|module| module.name()).unwrap_or("???");
+    ///     for indicator in &tampered.indicators {
+    ///         println!("{name}: {indicator}");
+    ///     }
+    /// }
+    /// ```
+    pub fn tampered_modules(&self) -> Vec<TamperedModule> {
+        self.modules()
+            .values()
+            .filter_map(|module| {
+                let indicators = self.module_tampering(module);
+                (!indicators.is_empty()).then_some(TamperedModule {
+                    module_base: module.start_addr(),
+                    indicators,
+                })
+            })
+            .collect()
+    }
+
+    /// Runs the checks of [`UserDump::tampered_modules`] on a single module.
+    fn module_tampering(&self, module: &Module) -> Vec<TamperingIndicator> {
+        let base = module.start_addr();
+        let mut indicators = Vec::new();
+
+        // Regions only known from the memory lists carry no type and are not judged.
+        if let Some(memory) = self
+            .memorys()
+            .range(module.range.clone())
+            .map(|(_, memory)| memory)
+            .find(|memory| !memory.type_.is_empty() && !memory.type_.is_image())
+        {
+            indicators.push(TamperingIndicator::NotImageBacked {
+                address: memory.range.start,
+                type_: memory.type_,
+            });
+        }
+
+        let headers = match self.pe_headers(base) {
+            Ok(headers) => headers,
+            Err(_) => {
+                let captured = self
+                    .memory_at(base)
+                    .is_some_and(|memory| !memory.data.is_empty());
+                if captured {
+                    indicators.push(TamperingIndicator::InvalidHeaders);
+                }
+
+                return indicators;
+            }
+        };
+
+        if headers.size_of_image as u64 != module.len() {
+            indicators.push(TamperingIndicator::SizeOfImageMismatch {
+                headers: headers.size_of_image,
+                module: module.len(),
+            });
+        }
+
+        if module.time_date_stamp != 0 && headers.time_date_stamp != module.time_date_stamp {
+            indicators.push(TamperingIndicator::TimestampMismatch {
+                headers: headers.time_date_stamp,
+                module: module.time_date_stamp,
+            });
+        }
+
+        // Compares the protection of each committed image region with the sections it overlaps.
+        let headers_end = base.saturating_add(headers.size_of_headers.into());
+        for memory in self
+            .memorys()
+            .range(module.range.clone())
+            .map(|(_, memory)| memory)
+            .filter(|memory| memory.type_.is_image() && memory.state.is_committed())
+        {
+            let sections = headers
+                .sections
+                .iter()
+                .filter(|section| {
+                    let range = section.range(base);
+                    range.start < memory.range.end && memory.range.start < range.end
+                })
+                .collect::<Vec<_>>();
+
+            let (name, executable, writable) = match sections.first() {
+                _ if memory.range.start < headers_end => (String::from("headers"), false, false),
+                Some(section) => (
+                    section.name.clone(),
+                    sections
+                        .iter()
+                        .any(|section| section.is_executable()),
+                    sections
+                        .iter()
+                        .any(|section| section.is_writable()),
+                ),
+                None => continue,
+            };
+
+            if (memory.protect.is_executable() && !executable) || (memory.protect.is_writable() && !writable) {
+                indicators.push(TamperingIndicator::UnexpectedProtection {
+                    address: memory.range.start,
+                    section: name,
+                    protect: memory.protect,
+                });
+            }
+        }
+
+        // Checks where the entry point lives and where it leads. DLLs may have no entry point.
+        if headers.entry_point != 0 {
+            let entry_point = base.saturating_add(headers.entry_point.into());
+            let in_code = headers
+                .section_at(headers.entry_point)
+                .is_some_and(|section| section.is_executable());
+            if !in_code {
+                indicators.push(TamperingIndicator::EntryPointOutsideCode { entry_point });
+            }
+
+            if let Some(jump) = decode_jump(self, entry_point)
+                && !module.range.contains(&jump.target)
+            {
+                indicators.push(TamperingIndicator::EntryPointRedirected {
+                    entry_point,
+                    target: jump.target,
+                });
+            }
+        }
+
+        indicators
+    }
+}
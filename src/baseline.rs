@@ -0,0 +1,428 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+use crate::analysis::passes::{Analysis, Finding, Severity};
+use crate::error::UserDmpError;
+use crate::parse::{Result, UserDump};
+
+/// Header line of a serialized [`BaselineProfile`].
+const HEADER: &str = "userdmp-baseline 1";
+
+/// Number of standard deviations from the mean beyond which a metric deviates.
+const DEVIATION_THRESHOLD: f64 = 3.0;
+
+/// Smallest spread accepted around a mean, as a fraction of it, so profiles built
+/// from near-identical samples do not flag every small variation.
+const MIN_RELATIVE_SPREAD: f64 = 0.1;
+
+/// The quantities measured on every dump added to a [`BaselineProfile`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MetricKind {
+    /// The total size of the committed memory regions, in bytes.
+    CommittedMemory,
+
+    /// The number of open handles.
+    Handles,
+
+    /// The number of threads.
+    Threads,
+}
+
+impl MetricKind {
+    /// Every metric, in serialization order.
+    const ALL: [MetricKind; 3] = [MetricKind::CommittedMemory, MetricKind::Handles, MetricKind::Threads];
+
+    /// Returns the stable name of the metric (e.g., `committed-memory`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::CommittedMemory => "committed-memory",
+            MetricKind::Handles => "handles",
+            MetricKind::Threads => "threads",
+        }
+    }
+
+    /// Measures the metric on a dump.
+    ///
+    /// Regions without `MemoryInfoListStream` metadata count their captured bytes
+    /// towards the committed memory.
+    pub fn measure(&self, dump: &UserDump) -> u64 {
+        match self {
+            MetricKind::CommittedMemory => dump
+                .memorys()
+                .values()
+                .map(|memory| match memory.state {
+                    state if state.is_committed() => memory.len(),
+                    state if state.is_empty() => memory.data.len() as u64,
+                    _ => 0,
+                })
+                .sum(),
+            MetricKind::Handles => dump.handles().len() as u64,
+            MetricKind::Threads => dump.threads().len() as u64,
+        }
+    }
+}
+
+impl fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Running statistics of a metric over the samples of a profile.
+#[derive(Copy, Debug, Clone, Default, PartialEq)]
+pub struct MetricStats {
+    /// The smallest value seen.
+    pub min: u64,
+
+    /// The largest value seen.
+    pub max: u64,
+
+    /// The sum of the values.
+    sum: f64,
+
+    /// The sum of the squared values.
+    sum_of_squares: f64,
+}
+
+impl MetricStats {
+    /// Adds a value to the statistics, `samples` being the number of values already added.
+    fn add(&mut self, value: u64, samples: usize) {
+        self.min = if samples == 0 { value } else { self.min.min(value) };
+        self.max = self.max.max(value);
+        self.sum += value as f64;
+        self.sum_of_squares += (value as f64) * (value as f64);
+    }
+
+    /// Returns the mean of `samples` values.
+    pub fn mean(&self, samples: usize) -> f64 {
+        if samples == 0 { 0.0 } else { self.sum / samples as f64 }
+    }
+
+    /// Returns the population standard deviation of `samples` values.
+    pub fn std_dev(&self, samples: usize) -> f64 {
+        if samples == 0 {
+            return 0.0;
+        }
+
+        let mean = self.mean(samples);
+        (self.sum_of_squares / samples as f64 - mean * mean)
+            .max(0.0)
+            .sqrt()
+    }
+}
+
+/// Describes how a dump differs from a [`BaselineProfile`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Deviation {
+    /// A module was loaded that no sample of the profile had.
+    UnexpectedModule(String),
+
+    /// A module loaded by every sample of the profile is missing.
+    MissingModule(String),
+
+    /// A metric is far from its typical value.
+    Metric {
+        /// The metric that deviates.
+        kind: MetricKind,
+
+        /// The value measured on the dump.
+        value: u64,
+
+        /// The mean of the metric over the samples of the profile.
+        mean: f64,
+
+        /// The distance from the mean, in standard deviations.
+        score: f64,
+    },
+}
+
+impl fmt::Display for Deviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Deviation::UnexpectedModule(name) => write!(f, "unexpected module {name}"),
+            Deviation::MissingModule(name) => write!(f, "missing module {name}"),
+            Deviation::Metric { kind, value, mean, score } => {
+                write!(f, "{kind} is {value}, typically {mean:.0} ({score:+.1} standard deviations)")
+            }
+        }
+    }
+}
+
+/// Describes the expected state of a healthy process, learned from sample dumps.
+///
+/// The profile records which modules the samples load and the distribution of a few
+/// [`MetricKind`]s. New dumps are then scored against it with [`BaselineProfile::compare`].
+/// The profile can be saved as text and reloaded, and it implements [`Analysis`] so it
+/// can run alongside the other passes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, baseline::BaselineProfile};
+///
+/// let mut profile = BaselineProfile::new();
+/// for path in ["healthy1.dmp", "healthy2.dmp", "healthy3.dmp"] {
+///     profile.add(&UserDump::new(path).unwrap());
+/// }
+///
+/// profile.save("service.baseline").unwrap();
+///
+/// let dump = UserDump::new("crash.dmp").unwrap();
+/// for deviation in profile.compare(&dump) {
+///     println!("{deviation}");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BaselineProfile {
+    /// The number of dumps the profile was built from.
+    samples: usize,
+
+    /// The lowercase name of every module seen, with the number of samples loading it.
+    modules: BTreeMap<String, usize>,
+
+    /// The statistics of every metric.
+    metrics: BTreeMap<MetricKind, MetricStats>,
+}
+
+impl BaselineProfile {
+    /// Creates a new, empty [`BaselineProfile`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a profile from the dumps of a healthy process.
+    ///
+    /// # Arguments
+    ///
+    /// * `dumps` - The sample dumps.
+    pub fn from_dumps<'d, 'a: 'd>(dumps: impl IntoIterator<Item = &'d UserDump<'a>>) -> Self {
+        let mut profile = Self::new();
+        for dump in dumps {
+            profile.add(dump);
+        }
+
+        profile
+    }
+
+    /// Loads a profile saved with [`BaselineProfile::save`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the profile.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Saves the profile as text.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Adds a sample dump to the profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - A dump of the healthy process.
+    pub fn add(&mut self, dump: &UserDump) {
+        for name in module_names(dump) {
+            *self.modules.entry(name).or_insert(0) += 1;
+        }
+
+        for kind in MetricKind::ALL {
+            self.metrics
+                .entry(kind)
+                .or_default()
+                .add(kind.measure(dump), self.samples);
+        }
+
+        self.samples += 1;
+    }
+
+    /// Returns the number of dumps the profile was built from.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Returns the statistics of a metric.
+    pub fn metric(&self, kind: MetricKind) -> Option<&MetricStats> {
+        self.metrics.get(&kind)
+    }
+
+    /// Returns the lowercase names of the modules loaded by every sample.
+    pub fn expected_modules(&self) -> impl Iterator<Item = &str> {
+        self.modules
+            .iter()
+            .filter(|(_, count)| **count == self.samples)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Compares a dump with the profile.
+    ///
+    /// A module is unexpected when no sample loaded it, and missing when every sample
+    /// loaded it. A metric deviates when it is more than three standard deviations away
+    /// from its mean, the spread being at least 10% of the mean.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump to score.
+    ///
+    /// # Returns
+    ///
+    /// * The deviations found, modules first. Empty when the profile holds no sample.
+    pub fn compare(&self, dump: &UserDump) -> Vec<Deviation> {
+        if self.samples == 0 {
+            return Vec::new();
+        }
+
+        let loaded = module_names(dump);
+        let mut deviations = loaded
+            .iter()
+            .filter(|name| !self.modules.contains_key(*name))
+            .map(|name| Deviation::UnexpectedModule(name.clone()))
+            .collect::<Vec<Deviation>>();
+
+        deviations.extend(
+            self.expected_modules()
+                .filter(|name| {
+                    !loaded
+                        .iter()
+                        .any(|loaded| loaded == name)
+                })
+                .map(|name| Deviation::MissingModule(name.to_string())),
+        );
+
+        for (kind, stats) in &self.metrics {
+            let value = kind.measure(dump);
+            let mean = stats.mean(self.samples);
+            let spread = stats
+                .std_dev(self.samples)
+                .max(mean * MIN_RELATIVE_SPREAD)
+                .max(1.0);
+            let score = (value as f64 - mean) / spread;
+            if score.abs() > DEVIATION_THRESHOLD {
+                deviations.push(Deviation::Metric {
+                    kind: *kind,
+                    value,
+                    mean,
+                    score,
+                });
+            }
+        }
+
+        deviations
+    }
+}
+
+impl Analysis for BaselineProfile {
+    fn name(&self) -> &'static str {
+        "baseline"
+    }
+
+    fn run(&self, dump: &UserDump) -> Vec<Finding> {
+        self.compare(dump)
+            .into_iter()
+            .map(|deviation| {
+                let severity = match &deviation {
+                    Deviation::UnexpectedModule(_) => Severity::Medium,
+                    Deviation::MissingModule(_) => Severity::Low,
+                    Deviation::Metric { score, .. } if score.abs() > DEVIATION_THRESHOLD * 2.0 => Severity::High,
+                    Deviation::Metric { .. } => Severity::Medium,
+                };
+
+                Finding::new(self.name(), severity, deviation.to_string())
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for BaselineProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        writeln!(f, "samples {}", self.samples)?;
+        for (kind, stats) in &self.metrics {
+            writeln!(f, "metric {kind} {} {} {} {}", stats.min, stats.max, stats.sum, stats.sum_of_squares)?;
+        }
+
+        // The name comes last since it may contain spaces.
+        for (name, count) in &self.modules {
+            writeln!(f, "module {count} {name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for BaselineProfile {
+    type Err = UserDmpError;
+
+    /// Parses a profile in the format written by its `Display` implementation.
+    fn from_str(text: &str) -> Result<Self> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim_end()));
+        if lines.next().map(|(_, line)| line) != Some(HEADER) {
+            return Err(UserDmpError::InvalidBaseline(1));
+        }
+
+        let mut profile = Self::new();
+        for (number, line) in lines.filter(|(_, line)| !line.is_empty()) {
+            let invalid = || UserDmpError::InvalidBaseline(number);
+            let (keyword, rest) = line
+                .split_once(' ')
+                .ok_or_else(invalid)?;
+            match keyword {
+                "samples" => profile.samples = rest.parse().map_err(|_| invalid())?,
+                "metric" => {
+                    let fields = rest.split(' ').collect::<Vec<&str>>();
+                    let [name, min, max, sum, sum_of_squares] = fields[..] else {
+                        return Err(invalid());
+                    };
+
+                    let kind = MetricKind::ALL
+                        .into_iter()
+                        .find(|kind| kind.as_str() == name)
+                        .ok_or_else(invalid)?;
+                    let stats = MetricStats {
+                        min: min.parse().map_err(|_| invalid())?,
+                        max: max.parse().map_err(|_| invalid())?,
+                        sum: sum.parse().map_err(|_| invalid())?,
+                        sum_of_squares: sum_of_squares
+                            .parse()
+                            .map_err(|_| invalid())?,
+                    };
+
+                    profile.metrics.insert(kind, stats);
+                }
+                "module" => {
+                    let (count, name) = rest
+                        .split_once(' ')
+                        .ok_or_else(invalid)?;
+                    profile
+                        .modules
+                        .insert(name.to_string(), count.parse().map_err(|_| invalid())?);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(profile)
+    }
+}
+
+/// Returns the lowercase names of the modules of a dump, without duplicates.
+fn module_names(dump: &UserDump) -> Vec<String> {
+    let mut names = dump
+        .modules()
+        .values()
+        .filter_map(|module| module.name())
+        .map(|name| name.to_lowercase())
+        .collect::<Vec<String>>();
+
+    names.sort();
+    names.dedup();
+    names
+}
@@ -0,0 +1,283 @@
+use std::{env, error::Error, fs::File, io::BufWriter, process::ExitCode};
+use userdmp::{UserDump, analysis::passes::AnalysisRegistry, parse::ModuleOrder, report::ReportOptions, triage::Verdict};
+use userdmp::report::render::{HtmlRenderer, JsonRenderer, MarkdownRenderer, ReportRenderer, TextRenderer};
+
+/// Number of bytes shown by `memory --at`.
+const DUMP_BYTES: usize = 0x100;
+
+const USAGE: &str = "\
+Usage: userdmp <command> <dump> [options]
+
+Commands:
+  summary                          One-screen overview of the dump
+  modules                          List the modules, in load order
+  threads                          List the threads with their stack trace
+  memory [--at <address>]          List the memory regions, or show the bytes at an address
+  handles [--type <type>]          List the handles, optionally of a single object type
+  extract-module <name> [-o path]  Write the in-memory image of a module to a file
+  report [--format <format>]       Print a report of the dump as json (default), text,
+                                   markdown or html
+  triage [--exit-code]             Print the verdict and the findings of the analysis passes;
+                                   with --exit-code, exit with the verdict code";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (Some(command), Some(path)) = (args.first(), args.get(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let options = &args[2..];
+    let dump = match UserDump::new(path) {
+        Ok(dump) => dump,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return match command.as_str() {
+                "triage" if flag(options, "--exit-code") => exit_code(Verdict::CorruptDump),
+                _ => ExitCode::FAILURE,
+            };
+        }
+    };
+
+    let result = match command.as_str() {
+        "summary" => {
+            print!("{}", dump.summary());
+            Ok(())
+        }
+        "modules" => modules(&dump),
+        "threads" => threads(&dump),
+        "memory" => memory(&dump, options),
+        "handles" => handles(&dump, value(options, "--type")),
+        "extract-module" => extract_module(&dump, options),
+        "report" => report(&dump, value(options, "--format").unwrap_or("json")),
+        "triage" => {
+            let report = dump.triage(&AnalysisRegistry::builtin());
+            print!("{report}");
+            if flag(options, "--exit-code") {
+                return exit_code(report.verdict);
+            }
+
+            Ok(())
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Lists the modules, in load order.
+fn modules(dump: &UserDump) -> Result<(), Box<dyn Error>> {
+    for module in dump.modules_sorted(ModuleOrder::LoadOrder) {
+        let version = module
+            .file_version_string()
+            .unwrap_or_default();
+        println!("{:#018x} {:#010x} {version:<16} {}", module.start_addr(), module.len(), module.path.display());
+    }
+
+    Ok(())
+}
+
+/// Lists the threads with their stack trace.
+fn threads(dump: &UserDump) -> Result<(), Box<dyn Error>> {
+    for (tid, thread) in dump.threads() {
+        println!(
+            "thread {tid} {} teb={:#x} stack={:#x}..{:#x}",
+            thread.name().unwrap_or(""),
+            thread.teb,
+            thread.stack.start,
+            thread.stack.end
+        );
+
+        match dump.stack_trace(thread) {
+            Ok(frames) => {
+                for frame in frames {
+                    println!("  {:#018x} {}", frame.instruction_pointer, location(dump, frame.instruction_pointer));
+                }
+            }
+            Err(_) => {
+                if let Some(ip) = thread.context().instruction_pointer() {
+                    println!("  {ip:#018x} {}", location(dump, ip));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the memory regions, or shows the region and the bytes at `--at`.
+fn memory(dump: &UserDump, options: &[String]) -> Result<(), Box<dyn Error>> {
+    let Some(address) = value(options, "--at") else {
+        for memory in dump.memorys().values() {
+            println!(
+                "{:#018x}-{:#018x} state={} protect={} type={} {}",
+                memory.range.start,
+                memory.range.end,
+                memory.state,
+                memory.protect,
+                memory.type_,
+                region_name(
+                    dump,
+                    memory.range.start,
+                    memory
+                        .owner()
+                        .map(|owner| owner.to_string())
+                )
+            );
+        }
+
+        return Ok(());
+    };
+
+    let address = parse_address(address)?;
+    let memory = dump
+        .memory_at(address)
+        .ok_or_else(|| format!("address {address:#x} is not in the dump"))?;
+
+    println!(
+        "region {:#x}-{:#x} protect={} {}",
+        memory.range.start,
+        memory.range.end,
+        memory.protect,
+        region_name(
+            dump,
+            address,
+            memory
+                .owner()
+                .map(|owner| owner.to_string())
+        )
+    );
+
+    let len = DUMP_BYTES.min((memory.range.end - address) as usize);
+    let bytes = dump.read_bytes(address, len)?;
+    for (index, line) in bytes.chunks(16).enumerate() {
+        let hex = line
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = line
+            .iter()
+            .map(|&byte| if byte.is_ascii_graphic() { byte as char } else { '.' })
+            .collect::<String>();
+        println!("{:#018x}  {hex:<47}  {text}", address + index as u64 * 16);
+    }
+
+    Ok(())
+}
+
+/// Lists the handles, optionally of a single object type.
+fn handles(dump: &UserDump, type_name: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let handles = dump
+        .handles()
+        .values()
+        .filter(|handle| match type_name {
+            Some(type_name) => handle
+                .type_name()
+                .is_some_and(|name| name.eq_ignore_ascii_case(type_name)),
+            None => true,
+        });
+
+    for handle in handles {
+        println!(
+            "{:#06x} {:<16} {:#010x} {}",
+            handle.handle,
+            handle.type_name().unwrap_or(""),
+            handle.granted_access,
+            handle.object_name().unwrap_or("")
+        );
+    }
+
+    Ok(())
+}
+
+/// Writes the in-memory image of a module to `-o`, or to its name in the current directory.
+fn extract_module(dump: &UserDump, options: &[String]) -> Result<(), Box<dyn Error>> {
+    let Some(name) = options
+        .first()
+        .filter(|name| !name.starts_with('-'))
+    else {
+        return Err("missing module name".into());
+    };
+
+    let module = dump
+        .module_by_name(name)
+        .ok_or_else(|| format!("module {name} is not loaded"))?;
+    let output = value(options, "-o").unwrap_or(name);
+
+    let gaps = dump.extract_module(module.start_addr(), BufWriter::new(File::create(output)?))?;
+    println!("{name} written to {output} ({} ranges missing from the dump)", gaps.len());
+    Ok(())
+}
+
+/// Prints a report of the dump in the requested format.
+fn report(dump: &UserDump, format: &str) -> Result<(), Box<dyn Error>> {
+    let renderer: &dyn ReportRenderer = match format {
+        "json" => &JsonRenderer,
+        "text" => &TextRenderer,
+        "markdown" | "md" => &MarkdownRenderer,
+        "html" => &HtmlRenderer,
+        _ => return Err(format!("unknown report format {format}").into()),
+    };
+
+    println!("{}", dump.render_report(&ReportOptions::new(), renderer));
+    Ok(())
+}
+
+/// Describes an address as `module+offset`, or by its registered label.
+fn location(dump: &UserDump, address: u64) -> String {
+    match dump.module_at(address) {
+        Some(module) => format!("{}+{:#x}", module.name().unwrap_or("<unknown>"), address - module.start_addr()),
+        None => region_name(dump, address, None),
+    }
+}
+
+/// Names the memory at an address: its module, its owner or its label.
+fn region_name(dump: &UserDump, address: u64, owner: Option<String>) -> String {
+    dump.module_at(address)
+        .and_then(|module| module.name())
+        .or_else(|| dump.label_at(address))
+        .map(str::to_string)
+        .or(owner)
+        .unwrap_or_default()
+}
+
+/// Returns true if `name` is among the options.
+fn flag(options: &[String], name: &str) -> bool {
+    options
+        .iter()
+        .any(|option| option == name)
+}
+
+/// Returns the value following `name` among the options.
+fn value<'o>(options: &'o [String], name: &str) -> Option<&'o str> {
+    options
+        .iter()
+        .position(|option| option == name)
+        .and_then(|index| options.get(index + 1))
+        .map(String::as_str)
+}
+
+/// Parses an address written in hexadecimal, with or without the `0x` prefix.
+fn parse_address(text: &str) -> Result<u64, Box<dyn Error>> {
+    let digits = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+
+    u64::from_str_radix(&digits.replace('`', ""), 16).map_err(|_| format!("invalid address {text}").into())
+}
+
+/// Converts a verdict into the exit code of the process.
+fn exit_code(verdict: Verdict) -> ExitCode {
+    ExitCode::from(verdict.code() as u8)
+}
@@ -0,0 +1,129 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// A flag shared between an operation and the code that may want to cancel it.
+///
+/// Clones share the same flag, so a clone can be handed to another thread (e.g., a
+/// UI thread) and cancelled from there.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`] that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels every operation observing the token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits how long a scan, an unwind or a heap walk may run.
+///
+/// The budget is exhausted once its deadline passes or its [`CancellationToken`] is
+/// cancelled. Operations check it regularly and return what they found so far,
+/// wrapped in a [`Partial`] with [`Partial::timed_out`] set.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use userdmp::{UserDump, budget::{Budget, CancellationToken}};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let token = CancellationToken::new();
+/// let budget = Budget::timeout(Duration::from_millis(500)).cancellation(token.clone());
+///
+/// let strings = dump.strings_within(8, &budget);
+/// if strings.timed_out {
+///     println!("stopped early after {} strings", strings.value.len());
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Budget {
+    /// The instant after which the operation stops.
+    pub deadline: Option<Instant>,
+
+    /// The token that stops the operation when cancelled.
+    pub token: Option<CancellationToken>,
+}
+
+impl Budget {
+    /// Creates a new [`Budget`] that is never exhausted.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Budget`] exhausted after the given duration, starting now.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How long the operation may run.
+    pub fn timeout(duration: Duration) -> Self {
+        Self::unlimited().deadline(Instant::now() + duration)
+    }
+
+    /// Sets the instant after which the operation stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The deadline of the operation.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the token that stops the operation when cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The cancellation token.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Returns true if the deadline passed or the token was cancelled.
+    pub fn is_exhausted(&self) -> bool {
+        self.token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// The result of an operation run under a [`Budget`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partial<T> {
+    /// The results found before the operation completed or stopped.
+    pub value: T,
+
+    /// Indicates whether the budget was exhausted before the operation completed.
+    pub timed_out: bool,
+}
+
+impl<T> Partial<T> {
+    /// Returns true if the operation ran to completion.
+    pub fn is_complete(&self) -> bool {
+        !self.timed_out
+    }
+
+    /// Returns the results, discarding the completion marker.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
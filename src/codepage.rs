@@ -0,0 +1,293 @@
+use std::fmt;
+use crate::parse::UserDump;
+
+/// Identifier of the UTF-8 code page.
+const CP_UTF8: u16 = 65001;
+
+/// Identifier of the Windows Western European code page.
+const CP_WINDOWS_1252: u16 = 1252;
+
+/// Identifier of the ISO 8859-1 (Latin-1) code page.
+const CP_LATIN1: u16 = 28591;
+
+/// Upper bound on the size of the multi-byte tables of an NLS file, to survive corrupted headers.
+const MAX_NLS_TABLE_SIZE: usize = 0x4_0000;
+
+/// The characters of Windows-1252 in `0x80..0xA0`, where it departs from Latin-1.
+///
+/// The five unassigned bytes map to the matching C1 control characters, as `MultiByteToWideChar` does.
+const WINDOWS_1252_HIGH: [u16; 32] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018,
+    0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014, 0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+];
+
+/// Decodes text stored in a Windows code page (the "ANSI" encoding of a process).
+///
+/// UTF-8, Windows-1252 and Latin-1 are built in. Other code pages, including the
+/// double-byte ones of East Asian systems, are loaded from their NLS table: either
+/// the one mapped in the captured process ([`UserDump::ansi_code_page`]) or a
+/// `C_<id>.NLS` file supplied by the caller ([`CodePage::from_nls`]).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, codepage::CodePage, options::ParseOptions};
+///
+/// let table = std::fs::read(r"C:\Windows\System32\C_932.NLS").unwrap();
+/// let options = ParseOptions::new().code_page(CodePage::from_nls(&table).unwrap());
+/// let dump = UserDump::with_options("example.dmp", options).unwrap();
+/// println!("{}", dump.read_ansi_string(0x7ff6_1234_5000).unwrap());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CodePage {
+    /// The code page identifier (e.g., `1252`).
+    id: u16,
+
+    /// How bytes map to characters.
+    decoder: Decoder,
+}
+
+/// The mapping of a [`CodePage`].
+#[derive(Clone, PartialEq, Eq)]
+enum Decoder {
+    /// UTF-8, decoded by the standard library.
+    Utf8,
+
+    /// A table of UTF-16 units indexed by byte, and for double-byte code pages, the
+    /// `DBCSOffsets` of the NLS file: 256 offsets indexed by lead byte, followed by
+    /// the tables indexed by trail byte they point to.
+    Table(Box<[u16; 256]>, Option<Vec<u16>>),
+}
+
+impl CodePage {
+    /// Returns the UTF-8 code page (`65001`).
+    pub fn utf8() -> Self {
+        Self {
+            id: CP_UTF8,
+            decoder: Decoder::Utf8,
+        }
+    }
+
+    /// Returns the Windows Western European code page (`1252`), the ANSI code page of
+    /// English and Western European systems.
+    pub fn windows_1252() -> Self {
+        let mut table = Self::latin1_table();
+        table[0x80..0xA0].copy_from_slice(&WINDOWS_1252_HIGH);
+        Self {
+            id: CP_WINDOWS_1252,
+            decoder: Decoder::Table(table, None),
+        }
+    }
+
+    /// Returns the ISO 8859-1 (Latin-1) code page (`28591`).
+    pub fn latin1() -> Self {
+        Self {
+            id: CP_LATIN1,
+            decoder: Decoder::Table(Self::latin1_table(), None),
+        }
+    }
+
+    /// Returns a built-in code page by identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The code page identifier: `65001`, `1252` or `28591`.
+    pub fn builtin(id: u16) -> Option<Self> {
+        match id {
+            CP_UTF8 => Some(Self::utf8()),
+            CP_WINDOWS_1252 => Some(Self::windows_1252()),
+            CP_LATIN1 => Some(Self::latin1()),
+            _ => None,
+        }
+    }
+
+    /// Loads a code page from an NLS table, in the format of the `C_<id>.NLS` files
+    /// of the Windows system directory.
+    ///
+    /// Only the multi-byte to Unicode tables are read, so the bytes may stop right
+    /// after them, as in the copies mapped into processes.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The content of the NLS table.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(CodePage)` - The code page described by the table.
+    /// * `None` - If the table is truncated or malformed.
+    pub fn from_nls(data: &[u8]) -> Option<Self> {
+        let words = data
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|word| u16::from_le_bytes(*word))
+            .collect::<Vec<u16>>();
+
+        let header_size = *words.first()? as usize;
+        let id = *words.get(1)?;
+        let section = words.get(header_size..)?;
+
+        // The section size covers the multi-byte table, the glyph table and the DBCS tables.
+        let section = section.get(1..=*section.first()? as usize)?;
+        let table = Box::new(<[u16; 256]>::try_from(section.get(..256)?).ok()?);
+
+        // Skips the OEM glyph table, when present.
+        let (&glyphs, rest) = section.get(256..)?.split_first()?;
+        let rest = if glyphs != 0 { rest.get(256..)? } else { rest };
+
+        // A non-zero count of DBCS ranges introduces the lead byte offsets.
+        let (&ranges, offsets) = rest.split_first()?;
+        let dbcs = (ranges != 0 && offsets.len() >= 256).then(|| offsets.to_vec());
+
+        Some(Self {
+            id,
+            decoder: Decoder::Table(table, dbcs),
+        })
+    }
+
+    /// Returns the code page identifier (e.g., `1252`).
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns true for the double-byte code pages (e.g., `932` for Japanese).
+    pub fn is_double_byte(&self) -> bool {
+        matches!(self.decoder, Decoder::Table(_, Some(_)))
+    }
+
+    /// Decodes bytes in the code page. Bytes without a mapping are replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded text.
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        if self.decoder == Decoder::Utf8 {
+            return String::from_utf8_lossy(bytes).into_owned();
+        }
+
+        let mut text = String::with_capacity(bytes.len());
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (c, len) = self
+                .decode_char(rest)
+                .unwrap_or((char::REPLACEMENT_CHARACTER, 1));
+            text.push(c);
+            rest = &rest[len..];
+        }
+
+        text
+    }
+
+    /// Decodes the character at the start of `bytes`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((char, usize))` - The character and the number of bytes it takes.
+    /// * `None` - If the bytes do not start with a valid character.
+    pub(crate) fn decode_char(&self, bytes: &[u8]) -> Option<(char, usize)> {
+        let &lead = bytes.first()?;
+        match &self.decoder {
+            Decoder::Utf8 => {
+                let len = match lead {
+                    0x00..0x80 => 1,
+                    0xC2..0xE0 => 2,
+                    0xE0..0xF0 => 3,
+                    0xF0..0xF5 => 4,
+                    _ => return None,
+                };
+
+                let c = std::str::from_utf8(bytes.get(..len)?)
+                    .ok()?
+                    .chars()
+                    .next()?;
+                Some((c, len))
+            }
+            Decoder::Table(table, dbcs) => {
+                let offset = dbcs
+                    .as_ref()
+                    .and_then(|dbcs| Some((dbcs, *dbcs.get(lead as usize)? as usize)))
+                    .filter(|(_, offset)| *offset != 0);
+
+                match offset {
+                    Some((dbcs, offset)) => {
+                        let &trail = bytes.get(1)?;
+                        let unit = *dbcs.get(offset + trail as usize)?;
+                        Some((char::from_u32(unit as u32)?, 2))
+                    }
+                    None => Some((char::from_u32(table[lead as usize] as u32)?, 1)),
+                }
+            }
+        }
+    }
+
+    /// Returns the table mapping every byte to the character of the same value.
+    fn latin1_table() -> Box<[u16; 256]> {
+        Box::new(std::array::from_fn(|byte| byte as u16))
+    }
+}
+
+impl fmt::Debug for CodePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CodePage")
+            .field("id", &self.id)
+            .field("double_byte", &self.is_double_byte())
+            .finish()
+    }
+}
+
+impl fmt::Display for CodePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.id {
+            CP_UTF8 => write!(f, "UTF-8"),
+            id => write!(f, "CP{id}"),
+        }
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns the ANSI code page of the captured process, read from the NLS table
+    /// pointed to by `PEB.AnsiCodePageData`.
+    ///
+    /// When the table itself was not captured but its header was, the code page is
+    /// identified and replaced by the matching built-in code page, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(CodePage)` - The code page the process used for ANSI strings.
+    /// * `None` - If the PEB or the NLS table is not present in the dump.
+    pub fn ansi_code_page(&self) -> Option<CodePage> {
+        let table = self.process()?.ansi_code_page_data()?;
+        let header_size = self.read_u16(table).ok()? as u64;
+        let id = self.read_u16(table + 2).ok()?;
+
+        let section_size = self
+            .read_u16(table + header_size * 2)
+            .ok()? as u64;
+        let len = ((header_size + 1 + section_size) * 2) as usize;
+        if len <= MAX_NLS_TABLE_SIZE
+            && let Some(code_page) = self
+                .read_bytes(table, len)
+                .ok()
+                .and_then(|data| CodePage::from_nls(&data))
+        {
+            return Some(code_page);
+        }
+
+        CodePage::builtin(id)
+    }
+
+    /// Returns the code page used to decode ANSI strings: the one set with
+    /// [`crate::options::ParseOptions::code_page`], else the ANSI code page of the
+    /// process, else Windows-1252.
+    pub fn code_page(&self) -> CodePage {
+        self.configured_code_page()
+            .unwrap_or_else(CodePage::windows_1252)
+    }
+
+    /// Returns the code page set by the caller, or the ANSI code page of the process.
+    pub(crate) fn configured_code_page(&self) -> Option<CodePage> {
+        self.code_page
+            .clone()
+            .or_else(|| self.ansi_code_page())
+    }
+}
@@ -0,0 +1,173 @@
+use std::{fmt, io::Cursor};
+use binrw::BinRead;
+use crate::parse::Module;
+use crate::reader::decode_utf16;
+use crate::data::*;
+
+/// Size of the `IMAGE_DEBUG_MISC` header, before the data.
+const IMAGE_DEBUG_MISC_HEADER_SIZE: usize = 12;
+
+impl fmt::Display for GUID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08X}-{:04X}-{:04X}-", self.Data1, self.Data2, self.Data3)?;
+        for (index, byte) in self.Data4.iter().enumerate() {
+            if index == 2 {
+                write!(f, "-")?;
+            }
+
+            write!(f, "{byte:02X}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Identifies the PDB matching a module.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PdbSignature {
+    /// A PDB 7.0 GUID, found in `RSDS` records.
+    Guid(GUID),
+
+    /// A PDB 2.0 timestamp, found in `NB10` records.
+    Timestamp(u32),
+}
+
+/// Represents the debug information decoded from a module's CodeView record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugInfo {
+    /// The signature identifying the PDB.
+    pub signature: PdbSignature,
+
+    /// The age of the PDB, incremented each time it is updated.
+    pub age: u32,
+
+    /// The path of the PDB, as recorded by the linker.
+    pub pdb_path: String,
+}
+
+impl DebugInfo {
+    /// Decodes a CodeView record.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The raw bytes of an `RSDS` or `NB10` CodeView record.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DebugInfo)` - If the record is in a supported format.
+    /// * `None` - If the record is empty, truncated, or in an unknown format.
+    pub fn parse(record: &[u8]) -> Option<Self> {
+        let signature = u32::from_le_bytes(record.get(..4)?.try_into().ok()?);
+        let mut cursor = Cursor::new(record);
+
+        match signature {
+            CV_SIGNATURE_RSDS => {
+                let info = CV_INFO_PDB70::read(&mut cursor).ok()?;
+                Some(Self {
+                    signature: PdbSignature::Guid(info.Signature),
+                    age: info.Age,
+                    pdb_path: info.PdbFileName.to_string(),
+                })
+            }
+            CV_SIGNATURE_NB10 => {
+                let info = CV_INFO_PDB20::read(&mut cursor).ok()?;
+                Some(Self {
+                    signature: PdbSignature::Timestamp(info.Signature),
+                    age: info.Age,
+                    pdb_path: info.PdbFileName.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the file name of the PDB, without its directory.
+    pub fn pdb_name(&self) -> &str {
+        self.pdb_path
+            .rsplit(['\\', '/'])
+            .next()
+            .unwrap_or(&self.pdb_path)
+    }
+
+    /// Returns the identifier used by symbol servers to locate the PDB.
+    ///
+    /// This is the signature in uppercase hexadecimal (the GUID without dashes
+    /// for PDB 7.0) followed by the age in hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     if let Some(info) = module.debug_info() {
+    ///         println!("{}/{}/{}", info.pdb_name(), info.debug_id(), info.pdb_name());
+    ///     }
+    /// }
+    /// ```
+    pub fn debug_id(&self) -> String {
+        match self.signature {
+            PdbSignature::Guid(guid) => format!("{}{:X}", guid.to_string().replace('-', ""), self.age),
+            PdbSignature::Timestamp(timestamp) => format!("{timestamp:08X}{:X}", self.age),
+        }
+    }
+}
+
+impl Module<'_> {
+    /// Decodes the CodeView record of the module into its PDB identification.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DebugInfo)` - The PDB signature, age and path of the module.
+    /// * `None` - If the module has no CodeView record or it is in an unsupported format.
+    pub fn debug_info(&self) -> Option<DebugInfo> {
+        DebugInfo::parse(self.cv_record)
+    }
+
+    /// Decodes the MISC record of the module into the path of its DBG file.
+    ///
+    /// MISC records come from images whose debug information was split into a `.dbg`
+    /// file, as built by older toolchains; modern images only carry a CodeView record.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The path of the DBG file, as recorded by the linker.
+    /// * `None` - If the module has no MISC record, or it holds no file name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     if let Some(path) = module.dbg_path() {
+    ///         println!("{} -> {path}", module.name().unwrap_or("???"));
+    ///     }
+    /// }
+    /// ```
+    pub fn dbg_path(&self) -> Option<String> {
+        let header = IMAGE_DEBUG_MISC::read(&mut Cursor::new(self.misc_record)).ok()?;
+        if header.DataType != IMAGE_DEBUG_MISC_EXENAME {
+            return None;
+        }
+
+        // The data is padded with zeros up to `Length`, which may not match the record size.
+        let end = (header.Length as usize).min(self.misc_record.len());
+        let data = self
+            .misc_record
+            .get(IMAGE_DEBUG_MISC_HEADER_SIZE..end)?;
+        let path = if header.Unicode != 0 {
+            decode_utf16(data)
+        } else {
+            String::from_utf8_lossy(data).into_owned()
+        };
+
+        let path = path
+            .split('\0')
+            .next()
+            .unwrap_or_default();
+        (!path.is_empty()).then(|| path.to_string())
+    }
+}
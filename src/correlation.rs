@@ -0,0 +1,296 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+use crate::exception::ExceptionCode;
+use crate::parse::UserDump;
+
+/// Identifies a crash independently of the address the faulting module was loaded at.
+///
+/// Two dumps share a signature when they hold the same exception code raised at the
+/// same offset of the same module.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CrashSignature {
+    /// The exception code.
+    pub code: u32,
+
+    /// The lowercase name of the module containing the exception address, if any.
+    pub module: Option<String>,
+
+    /// The offset of the exception address in the module, or the address itself
+    /// when it lies outside every module.
+    pub offset: u64,
+}
+
+impl CrashSignature {
+    /// Computes the signature of the crash captured in a dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The parsed dump.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(CrashSignature)` - The signature of the exception.
+    /// * `None` - If the dump holds no exception.
+    pub fn new(dump: &UserDump) -> Option<Self> {
+        let exception = dump.exception()?;
+        let module = dump.module_at(exception.address);
+        Some(Self {
+            code: exception.code,
+            module: module
+                .and_then(|module| module.name())
+                .map(str::to_lowercase),
+            offset: module.map_or(exception.address, |module| exception.address - module.range.start),
+        })
+    }
+}
+
+impl fmt::Display for CrashSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at ", ExceptionCode::from(self.code))?;
+        match &self.module {
+            Some(module) => write!(f, "{module}+{:#x}", self.offset),
+            None => write!(f, "{:#x}", self.offset),
+        }
+    }
+}
+
+/// Identifies a module build: its lowercase file name and the debug id of its PDB.
+///
+/// Builds of a module without a CodeView record are told apart by name only.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ModuleKey {
+    /// The lowercase file name of the module (e.g., `vendor_hook.dll`).
+    pub name: String,
+
+    /// The debug id of the module (see [`crate::codeview::DebugInfo::debug_id`]), if any.
+    pub debug_id: Option<String>,
+}
+
+impl fmt::Display for ModuleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.debug_id {
+            Some(debug_id) => write!(f, "{} ({debug_id})", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// Indexes which modules were loaded when each crash signature was seen, across a
+/// corpus of dumps.
+///
+/// Dumps without an exception count towards the prevalence of the modules only.
+/// Queries by name match every build of a module, case-insensitively.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, correlation::CoOccurrenceIndex};
+///
+/// let mut index = CoOccurrenceIndex::new();
+/// for path in ["crash1.dmp", "crash2.dmp", "crash3.dmp"] {
+///     index.add(&UserDump::new(path).unwrap());
+/// }
+///
+/// for (signature, count) in index.signatures_only_with("vendor_hook.dll") {
+///     println!("{count:>4} {signature}");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoOccurrenceIndex {
+    /// The number of dumps added.
+    dumps: usize,
+
+    /// Every signature seen, with the number of dumps holding it.
+    signatures: BTreeMap<CrashSignature, usize>,
+
+    /// Every module seen, with the number of dumps loading it.
+    modules: BTreeMap<ModuleKey, usize>,
+
+    /// For every module, the signatures seen while it was loaded, with their count.
+    pairs: BTreeMap<ModuleKey, BTreeMap<CrashSignature, usize>>,
+}
+
+impl CoOccurrenceIndex {
+    /// Creates a new, empty [`CoOccurrenceIndex`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds an index from a corpus of dumps.
+    ///
+    /// # Arguments
+    ///
+    /// * `dumps` - The dumps to index.
+    pub fn from_dumps<'d, 'a: 'd>(dumps: impl IntoIterator<Item = &'d UserDump<'a>>) -> Self {
+        let mut index = Self::new();
+        for dump in dumps {
+            index.add(dump);
+        }
+
+        index
+    }
+
+    /// Adds a dump to the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump to index.
+    ///
+    /// # Returns
+    ///
+    /// * The signature of the crash in the dump, if it holds an exception.
+    pub fn add(&mut self, dump: &UserDump) -> Option<CrashSignature> {
+        let signature = CrashSignature::new(dump);
+        if let Some(signature) = &signature {
+            *self
+                .signatures
+                .entry(signature.clone())
+                .or_insert(0) += 1;
+        }
+
+        for key in module_keys(dump) {
+            if let Some(signature) = &signature {
+                *self
+                    .pairs
+                    .entry(key.clone())
+                    .or_default()
+                    .entry(signature.clone())
+                    .or_insert(0) += 1;
+            }
+
+            *self.modules.entry(key).or_insert(0) += 1;
+        }
+
+        self.dumps += 1;
+        signature
+    }
+
+    /// Returns the number of dumps added.
+    pub fn dumps(&self) -> usize {
+        self.dumps
+    }
+
+    /// Returns every signature seen, with the number of dumps holding it.
+    pub fn signatures(&self) -> impl Iterator<Item = (&CrashSignature, usize)> {
+        self.signatures
+            .iter()
+            .map(|(signature, count)| (signature, *count))
+    }
+
+    /// Returns every module build seen, with the number of dumps loading it.
+    pub fn modules(&self) -> impl Iterator<Item = (&ModuleKey, usize)> {
+        self.modules
+            .iter()
+            .map(|(key, count)| (key, *count))
+    }
+
+    /// Returns the number of dumps holding a signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The signature to count.
+    pub fn occurrences(&self, signature: &CrashSignature) -> usize {
+        self.signatures
+            .get(signature)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the signatures seen while a module was loaded, with the number of
+    /// dumps holding both, the most frequent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The file name of the module (e.g., `vendor_hook.dll`).
+    pub fn signatures_with(&self, module: &str) -> Vec<(CrashSignature, usize)> {
+        let mut signatures = self
+            .co_occurrences(module)
+            .into_iter()
+            .collect::<Vec<_>>();
+        signatures.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        signatures
+    }
+
+    /// Returns the signatures seen only while a module was loaded, with the number
+    /// of dumps holding them, the most frequent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The file name of the module (e.g., `vendor_hook.dll`).
+    pub fn signatures_only_with(&self, module: &str) -> Vec<(CrashSignature, usize)> {
+        let mut signatures = self
+            .co_occurrences(module)
+            .into_iter()
+            .filter(|(signature, count)| *count == self.occurrences(signature))
+            .collect::<Vec<_>>();
+        signatures.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        signatures
+    }
+
+    /// Returns the module builds loaded in every dump holding a signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The signature to look up.
+    ///
+    /// # Returns
+    ///
+    /// * The module builds, empty when the signature was never seen.
+    pub fn modules_always_with(&self, signature: &CrashSignature) -> Vec<&ModuleKey> {
+        let occurrences = self.occurrences(signature);
+        if occurrences == 0 {
+            return Vec::new();
+        }
+
+        self.pairs
+            .iter()
+            .filter(|(_, signatures)| signatures.get(signature) == Some(&occurrences))
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Counts the dumps holding each signature while any build of a module was loaded.
+    fn co_occurrences(&self, module: &str) -> BTreeMap<CrashSignature, usize> {
+        let module = module.to_lowercase();
+        let mut counts = BTreeMap::new();
+        for (_, signatures) in self
+            .pairs
+            .iter()
+            .filter(|(key, _)| key.name == module)
+        {
+            for (signature, count) in signatures {
+                *counts
+                    .entry(signature.clone())
+                    .or_insert(0) += count;
+            }
+        }
+
+        counts
+    }
+}
+
+/// Returns the keys of the modules of a dump, without duplicates.
+///
+/// A name is kept once per dump even when several builds of it are loaded, so the
+/// counts of a name never exceed the number of dumps.
+fn module_keys(dump: &UserDump) -> BTreeSet<ModuleKey> {
+    let mut names = BTreeSet::new();
+    dump.modules()
+        .values()
+        .filter_map(|module| {
+            let name = module.name()?.to_lowercase();
+            names
+                .insert(name.clone())
+                .then(|| ModuleKey {
+                    name,
+                    debug_id: module
+                        .debug_info()
+                        .map(|info| info.debug_id()),
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,243 @@
+use crate::data::CPU_INFORMATION;
+use crate::parse::{Arch, System};
+
+/// Names of the `CPUID` leaf 1 `EDX` feature bits, indexed by bit.
+const CPUID_FEATURES: [(u32, &str); 29] = [
+    (0, "FPU"),
+    (1, "VME"),
+    (2, "DE"),
+    (3, "PSE"),
+    (4, "TSC"),
+    (5, "MSR"),
+    (6, "PAE"),
+    (7, "MCE"),
+    (8, "CX8"),
+    (9, "APIC"),
+    (11, "SEP"),
+    (12, "MTRR"),
+    (13, "PGE"),
+    (14, "MCA"),
+    (15, "CMOV"),
+    (16, "PAT"),
+    (17, "PSE36"),
+    (18, "PSN"),
+    (19, "CLFSH"),
+    (21, "DS"),
+    (22, "ACPI"),
+    (23, "MMX"),
+    (24, "FXSR"),
+    (25, "SSE"),
+    (26, "SSE2"),
+    (27, "SS"),
+    (28, "HTT"),
+    (29, "TM"),
+    (31, "PBE"),
+];
+
+/// Names of the `CPUID` leaf `0x80000001` `EDX` feature bits reported by AMD processors.
+const AMD_EXTENDED_FEATURES: [(u32, &str); 9] = [
+    (11, "SYSCALL"),
+    (20, "NX"),
+    (22, "MMXEXT"),
+    (25, "FFXSR"),
+    (26, "PDPE1GB"),
+    (27, "RDTSCP"),
+    (29, "LM"),
+    (30, "3DNOWEXT"),
+    (31, "3DNOW"),
+];
+
+/// Names of the `PF_*` processor features reported by `IsProcessorFeaturePresent`, indexed by feature.
+const PROCESSOR_FEATURES: [&str; 46] = [
+    "FP_ERRATA",
+    "FP_EMULATED",
+    "CMPXCHG8B",
+    "MMX",
+    "PPC_MOVEMEM_64BIT",
+    "ALPHA_BYTE",
+    "SSE",
+    "3DNOW",
+    "RDTSC",
+    "PAE",
+    "SSE2",
+    "SSE_DAZ",
+    "NX",
+    "SSE3",
+    "CMPXCHG16B",
+    "CMP8XCHG16",
+    "CHANNELS",
+    "XSAVE",
+    "ARM_VFP_32",
+    "ARM_NEON",
+    "SLAT",
+    "VIRT_FIRMWARE",
+    "RDWRFSGSBASE",
+    "FASTFAIL",
+    "ARM_DIVIDE",
+    "ARM_64BIT_LOADSTORE_ATOMIC",
+    "ARM_EXTERNAL_CACHE",
+    "ARM_FMAC",
+    "RDRAND",
+    "ARM_V8",
+    "ARM_V8_CRYPTO",
+    "ARM_V8_CRC32",
+    "RDTSCP",
+    "RDPID",
+    "ARM_V81_ATOMIC",
+    "MONITORX",
+    "SSSE3",
+    "SSE4_1",
+    "SSE4_2",
+    "AVX",
+    "AVX2",
+    "AVX512F",
+    "ERMS",
+    "ARM_V82_DP",
+    "ARM_V83_JSCVT",
+    "ARM_V83_LRCPC",
+];
+
+/// Represents the processor information stored after `MINIDUMP_SYSTEM_INFO`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CpuInfo {
+    /// The `CPUID` information of an x86 or x64 processor.
+    X86 {
+        /// The vendor identification string (e.g., `GenuineIntel`).
+        vendor_id: [u8; 12],
+
+        /// The `EAX` value of `CPUID` leaf 1 (family, model and stepping).
+        version_information: u32,
+
+        /// The `EDX` value of `CPUID` leaf 1.
+        feature_information: u32,
+
+        /// The `EDX` value of `CPUID` leaf `0x80000001`, on AMD processors.
+        amd_extended_cpu_features: u32,
+    },
+
+    /// The `PF_*` processor feature bits, as reported by `IsProcessorFeaturePresent`.
+    Other {
+        /// The feature bits, indexed by `PF_*` value.
+        processor_features: [u64; 2],
+    },
+}
+
+impl Default for CpuInfo {
+    fn default() -> Self {
+        CpuInfo::Other { processor_features: [0; 2] }
+    }
+}
+
+impl CpuInfo {
+    /// Interprets the `CPU_INFORMATION` union for the given architecture.
+    ///
+    /// Windows fills `OtherCpuInfo` on x64 while Breakpad and Crashpad fill `X86CpuInfo`,
+    /// so on x86 and x64 the `CPUID` form is used when the vendor string is printable.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The raw union.
+    /// * `arch` - The processor architecture of the dump.
+    pub(crate) fn new(info: CPU_INFORMATION, arch: Arch) -> Self {
+        let words = info.Data;
+        let mut vendor_id = [0; 12];
+        for (chunk, word) in vendor_id
+            .as_chunks_mut::<4>()
+            .0
+            .iter_mut()
+            .zip(words)
+        {
+            *chunk = word.to_le_bytes();
+        }
+
+        let printable = vendor_id
+            .iter()
+            .all(|byte| byte.is_ascii_graphic() || *byte == b' ');
+        if matches!(arch, Arch::X86 | Arch::X64) && printable {
+            return CpuInfo::X86 {
+                vendor_id,
+                version_information: words[3],
+                feature_information: words[4],
+                amd_extended_cpu_features: words[5],
+            };
+        }
+
+        CpuInfo::Other {
+            processor_features: [words[0] as u64 | (words[1] as u64) << 32, words[2] as u64 | (words[3] as u64) << 32],
+        }
+    }
+}
+
+impl System {
+    /// Returns the vendor identification string of the processor (e.g., `GenuineIntel` or `AuthenticAMD`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The vendor, if the dump stores the `CPUID` form of the processor information.
+    /// * `None` - Otherwise, which is the case of Windows dumps of x64 and ARM processes.
+    pub fn cpu_vendor(&self) -> Option<String> {
+        match &self.cpu {
+            CpuInfo::X86 { vendor_id, .. } => Some(
+                String::from_utf8_lossy(vendor_id)
+                    .trim_end()
+                    .to_string(),
+            ),
+            CpuInfo::Other { .. } => None,
+        }
+    }
+
+    /// Returns the names of the features supported by the processor.
+    ///
+    /// The names follow the `CPUID` feature flags (e.g., `SSE2`, `NX`) for the `CPUID`
+    /// form, or the `PF_*` processor features (e.g., `AVX2`, `AVX512F`, `ARM_V8_CRYPTO`)
+    /// otherwise. Only the features known to the dump writer are reported; notably,
+    /// AVX-512 support is only visible through the `PF_*` form.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let features = dump.system.cpu_features();
+    /// if features.contains(&"AVX512F") {
+    ///     println!("Captured on an AVX-512 machine ({:?})", dump.system.cpu_vendor());
+    /// }
+    /// ```
+    pub fn cpu_features(&self) -> Vec<&'static str> {
+        let set = |value: u64, bit: u32| bit < 64 && value >> bit & 1 != 0;
+        match self.cpu {
+            CpuInfo::X86 {
+                feature_information,
+                amd_extended_cpu_features,
+                vendor_id,
+                ..
+            } => {
+                let mut features = CPUID_FEATURES
+                    .iter()
+                    .filter(|(bit, _)| set(feature_information.into(), *bit))
+                    .map(|(_, name)| *name)
+                    .collect::<Vec<_>>();
+
+                // The extended word is only meaningful on AMD processors.
+                if &vendor_id == b"AuthenticAMD" {
+                    features.extend(
+                        AMD_EXTENDED_FEATURES
+                            .iter()
+                            .filter(|(bit, _)| set(amd_extended_cpu_features.into(), *bit))
+                            .map(|(_, name)| *name),
+                    );
+                }
+
+                features
+            }
+            CpuInfo::Other { processor_features } => PROCESSOR_FEATURES
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| set(processor_features[index / 64], (index % 64) as u32))
+                .map(|(_, name)| *name)
+                .collect(),
+        }
+    }
+}
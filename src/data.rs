@@ -3,6 +3,9 @@
 /// Maximum number of parameters associated with an exception.
 pub const EXCEPTION_MAXIMUM_PARAMETERS: usize = 15;
 
+/// Exception flag marking an exception that cannot be continued.
+pub const EXCEPTION_NONCONTINUABLE: u32 = 0x1;
+
 /// Signature to identify Minidump files ("MDMP" in ASCII).
 pub const MINIDUMP_SIGNATURE: u32 = 0x504D_444D;
 
@@ -15,6 +18,75 @@ pub const ARCH_X64: u16 = 9;
 /// Architecture code for 32-bit systems (x86).
 pub const ARCH_X86: u16 = 0;
 
+/// Architecture code for 32-bit ARM systems.
+pub const ARCH_ARM: u16 = 5;
+
+/// Architecture code for Intel Itanium systems.
+pub const ARCH_IA64: u16 = 6;
+
+/// Architecture code for 64-bit ARM systems (AArch64).
+pub const ARCH_ARM64: u16 = 12;
+
+/// Indicates that the memory pages within the region are committed.
+pub const MEM_COMMIT: u32 = 0x1_000;
+
+/// Indicates that the memory pages within the region are mapped into the view of an image section.
+pub const MEM_IMAGE: u32 = 0x1_000_000;
+
+/// Indicates that the memory pages within the region are private (not shared with other processes).
+pub const MEM_PRIVATE: u32 = 0x20_000;
+
+/// Indicates that the memory pages within the region are mapped into the view of a data section.
+pub const MEM_MAPPED: u32 = 0x40_000;
+
+/// Signature of the MS-DOS header ("MZ" in ASCII).
+pub const IMAGE_DOS_SIGNATURE: u16 = 0x5A4D;
+
+/// Signature of the NT headers ("PE\0\0" in ASCII).
+pub const IMAGE_NT_SIGNATURE: u32 = 0x0000_4550;
+
+/// Magic value of a 32-bit optional header.
+pub const IMAGE_NT_OPTIONAL_HDR32_MAGIC: u16 = 0x10B;
+
+/// Magic value of a 64-bit optional header.
+pub const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20B;
+
+/// Number of entries in the data directory of the optional header.
+pub const IMAGE_NUMBEROF_DIRECTORY_ENTRIES: usize = 16;
+
+/// Platform identifier of Windows NT based systems.
+pub const VER_PLATFORM_WIN32_NT: u32 = 2;
+
+/// Product type of a workstation edition of Windows.
+pub const VER_NT_WORKSTATION: u8 = 1;
+
+/// Product type of a Windows server acting as a domain controller.
+pub const VER_NT_DOMAIN_CONTROLLER: u8 = 2;
+
+/// Product type of a Windows server edition.
+pub const VER_NT_SERVER: u8 = 3;
+
+/// Platform identifier of macOS dumps written by Breakpad/Crashpad.
+pub const MD_OS_MAC_OS_X: u32 = 0x8101;
+
+/// Platform identifier of iOS dumps written by Breakpad/Crashpad.
+pub const MD_OS_IOS: u32 = 0x8102;
+
+/// Platform identifier of Linux dumps written by Breakpad/Crashpad.
+pub const MD_OS_LINUX: u32 = 0x8201;
+
+/// Platform identifier of Solaris dumps written by Breakpad.
+pub const MD_OS_SOLARIS: u32 = 0x8202;
+
+/// Platform identifier of Android dumps written by Breakpad/Crashpad.
+pub const MD_OS_ANDROID: u32 = 0x8203;
+
+/// Platform identifier of Fuchsia dumps written by Crashpad.
+pub const MD_OS_FUCHSIA: u32 = 0x8206;
+
+/// Breakpad stream holding the contents of `/etc/lsb-release` on Linux.
+pub const MD_LINUX_LSB_RELEASE: u32 = 0x4767_0005;
+
 /// Contains header information for the minidump file.
 ///
 /// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_header).
@@ -167,6 +239,21 @@ pub struct MINIDUMP_MEMORY_INFO {
     pub alignment2: u32,
 }
 
+/// Contains a list of memory ranges, each with its own location in the file.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_memory_list).
+#[derive(Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_MEMORY_LIST {
+    /// The number of structures in the MemoryRanges array.
+    pub NumberOfMemoryRanges: u32,
+
+    /// Memory descriptors.
+    #[br(count = NumberOfMemoryRanges)]
+    pub MemoryRanges: Vec<MINIDUMP_MEMORY_DESCRIPTOR>,
+}
+
 /// Contains a list of memory ranges.
 ///
 /// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_memory64_list).
@@ -241,6 +328,27 @@ pub struct MINIDUMP_SYSTEM_INFO {
 
     /// This member is reserved for future use.
     pub Reserved2: u16,
+
+    /// The processor information that follows the structure.
+    pub Cpu: CPU_INFORMATION,
+}
+
+/// Contains the processor information of `MINIDUMP_SYSTEM_INFO`.
+///
+/// The structure is a union: x86 systems store `X86CpuInfo` (the CPUID vendor, version
+/// and feature words), other systems store `OtherCpuInfo` (the `PF_*` processor
+/// feature bits). Both are kept here as raw words.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-cpu_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct CPU_INFORMATION {
+    /// The raw words of the union.
+    ///
+    /// For `X86CpuInfo`: `VendorId[3]`, `VersionInformation`, `FeatureInformation`
+    /// and `AMDExtendedCpuFeatures`. For `OtherCpuInfo`: `ProcessorFeatures[2]`.
+    pub Data: [u32; 6],
 }
 
 /// Contains a list of modules.
@@ -493,11 +601,406 @@ pub struct MINIDUMP_HANDLE_DESCRIPTOR {
     /// The meaning of this member depends on the handle type and the operating system.
     pub PointerCount: u32,
 
+    /// An RVA to a MINIDUMP_HANDLE_OBJECT_INFORMATION structure that specifies object-specific information.
+    /// Only present in `MINIDUMP_HANDLE_DESCRIPTOR_2`, zero otherwise.
+    #[br(if(size_of_descriptor >= MINIDUMP_HANDLE_DESCRIPTOR_2_SIZE))]
+    pub ObjectInfoRva: u32,
+
+    /// Reserved for future use; must be zero.
+    #[br(if(size_of_descriptor >= MINIDUMP_HANDLE_DESCRIPTOR_2_SIZE))]
+    pub Reserved0: u32,
+
     /// Extra space to adjust the size of the descriptor.
-    #[br(pad_after = (size_of_descriptor - size_of::<Self>() as u32) as usize)]
+    #[br(pad_after = size_of_descriptor.saturating_sub(if size_of_descriptor >= MINIDUMP_HANDLE_DESCRIPTOR_2_SIZE {
+        MINIDUMP_HANDLE_DESCRIPTOR_2_SIZE
+    } else {
+        MINIDUMP_HANDLE_DESCRIPTOR_SIZE
+    }) as usize)]
     _padding: (),
 }
 
+/// Size of `MINIDUMP_HANDLE_DESCRIPTOR`, in bytes.
+pub const MINIDUMP_HANDLE_DESCRIPTOR_SIZE: u32 = 32;
+
+/// Size of `MINIDUMP_HANDLE_DESCRIPTOR_2`, in bytes.
+pub const MINIDUMP_HANDLE_DESCRIPTOR_2_SIZE: u32 = 40;
+
+/// No object-specific information.
+pub const MINI_HANDLE_OBJECT_INFORMATION_NONE: u32 = 0;
+
+/// Thread object information.
+pub const MINI_THREAD_INFORMATION_1: u32 = 1;
+
+/// Mutant object information.
+pub const MINI_MUTANT_INFORMATION_1: u32 = 2;
+
+/// Mutant object information, including the owner.
+pub const MINI_MUTANT_INFORMATION_2: u32 = 3;
+
+/// Process object information.
+pub const MINI_PROCESS_INFORMATION_1: u32 = 4;
+
+/// Process object information, extended.
+pub const MINI_PROCESS_INFORMATION_2: u32 = 5;
+
+/// Event object information.
+pub const MINI_EVENT_INFORMATION_1: u32 = 6;
+
+/// Section object information.
+pub const MINI_SECTION_INFORMATION_1: u32 = 7;
+
+/// Semaphore object information.
+pub const MINI_SEMAPHORE_INFORMATION_1: u32 = 8;
+
+/// Header of an object-specific information block, chained from `MINIDUMP_HANDLE_DESCRIPTOR_2.ObjectInfoRva`.
+///
+/// The header is followed by `SizeOfInfo` bytes of information whose layout depends on `InfoType`.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_handle_object_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_HANDLE_OBJECT_INFORMATION {
+    /// An RVA to the next MINIDUMP_HANDLE_OBJECT_INFORMATION structure, or zero for the last one.
+    pub NextInfoRva: u32,
+
+    /// The type of information (`MINI_*_INFORMATION_*`).
+    pub InfoType: u32,
+
+    /// The size of the information that follows this header, in bytes.
+    pub SizeOfInfo: u32,
+}
+
+/// Maximum number of return addresses recorded in an Application Verifier backtrace.
+pub const AVRF_MAX_TRACES: usize = 32;
+
+/// The handle trace entry is unused.
+pub const OPERATION_DB_UNUSED: u32 = 0;
+
+/// The handle was opened.
+pub const OPERATION_DB_OPEN: u32 = 1;
+
+/// The handle was closed.
+pub const OPERATION_DB_CLOSE: u32 = 2;
+
+/// The handle was referenced while invalid.
+pub const OPERATION_DB_BAD_REF: u32 = 3;
+
+/// Represents the header of a handle operation list stream.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_handle_operation_list)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_HANDLE_OPERATION_LIST {
+    /// The size of the header data for the stream, in bytes.
+    pub SizeOfHeader: u32,
+
+    /// The size of each entry following the header, in bytes.
+    pub SizeOfEntry: u32,
+
+    /// The number of entries in the stream.
+    pub NumberOfEntries: u32,
+
+    /// Reserved for future use; must be zero.
+    pub Reserved: u32,
+}
+
+/// Contains the call stack recorded by Application Verifier for a handle operation.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/avrfsdk/ns-avrfsdk-avrf_backtrace_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct AVRF_BACKTRACE_INFORMATION {
+    /// The number of valid return addresses.
+    pub Depth: u32,
+
+    /// The index of the backtrace in the trace database.
+    pub Index: u32,
+
+    /// The return addresses of the call stack.
+    pub ReturnAddresses: [u64; AVRF_MAX_TRACES],
+}
+
+/// Contains a handle operation traced by Application Verifier.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/avrfsdk/ns-avrfsdk-avrf_handle_operation)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct AVRF_HANDLE_OPERATION {
+    /// The handle value.
+    pub Handle: u64,
+
+    /// The identifier of the process that performed the operation.
+    pub ProcessId: u32,
+
+    /// The identifier of the thread that performed the operation.
+    pub ThreadId: u32,
+
+    /// The type of operation (`OPERATION_DB_*`).
+    pub OperationType: u32,
+
+    /// Reserved for future use.
+    pub Spare0: u32,
+
+    /// The call stack of the operation.
+    pub BackTraceInformation: AVRF_BACKTRACE_INFORMATION,
+}
+
+/// Represents the header of a token information stream.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_token_info_list)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_TOKEN_INFO_LIST {
+    /// The size of the token information list, in bytes.
+    pub TokenListSize: u32,
+
+    /// The number of entries in the list.
+    pub TokenListEntries: u32,
+
+    /// The size of this header, in bytes.
+    pub ListHeaderSize: u32,
+
+    /// The size of the header of each entry, in bytes.
+    pub ElementHeaderSize: u32,
+}
+
+/// Contains the header of a token entry, followed by the token information.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_token_info_header)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_TOKEN_INFO_HEADER {
+    /// The size of the entry, including this header, in bytes.
+    pub TokenSize: u32,
+
+    /// The identifier of the token.
+    pub TokenId: u32,
+
+    /// The handle of the token.
+    pub TokenHandle: u64,
+}
+
+/// The basic virtual memory counters of `MINIDUMP_PROCESS_VM_COUNTERS_2` are valid.
+pub const MINIDUMP_PROCESS_VM_COUNTERS: u16 = 0x0001;
+
+/// `PeakVirtualSize` and `VirtualSize` of `MINIDUMP_PROCESS_VM_COUNTERS_2` are valid.
+pub const MINIDUMP_PROCESS_VM_COUNTERS_VIRTUALSIZE: u16 = 0x0002;
+
+/// `PrivateUsage` of `MINIDUMP_PROCESS_VM_COUNTERS_2` is valid.
+pub const MINIDUMP_PROCESS_VM_COUNTERS_EX: u16 = 0x0004;
+
+/// `PrivateWorkingSetSize` and `SharedCommitUsage` of `MINIDUMP_PROCESS_VM_COUNTERS_2` are valid.
+pub const MINIDUMP_PROCESS_VM_COUNTERS_EX2: u16 = 0x0008;
+
+/// The job counters of `MINIDUMP_PROCESS_VM_COUNTERS_2` are valid.
+pub const MINIDUMP_PROCESS_VM_COUNTERS_JOB: u16 = 0x0010;
+
+/// Contains the virtual memory counters of the process, covering both revisions.
+///
+/// Revision 1 has no flags and stops after `PrivateUsage`; the fields it lacks are zeroed.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_process_vm_counters_2)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_PROCESS_VM_COUNTERS_2 {
+    /// The revision of the structure (1 or 2).
+    pub Revision: u16,
+
+    /// The flags that indicate the valid members of this structure (revision 2).
+    pub Flags: u16,
+
+    /// The number of page faults.
+    pub PageFaultCount: u32,
+
+    /// The peak working set size, in bytes.
+    pub PeakWorkingSetSize: u64,
+
+    /// The current working set size, in bytes.
+    pub WorkingSetSize: u64,
+
+    /// The peak paged pool usage, in bytes.
+    pub QuotaPeakPagedPoolUsage: u64,
+
+    /// The current paged pool usage, in bytes.
+    pub QuotaPagedPoolUsage: u64,
+
+    /// The peak nonpaged pool usage, in bytes.
+    pub QuotaPeakNonPagedPoolUsage: u64,
+
+    /// The current nonpaged pool usage, in bytes.
+    pub QuotaNonPagedPoolUsage: u64,
+
+    /// The commit charge of the process, in bytes.
+    pub PagefileUsage: u64,
+
+    /// The peak commit charge of the process, in bytes.
+    pub PeakPagefileUsage: u64,
+
+    /// The peak virtual size of the process, in bytes.
+    #[br(if(Revision >= 2))]
+    pub PeakVirtualSize: u64,
+
+    /// The current virtual size of the process, in bytes.
+    #[br(if(Revision >= 2))]
+    pub VirtualSize: u64,
+
+    /// The private memory usage of the process, in bytes.
+    pub PrivateUsage: u64,
+
+    /// The private working set size, in bytes.
+    #[br(if(Revision >= 2))]
+    pub PrivateWorkingSetSize: u64,
+
+    /// The shared commit usage, in bytes.
+    #[br(if(Revision >= 2))]
+    pub SharedCommitUsage: u64,
+
+    /// The shared commit usage of the job, in bytes.
+    #[br(if(Revision >= 2))]
+    pub JobSharedCommitUsage: u64,
+
+    /// The private commit usage of the job, in bytes.
+    #[br(if(Revision >= 2))]
+    pub JobPrivateCommitUsage: u64,
+
+    /// The peak private commit usage of the job, in bytes.
+    #[br(if(Revision >= 2))]
+    pub JobPeakPrivateCommitUsage: u64,
+
+    /// The private commit limit of the job, in bytes.
+    #[br(if(Revision >= 2))]
+    pub JobPrivateCommitLimit: u64,
+
+    /// The total commit limit of the job, in bytes.
+    #[br(if(Revision >= 2))]
+    pub JobTotalCommitLimit: u64,
+}
+
+/// Contains the basic memory configuration of the system.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_system_basic_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_SYSTEM_BASIC_INFORMATION {
+    /// The resolution of the system timer, in 100-nanosecond units.
+    pub TimerResolution: u32,
+
+    /// The size of a page, in bytes.
+    pub PageSize: u32,
+
+    /// The number of physical pages.
+    pub NumberOfPhysicalPages: u32,
+
+    /// The lowest physical page number.
+    pub LowestPhysicalPageNumber: u32,
+
+    /// The highest physical page number.
+    pub HighestPhysicalPageNumber: u32,
+
+    /// The granularity of virtual allocations, in bytes.
+    pub AllocationGranularity: u32,
+
+    /// The lowest user-mode address.
+    pub MinimumUserModeAddress: u64,
+
+    /// The highest user-mode address.
+    pub MaximumUserModeAddress: u64,
+
+    /// The mask of the active processors.
+    pub ActiveProcessorsAffinityMask: u64,
+
+    /// The number of processors.
+    pub NumberOfProcessors: u32,
+}
+
+/// Contains the state of the system file cache.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_system_filecache_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_SYSTEM_FILECACHE_INFORMATION {
+    /// The current size of the file cache, in bytes.
+    pub CurrentSize: u64,
+
+    /// The peak size of the file cache, in bytes.
+    pub PeakSize: u64,
+
+    /// The number of page faults of the file cache.
+    pub PageFaultCount: u32,
+
+    /// The minimum working set of the file cache, in bytes.
+    pub MinimumWorkingSet: u64,
+
+    /// The maximum working set of the file cache, in bytes.
+    pub MaximumWorkingSet: u64,
+
+    /// The current size of the file cache including transition pages, in pages.
+    pub CurrentSizeIncludingTransitionInPages: u64,
+
+    /// The peak size of the file cache including transition pages, in pages.
+    pub PeakSizeIncludingTransitionInPages: u64,
+
+    /// The number of repurposed transition pages.
+    pub TransitionRePurposeCount: u32,
+
+    /// The flags of the file cache.
+    pub Flags: u32,
+}
+
+/// Contains the commit state of the system.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_system_basic_performance_information)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_SYSTEM_BASIC_PERFORMANCE_INFORMATION {
+    /// The number of available physical pages.
+    pub AvailablePages: u64,
+
+    /// The number of committed pages.
+    pub CommittedPages: u64,
+
+    /// The maximum number of pages that can be committed.
+    pub CommitLimit: u64,
+
+    /// The peak number of committed pages.
+    pub PeakCommitment: u64,
+}
+
+/// Contains the leading part of `MINIDUMP_SYSTEM_MEMORY_INFO_1`.
+///
+/// The detailed `MINIDUMP_SYSTEM_PERFORMANCE_INFORMATION` that follows is not read.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_system_memory_info_1)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_SYSTEM_MEMORY_INFO_1 {
+    /// The revision of the structure.
+    pub Revision: u16,
+
+    /// The flags of the structure.
+    pub Flags: u16,
+
+    /// The basic memory configuration of the system.
+    pub BasicInfo: MINIDUMP_SYSTEM_BASIC_INFORMATION,
+
+    /// The state of the file cache.
+    pub FileCacheInfo: MINIDUMP_SYSTEM_FILECACHE_INFORMATION,
+
+    /// The commit state of the system.
+    pub BasicPerfInfo: MINIDUMP_SYSTEM_BASIC_PERFORMANCE_INFORMATION,
+}
+
 /// Describes a string.
 ///
 /// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_string)
@@ -512,11 +1015,936 @@ pub struct MINIDUMP_STRING {
     pub Buffer: Vec<u16>,
 }
 
+/// Contains a list of thread names.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_thread_name_list)
+#[derive(Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_THREAD_NAME_LIST {
+    /// The number of structures in the ThreadNames array.
+    pub NumberOfThreadNames: u32,
+
+    /// An array of MINIDUMP_THREAD_NAME structures.
+    #[br(count = NumberOfThreadNames)]
+    pub ThreadNames: Vec<MINIDUMP_THREAD_NAME>,
+}
+
+/// Contains the name of a specific thread.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_thread_name)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_THREAD_NAME {
+    /// The identifier of the thread.
+    pub ThreadId: u32,
+
+    /// An RVA to a MINIDUMP_STRING structure that specifies the name of the thread.
+    pub RvaOfThreadName: u64,
+}
+
+/// Contains a list of threads with additional information.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_thread_info_list)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_THREAD_INFO_LIST {
+    /// The size of the header data for the stream, in bytes.
+    pub SizeOfHeader: u32,
+
+    /// The size of each entry following the header, in bytes.
+    pub SizeOfEntry: u32,
+
+    /// The number of entries in the stream.
+    pub NumberOfEntries: u32,
+}
+
+/// A minidump thread info flag: the thread raised the exception that triggered the dump.
+pub const MINIDUMP_THREAD_INFO_ERROR_THREAD: u32 = 0x0000_0001;
+
+/// A minidump thread info flag: the thread was writing the dump.
+pub const MINIDUMP_THREAD_INFO_WRITING_THREAD: u32 = 0x0000_0002;
+
+/// A minidump thread info flag: the thread had exited when the dump was written.
+pub const MINIDUMP_THREAD_INFO_EXITED_THREAD: u32 = 0x0000_0004;
+
+/// A minidump thread info flag: the thread information could not be retrieved.
+pub const MINIDUMP_THREAD_INFO_INVALID_INFO: u32 = 0x0000_0008;
+
+/// A minidump thread info flag: the thread context could not be retrieved.
+pub const MINIDUMP_THREAD_INFO_INVALID_CONTEXT: u32 = 0x0000_0010;
+
+/// A minidump thread info flag: the TEB could not be retrieved.
+pub const MINIDUMP_THREAD_INFO_INVALID_TEB: u32 = 0x0000_0020;
+
+/// Contains thread state information.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_thread_info)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_THREAD_INFO {
+    /// The identifier of the thread.
+    pub ThreadId: u32,
+
+    /// The flags that indicate the thread state (`MINIDUMP_THREAD_INFO_*`).
+    pub DumpFlags: u32,
+
+    /// An `HRESULT` value that indicates the dump status.
+    pub DumpError: u32,
+
+    /// The thread termination status code.
+    pub ExitStatus: u32,
+
+    /// The time when the thread was created, in 100-nanosecond intervals since January 1, 1601 (UTC).
+    pub CreateTime: u64,
+
+    /// The time when the thread exited, in 100-nanosecond intervals since January 1, 1601 (UTC).
+    pub ExitTime: u64,
+
+    /// The time executed in kernel mode, in 100-nanosecond intervals.
+    pub KernelTime: u64,
+
+    /// The time executed in user mode, in 100-nanosecond intervals.
+    pub UserTime: u64,
+
+    /// The starting address of the thread.
+    pub StartAddress: u64,
+
+    /// The processor affinity mask.
+    pub Affinity: u64,
+}
+
+/// Represents the MS-DOS header of a PE image.
+///
+/// For more details, see the [PE format documentation](https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#ms-dos-stub-image-only).
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_DOS_HEADER {
+    /// The magic number ("MZ").
+    pub e_magic: u16,
+
+    /// The fields of the MS-DOS stub header that are irrelevant for PE parsing.
+    pub e_reserved: [u16; 29],
+
+    /// The file offset of the NT headers.
+    pub e_lfanew: u32,
+}
+
+/// Represents the COFF file header of a PE image.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_file_header)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_FILE_HEADER {
+    /// The architecture type of the computer.
+    pub Machine: u16,
+
+    /// The number of sections.
+    pub NumberOfSections: u16,
+
+    /// The low 32 bits of the time stamp of the image.
+    pub TimeDateStamp: u32,
+
+    /// The offset of the symbol table, in bytes, or zero if no COFF symbol table exists.
+    pub PointerToSymbolTable: u32,
+
+    /// The number of symbols in the symbol table.
+    pub NumberOfSymbols: u32,
+
+    /// The size of the optional header, in bytes.
+    pub SizeOfOptionalHeader: u16,
+
+    /// The characteristics of the image.
+    pub Characteristics: u16,
+}
+
+/// Represents a data directory of a PE image.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_data_directory)
+#[derive(Copy, Clone, Debug, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_DATA_DIRECTORY {
+    /// The relative virtual address of the table.
+    pub VirtualAddress: u32,
+
+    /// The size of the table, in bytes.
+    pub Size: u32,
+}
+
+/// Represents the optional header of a 32-bit PE image.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_optional_header32)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_OPTIONAL_HEADER32 {
+    /// The state of the image file.
+    pub Magic: u16,
+
+    /// The major version number of the linker.
+    pub MajorLinkerVersion: u8,
+
+    /// The minor version number of the linker.
+    pub MinorLinkerVersion: u8,
+
+    /// The size of the code section, in bytes.
+    pub SizeOfCode: u32,
+
+    /// The size of the initialized data section, in bytes.
+    pub SizeOfInitializedData: u32,
+
+    /// The size of the uninitialized data section, in bytes.
+    pub SizeOfUninitializedData: u32,
+
+    /// A pointer to the entry point function, relative to the image base address.
+    pub AddressOfEntryPoint: u32,
+
+    /// A pointer to the beginning of the code section, relative to the image base.
+    pub BaseOfCode: u32,
+
+    /// A pointer to the beginning of the data section, relative to the image base.
+    pub BaseOfData: u32,
+
+    /// The preferred address of the first byte of the image when it is loaded in memory.
+    pub ImageBase: u32,
+
+    /// The alignment of sections loaded in memory, in bytes.
+    pub SectionAlignment: u32,
+
+    /// The alignment of the raw data of sections in the image file, in bytes.
+    pub FileAlignment: u32,
+
+    /// The major version number of the required operating system.
+    pub MajorOperatingSystemVersion: u16,
+
+    /// The minor version number of the required operating system.
+    pub MinorOperatingSystemVersion: u16,
+
+    /// The major version number of the image.
+    pub MajorImageVersion: u16,
+
+    /// The minor version number of the image.
+    pub MinorImageVersion: u16,
+
+    /// The major version number of the subsystem.
+    pub MajorSubsystemVersion: u16,
+
+    /// The minor version number of the subsystem.
+    pub MinorSubsystemVersion: u16,
+
+    /// This member is reserved and must be 0.
+    pub Win32VersionValue: u32,
+
+    /// The size of the image, in bytes, including all headers.
+    pub SizeOfImage: u32,
+
+    /// The combined size of the MS-DOS stub, the PE header, and the section headers.
+    pub SizeOfHeaders: u32,
+
+    /// The image file checksum.
+    pub CheckSum: u32,
+
+    /// The subsystem required to run this image.
+    pub Subsystem: u16,
+
+    /// The DLL characteristics of the image.
+    pub DllCharacteristics: u16,
+
+    /// The number of bytes to reserve for the stack.
+    pub SizeOfStackReserve: u32,
+
+    /// The number of bytes to commit for the stack.
+    pub SizeOfStackCommit: u32,
+
+    /// The number of bytes to reserve for the local heap.
+    pub SizeOfHeapReserve: u32,
+
+    /// The number of bytes to commit for the local heap.
+    pub SizeOfHeapCommit: u32,
+
+    /// This member is obsolete.
+    pub LoaderFlags: u32,
+
+    /// The number of directory entries in the remainder of the optional header.
+    pub NumberOfRvaAndSizes: u32,
+
+    /// The data directories of the image.
+    pub DataDirectory: [IMAGE_DATA_DIRECTORY; IMAGE_NUMBEROF_DIRECTORY_ENTRIES],
+}
+
+/// Represents the optional header of a 64-bit PE image.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_optional_header64)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_OPTIONAL_HEADER64 {
+    /// The state of the image file.
+    pub Magic: u16,
+
+    /// The major version number of the linker.
+    pub MajorLinkerVersion: u8,
+
+    /// The minor version number of the linker.
+    pub MinorLinkerVersion: u8,
+
+    /// The size of the code section, in bytes.
+    pub SizeOfCode: u32,
+
+    /// The size of the initialized data section, in bytes.
+    pub SizeOfInitializedData: u32,
+
+    /// The size of the uninitialized data section, in bytes.
+    pub SizeOfUninitializedData: u32,
+
+    /// A pointer to the entry point function, relative to the image base address.
+    pub AddressOfEntryPoint: u32,
+
+    /// A pointer to the beginning of the code section, relative to the image base.
+    pub BaseOfCode: u32,
+
+    /// The preferred address of the first byte of the image when it is loaded in memory.
+    pub ImageBase: u64,
+
+    /// The alignment of sections loaded in memory, in bytes.
+    pub SectionAlignment: u32,
+
+    /// The alignment of the raw data of sections in the image file, in bytes.
+    pub FileAlignment: u32,
+
+    /// The major version number of the required operating system.
+    pub MajorOperatingSystemVersion: u16,
+
+    /// The minor version number of the required operating system.
+    pub MinorOperatingSystemVersion: u16,
+
+    /// The major version number of the image.
+    pub MajorImageVersion: u16,
+
+    /// The minor version number of the image.
+    pub MinorImageVersion: u16,
+
+    /// The major version number of the subsystem.
+    pub MajorSubsystemVersion: u16,
+
+    /// The minor version number of the subsystem.
+    pub MinorSubsystemVersion: u16,
+
+    /// This member is reserved and must be 0.
+    pub Win32VersionValue: u32,
+
+    /// The size of the image, in bytes, including all headers.
+    pub SizeOfImage: u32,
+
+    /// The combined size of the MS-DOS stub, the PE header, and the section headers.
+    pub SizeOfHeaders: u32,
+
+    /// The image file checksum.
+    pub CheckSum: u32,
+
+    /// The subsystem required to run this image.
+    pub Subsystem: u16,
+
+    /// The DLL characteristics of the image.
+    pub DllCharacteristics: u16,
+
+    /// The number of bytes to reserve for the stack.
+    pub SizeOfStackReserve: u64,
+
+    /// The number of bytes to commit for the stack.
+    pub SizeOfStackCommit: u64,
+
+    /// The number of bytes to reserve for the local heap.
+    pub SizeOfHeapReserve: u64,
+
+    /// The number of bytes to commit for the local heap.
+    pub SizeOfHeapCommit: u64,
+
+    /// This member is obsolete.
+    pub LoaderFlags: u32,
+
+    /// The number of directory entries in the remainder of the optional header.
+    pub NumberOfRvaAndSizes: u32,
+
+    /// The data directories of the image.
+    pub DataDirectory: [IMAGE_DATA_DIRECTORY; IMAGE_NUMBEROF_DIRECTORY_ENTRIES],
+}
+
+/// Represents a section header of a PE image.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_section_header)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_SECTION_HEADER {
+    /// An 8-byte, null-padded UTF-8 string.
+    pub Name: [u8; 8],
+
+    /// The total size of the section when loaded into memory, in bytes.
+    pub VirtualSize: u32,
+
+    /// The address of the first byte of the section when loaded into memory, relative to the image base.
+    pub VirtualAddress: u32,
+
+    /// The size of the initialized data on disk, in bytes.
+    pub SizeOfRawData: u32,
+
+    /// A file pointer to the first page within the COFF file.
+    pub PointerToRawData: u32,
+
+    /// A file pointer to the beginning of the relocation entries for the section.
+    pub PointerToRelocations: u32,
+
+    /// A file pointer to the beginning of the line-number entries for the section.
+    pub PointerToLinenumbers: u32,
+
+    /// The number of relocation entries for the section.
+    pub NumberOfRelocations: u16,
+
+    /// The number of line-number entries for the section.
+    pub NumberOfLinenumbers: u16,
+
+    /// The characteristics of the section.
+    pub Characteristics: u32,
+}
+
+/// Specifies a date and time, using individual members for the month, day, year, weekday, hour, minute, second, and millisecond.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime)
+#[derive(Copy, Clone, Debug, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct SYSTEMTIME {
+    /// The year.
+    pub wYear: u16,
+
+    /// The month (January = 1).
+    pub wMonth: u16,
+
+    /// The day of the week (Sunday = 0).
+    pub wDayOfWeek: u16,
+
+    /// The day of the month.
+    pub wDay: u16,
+
+    /// The hour.
+    pub wHour: u16,
+
+    /// The minute.
+    pub wMinute: u16,
+
+    /// The second.
+    pub wSecond: u16,
+
+    /// The millisecond.
+    pub wMilliseconds: u16,
+}
+
+/// Specifies settings for a time zone.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-time_zone_information)
+#[derive(Copy, Clone, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct TIME_ZONE_INFORMATION {
+    /// The current bias for local time translation on this computer, in minutes.
+    pub Bias: i32,
+
+    /// A description for standard time.
+    pub StandardName: [u16; 32],
+
+    /// The date and local time when the transition from daylight saving time to standard time occurs.
+    pub StandardDate: SYSTEMTIME,
+
+    /// The bias value to be used during local time translations that occur during standard time.
+    pub StandardBias: i32,
+
+    /// A description for daylight saving time.
+    pub DaylightName: [u16; 32],
+
+    /// The date and local time when the transition from standard time to daylight saving time occurs.
+    pub DaylightDate: SYSTEMTIME,
+
+    /// The bias value to be used during local time translations that occur during daylight saving time.
+    pub DaylightBias: i32,
+}
+
+/// Describes an extended processor state feature.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_misc_info_5)
+#[derive(Copy, Clone, Debug, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct XSTATE_FEATURE {
+    /// The offset of the feature in the extended context.
+    pub Offset: u32,
+
+    /// The size of the feature, in bytes.
+    pub Size: u32,
+}
+
+/// Contains the extended processor state configuration.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_misc_info_5)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct XSTATE_CONFIG_FEATURE_MSC_INFO {
+    /// The size of the structure, in bytes.
+    pub SizeOfInfo: u32,
+
+    /// The size of the extended context, in bytes.
+    pub ContextSize: u32,
+
+    /// The mask of the enabled extended state features.
+    pub EnabledFeatures: u64,
+
+    /// The description of each extended state feature.
+    pub Features: [XSTATE_FEATURE; 64],
+}
+
+impl Default for XSTATE_CONFIG_FEATURE_MSC_INFO {
+    fn default() -> Self {
+        Self {
+            SizeOfInfo: 0,
+            ContextSize: 0,
+            EnabledFeatures: 0,
+            Features: [XSTATE_FEATURE::default(); 64],
+        }
+    }
+}
+
+/// `MINIDUMP_MISC_INFO.ProcessId` is valid.
+pub const MINIDUMP_MISC1_PROCESS_ID: u32 = 0x0000_0001;
+
+/// `MINIDUMP_MISC_INFO.ProcessCreateTime`, `ProcessUserTime` and `ProcessKernelTime` are valid.
+pub const MINIDUMP_MISC1_PROCESS_TIMES: u32 = 0x0000_0002;
+
+/// The processor power information of `MINIDUMP_MISC_INFO_2` is valid.
+pub const MINIDUMP_MISC1_PROCESSOR_POWER_INFO: u32 = 0x0000_0004;
+
+/// `MINIDUMP_MISC_INFO_3.ProcessIntegrityLevel` is valid.
+pub const MINIDUMP_MISC3_PROCESS_INTEGRITY: u32 = 0x0000_0010;
+
+/// `MINIDUMP_MISC_INFO_3.ProcessExecuteFlags` is valid.
+pub const MINIDUMP_MISC3_PROCESS_EXECUTE_FLAGS: u32 = 0x0000_0020;
+
+/// `MINIDUMP_MISC_INFO_3.TimeZoneId` and `TimeZone` are valid.
+pub const MINIDUMP_MISC3_TIMEZONE: u32 = 0x0000_0040;
+
+/// `MINIDUMP_MISC_INFO_3.ProtectedProcess` is valid.
+pub const MINIDUMP_MISC3_PROTECTED_PROCESS: u32 = 0x0000_0080;
+
+/// `MINIDUMP_MISC_INFO_4.BuildString` and `DbgBldStr` are valid.
+pub const MINIDUMP_MISC4_BUILDSTRING: u32 = 0x0000_0100;
+
+/// `MINIDUMP_MISC_INFO_5.ProcessCookie` is valid.
+pub const MINIDUMP_MISC5_PROCESS_COOKIE: u32 = 0x0000_0200;
+
+/// Size of `MINIDUMP_MISC_INFO`, in bytes.
+pub const MINIDUMP_MISC_INFO_SIZE: u32 = 24;
+
+/// Size of `MINIDUMP_MISC_INFO_2`, in bytes.
+pub const MINIDUMP_MISC_INFO_2_SIZE: u32 = 44;
+
+/// Size of `MINIDUMP_MISC_INFO_3`, in bytes.
+pub const MINIDUMP_MISC_INFO_3_SIZE: u32 = 232;
+
+/// Size of `MINIDUMP_MISC_INFO_4`, in bytes.
+pub const MINIDUMP_MISC_INFO_4_SIZE: u32 = 832;
+
+/// Size of `MINIDUMP_MISC_INFO_5`, in bytes.
+pub const MINIDUMP_MISC_INFO_5_SIZE: u32 = 1364;
+
+/// Contains a variety of information, covering every `MINIDUMP_MISC_INFO` revision.
+///
+/// Only the fields present in the revision indicated by `SizeOfInfo` are read;
+/// the remaining ones are zeroed.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_misc_info_5)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_MISC_INFO_N {
+    /// The size of the structure, in bytes.
+    pub SizeOfInfo: u32,
+
+    /// The flags that indicate the valid members of this structure.
+    pub Flags1: u32,
+
+    /// The identifier of the process.
+    pub ProcessId: u32,
+
+    /// The creation time of the process, in time_t format.
+    pub ProcessCreateTime: u32,
+
+    /// The time the process has executed in user mode, in seconds.
+    pub ProcessUserTime: u32,
+
+    /// The time the process has executed in kernel mode, in seconds.
+    pub ProcessKernelTime: u32,
+
+    /// The maximum specified clock frequency of the system processor, in MHz.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE))]
+    pub ProcessorMaxMhz: u32,
+
+    /// The processor clock frequency, in MHz.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE))]
+    pub ProcessorCurrentMhz: u32,
+
+    /// The limit on the processor clock frequency, in MHz.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE))]
+    pub ProcessorMhzLimit: u32,
+
+    /// The maximum idle state of the processor.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE))]
+    pub ProcessorMaxIdleState: u32,
+
+    /// The current idle state of the processor.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE))]
+    pub ProcessorCurrentIdleState: u32,
+
+    /// The integrity level of the process.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE))]
+    pub ProcessIntegrityLevel: u32,
+
+    /// The execute flags of the process.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE))]
+    pub ProcessExecuteFlags: u32,
+
+    /// Indicates whether the process is protected.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE))]
+    pub ProtectedProcess: u32,
+
+    /// The time zone identifier.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE))]
+    pub TimeZoneId: u32,
+
+    /// The time zone settings of the system.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE))]
+    pub TimeZone: TIME_ZONE_INFORMATION,
+
+    /// The build string of the operating system.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_4_SIZE, [0; 260]))]
+    pub BuildString: [u16; 260],
+
+    /// The build string of the debugger engine.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_4_SIZE, [0; 40]))]
+    pub DbgBldStr: [u16; 40],
+
+    /// The extended processor state configuration.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_5_SIZE))]
+    pub XStateData: XSTATE_CONFIG_FEATURE_MSC_INFO,
+
+    /// The process cookie.
+    #[br(if(SizeOfInfo >= MINIDUMP_MISC_INFO_5_SIZE))]
+    pub ProcessCookie: u32,
+}
+
+/// Signature of a PDB 7.0 CodeView record ("RSDS" in ASCII).
+pub const CV_SIGNATURE_RSDS: u32 = 0x5344_5352;
+
+/// Signature of a PDB 2.0 CodeView record ("NB10" in ASCII).
+pub const CV_SIGNATURE_NB10: u32 = 0x3031_424E;
+
+/// Represents a globally unique identifier.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/guiddef/ns-guiddef-guid)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct GUID {
+    /// The first 8 hexadecimal digits of the GUID.
+    pub Data1: u32,
+
+    /// The first group of 4 hexadecimal digits.
+    pub Data2: u16,
+
+    /// The second group of 4 hexadecimal digits.
+    pub Data3: u16,
+
+    /// The remaining 16 hexadecimal digits.
+    pub Data4: [u8; 8],
+}
+
+/// Represents a PDB 7.0 CodeView record, as referenced by `MINIDUMP_MODULE.CvRecord`.
+///
+/// For more details, see the [CodeView debug directory format](https://github.com/dotnet/runtime/blob/main/docs/design/specs/PE-COFF.md#codeview-debug-directory-entry-type-2).
+#[derive(Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct CV_INFO_PDB70 {
+    /// The signature of the record ("RSDS").
+    pub CvSignature: u32,
+
+    /// The GUID identifying the PDB.
+    pub Signature: GUID,
+
+    /// The age of the PDB.
+    pub Age: u32,
+
+    /// The null-terminated path of the PDB.
+    pub PdbFileName: binrw::NullString,
+}
+
+/// Represents a PDB 2.0 CodeView record, as referenced by `MINIDUMP_MODULE.CvRecord`.
+#[derive(Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct CV_INFO_PDB20 {
+    /// The signature of the record ("NB10").
+    pub CvSignature: u32,
+
+    /// The offset of the debug information, always zero for PDB files.
+    pub Offset: u32,
+
+    /// The timestamp identifying the PDB.
+    pub Signature: u32,
+
+    /// The age of the PDB.
+    pub Age: u32,
+
+    /// The null-terminated path of the PDB.
+    pub PdbFileName: binrw::NullString,
+}
+
+/// The data of an `IMAGE_DEBUG_MISC` record is the name of the image's DBG file.
+pub const IMAGE_DEBUG_MISC_EXENAME: u32 = 1;
+
+/// Represents the header of a miscellaneous debug record, as referenced by `MINIDUMP_MODULE.MiscRecord`.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_debug_misc).
+#[derive(Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_DEBUG_MISC {
+    /// The type of the data (`IMAGE_DEBUG_MISC_EXENAME`).
+    pub DataType: u32,
+
+    /// The total length of the record, header included, rounded up to a multiple of four bytes.
+    pub Length: u32,
+
+    /// Whether the data is a UTF-16 string rather than an ANSI string.
+    pub Unicode: u8,
+
+    /// Reserved.
+    pub Reserved: [u8; 3],
+}
+
+/// Index of the export directory in the optional header.
+pub const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+
+/// Represents the export directory of a PE image.
+///
+/// For more details, see the [PE format documentation](https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#export-directory-table).
+#[derive(Copy, Clone, Debug, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_EXPORT_DIRECTORY {
+    /// Reserved, must be 0.
+    pub Characteristics: u32,
+
+    /// The time and date that the export data was created.
+    pub TimeDateStamp: u32,
+
+    /// The major version number.
+    pub MajorVersion: u16,
+
+    /// The minor version number.
+    pub MinorVersion: u16,
+
+    /// The address of the ASCII string containing the name of the DLL, relative to the image base.
+    pub Name: u32,
+
+    /// The starting ordinal number for exports in this image.
+    pub Base: u32,
+
+    /// The number of entries in the export address table.
+    pub NumberOfFunctions: u32,
+
+    /// The number of entries in the name pointer table.
+    pub NumberOfNames: u32,
+
+    /// The address of the export address table, relative to the image base.
+    pub AddressOfFunctions: u32,
+
+    /// The address of the export name pointer table, relative to the image base.
+    pub AddressOfNames: u32,
+
+    /// The address of the ordinal table, relative to the image base.
+    pub AddressOfNameOrdinals: u32,
+}
+
+/// Index of the import directory in the optional header.
+pub const IMAGE_DIRECTORY_ENTRY_IMPORT: usize = 1;
+
+/// Flag marking an import by ordinal in a 32-bit import lookup table entry.
+pub const IMAGE_ORDINAL_FLAG32: u64 = 0x8000_0000;
+
+/// Flag marking an import by ordinal in a 64-bit import lookup table entry.
+pub const IMAGE_ORDINAL_FLAG64: u64 = 0x8000_0000_0000_0000;
+
+/// Represents an entry of the import directory of a PE image, describing the imports from one DLL.
+///
+/// For more details, see the [PE format documentation](https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#import-directory-table).
+#[derive(Copy, Clone, Debug, Default)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct IMAGE_IMPORT_DESCRIPTOR {
+    /// The address of the import lookup table, relative to the image base.
+    pub OriginalFirstThunk: u32,
+
+    /// The time stamp of the bound DLL, or 0 if the image is not bound.
+    pub TimeDateStamp: u32,
+
+    /// The index of the first forwarder reference.
+    pub ForwarderChain: u32,
+
+    /// The address of the ASCII name of the DLL, relative to the image base.
+    pub Name: u32,
+
+    /// The address of the import address table, relative to the image base.
+    pub FirstThunk: u32,
+}
+
+/// Index of the exception (`.pdata`) directory in the optional header.
+pub const IMAGE_DIRECTORY_ENTRY_EXCEPTION: usize = 3;
+
+/// Index of the base relocation directory in the optional header.
+pub const IMAGE_DIRECTORY_ENTRY_BASERELOC: usize = 5;
+
+/// Index of the load configuration directory in the optional header.
+pub const IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG: usize = 10;
+
+/// Image flag indicating that base relocations were removed from the file.
+pub const IMAGE_FILE_RELOCS_STRIPPED: u16 = 0x0001;
+
+/// DLL characteristic indicating that the image can be relocated at load time (ASLR).
+pub const IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE: u16 = 0x0040;
+
+/// DLL characteristic indicating that the image is compatible with data execution prevention (DEP).
+pub const IMAGE_DLLCHARACTERISTICS_NX_COMPAT: u16 = 0x0100;
+
+/// DLL characteristic indicating that the image does not use structured exception handling.
+pub const IMAGE_DLLCHARACTERISTICS_NO_SEH: u16 = 0x0400;
+
+/// DLL characteristic indicating that the image supports Control Flow Guard.
+pub const IMAGE_DLLCHARACTERISTICS_GUARD_CF: u16 = 0x4000;
+
+/// The unwind information is chained to a previous `RUNTIME_FUNCTION` entry.
+pub const UNW_FLAG_CHAININFO: u8 = 0x4;
+
+/// Pushes a nonvolatile integer register, decrementing RSP by 8.
+pub const UWOP_PUSH_NONVOL: u8 = 0;
+
+/// Allocates a large-sized area on the stack.
+pub const UWOP_ALLOC_LARGE: u8 = 1;
+
+/// Allocates a small-sized area on the stack.
+pub const UWOP_ALLOC_SMALL: u8 = 2;
+
+/// Establishes the frame pointer register.
+pub const UWOP_SET_FPREG: u8 = 3;
+
+/// Saves a nonvolatile integer register on the stack using a MOV instead of a PUSH.
+pub const UWOP_SAVE_NONVOL: u8 = 4;
+
+/// Saves a nonvolatile integer register on the stack with a long offset.
+pub const UWOP_SAVE_NONVOL_FAR: u8 = 5;
+
+/// Describes an epilog (unwind info version 2).
+pub const UWOP_EPILOG: u8 = 6;
+
+/// Reserved operation, occupying two slots.
+pub const UWOP_SPARE_CODE: u8 = 7;
+
+/// Saves all 128 bits of a nonvolatile XMM register on the stack.
+pub const UWOP_SAVE_XMM128: u8 = 8;
+
+/// Saves all 128 bits of a nonvolatile XMM register on the stack with a long offset.
+pub const UWOP_SAVE_XMM128_FAR: u8 = 9;
+
+/// Pushes a machine frame, recording the effect of a hardware interrupt or exception.
+pub const UWOP_PUSH_MACHFRAME: u8 = 10;
+
+/// Represents an entry of the x64 exception (`.pdata`) directory.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/cpp/build/exception-handling-x64#struct-runtime_function)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct RUNTIME_FUNCTION {
+    /// The address of the start of the function, relative to the image base.
+    pub BeginAddress: u32,
+
+    /// The address of the end of the function, relative to the image base.
+    pub EndAddress: u32,
+
+    /// The address of the unwind information, relative to the image base.
+    pub UnwindInfoAddress: u32,
+}
+
+/// Contains the header of the function table stream.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_function_table_stream)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_FUNCTION_TABLE_STREAM {
+    /// The size of this header, in bytes.
+    pub SizeOfHeader: u32,
+
+    /// The size of each `MINIDUMP_FUNCTION_TABLE_DESCRIPTOR`, in bytes.
+    pub SizeOfDescriptor: u32,
+
+    /// The size of the native function table descriptor following each descriptor, in bytes.
+    pub SizeOfNativeDescriptor: u32,
+
+    /// The size of each function entry, in bytes.
+    pub SizeOfFunctionEntry: u32,
+
+    /// The number of descriptors in the stream.
+    pub NumberOfDescriptors: u32,
+
+    /// The size of the padding following this header, in bytes.
+    pub SizeOfAlignPad: u32,
+}
+
+/// Describes a function table registered with `RtlAddFunctionTable` or `RtlInstallFunctionTableCallback`.
+///
+/// For more details, see the official [Microsoft documentation](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_function_table_descriptor)
+#[derive(Copy, Clone)]
+#[binrw::binrw]
+#[brw(little)]
+pub struct MINIDUMP_FUNCTION_TABLE_DESCRIPTOR {
+    /// The lowest address covered by the function table.
+    pub MinimumAddress: u64,
+
+    /// The highest address covered by the function table.
+    pub MaximumAddress: u64,
+
+    /// The address the function entries are relative to.
+    pub BaseAddress: u64,
+
+    /// The number of function entries.
+    pub EntryCount: u32,
+
+    /// The size of the padding following the function entries, in bytes.
+    pub SizeOfAlignPad: u32,
+}
+
 /// Represents the type of a minidump data stream.
 ///
 /// <https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ne-minidumpapiset-minidump_stream_type>
 #[allow(dead_code)]
 #[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum MINIDUMP_STREAM_TYPE {
     UnusedStream = 0,
     ReservedStream0 = 1,
@@ -0,0 +1,122 @@
+use std::{fmt, ops::Range};
+
+/// Represents an anomaly found while parsing a minidump file.
+///
+/// Diagnostics do not prevent the dump from being parsed, but indicate that
+/// the file deviates from what a well-formed minidump looks like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The stream directory contains more than one entry for the same stream type.
+    DuplicateStream {
+        /// The raw stream type that was duplicated.
+        stream_type: u32,
+
+        /// The RVA of the duplicated entry.
+        rva: u32,
+    },
+
+    /// The stream directory lists a stream type in the reserved range that the parser does not know.
+    ///
+    /// Stream types above `LastReservedStream` belong to applications and are not reported.
+    UnknownStream {
+        /// The raw stream type.
+        stream_type: u32,
+
+        /// The RVA of the stream.
+        rva: u32,
+    },
+
+    /// A string extends past the end of the file and was cut.
+    TruncatedString {
+        /// The RVA of the `MINIDUMP_STRING`.
+        rva: u64,
+
+        /// The length of the string declared in the file, in bytes.
+        length: u32,
+    },
+
+    /// A module debug record extends past the end of the file and was replaced by an empty record.
+    TruncatedRecord {
+        /// The RVA of the record.
+        rva: u64,
+
+        /// The size of the record declared in the file, in bytes.
+        size: u64,
+    },
+
+    /// Two regions of the same memory stream overlap.
+    OverlappingMemory {
+        /// The range of the lower region.
+        first: Range<u64>,
+
+        /// The range of the region starting inside it.
+        second: Range<u64>,
+    },
+
+    /// The thread list contains more than one entry for the same thread ID.
+    DuplicateThread {
+        /// The repeated thread ID.
+        thread_id: u32,
+    },
+
+    /// A stream could not be parsed and was skipped in lenient mode.
+    MalformedStream {
+        /// The raw type of the stream.
+        stream_type: u32,
+
+        /// The RVA of the stream.
+        rva: u32,
+
+        /// The error raised while parsing the stream.
+        error: String,
+    },
+
+    /// A module or memory region with an empty or overflowing range was skipped.
+    ///
+    /// Thread stacks with an overflowing range are kept with an empty range instead.
+    InvalidRange {
+        /// The raw type of the stream listing the entry.
+        stream_type: u32,
+
+        /// The start address of the entry.
+        start: u64,
+
+        /// The size of the entry, in bytes.
+        size: u64,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::DuplicateStream { stream_type, rva } => {
+                write!(f, "Duplicate stream directory entry for stream type {stream_type} at RVA {rva:#x}")
+            }
+            Diagnostic::UnknownStream { stream_type, rva } => {
+                write!(f, "Unknown stream type {stream_type:#x} at RVA {rva:#x}")
+            }
+            Diagnostic::TruncatedString { rva, length } => {
+                write!(f, "String of {length} bytes at RVA {rva:#x} extends past the end of the file")
+            }
+            Diagnostic::TruncatedRecord { rva, size } => {
+                write!(f, "Debug record of {size} bytes at RVA {rva:#x} extends past the end of the file")
+            }
+            Diagnostic::OverlappingMemory { first, second } => {
+                write!(
+                    f,
+                    "Memory region {:#x}..{:#x} overlaps {:#x}..{:#x}",
+                    second.start, second.end, first.start, first.end
+                )
+            }
+            Diagnostic::DuplicateThread { thread_id } => {
+                write!(f, "Duplicate thread list entry for thread ID {thread_id}")
+            }
+            Diagnostic::MalformedStream { stream_type, rva, error } => {
+                write!(f, "Skipped malformed stream of type {stream_type} at RVA {rva:#x}: {error}")
+            }
+            Diagnostic::InvalidRange { stream_type, start, size } => {
+                write!(f, "Entry of stream type {stream_type} has an invalid range of {size:#x} bytes at {start:#x}")
+            }
+        }
+    }
+}
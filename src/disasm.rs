@@ -0,0 +1,176 @@
+use core::fmt;
+use iced_x86::{Decoder, DecoderOptions, Formatter, IntelFormatter};
+use crate::error::UserDmpError;
+use crate::options::UnmappedReadPolicy;
+use crate::parse::{Arch, Exception, Result, UserDump};
+
+/// Maximum length of an x86 instruction, in bytes.
+const MAX_INSTRUCTION_LEN: usize = 15;
+
+/// Number of instructions shown before the faulting instruction.
+const INSTRUCTIONS_BEFORE: usize = 3;
+
+/// Number of instructions shown from the faulting instruction onwards.
+const INSTRUCTIONS_AFTER: usize = 5;
+
+/// Represents an instruction decoded from the memory of the captured process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Instruction {
+    /// The address of the instruction.
+    pub address: u64,
+
+    /// The encoded bytes of the instruction.
+    pub bytes: Vec<u8>,
+
+    /// The instruction in Intel syntax (e.g., `mov rax,[rcx+8]`).
+    pub text: String,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self
+            .bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        write!(f, "{:#018x} {bytes:<30} {}", self.address, self.text)
+    }
+}
+
+/// Decodes up to `count` instructions from `code`, stopping at the first invalid or truncated one.
+fn decode(code: &[u8], bitness: u32, address: u64, count: usize) -> Vec<Instruction> {
+    let mut decoder = Decoder::with_ip(bitness, code, address, DecoderOptions::NONE);
+    let mut formatter = IntelFormatter::new();
+    let mut instructions = Vec::new();
+    while instructions.len() < count && decoder.can_decode() {
+        let instruction = decoder.decode();
+        if instruction.is_invalid() {
+            break;
+        }
+
+        let mut text = String::new();
+        formatter.format(&instruction, &mut text);
+        let offset = (instruction.ip() - address) as usize;
+        instructions.push(Instruction {
+            address: instruction.ip(),
+            bytes: code[offset..offset + instruction.len()].to_vec(),
+            text,
+        });
+    }
+
+    instructions
+}
+
+impl UserDump<'_> {
+    /// Returns the bitness of the code of the captured process, for the decoder.
+    fn bitness(&self) -> Result<u32> {
+        match self.system.processor_architecture {
+            Arch::X64 => Ok(64),
+            Arch::X86 => Ok(32),
+            arch => Err(UserDmpError::UnsupportedArchitecture(arch.into())),
+        }
+    }
+
+    /// Disassembles instructions from the memory of the captured process.
+    ///
+    /// Decoding stops early at the first invalid instruction or where the captured memory ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The address of the first instruction.
+    /// * `count` - The maximum number of instructions to decode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Instruction>)` - The decoded instructions, possibly fewer than `count`.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the memory at `va` was not captured.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the process is neither x86 nor x64.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for instruction in dump.disassemble_at(0x7ffa_1234_5678, 10).unwrap() {
+    ///     println!("{instruction}");
+    /// }
+    /// ```
+    pub fn disassemble_at(&self, va: u64, count: usize) -> Result<Vec<Instruction>> {
+        let bitness = self.bitness()?;
+        let code = self
+            .read_range_with(va, count.saturating_mul(MAX_INSTRUCTION_LEN), UnmappedReadPolicy::Partial)?
+            .data;
+        if code.is_empty() && count != 0 {
+            return Err(UserDmpError::AddressNotFound(va));
+        }
+
+        Ok(decode(&code, bitness, va, count))
+    }
+}
+
+impl Exception {
+    /// Disassembles the code around the address of the exception.
+    ///
+    /// x86 code cannot be decoded backwards reliably, so the preceding instructions are found
+    /// by decoding from earlier addresses until one of them lands exactly on the faulting
+    /// instruction. When none does, the listing starts at the faulting instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the exception belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Instruction>)` - Up to three instructions before the faulting one, the faulting
+    ///   instruction at [`Exception::address`] and up to four instructions after it.
+    /// * `Err(UserDmpError)` - If the code at the exception address was not captured, or the
+    ///   process is neither x86 nor x64.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(exception) = dump.exception() {
+    ///     for instruction in exception.faulting_instructions(&dump).unwrap() {
+    ///         let marker = if instruction.address == exception.address { ">" } else { " " };
+    ///         println!("{marker} {instruction}");
+    ///     }
+    /// }
+    /// ```
+    pub fn faulting_instructions(&self, dump: &UserDump) -> Result<Vec<Instruction>> {
+        let bitness = dump.bitness()?;
+        let after = dump.disassemble_at(self.address, INSTRUCTIONS_AFTER)?;
+
+        // Tries the furthest start first, so the listing shows as much context as possible.
+        let window = (INSTRUCTIONS_BEFORE * MAX_INSTRUCTION_LEN) as u64;
+        let before = (1..=window.min(self.address))
+            .rev()
+            .find_map(|distance| {
+                let start = self.address - distance;
+                let code = dump
+                    .read_range_with(start, distance as usize, UnmappedReadPolicy::Fail)
+                    .ok()?
+                    .data;
+                let instructions = decode(&code, bitness, start, usize::MAX);
+                let lands = instructions
+                    .last()
+                    .is_some_and(|last| last.address + last.bytes.len() as u64 == self.address);
+                lands.then(|| {
+                    let skip = instructions
+                        .len()
+                        .saturating_sub(INSTRUCTIONS_BEFORE);
+                    instructions[skip..].to_vec()
+                })
+            })
+            .unwrap_or_default();
+
+        Ok(before
+            .into_iter()
+            .chain(after)
+            .collect())
+    }
+}
@@ -0,0 +1,431 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+use crate::data::{CONTEXT_X64, CONTEXT_X86};
+use crate::error::UserDmpError;
+use crate::exception::ExceptionCode;
+use crate::parse::{Arch, Memory, Result, Thread, ThreadContext, UserDump};
+
+/// `ET_CORE`, the ELF file type of core files.
+const ET_CORE: u16 = 4;
+
+/// `EM_386`, the ELF machine of x86 code.
+const EM_386: u16 = 3;
+
+/// `EM_X86_64`, the ELF machine of x64 code.
+const EM_X86_64: u16 = 62;
+
+/// `PT_LOAD`, a segment mapped in the address space of the process.
+const PT_LOAD: u32 = 1;
+
+/// `PT_NOTE`, a segment holding notes.
+const PT_NOTE: u32 = 4;
+
+/// `PF_X`, `PF_W` and `PF_R`, the access flags of a segment.
+const PF_X: u32 = 1;
+const PF_W: u32 = 2;
+const PF_R: u32 = 4;
+
+/// `NT_PRSTATUS`, the note holding the status and the registers of a thread.
+const NT_PRSTATUS: u32 = 1;
+
+/// Owner of the notes describing the process.
+const NOTE_NAME: &[u8; 5] = b"CORE\0";
+
+/// Alignment of the `PT_LOAD` segments in the file, so their offsets match their addresses modulo a page.
+const SEGMENT_ALIGN: u64 = 0x1000;
+
+/// Maximum number of `PT_LOAD` segments, leaving room for the note segment below `PN_XNUM`.
+const MAX_SEGMENTS: usize = 0xFFFD;
+
+/// Linux signal numbers reported for the exception thread.
+const SIGILL: u16 = 4;
+const SIGTRAP: u16 = 5;
+const SIGABRT: u16 = 6;
+const SIGBUS: u16 = 7;
+const SIGFPE: u16 = 8;
+const SIGSEGV: u16 = 11;
+
+/// Describes the layout of the ELF class matching the architecture of the dump.
+struct Class {
+    /// True for `ELFCLASS64`, false for `ELFCLASS32`.
+    is_64: bool,
+
+    /// The ELF machine.
+    machine: u16,
+
+    /// The size of the ELF header.
+    header_size: u16,
+
+    /// The size of a program header.
+    phdr_size: u16,
+
+    /// The size of an `elf_prstatus` note.
+    prstatus_size: usize,
+}
+
+const ELF64: Class = Class {
+    is_64: true,
+    machine: EM_X86_64,
+    header_size: 64,
+    phdr_size: 56,
+    prstatus_size: 336,
+};
+
+const ELF32: Class = Class {
+    is_64: false,
+    machine: EM_386,
+    header_size: 52,
+    phdr_size: 32,
+    prstatus_size: 144,
+};
+
+/// A little-endian buffer with the helpers used to lay out ELF structures.
+#[derive(Default)]
+struct Buffer(Vec<u8>);
+
+impl Buffer {
+    fn u16(&mut self, value: u16) {
+        self.0
+            .extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0
+            .extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.0
+            .extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Writes an address-sized field: 8 bytes for `ELFCLASS64`, 4 bytes for `ELFCLASS32`.
+    fn word(&mut self, class: &Class, value: u64) {
+        if class.is_64 {
+            self.u64(value);
+        } else {
+            self.u32(value as u32);
+        }
+    }
+
+    /// Pads the buffer with zeros up to `len` bytes.
+    fn pad_to(&mut self, len: usize) {
+        self.0.resize(len.max(self.0.len()), 0);
+    }
+
+    /// Pads the buffer with zeros up to a multiple of 4 bytes, as notes require.
+    fn align4(&mut self) {
+        self.pad_to(self.0.len().next_multiple_of(4));
+    }
+}
+
+/// Returns the Linux signal closest to an exception code, as debuggers expect in `pr_cursig`.
+fn signal(code: u32) -> u16 {
+    match ExceptionCode::from(code) {
+        ExceptionCode::AccessViolation | ExceptionCode::StackOverflow | ExceptionCode::GuardPage => SIGSEGV,
+        ExceptionCode::InPageError | ExceptionCode::DatatypeMisalignment => SIGBUS,
+        ExceptionCode::IllegalInstruction | ExceptionCode::PrivilegedInstruction => SIGILL,
+        ExceptionCode::Breakpoint | ExceptionCode::SingleStep | ExceptionCode::Wow64Breakpoint => SIGTRAP,
+        ExceptionCode::IntegerDivideByZero
+        | ExceptionCode::IntegerOverflow
+        | ExceptionCode::FloatDivideByZero
+        | ExceptionCode::FloatInvalidOperation
+        | ExceptionCode::FloatOverflow => SIGFPE,
+        _ => SIGABRT,
+    }
+}
+
+/// Returns the `PF_*` flags matching the protection of a region.
+fn segment_flags(memory: &Memory) -> u32 {
+    let protect = memory.protect;
+
+    // Regions listed without a `MemoryInfoListStream` have no protection, so they are assumed accessible.
+    if protect.is_empty() {
+        return PF_R | PF_W;
+    }
+
+    let mut flags = 0;
+    if protect.is_readable() {
+        flags |= PF_R;
+    }
+
+    if protect.is_writable() {
+        flags |= PF_W;
+    }
+
+    if protect.is_executable() {
+        flags |= PF_X;
+    }
+
+    flags
+}
+
+/// Writes the `user_regs_struct` of an x64 thread.
+fn x64_registers(buffer: &mut Buffer, context: &CONTEXT_X64, teb: u64) {
+    for value in [
+        context.R15,
+        context.R14,
+        context.R13,
+        context.R12,
+        context.Rbp,
+        context.Rbx,
+        context.R11,
+        context.R10,
+        context.R9,
+        context.R8,
+        context.Rax,
+        context.Rcx,
+        context.Rdx,
+        context.Rsi,
+        context.Rdi,
+        // orig_rax, only meaningful for system calls.
+        u64::MAX,
+        context.Rip,
+        context.SegCs as u64,
+        context.EFlags as u64,
+        context.Rsp,
+        context.SegSs as u64,
+        // fs_base and gs_base, the TEB being addressed through GS on x64 Windows.
+        0,
+        teb,
+        context.SegDs as u64,
+        context.SegEs as u64,
+        context.SegFs as u64,
+        context.SegGs as u64,
+    ] {
+        buffer.u64(value);
+    }
+}
+
+/// Writes the `user_regs_struct` of an x86 thread.
+fn x86_registers(buffer: &mut Buffer, context: &CONTEXT_X86) {
+    for value in [
+        context.Ebx,
+        context.Ecx,
+        context.Edx,
+        context.Esi,
+        context.Edi,
+        context.Ebp,
+        context.Eax,
+        context.SegDs,
+        context.SegEs,
+        context.SegFs,
+        context.SegGs,
+        // orig_eax, only meaningful for system calls.
+        u32::MAX,
+        context.Eip,
+        context.SegCs,
+        context.EFlags,
+        context.Esp,
+        context.SegSs,
+    ] {
+        buffer.u32(value);
+    }
+}
+
+impl UserDump<'_> {
+    /// Writes the dump as a Linux ELF core file, for tooling that only understands that format
+    /// (e.g., `gdb` or `lldb`).
+    ///
+    /// Each region with captured bytes becomes a `PT_LOAD` segment with the flags of its
+    /// protection, and each thread with a decoded context an `NT_PRSTATUS` note holding its
+    /// registers. The thread that raised the exception comes first, so debuggers select it,
+    /// and carries the signal closest to the exception code. x64 dumps produce an
+    /// `ELFCLASS64` core and x86 dumps an `ELFCLASS32` one.
+    ///
+    /// Only the memory and the registers are converted: the Windows modules are not described
+    /// to the debugger, and floating-point and vector registers are left out. Regions past
+    /// the 65533rd are dropped, since the program header count would overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the core file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of `PT_LOAD` segments written.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the process is neither x86 nor x64.
+    /// * `Err(UserDmpError)` - If writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::fs::File;
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let segments = dump.write_elf_core(File::create("example.core").unwrap()).unwrap();
+    /// println!("{segments} segments written, open with `gdb -c example.core`");
+    /// ```
+    pub fn write_elf_core(&self, mut writer: impl Write) -> Result<usize> {
+        let class = match self.system.processor_architecture {
+            Arch::X64 => ELF64,
+            Arch::X86 => ELF32,
+            arch => return Err(UserDmpError::UnsupportedArchitecture(arch.into())),
+        };
+
+        // An `ELFCLASS32` core cannot describe addresses above 4 GiB.
+        let regions = self
+            .memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty())
+            .filter(|memory| class.is_64 || memory.range.start + memory.data.len() as u64 <= u32::MAX as u64 + 1)
+            .take(MAX_SEGMENTS)
+            .collect::<Vec<_>>();
+
+        // Builds the notes, with the exception thread first.
+        let exception = self.exception();
+        let mut threads = self
+            .threads()
+            .values()
+            .collect::<Vec<&Thread>>();
+        threads.sort_by_key(|thread| exception.is_none_or(|exception| exception.thread_id != thread.thread_id));
+
+        let mut notes = Buffer::default();
+        for thread in threads {
+            let cursig = exception
+                .filter(|exception| exception.thread_id == thread.thread_id)
+                .map_or(0, |exception| signal(exception.code));
+
+            let mut prstatus = Buffer::default();
+
+            // si_signo, si_code, si_errno, pr_cursig and padding.
+            prstatus.u32(cursig as u32);
+            prstatus.u32(0);
+            prstatus.u32(0);
+            prstatus.u16(cursig);
+            prstatus.u16(0);
+
+            // pr_sigpend and pr_sighold.
+            prstatus.word(&class, 0);
+            prstatus.word(&class, 0);
+
+            // pr_pid, pr_ppid, pr_pgrp and pr_sid, where debuggers read the thread identifier.
+            let pid = self
+                .misc_info()
+                .and_then(|misc| misc.process_id)
+                .unwrap_or(0);
+            prstatus.u32(thread.thread_id);
+            prstatus.u32(0);
+            prstatus.u32(pid);
+            prstatus.u32(pid);
+
+            // pr_utime, pr_stime, pr_cutime and pr_cstime.
+            for _ in 0..8 {
+                prstatus.word(&class, 0);
+            }
+
+            match (thread.context(), class.is_64) {
+                (ThreadContext::X64(context), true) => x64_registers(&mut prstatus, context, thread.teb),
+                (ThreadContext::X86(context), false) => x86_registers(&mut prstatus, context),
+                _ => continue,
+            }
+
+            // pr_fpvalid, left unset since no floating-point note is written.
+            prstatus.pad_to(class.prstatus_size);
+
+            notes.u32(NOTE_NAME.len() as u32);
+            notes.u32(prstatus.0.len() as u32);
+            notes.u32(NT_PRSTATUS);
+            notes.0.extend_from_slice(NOTE_NAME);
+            notes.align4();
+            notes.0.extend_from_slice(&prstatus.0);
+            notes.align4();
+        }
+
+        // Lays out the file: headers, notes, then each segment at an offset congruent to its address.
+        let phnum = regions.len() + 1;
+        let notes_offset = class.header_size as u64 + phnum as u64 * class.phdr_size as u64;
+        let mut offset = notes_offset + notes.0.len() as u64;
+        let offsets = regions
+            .iter()
+            .map(|memory| {
+                let misalignment = (memory.range.start.wrapping_sub(offset)) % SEGMENT_ALIGN;
+                let start = offset + misalignment;
+                offset = start + memory.data.len() as u64;
+                start
+            })
+            .collect::<Vec<_>>();
+
+        let mut header = Buffer::default();
+        header.0.extend_from_slice(b"\x7fELF");
+        header
+            .0
+            .push(if class.is_64 { 2 } else { 1 });
+
+        // Little-endian, version 1, System V ABI.
+        header.0.extend_from_slice(&[1, 1, 0]);
+        header.pad_to(16);
+        header.u16(ET_CORE);
+        header.u16(class.machine);
+        header.u32(1);
+
+        // e_entry, e_phoff and e_shoff.
+        header.word(&class, 0);
+        header.word(&class, class.header_size as u64);
+        header.word(&class, 0);
+        header.u32(0);
+        header.u16(class.header_size);
+        header.u16(class.phdr_size);
+        header.u16(phnum as u16);
+        header.u16(0);
+        header.u16(0);
+        header.u16(0);
+
+        let phdr = |header: &mut Buffer, kind: u32, flags: u32, offset: u64, vaddr: u64, size: u64, align: u64| {
+            header.u32(kind);
+            if class.is_64 {
+                header.u32(flags);
+            }
+
+            header.word(&class, offset);
+            header.word(&class, vaddr);
+            header.word(&class, 0);
+            header.word(&class, size);
+            header.word(&class, size);
+            if !class.is_64 {
+                header.u32(flags);
+            }
+
+            header.word(&class, align);
+        };
+
+        phdr(&mut header, PT_NOTE, 0, notes_offset, 0, notes.0.len() as u64, 4);
+        for (memory, offset) in regions.iter().zip(&offsets) {
+            let flags = segment_flags(memory);
+            phdr(&mut header, PT_LOAD, flags, *offset, memory.range.start, memory.data.len() as u64, SEGMENT_ALIGN);
+        }
+
+        writer.write_all(&header.0)?;
+        writer.write_all(&notes.0)?;
+
+        let mut position = notes_offset + notes.0.len() as u64;
+        for (memory, offset) in regions.iter().zip(offsets) {
+            writer.write_all(&vec![0; (offset - position) as usize])?;
+            writer.write_all(memory.data)?;
+            position = offset + memory.data.len() as u64;
+        }
+
+        writer.flush()?;
+        Ok(regions.len())
+    }
+
+    /// Writes the dump as a Linux ELF core file.
+    ///
+    /// See [`UserDump::write_elf_core`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of `PT_LOAD` segments written.
+    /// * `Err(UserDmpError)` - If the architecture is unsupported, or the file cannot be created or written.
+    pub fn save_elf_core(&self, path: impl AsRef<Path>) -> Result<usize> {
+        self.write_elf_core(BufWriter::new(File::create(path)?))
+    }
+}
@@ -1,5 +1,6 @@
 use binrw::Error as BinrwError;
 use thiserror::Error;
+use crate::data::MINIDUMP_STREAM_TYPE;
 
 /// Represents errors that may occur during the processing of a minidump file.
 #[derive(Debug, Error)]
@@ -16,6 +17,14 @@ pub enum UserDmpError {
     #[error("Invalid minidump signature.")]
     InvalidSignature,
 
+    /// Raised when a byte signature (e.g., `48 8B ?? ?? 90`) cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The token that could not be parsed.
+    #[error("Invalid byte pattern token: {0:?}")]
+    InvalidPattern(String),
+
     /// Raised when the minidump contains invalid or unsupported flags.
     ///
     /// # Arguments
@@ -52,6 +61,33 @@ pub enum UserDmpError {
     #[error("Invalid memory range in module.")]
     InvalidMemoryRange,
 
+    /// Raised when a module or memory region of the minidump has an empty or overflowing range.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start address of the range.
+    /// * `size` - The size of the range, in bytes.
+    #[error("Invalid range of {size:#x} bytes at address {start:#x}")]
+    InvalidRange { start: u64, size: u64 },
+
+    /// Raised when data referenced by the minidump extends past the end of the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The offset of the data in the file.
+    /// * `size` - The size of the data, in bytes.
+    #[error("Data of {size:#x} bytes at RVA {rva:#x} extends past the end of the file")]
+    TruncatedStream { rva: u64, size: u64 },
+
+    /// Raised when a list stream declares entries smaller than the structure they hold.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The entry size declared in the stream, in bytes.
+    /// * `expected` - The minimum size of an entry, in bytes.
+    #[error("Invalid entry size of {size} bytes, expected at least {expected}")]
+    InvalidEntrySize { size: u32, expected: usize },
+
     /// Raised when the application fails to create a file mapping for the minidump.
     #[error("Failed to create file mapping.")]
     CreateFileMappingError,
@@ -72,14 +108,54 @@ pub enum UserDmpError {
     #[error("Parsing error: {0}")]
     BinrwError(#[from] BinrwError),
 
-    /// Raised when an address cannot be found in the `Memory64ListStream`.
+    /// Raised when an address cannot be found in the memory captured in the dump.
     ///
     /// # Arguments
     ///
     /// * `{0}` - The address that could not be found, represented as a hexadecimal value.
-    #[error("Address {0:#x?} was not found in Memory64ListStream")]
+    #[error("Address {0:#x?} was not found in the memory captured in the dump")]
     AddressNotFound(u64),
 
+    /// Raised when the PE headers of an image in the dump are malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The base address of the image.
+    #[error("Invalid PE header at address {0:#x}")]
+    InvalidPeHeader(u64),
+
+    /// Raised when no module is loaded at the requested base address.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The requested base address.
+    #[error("No module is loaded at address {0:#x}")]
+    ModuleNotFound(u64),
+
+    /// Raised when a serialized baseline profile cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The line number of the malformed entry, starting at 1.
+    #[error("Invalid baseline profile at line {0}")]
+    InvalidBaseline(usize),
+
+    /// Raised when a label map contains a malformed entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The line number of the malformed entry, starting at 1.
+    #[error("Invalid label map at line {0}")]
+    InvalidLabel(usize),
+
+    /// Raised by [`crate::fuzz::parse_all`] when a parser panicked.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The message of the panic.
+    #[error("A parser panicked: {0}")]
+    Panic(String),
+
     /// Raised when the context is invalid.
     ///
     /// # Arguments
@@ -87,4 +163,44 @@ pub enum UserDmpError {
     /// * `{0}` - The size of the context that was invalid.
     #[error("Invalid context")]
     InvalidContext,
+
+    /// Raised when a stream is requested but not listed in the stream directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The type of the missing stream.
+    #[error("Stream {0:?} was not found in the minidump")]
+    StreamNotFound(MINIDUMP_STREAM_TYPE),
+
+    /// Raised when an operation is requested on a stream the library does not parse.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The type of the unsupported stream.
+    #[error("Stream {0:?} is not supported")]
+    UnsupportedStream(MINIDUMP_STREAM_TYPE),
+
+    /// Raised when no symbol server holds the requested PDB or binary.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The symbol server path of the file (e.g., `ntdll.pdb/<id>/ntdll.pdb`).
+    #[error("Symbol file {0} was not found on any symbol server")]
+    SymbolNotFound(String),
+
+    /// Raised when a symbol server request fails for a reason other than a missing file.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The description of the failure.
+    #[error("Symbol server request failed: {0}")]
+    SymbolServer(String),
+
+    /// Raised when a PDB cannot be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The description of the failure.
+    #[error("Invalid PDB: {0}")]
+    InvalidPdb(String),
 }
@@ -0,0 +1,422 @@
+use std::{collections::HashSet, fmt, io::Cursor};
+use binrw::BinRead;
+use crate::data::{EXCEPTION_MAXIMUM_PARAMETERS, MINIDUMP_EXCEPTION};
+use crate::parse::{Exception, UserDump};
+
+/// Maximum number of nested exception records followed, to survive corrupted chains.
+const MAX_NESTED_EXCEPTIONS: usize = 64;
+
+/// Size of an `EXCEPTION_RECORD` in a 32-bit process.
+const EXCEPTION_RECORD32_SIZE: usize = 0x50;
+
+/// Size of an `EXCEPTION_RECORD` in a 64-bit process, laid out as a `MINIDUMP_EXCEPTION`.
+const EXCEPTION_RECORD64_SIZE: usize = 0x98;
+
+/// Identifies a well-known exception code.
+///
+/// Codes the crate does not know are kept in [`ExceptionCode::Unknown`], so converting a
+/// code to the enum and back never loses information.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, exception::ExceptionCode};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// if let Some(exception) = dump.exception() {
+///     if exception.exception_code() == ExceptionCode::StackOverflow {
+///         println!("stack overflow on thread {}", exception.thread_id);
+///     }
+/// }
+/// ```
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ExceptionCode {
+    /// `EXCEPTION_GUARD_PAGE`, a guard page was accessed.
+    GuardPage,
+
+    /// `EXCEPTION_DATATYPE_MISALIGNMENT`, misaligned data was accessed.
+    DatatypeMisalignment,
+
+    /// `EXCEPTION_BREAKPOINT`, a breakpoint was hit.
+    Breakpoint,
+
+    /// `EXCEPTION_SINGLE_STEP`, a trace trap or other single-instruction mechanism signaled.
+    SingleStep,
+
+    /// `STATUS_WX86_BREAKPOINT`, a breakpoint was hit in the 32-bit code of a WOW64 process.
+    Wow64Breakpoint,
+
+    /// `EXCEPTION_ACCESS_VIOLATION`, an invalid address was read, written or executed.
+    AccessViolation,
+
+    /// `EXCEPTION_IN_PAGE_ERROR`, a page could not be read from its backing file.
+    InPageError,
+
+    /// `EXCEPTION_INVALID_HANDLE`, an invalid handle was used.
+    InvalidHandle,
+
+    /// `STATUS_NO_MEMORY`, memory could not be allocated.
+    NoMemory,
+
+    /// `EXCEPTION_ILLEGAL_INSTRUCTION`, an invalid instruction was executed.
+    IllegalInstruction,
+
+    /// `EXCEPTION_NONCONTINUABLE_EXCEPTION`, execution continued after a noncontinuable exception.
+    NoncontinuableException,
+
+    /// `EXCEPTION_INVALID_DISPOSITION`, an exception handler returned an invalid disposition.
+    InvalidDisposition,
+
+    /// `EXCEPTION_ARRAY_BOUNDS_EXCEEDED`, an array was accessed out of bounds.
+    ArrayBoundsExceeded,
+
+    /// `EXCEPTION_FLT_DIVIDE_BY_ZERO`, a floating-point value was divided by zero.
+    FloatDivideByZero,
+
+    /// `EXCEPTION_FLT_INVALID_OPERATION`, a floating-point operation was invalid.
+    FloatInvalidOperation,
+
+    /// `EXCEPTION_FLT_OVERFLOW`, a floating-point operation overflowed.
+    FloatOverflow,
+
+    /// `EXCEPTION_INT_DIVIDE_BY_ZERO`, an integer was divided by zero.
+    IntegerDivideByZero,
+
+    /// `EXCEPTION_INT_OVERFLOW`, an integer operation overflowed.
+    IntegerOverflow,
+
+    /// `EXCEPTION_PRIV_INSTRUCTION`, a privileged instruction was executed in user mode.
+    PrivilegedInstruction,
+
+    /// `EXCEPTION_STACK_OVERFLOW`, the thread used up its stack.
+    StackOverflow,
+
+    /// `STATUS_POSSIBLE_DEADLOCK`, a wait on a critical section timed out.
+    PossibleDeadlock,
+
+    /// `STATUS_HEAP_CORRUPTION`, the heap manager detected a corrupted heap.
+    HeapCorruption,
+
+    /// `STATUS_STACK_BUFFER_OVERRUN`, raised by `__fastfail` and the `/GS` security checks.
+    StackBufferOverrun,
+
+    /// `STATUS_INVALID_CRUNTIME_PARAMETER`, the C runtime received an invalid parameter.
+    InvalidCruntimeParameter,
+
+    /// `STATUS_ASSERTION_FAILURE`, an assertion failed.
+    AssertionFailure,
+
+    /// `STATUS_FAIL_FAST_EXCEPTION`, raised by `RaiseFailFastException`.
+    FailFast,
+
+    /// The code reported by Windows Error Reporting for unresponsive applications.
+    ApplicationHang,
+
+    /// A C++ exception thrown by the Microsoft C++ runtime (`0xE06D7363`, `.msc`).
+    CppException,
+
+    /// A managed exception thrown by the .NET runtime (`0xE0434352`, `.CCR`).
+    DotNet,
+
+    /// A managed exception thrown by the .NET Framework 1.x runtime (`0xE0434F4D`, `.COM`).
+    DotNetLegacy,
+
+    /// A code not known by the crate.
+    Unknown(u32),
+}
+
+/// The known codes, with their value and their description.
+const KNOWN_CODES: [(ExceptionCode, u32, &str); 30] = [
+    (ExceptionCode::GuardPage, 0x8000_0001, "guard page violation"),
+    (ExceptionCode::DatatypeMisalignment, 0x8000_0002, "datatype misalignment"),
+    (ExceptionCode::Breakpoint, 0x8000_0003, "breakpoint"),
+    (ExceptionCode::SingleStep, 0x8000_0004, "single step"),
+    (ExceptionCode::Wow64Breakpoint, 0x4000_001F, "WOW64 breakpoint"),
+    (ExceptionCode::AccessViolation, 0xC000_0005, "access violation"),
+    (ExceptionCode::InPageError, 0xC000_0006, "in-page error"),
+    (ExceptionCode::InvalidHandle, 0xC000_0008, "invalid handle"),
+    (ExceptionCode::NoMemory, 0xC000_0017, "out of memory"),
+    (ExceptionCode::IllegalInstruction, 0xC000_001D, "illegal instruction"),
+    (ExceptionCode::NoncontinuableException, 0xC000_0025, "noncontinuable exception"),
+    (ExceptionCode::InvalidDisposition, 0xC000_0026, "invalid disposition"),
+    (ExceptionCode::ArrayBoundsExceeded, 0xC000_008C, "array bounds exceeded"),
+    (ExceptionCode::FloatDivideByZero, 0xC000_008E, "floating-point divide by zero"),
+    (ExceptionCode::FloatInvalidOperation, 0xC000_0090, "floating-point invalid operation"),
+    (ExceptionCode::FloatOverflow, 0xC000_0091, "floating-point overflow"),
+    (ExceptionCode::IntegerDivideByZero, 0xC000_0094, "integer divide by zero"),
+    (ExceptionCode::IntegerOverflow, 0xC000_0095, "integer overflow"),
+    (ExceptionCode::PrivilegedInstruction, 0xC000_0096, "privileged instruction"),
+    (ExceptionCode::StackOverflow, 0xC000_00FD, "stack overflow"),
+    (ExceptionCode::PossibleDeadlock, 0xC000_0194, "possible deadlock"),
+    (ExceptionCode::HeapCorruption, 0xC000_0374, "heap corruption"),
+    (ExceptionCode::StackBufferOverrun, 0xC000_0409, "stack buffer overrun"),
+    (ExceptionCode::InvalidCruntimeParameter, 0xC000_0417, "invalid C runtime parameter"),
+    (ExceptionCode::AssertionFailure, 0xC000_0420, "assertion failure"),
+    (ExceptionCode::FailFast, 0xC000_0602, "fail fast"),
+    (ExceptionCode::ApplicationHang, 0xCFFF_FFFF, "application hang"),
+    (ExceptionCode::CppException, 0xE06D_7363, "C++ exception"),
+    (ExceptionCode::DotNet, 0xE043_4352, ".NET exception"),
+    (ExceptionCode::DotNetLegacy, 0xE043_4F4D, ".NET Framework 1.x exception"),
+];
+
+impl ExceptionCode {
+    /// Returns the numeric value of the code.
+    pub fn code(&self) -> u32 {
+        match self {
+            ExceptionCode::Unknown(code) => *code,
+            known => KNOWN_CODES
+                .iter()
+                .find(|(variant, ..)| variant == known)
+                .map_or(0, |(_, code, _)| *code),
+        }
+    }
+
+    /// Returns a short description of the exception (e.g., `access violation`).
+    pub fn description(&self) -> Option<&'static str> {
+        match self {
+            ExceptionCode::Unknown(_) => None,
+            known => KNOWN_CODES
+                .iter()
+                .find(|(variant, ..)| variant == known)
+                .map(|(.., description)| *description),
+        }
+    }
+}
+
+impl From<u32> for ExceptionCode {
+    fn from(code: u32) -> Self {
+        KNOWN_CODES
+            .iter()
+            .find(|(_, value, _)| *value == code)
+            .map_or(ExceptionCode::Unknown(code), |(variant, ..)| *variant)
+    }
+}
+
+impl From<ExceptionCode> for u32 {
+    fn from(code: ExceptionCode) -> Self {
+        code.code()
+    }
+}
+
+impl fmt::Display for ExceptionCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.description() {
+            Some(description) => f.write_str(description),
+            None => write!(f, "exception {:#010x}", self.code()),
+        }
+    }
+}
+
+/// The kind of access that caused an access violation or an in-page error.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AccessKind {
+    /// The faulting instruction read the address.
+    Read,
+
+    /// The faulting instruction wrote to the address.
+    Write,
+
+    /// The processor fetched an instruction from a non-executable address (DEP).
+    Execute,
+
+    /// A value not documented for the first parameter of the exception.
+    Unknown(u64),
+}
+
+impl From<u64> for AccessKind {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => AccessKind::Read,
+            1 => AccessKind::Write,
+            8 => AccessKind::Execute,
+            value => AccessKind::Unknown(value),
+        }
+    }
+}
+
+impl fmt::Display for AccessKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessKind::Read => f.write_str("read"),
+            AccessKind::Write => f.write_str("write"),
+            AccessKind::Execute => f.write_str("execute"),
+            AccessKind::Unknown(value) => write!(f, "access {value}"),
+        }
+    }
+}
+
+/// The decoded parameters of an access violation or an in-page error.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AccessViolation {
+    /// The kind of access that failed.
+    pub kind: AccessKind,
+
+    /// The address that could not be accessed.
+    pub address: u64,
+
+    /// The `NTSTATUS` of the failed I/O, for in-page errors.
+    pub status: Option<u32>,
+}
+
+impl fmt::Display for AccessViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {:#x}", self.kind, self.address)?;
+        if let Some(status) = self.status {
+            write!(f, " (status {status:#010x})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Exception {
+    /// Returns the code of the exception as an [`ExceptionCode`].
+    pub fn exception_code(&self) -> ExceptionCode {
+        ExceptionCode::from(self.code)
+    }
+
+    /// Decodes the parameters of an access violation or an in-page error.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(AccessViolation)` - The kind of access and the inaccessible address.
+    /// * `None` - If the exception is of another kind or lacks its parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(violation) = dump.exception().and_then(|exception| exception.access_violation()) {
+    ///     println!("{violation}");
+    /// }
+    /// ```
+    pub fn access_violation(&self) -> Option<AccessViolation> {
+        let status = match self.exception_code() {
+            ExceptionCode::AccessViolation => None,
+            ExceptionCode::InPageError => self
+                .parameter(2)
+                .map(|status| status as u32),
+            _ => return None,
+        };
+
+        Some(AccessViolation {
+            kind: AccessKind::from(self.parameter(0)?),
+            address: self.parameter(1)?,
+            status,
+        })
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:#010x}) at {:#x}", self.exception_code(), self.code, self.address)?;
+        if let Some(violation) = self.access_violation() {
+            write!(f, ": {violation}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Exception {
+    /// Follows the chain of nested exception records linked from this exception.
+    ///
+    /// An exception raised while another one is being dispatched (e.g., a C++ exception
+    /// thrown from a handler of a SEH fault) points through `ExceptionRecord` to the record
+    /// of the original exception, which lives in the memory of the process.
+    ///
+    /// The chain stops at a null link, at a record missing from the dump and at a record
+    /// already visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the exception was read from.
+    ///
+    /// # Returns
+    ///
+    /// * A list of the nested [`Exception`], in link order. They carry the thread
+    ///   identifier of this exception.
+    pub fn nested(&self, dump: &UserDump) -> Vec<Exception> {
+        let mut nested = Vec::new();
+        let mut visited = HashSet::new();
+        let mut link = self.record;
+        while link != 0 && visited.insert(link) && nested.len() < MAX_NESTED_EXCEPTIONS {
+            let Some(record) = read_exception_record(dump, link) else {
+                break;
+            };
+
+            link = record.ExceptionRecord;
+            nested.push(Exception::from_record(self.thread_id, &record));
+        }
+
+        nested
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns the exception of the `ExceptionStream` followed by its nested exceptions.
+    ///
+    /// # Returns
+    ///
+    /// * A list of [`Exception`], starting with [`UserDump::exception`], empty if the dump has no exception.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (depth, exception) in dump.exception_chain().iter().enumerate() {
+    ///     println!("{:indent$}{exception}", "", indent = depth * 2);
+    /// }
+    /// ```
+    pub fn exception_chain(&self) -> Vec<Exception> {
+        let Some(exception) = self.exception() else {
+            return Vec::new();
+        };
+
+        let mut chain = vec![exception.clone()];
+        chain.extend(exception.nested(self));
+        chain
+    }
+}
+
+/// Reads an `EXCEPTION_RECORD` from the memory of the process, widening the 32-bit layout.
+fn read_exception_record(dump: &UserDump, address: u64) -> Option<MINIDUMP_EXCEPTION> {
+    if dump.pointer_size() == 8 {
+        let bytes = dump
+            .read_bytes(address, EXCEPTION_RECORD64_SIZE)
+            .ok()?;
+        return MINIDUMP_EXCEPTION::read(&mut Cursor::new(bytes)).ok();
+    }
+
+    let bytes = dump
+        .read_bytes(address, EXCEPTION_RECORD32_SIZE)
+        .ok()?;
+    let field = |index: usize| {
+        u32::from_le_bytes(
+            bytes[index * 4..index * 4 + 4]
+                .try_into()
+                .unwrap_or_default(),
+        )
+    };
+    let mut information = [0; EXCEPTION_MAXIMUM_PARAMETERS];
+    for (index, value) in information.iter_mut().enumerate() {
+        *value = field(5 + index) as u64;
+    }
+
+    Some(MINIDUMP_EXCEPTION {
+        ExceptionCode: field(0),
+        ExceptionFlags: field(1),
+        ExceptionRecord: field(2) as u64,
+        ExceptionAddress: field(3) as u64,
+        NumberParameters: field(4),
+        unusedAlignment: 0,
+        ExceptionInformation: information,
+    })
+}
@@ -0,0 +1,87 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::Path,
+};
+use crate::error::UserDmpError;
+use crate::parse::{Module, Result, UserDump};
+use crate::reader::Span;
+
+/// Page of zeros written in place of the parts of an image missing from the dump.
+const ZEROS: [u8; 0x1000] = [0; 0x1000];
+
+impl UserDump<'_> {
+    /// Writes the in-memory image of the module loaded at `base`.
+    ///
+    /// The image is written as it was mapped in the process, from the module base up to
+    /// `SizeOfImage`: sections sit at their virtual offsets and relocations are applied.
+    /// Pages that were not captured in the dump are written as zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base address of the module.
+    /// * `writer` - The destination of the image.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Range<u64>>)` - The ranges of the image that were missing from the dump and zero-filled.
+    /// * `Err(UserDmpError)` - If no module is loaded at `base` or writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::fs::File;
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let ntdll = dump.module_by_name("ntdll.dll").unwrap();
+    /// let gaps = dump.extract_module(ntdll.start_addr(), File::create("ntdll.bin").unwrap()).unwrap();
+    /// println!("{} ranges missing", gaps.len());
+    /// ```
+    pub fn extract_module(&self, base: u64, mut writer: impl Write) -> Result<Vec<Range<u64>>> {
+        let module = self
+            .modules()
+            .get(&base)
+            .ok_or(UserDmpError::ModuleNotFound(base))?;
+        // The image is streamed region by region, since `SizeOfImage` comes from the dump.
+        let mut gaps = Vec::new();
+        for span in self.spans(base, module.range.end) {
+            match span {
+                Span::Captured(bytes) => writer.write_all(bytes)?,
+                Span::Missing(gap) => {
+                    let mut remaining = gap.end - gap.start;
+                    while remaining > 0 {
+                        let count = remaining.min(ZEROS.len() as u64);
+                        writer.write_all(&ZEROS[..count as usize])?;
+                        remaining -= count;
+                    }
+
+                    gaps.push(gap);
+                }
+            }
+        }
+
+        writer.flush()?;
+        Ok(gaps)
+    }
+}
+
+impl Module<'_> {
+    /// Writes the in-memory image of the module to a file.
+    ///
+    /// See [`UserDump::extract_module`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the module belongs to.
+    /// * `path` - The path of the file to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Range<u64>>)` - The ranges of the image that were missing from the dump and zero-filled.
+    /// * `Err(UserDmpError)` - If the module is not part of `dump` or the file cannot be written.
+    pub fn save_to(&self, dump: &UserDump<'_>, path: impl AsRef<Path>) -> Result<Vec<Range<u64>>> {
+        dump.extract_module(self.start_addr(), BufWriter::new(File::create(path)?))
+    }
+}
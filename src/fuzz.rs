@@ -0,0 +1,184 @@
+use std::{
+    any::Any,
+    io,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::analysis::passes::AnalysisRegistry;
+use crate::error::UserDmpError;
+use crate::options::{InvalidRangePolicy, ParseMode, ParseOptions};
+use crate::parse::{Result, UserDump};
+use crate::pe::rebuild::RebuildOptions;
+use crate::peb::LoaderList;
+
+/// Minimum length of the strings extracted from memory.
+const MIN_STRING_LEN: usize = 4;
+
+/// Parses a minidump held in memory and runs every parser of the crate over it.
+///
+/// This is the entry point for fuzzing: beyond the stream directory, it decodes the
+/// strings, thread contexts and stack traces, module headers and exports, handles,
+/// process parameters, loader lists, heaps and the analysis passes. It touches neither
+/// the file system nor the clock, so a given input always takes the same path.
+///
+/// A panic raised along the way is caught and returned as [`UserDmpError::Panic`], so the
+/// fuzzer reports it as a finding instead of stopping. The default panic hook still prints
+/// the message; fuzzers usually replace it. A failed allocation aborts the process and
+/// cannot be caught: reads are checked against the captured data before allocating and
+/// the counts read from the dump are capped, so a crash of this kind is a bug as well.
+///
+/// # Arguments
+///
+/// * `bytes` - The raw minidump data.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the input was parsed and every parser ran.
+/// * `Err(UserDmpError)` - If the input could not be parsed, or a parser panicked.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #![no_main]
+/// use libfuzzer_sys::fuzz_target;
+///
+/// fuzz_target!(|data: &[u8]| {
+///     let _ = userdmp::fuzz::parse_all(data);
+/// });
+/// ```
+pub fn parse_all(bytes: &[u8]) -> Result<()> {
+    panic::catch_unwind(AssertUnwindSafe(|| exercise(bytes))).unwrap_or_else(|payload| Err(UserDmpError::Panic(panic_message(payload))))
+}
+
+/// Runs the parsers over the dump. Only parsing the dump itself may fail; the other
+/// errors are expected on malformed input and ignored.
+fn exercise(bytes: &[u8]) -> Result<()> {
+    // Lenient parsing keeps going past malformed streams, so more parsers get to run.
+    let options = ParseOptions::new()
+        .mode(ParseMode::Lenient)
+        .invalid_ranges(InvalidRangePolicy::Skip);
+    let dump = UserDump::from_bytes_with_options(bytes, options)?;
+
+    for module in dump.modules().values() {
+        let _ = module.name();
+        let _ = module.origin();
+        let _ = module.section_entropy(&dump);
+        let _ = module
+            .debug_info()
+            .map(|info| info.debug_id());
+        let _ = module.is_32bit(&dump);
+        let _ = module.dbg_path();
+        let _ = module
+            .version_info()
+            .map(|info| info.to_string());
+        let _ = dump.module_resolution(module);
+        let _ = dump.exports(module.start_addr());
+        let _ = dump.rebuild_pe(module.start_addr(), &RebuildOptions::new().neutralize_relocations(true));
+        let _ = dump.extract_module(module.start_addr(), io::sink());
+    }
+
+    for thread in dump.threads().values() {
+        let _ = thread.name();
+        let _ = thread.start_symbol(&dump);
+        let _ = thread.context().registers();
+        let _ = thread.wow64_context(&dump);
+        let _ = dump.seh_chain(thread);
+        let _ = dump.stack_bounds(thread);
+        if let Ok(frames) = dump.stack_trace(thread) {
+            for frame in frames {
+                let _ = frame.stack_memory(&dump);
+            }
+        }
+    }
+
+    for memory in dump.memorys().values() {
+        let _ = memory.owner();
+        let _ = dump
+            .annotate_image_region(memory)
+            .map(|region| region.to_string());
+    }
+
+    for handle in dump.handles().values() {
+        let _ = handle.friendly_name();
+        let _ = handle.insight();
+        let _ = handle.mapped_regions(&dump);
+    }
+
+    for token in dump.tokens() {
+        let _ = token.user().map(|sid| sid.to_string());
+        let _ = token.integrity_level();
+    }
+
+    let _ = dump.exception_chain();
+    let _ = dump.integrity_level();
+    let _ = dump.performance_info();
+    let _ = dump.object_tree();
+    let _ = dump.unclosed_handle_opens();
+    let _ = dump.assemblies();
+    let _ = dump.strings(MIN_STRING_LEN).count();
+    let _ = dump.ansi_code_page();
+
+    if let Some(process) = dump.process() {
+        let _ = process.command_line();
+        let _ = process.image_path();
+        let _ = process.environment().count();
+        for list in [LoaderList::LoadOrder, LoaderList::MemoryOrder, LoaderList::InitializationOrder] {
+            let _ = process.loader_modules(list);
+        }
+    }
+
+    for heap in dump.heaps() {
+        let _ = dump.heap_entries(&heap);
+    }
+
+    let _ = dump.diff_module_lists();
+    let _ = dump.thread_starts();
+    let _ = dump.injected_regions();
+    let _ = dump.tampered_modules();
+    let _ = dump.hooks();
+
+    #[cfg(feature = "iced-x86")]
+    if let Some(exception) = dump.exception() {
+        let _ = exception.faulting_instructions(&dump);
+    }
+
+    let _ = dump.scan_secrets(&crate::budget::Budget::unlimited());
+    let _ = dump.high_entropy_regions(crate::analysis::entropy::HIGH_ENTROPY);
+    let _ = dump.write_elf_core(io::sink());
+
+    let space = dump.address_space();
+    for memory in dump.memorys().values() {
+        let _ = space.region_at(memory.range.start);
+        let _ = space.module_at(memory.range.end);
+        let _ = space.gap_at(memory.range.end);
+        let _ = space
+            .regions_in(memory.range.clone())
+            .count();
+        let _ = space.usage_at(memory.range.start);
+    }
+
+    let _ = space.to_string();
+    let _ = dump.scheduling_anomalies();
+    let _ = dump.handle_insights();
+    let _ = dump.os_info();
+    let _ = dump.system.os_description();
+    let _ = dump.system.cpu_vendor();
+    let _ = dump.system.cpu_features();
+    let _ = dump.summary().to_json();
+    let _ = dump.triage(&AnalysisRegistry::builtin());
+
+    Ok(())
+}
+
+/// Extracts the message of a panic payload.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| {
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+        })
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
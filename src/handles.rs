@@ -0,0 +1,834 @@
+use std::{collections::BTreeMap, fmt};
+use crate::parse::{Handle, HandleObjectInfoType, HandleOperation, HandleOperationType, Memory, UserDump};
+
+/// Prefixes of the NT object namespace rewritten by [`Handle::friendly_name`],
+/// checked in order. `*` matches a single path component (e.g., a session ID).
+const FRIENDLY_PREFIXES: [(&str, &str); 6] = [
+    ("\\Sessions\\*\\BaseNamedObjects\\", "Local\\"),
+    ("\\BaseNamedObjects\\", "Global\\"),
+    ("\\REGISTRY\\MACHINE\\", "HKLM\\"),
+    ("\\REGISTRY\\USER\\", "HKU\\"),
+    ("\\KnownDlls\\", "KnownDlls\\"),
+    ("\\KnownDlls32\\", "KnownDlls32\\"),
+];
+
+/// Returns the remainder of `name` after `prefix`, compared case-insensitively.
+fn strip_prefix_ignore_case<'n>(name: &'n str, prefix: &str) -> Option<&'n str> {
+    let mut rest = name;
+    for (index, component) in prefix.split('\\').enumerate() {
+        if index > 0 {
+            rest = rest.strip_prefix('\\')?;
+        }
+
+        let len = if component == "*" {
+            rest.find('\\').unwrap_or(rest.len())
+        } else {
+            component.len()
+        };
+        let head = rest.get(..len)?;
+        if component != "*" && !head.eq_ignore_ascii_case(component) {
+            return None;
+        }
+
+        rest = &rest[len..];
+    }
+
+    Some(rest)
+}
+
+impl Handle {
+    /// Returns the object directory containing the named object, e.g. `\Sessions\1\BaseNamedObjects`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - The path of the directory, `\` for objects in the root directory.
+    /// * `None` - If the handle has no object name or the name is not an absolute NT path.
+    pub fn directory(&self) -> Option<&str> {
+        let name = self
+            .object_name()
+            .filter(|name| name.starts_with('\\'))?;
+        match name.rfind('\\')? {
+            0 => Some("\\"),
+            end => Some(&name[..end]),
+        }
+    }
+
+    /// Returns the object name rewritten in the form used by Win32 APIs.
+    ///
+    /// Session-local and global named objects become `Local\name` and `Global\name`,
+    /// registry paths use the `HKLM` and `HKU` hive abbreviations, and known DLL sections
+    /// keep their directory. Other names are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The rewritten name.
+    /// * `None` - If the handle has no object name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for handle in dump.handles().values() {
+    ///     if let Some(name) = handle.friendly_name() {
+    ///         println!("{} {name}", handle.type_name().unwrap_or(""));
+    ///     }
+    /// }
+    /// ```
+    pub fn friendly_name(&self) -> Option<String> {
+        let name = self.object_name()?;
+        let friendly = FRIENDLY_PREFIXES
+            .iter()
+            .find_map(|(prefix, replacement)| strip_prefix_ignore_case(name, prefix).map(|rest| format!("{replacement}{rest}")));
+
+        Some(friendly.unwrap_or_else(|| name.to_string()))
+    }
+}
+
+/// Represents a directory of the NT object namespace, as seen through the handle table.
+#[derive(Debug, Clone, Default)]
+pub struct ObjectDirectory<'d> {
+    /// The name of the directory (empty for the root directory).
+    pub name: String,
+
+    /// The handles to objects located directly in this directory.
+    pub handles: Vec<&'d Handle>,
+
+    /// The subdirectories, indexed by name.
+    pub children: BTreeMap<String, ObjectDirectory<'d>>,
+}
+
+impl<'d> ObjectDirectory<'d> {
+    /// Returns the directory at a path relative to this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the directory, with components separated by `\`
+    ///   (e.g., `Sessions\1\BaseNamedObjects`).
+    pub fn get(&self, path: &str) -> Option<&ObjectDirectory<'d>> {
+        path.split('\\')
+            .filter(|component| !component.is_empty())
+            .try_fold(self, |directory, component| directory.children.get(component))
+    }
+
+    /// Returns the number of handles in this directory and all of its subdirectories.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+            + self
+                .children
+                .values()
+                .map(ObjectDirectory::len)
+                .sum::<usize>()
+    }
+
+    /// Returns true if neither this directory nor its subdirectories hold any handle.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl UserDump<'_> {
+    /// Groups the named handles by the object directory containing them.
+    ///
+    /// # Returns
+    ///
+    /// * A `BTreeMap` from directory path (e.g., `\Device`, `\KnownDlls`) to the handles
+    ///   of the objects it contains, ordered by handle value. Handles without an absolute
+    ///   object name are left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (directory, handles) in dump.handles_by_directory() {
+    ///     println!("{directory}: {} handles", handles.len());
+    /// }
+    /// ```
+    pub fn handles_by_directory(&self) -> BTreeMap<&str, Vec<&Handle>> {
+        let mut directories = BTreeMap::<&str, Vec<&Handle>>::new();
+        for handle in self.handles().values() {
+            if let Some(directory) = handle.directory() {
+                directories
+                    .entry(directory)
+                    .or_default()
+                    .push(handle);
+            }
+        }
+
+        directories
+    }
+
+    /// Builds a tree of the NT object namespace from the named handles.
+    ///
+    /// # Returns
+    ///
+    /// * The root [`ObjectDirectory`]. Handles without an absolute object name are left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let tree = dump.object_tree();
+    /// if let Some(objects) = tree.get("Sessions\\1\\BaseNamedObjects") {
+    ///     for handle in &objects.handles {
+    ///         println!("{}", handle.object_name().unwrap_or(""));
+    ///     }
+    /// }
+    /// ```
+    pub fn object_tree(&self) -> ObjectDirectory<'_> {
+        let mut root = ObjectDirectory::default();
+        for handle in self.handles().values() {
+            let Some(directory) = handle.directory() else {
+                continue;
+            };
+
+            let node = directory
+                .split('\\')
+                .filter(|component| !component.is_empty())
+                .fold(&mut root, |node, component| {
+                    node.children
+                        .entry(component.to_string())
+                        .or_insert_with(|| ObjectDirectory {
+                            name: component.to_string(),
+                            ..Default::default()
+                        })
+                });
+
+            node.handles.push(handle);
+        }
+
+        root
+    }
+}
+
+/// Standard access rights shared by every object type.
+const STANDARD_RIGHTS: [(u32, &str); 5] = [
+    (0x0001_0000, "DELETE"),
+    (0x0002_0000, "READ_CONTROL"),
+    (0x0004_0000, "WRITE_DAC"),
+    (0x0008_0000, "WRITE_OWNER"),
+    (0x0010_0000, "SYNCHRONIZE"),
+];
+
+/// Generic and special access rights, which may be requested for any object type.
+const GENERIC_RIGHTS: [(u32, &str); 6] = [
+    (0x0100_0000, "ACCESS_SYSTEM_SECURITY"),
+    (0x0200_0000, "MAXIMUM_ALLOWED"),
+    (0x1000_0000, "GENERIC_ALL"),
+    (0x2000_0000, "GENERIC_EXECUTE"),
+    (0x4000_0000, "GENERIC_WRITE"),
+    (0x8000_0000, "GENERIC_READ"),
+];
+
+/// Specific access rights of files, devices and pipes.
+const FILE_RIGHTS: [(u32, &str); 9] = [
+    (0x0001, "FILE_READ_DATA"),
+    (0x0002, "FILE_WRITE_DATA"),
+    (0x0004, "FILE_APPEND_DATA"),
+    (0x0008, "FILE_READ_EA"),
+    (0x0010, "FILE_WRITE_EA"),
+    (0x0020, "FILE_EXECUTE"),
+    (0x0040, "FILE_DELETE_CHILD"),
+    (0x0080, "FILE_READ_ATTRIBUTES"),
+    (0x0100, "FILE_WRITE_ATTRIBUTES"),
+];
+
+/// Specific access rights of processes.
+const PROCESS_RIGHTS: [(u32, &str); 14] = [
+    (0x0001, "PROCESS_TERMINATE"),
+    (0x0002, "PROCESS_CREATE_THREAD"),
+    (0x0004, "PROCESS_SET_SESSIONID"),
+    (0x0008, "PROCESS_VM_OPERATION"),
+    (0x0010, "PROCESS_VM_READ"),
+    (0x0020, "PROCESS_VM_WRITE"),
+    (0x0040, "PROCESS_DUP_HANDLE"),
+    (0x0080, "PROCESS_CREATE_PROCESS"),
+    (0x0100, "PROCESS_SET_QUOTA"),
+    (0x0200, "PROCESS_SET_INFORMATION"),
+    (0x0400, "PROCESS_QUERY_INFORMATION"),
+    (0x0800, "PROCESS_SUSPEND_RESUME"),
+    (0x1000, "PROCESS_QUERY_LIMITED_INFORMATION"),
+    (0x2000, "PROCESS_SET_LIMITED_INFORMATION"),
+];
+
+/// Specific access rights of threads.
+const THREAD_RIGHTS: [(u32, &str); 13] = [
+    (0x0001, "THREAD_TERMINATE"),
+    (0x0002, "THREAD_SUSPEND_RESUME"),
+    (0x0004, "THREAD_ALERT"),
+    (0x0008, "THREAD_GET_CONTEXT"),
+    (0x0010, "THREAD_SET_CONTEXT"),
+    (0x0020, "THREAD_SET_INFORMATION"),
+    (0x0040, "THREAD_QUERY_INFORMATION"),
+    (0x0080, "THREAD_SET_THREAD_TOKEN"),
+    (0x0100, "THREAD_IMPERSONATE"),
+    (0x0200, "THREAD_DIRECT_IMPERSONATION"),
+    (0x0400, "THREAD_SET_LIMITED_INFORMATION"),
+    (0x0800, "THREAD_QUERY_LIMITED_INFORMATION"),
+    (0x1000, "THREAD_RESUME"),
+];
+
+/// Specific access rights of registry keys.
+const KEY_RIGHTS: [(u32, &str); 8] = [
+    (0x0001, "KEY_QUERY_VALUE"),
+    (0x0002, "KEY_SET_VALUE"),
+    (0x0004, "KEY_CREATE_SUB_KEY"),
+    (0x0008, "KEY_ENUMERATE_SUB_KEYS"),
+    (0x0010, "KEY_NOTIFY"),
+    (0x0020, "KEY_CREATE_LINK"),
+    (0x0100, "KEY_WOW64_64KEY"),
+    (0x0200, "KEY_WOW64_32KEY"),
+];
+
+/// Specific access rights of access tokens.
+const TOKEN_RIGHTS: [(u32, &str); 9] = [
+    (0x0001, "TOKEN_ASSIGN_PRIMARY"),
+    (0x0002, "TOKEN_DUPLICATE"),
+    (0x0004, "TOKEN_IMPERSONATE"),
+    (0x0008, "TOKEN_QUERY"),
+    (0x0010, "TOKEN_QUERY_SOURCE"),
+    (0x0020, "TOKEN_ADJUST_PRIVILEGES"),
+    (0x0040, "TOKEN_ADJUST_GROUPS"),
+    (0x0080, "TOKEN_ADJUST_DEFAULT"),
+    (0x0100, "TOKEN_ADJUST_SESSIONID"),
+];
+
+/// Specific access rights of events.
+const EVENT_RIGHTS: [(u32, &str); 2] = [(0x0001, "EVENT_QUERY_STATE"), (0x0002, "EVENT_MODIFY_STATE")];
+
+/// Specific access rights of mutexes.
+const MUTANT_RIGHTS: [(u32, &str); 1] = [(0x0001, "MUTANT_QUERY_STATE")];
+
+/// Specific access rights of semaphores.
+const SEMAPHORE_RIGHTS: [(u32, &str); 2] = [(0x0001, "SEMAPHORE_QUERY_STATE"), (0x0002, "SEMAPHORE_MODIFY_STATE")];
+
+/// Specific access rights of waitable timers.
+const TIMER_RIGHTS: [(u32, &str); 2] = [(0x0001, "TIMER_QUERY_STATE"), (0x0002, "TIMER_MODIFY_STATE")];
+
+/// Specific access rights of I/O completion ports.
+const IO_COMPLETION_RIGHTS: [(u32, &str); 2] = [(0x0001, "IO_COMPLETION_QUERY_STATE"), (0x0002, "IO_COMPLETION_MODIFY_STATE")];
+
+/// Specific access rights of object manager directories.
+const DIRECTORY_RIGHTS: [(u32, &str); 4] = [
+    (0x0001, "DIRECTORY_QUERY"),
+    (0x0002, "DIRECTORY_TRAVERSE"),
+    (0x0004, "DIRECTORY_CREATE_OBJECT"),
+    (0x0008, "DIRECTORY_CREATE_SUBDIRECTORY"),
+];
+
+/// Specific access rights of job objects.
+const JOB_RIGHTS: [(u32, &str); 6] = [
+    (0x0001, "JOB_OBJECT_ASSIGN_PROCESS"),
+    (0x0002, "JOB_OBJECT_SET_ATTRIBUTES"),
+    (0x0004, "JOB_OBJECT_QUERY"),
+    (0x0008, "JOB_OBJECT_TERMINATE"),
+    (0x0010, "JOB_OBJECT_SET_SECURITY_ATTRIBUTES"),
+    (0x0020, "JOB_OBJECT_IMPERSONATE"),
+];
+
+/// Specific access rights of section objects.
+const SECTION_RIGHTS: [(u32, &str); 6] = [
+    (0x0001, "SECTION_QUERY"),
+    (0x0002, "SECTION_MAP_WRITE"),
+    (0x0004, "SECTION_MAP_READ"),
+    (0x0008, "SECTION_MAP_EXECUTE"),
+    (0x0010, "SECTION_EXTEND_SIZE"),
+    (0x0020, "SECTION_MAP_EXECUTE_EXPLICIT"),
+];
+
+/// Specific access rights of ALPC ports.
+const ALPC_PORT_RIGHTS: [(u32, &str); 1] = [(0x0001, "PORT_CONNECT")];
+
+/// Well-known section names and what they reveal about the process, matched against
+/// the object name (after the last `\`) case-insensitively. A trailing `*` matches any suffix.
+const KNOWN_SECTIONS: [(&str, &str); 6] = [
+    ("__ComCatalogCache__", "COM+ catalog cache"),
+    ("windows_shell_global_counters", "shell global counters"),
+    ("Cor_Private_IPCBlock*", ".NET runtime IPC block"),
+    ("Cor_SxSPublic_IPCBlock", ".NET runtime IPC block"),
+    ("CrSharedMem_*", "Chromium shared memory"),
+    ("SessionImmersiveColorPreference", "immersive color preferences"),
+];
+
+/// The kind of object decoded by [`Handle::insight`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum InsightKind {
+    /// A job object (`Job`), used by sandboxes and brokers to constrain processes.
+    Job,
+
+    /// A section object (`Section`), i.e. shared or file-backed memory.
+    Section,
+
+    /// An ALPC port (`ALPC Port`), the transport of LRPC and COM.
+    AlpcPort,
+}
+
+/// The decoded meaning of a job, section or ALPC port handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandleInsight {
+    /// The kind of object.
+    pub kind: InsightKind,
+
+    /// The names of the access rights granted to the handle, specific rights first.
+    /// Bits without a known name are reported in hexadecimal.
+    pub rights: Vec<String>,
+
+    /// What the handle most likely is used for, inferred from its name and access.
+    pub role: Option<&'static str>,
+}
+
+impl fmt::Display for HandleInsight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            InsightKind::Job => "job",
+            InsightKind::Section => "section",
+            InsightKind::AlpcPort => "ALPC port",
+        };
+
+        write!(f, "{kind}")?;
+        if let Some(role) = self.role {
+            write!(f, " ({role})")?;
+        }
+
+        write!(f, ": {}", self.rights.join(" | "))
+    }
+}
+
+/// Returns the specific access rights of an object type, as named by `NtQueryObject`.
+fn specific_rights(type_name: &str) -> &'static [(u32, &'static str)] {
+    match type_name {
+        "File" => &FILE_RIGHTS,
+        "Process" => &PROCESS_RIGHTS,
+        "Thread" => &THREAD_RIGHTS,
+        "Key" => &KEY_RIGHTS,
+        "Token" => &TOKEN_RIGHTS,
+        "Event" => &EVENT_RIGHTS,
+        "Mutant" => &MUTANT_RIGHTS,
+        "Semaphore" => &SEMAPHORE_RIGHTS,
+        "Timer" => &TIMER_RIGHTS,
+        "IoCompletion" => &IO_COMPLETION_RIGHTS,
+        "Directory" => &DIRECTORY_RIGHTS,
+        "Job" => &JOB_RIGHTS,
+        "Section" => &SECTION_RIGHTS,
+        "ALPC Port" => &ALPC_PORT_RIGHTS,
+        _ => &[],
+    }
+}
+
+/// Returns the names of the rights set in `access`, specific rights first, then
+/// standard and generic rights, with the bits left without a name.
+fn named_rights(access: u32, specific: &[(u32, &'static str)]) -> (Vec<&'static str>, u32) {
+    let mut remaining = access;
+    let mut rights = Vec::new();
+    for (mask, name) in specific
+        .iter()
+        .chain(STANDARD_RIGHTS.iter())
+        .chain(GENERIC_RIGHTS.iter())
+    {
+        if access & mask != 0 {
+            rights.push(*name);
+            remaining &= !mask;
+        }
+    }
+
+    (rights, remaining)
+}
+
+/// Returns the names of the rights set in `access`, specific rights first.
+fn access_rights(access: u32, specific: &[(u32, &'static str)]) -> Vec<String> {
+    let (rights, remaining) = named_rights(access, specific);
+    let mut rights = rights
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    if remaining != 0 {
+        rights.push(format!("{remaining:#x}"));
+    }
+
+    rights
+}
+
+/// Guesses the purpose of a section from its name.
+fn section_role(name: Option<&str>) -> Option<&'static str> {
+    let Some(name) = name else {
+        return Some("anonymous shared memory");
+    };
+
+    let leaf = name.rsplit('\\').next().unwrap_or(name);
+    if strip_prefix_ignore_case(name, "\\KnownDlls\\").is_some() || strip_prefix_ignore_case(name, "\\KnownDlls32\\").is_some() {
+        return Some("known DLL");
+    }
+
+    KNOWN_SECTIONS
+        .iter()
+        .find_map(|(pattern, role)| {
+            let matches = match pattern.strip_suffix('*') {
+                Some(prefix) => leaf
+                    .get(..prefix.len())
+                    .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+                None => leaf.eq_ignore_ascii_case(pattern),
+            };
+
+            matches.then_some(*role)
+        })
+}
+
+/// Guesses the purpose of an ALPC port from its name.
+fn alpc_port_role(name: Option<&str>) -> Option<&'static str> {
+    let name = name?;
+    let leaf = name.rsplit('\\').next().unwrap_or(name);
+    if leaf.eq_ignore_ascii_case("ApiPort") {
+        Some("CSRSS API port")
+    } else if leaf.eq_ignore_ascii_case("ThemeApiPort") {
+        Some("theme service port")
+    } else if leaf
+        .get(..3)
+        .is_some_and(|head| head.eq_ignore_ascii_case("OLE"))
+    {
+        Some("COM activation endpoint")
+    } else if leaf
+        .get(..5)
+        .is_some_and(|head| head.eq_ignore_ascii_case("LRPC-"))
+    {
+        Some("dynamic RPC endpoint")
+    } else if strip_prefix_ignore_case(name, "\\RPC Control\\").is_some() {
+        Some("RPC endpoint")
+    } else {
+        None
+    }
+}
+
+/// Guesses whether the process manages or merely belongs to a job from the granted access.
+fn job_role(access: u32) -> Option<&'static str> {
+    const ASSIGN_OR_CONFIGURE: u32 = 0x0001 | 0x0002 | 0x0008;
+    if access & ASSIGN_OR_CONFIGURE != 0 {
+        Some("managed by this process (broker)")
+    } else if access & 0x0004 != 0 {
+        Some("queried only (likely a member)")
+    } else {
+        None
+    }
+}
+
+impl Handle {
+    /// Translates the granted access mask into the names of the rights it holds.
+    ///
+    /// Specific rights are decoded according to the object type (e.g., `FILE_READ_DATA`
+    /// for files, `PROCESS_VM_WRITE` for processes, `KEY_SET_VALUE` for registry keys),
+    /// followed by the standard and generic rights. Bits without a known name for the
+    /// type, including every specific bit of unsupported types, are left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for handle in dump.handles().values() {
+    ///     println!("{} {}", handle.handle(), handle.access_rights().join(" | "));
+    /// }
+    /// ```
+    pub fn access_rights(&self) -> Vec<&'static str> {
+        let specific = self
+            .type_name()
+            .map_or(&[][..], specific_rights);
+        named_rights(self.granted_access, specific).0
+    }
+
+    /// Decodes the access rights and likely purpose of job, section and ALPC port handles.
+    ///
+    /// Sandboxed and broker processes reveal their configuration through these objects:
+    /// a broker holds jobs with `JOB_OBJECT_ASSIGN_PROCESS`, shared sections carry
+    /// well-known names and ALPC ports expose the RPC and COM endpoints in use.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(HandleInsight)` - If the handle refers to a job, a section or an ALPC port.
+    /// * `None` - For any other object type.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for handle in dump.handles().values() {
+    ///     if let Some(insight) = handle.insight() {
+    ///         println!("{} {insight}", handle.handle());
+    ///     }
+    /// }
+    /// ```
+    pub fn insight(&self) -> Option<HandleInsight> {
+        let name = self.object_name();
+        let type_name = self.type_name()?;
+        let (kind, role) = match type_name {
+            "Job" => (InsightKind::Job, job_role(self.granted_access)),
+            "Section" => (InsightKind::Section, section_role(name)),
+            "ALPC Port" => (InsightKind::AlpcPort, alpc_port_role(name)),
+            _ => return None,
+        };
+
+        Some(HandleInsight {
+            kind,
+            rights: access_rights(self.granted_access, specific_rights(type_name)),
+            role,
+        })
+    }
+}
+
+impl UserDump<'_> {
+    /// Decodes every job, section and ALPC port handle of the process.
+    ///
+    /// # Returns
+    ///
+    /// * A list of handles paired with their [`HandleInsight`], ordered by handle value.
+    pub fn handle_insights(&self) -> Vec<(&Handle, HandleInsight)> {
+        self.handles()
+            .values()
+            .filter_map(|handle| {
+                handle
+                    .insight()
+                    .map(|insight| (handle, insight))
+            })
+            .collect()
+    }
+}
+
+impl UserDump<'_> {
+    /// Groups the traced handle operations by handle value.
+    ///
+    /// # Returns
+    ///
+    /// * A `BTreeMap` from handle value to its operations, in the order they were traced.
+    ///   Empty if the dump has no `HandleOperationListStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (handle, operations) in dump.handle_traces() {
+    ///     println!("{handle:#x}: {} operations", operations.len());
+    /// }
+    /// ```
+    pub fn handle_traces(&self) -> BTreeMap<u64, Vec<&HandleOperation>> {
+        let mut traces = BTreeMap::<u64, Vec<&HandleOperation>>::new();
+        for operation in self.handle_operations() {
+            traces
+                .entry(operation.handle)
+                .or_default()
+                .push(operation);
+        }
+
+        traces
+    }
+
+    /// Returns the traced opens that were never followed by a close of the same handle value.
+    ///
+    /// These are the candidates of a handle leak: the backtrace of each operation shows
+    /// where the handle was opened.
+    ///
+    /// # Returns
+    ///
+    /// * The last operation of every handle whose trace ends with an open, ordered by handle value.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for open in dump.unclosed_handle_opens() {
+    ///     println!("{:#x} opened from {:x?}", open.handle, open.backtrace());
+    /// }
+    /// ```
+    pub fn unclosed_handle_opens(&self) -> Vec<&HandleOperation> {
+        self.handle_traces()
+            .into_values()
+            .filter_map(|operations| {
+                operations
+                    .into_iter()
+                    .rev()
+                    .find(|operation| operation.operation != HandleOperationType::BadReference)
+                    .filter(|operation| operation.operation == HandleOperationType::Open)
+            })
+            .collect()
+    }
+}
+
+/// Returns a path relative to its volume, lowercased, so that the DOS and NT forms of a
+/// path compare equal (e.g., `C:\Windows\a.dll` and `\Device\HarddiskVolume3\Windows\a.dll`).
+///
+/// UNC paths keep their server and share, and `\SystemRoot\` is expanded to `\windows\`.
+fn volume_relative_path(path: &str) -> Option<String> {
+    let path = path.to_lowercase().replace('/', "\\");
+    let path = ["\\\\?\\", "\\??\\"]
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix))
+        .unwrap_or(&path);
+
+    if let Some(share) = path
+        .strip_prefix("unc\\")
+        .or_else(|| path.strip_prefix("\\device\\mup\\"))
+        .or_else(|| path.strip_prefix("\\\\"))
+    {
+        return Some(format!("\\\\{share}"));
+    }
+
+    if let Some(rest) = path.strip_prefix("\\systemroot\\") {
+        return Some(format!("\\windows\\{rest}"));
+    }
+
+    let relative = if path.as_bytes().get(1) == Some(&b':') {
+        &path[2..]
+    } else {
+        let rest = path.strip_prefix("\\device\\")?;
+        &rest[rest.find('\\')?..]
+    };
+
+    relative
+        .starts_with('\\')
+        .then(|| relative.to_string())
+}
+
+/// Reads the `BaseAddress` and `MaximumSize` of a `SECTION_BASIC_INFORMATION`, as held in
+/// `MiniSectionInformation1` blocks, for both the 32-bit and 64-bit layouts.
+fn section_basic_information(data: &[u8]) -> Option<(u64, u64)> {
+    let u64_at = |offset: usize| {
+        data.get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap_or_default()))
+    };
+
+    match data.len() {
+        24 => Some((u64_at(0)?, u64_at(16)?)),
+        16 => {
+            let base = u32::from_le_bytes(data[..4].try_into().ok()?);
+            Some((base as u64, u64_at(8)?))
+        }
+        _ => None,
+    }
+}
+
+impl Handle {
+    /// Correlates a `File` or `Section` handle with the views of it mapped in the process.
+    ///
+    /// Minidumps do not record which file backs a mapped region, so the views are matched
+    /// through what is known about the object:
+    ///
+    /// * `File` handles match the image views of the modules loaded from the same path.
+    ///   Paths are compared relative to their volume, so a file with the same path on
+    ///   another volume would match as well.
+    /// * `Section` handles match the image view at the `BaseAddress` found in their
+    ///   object information, the views of the known DLL they name (`\KnownDlls\...`),
+    ///   and the mapped allocations whose size is the size of the section.
+    ///   The latter only finds views covering the whole section.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the handle was read from.
+    ///
+    /// # Returns
+    ///
+    /// * A list of the regions of every matching view, in address order. The list is
+    ///   empty for other object types and unnamed files.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for handle in dump.handles().values() {
+    ///     for region in handle.mapped_regions(&dump) {
+    ///         println!("{:#x} {:#x}-{:#x}", handle.handle(), region.range.start, region.range.end);
+    ///     }
+    /// }
+    /// ```
+    pub fn mapped_regions<'d, 'a>(&self, dump: &'d UserDump<'a>) -> Vec<&'d Memory<'a>> {
+        // Collects the allocation bases of the views, then their regions.
+        let mut bases = Vec::new();
+        let mut section_size = None;
+        match self.type_name() {
+            Some("File") => {
+                let Some(path) = self
+                    .object_name()
+                    .and_then(volume_relative_path)
+                else {
+                    return Vec::new();
+                };
+
+                bases.extend(
+                    dump.modules()
+                        .values()
+                        .filter(|module| volume_relative_path(&module.path.to_string_lossy()).as_deref() == Some(path.as_str()))
+                        .map(|module| module.range.start),
+                );
+            }
+            Some("Section") => {
+                for info in self
+                    .object_info()
+                    .iter()
+                    .filter(|info| info.info_type == HandleObjectInfoType::Section)
+                {
+                    if let Some((base, size)) = section_basic_information(&info.data) {
+                        if base != 0 {
+                            bases.push(base);
+                        } else if size != 0 {
+                            section_size = Some(size.next_multiple_of(0x1000));
+                        }
+                    }
+                }
+
+                // Known DLL sections are named after the DLL, in System32 or SysWOW64.
+                let known = self.object_name().and_then(|name| {
+                    strip_prefix_ignore_case(name, "\\KnownDlls\\")
+                        .map(|dll| ("\\system32\\", dll))
+                        .or_else(|| strip_prefix_ignore_case(name, "\\KnownDlls32\\").map(|dll| ("\\syswow64\\", dll)))
+                });
+
+                if let Some((directory, dll)) = known {
+                    let suffix = format!("{directory}{}", dll.to_lowercase());
+                    bases.extend(
+                        dump.modules()
+                            .values()
+                            .filter(|module| volume_relative_path(&module.path.to_string_lossy()).is_some_and(|path| path.ends_with(&suffix)))
+                            .map(|module| module.range.start),
+                    );
+                }
+            }
+            _ => return Vec::new(),
+        }
+
+        // Sizes the mapped allocations, to find the views covering the whole section.
+        if let Some(size) = section_size {
+            let mut allocations = BTreeMap::<u64, u64>::new();
+            for memory in dump
+                .memorys()
+                .values()
+                .filter(|memory| memory.type_.is_mapped())
+            {
+                *allocations
+                    .entry(memory.allocation_base)
+                    .or_default() += memory.len();
+            }
+
+            bases.extend(
+                allocations
+                    .into_iter()
+                    .filter(|(_, len)| *len == size)
+                    .map(|(base, _)| base),
+            );
+        }
+
+        dump.memorys()
+            .values()
+            .filter(|memory| !memory.type_.is_private() && bases.contains(&memory.allocation_base))
+            .collect()
+    }
+}
@@ -0,0 +1,750 @@
+use std::{collections::HashSet, ops::Range};
+use crate::budget::{Budget, Partial};
+use crate::offsets::StructOffsets;
+use crate::parse::{Arch, UserDump};
+use crate::pointer::looks_like_pointer;
+use crate::reader::offset_va;
+
+/// Signature stored in every segment of an NT heap.
+const NT_HEAP_SIGNATURE: u32 = 0xFFEE_FFEE;
+
+/// Signature stored in the header of a segment heap.
+const SEGMENT_HEAP_SIGNATURE: u32 = 0xDDEE_DDEE;
+
+/// The block is allocated.
+const HEAP_ENTRY_BUSY: u8 = 0x01;
+
+/// The block is the last one before an uncommitted range or the end of the segment.
+const HEAP_ENTRY_LAST_ENTRY: u8 = 0x10;
+
+/// Set in `UnusedBytes` of the blocks carved by the low-fragmentation heap.
+const HEAP_ENTRY_LFH: u8 = 0x80;
+
+/// Signature of the `_HEAP_USERDATA_HEADER` starting the user blocks of an LFH subsegment.
+const LFH_USERDATA_SIGNATURE: u32 = 0xF0E0_D0C0;
+
+/// The page range holds an LFH subsegment.
+const PAGE_RANGE_FLAGS_LFH_SUBSEGMENT: u8 = 0x01;
+
+/// The page range is allocated.
+const PAGE_RANGE_FLAGS_ALLOCATED: u8 = 0x04;
+
+/// The descriptor is the first of its page range, and holds its `UnitSize`.
+const PAGE_RANGE_FLAGS_FIRST: u8 = 0x08;
+
+/// Number of blocks walked between two checks of a [`Budget`].
+const BUDGET_CHECK_INTERVAL: usize = 0x400;
+
+/// Upper bound on the number of heaps, segments, entries and page descriptors followed, to survive corrupted lists.
+const MAX_ITEMS: usize = 0x10_0000;
+
+/// Identifies the allocator backing a heap.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum HeapBackend {
+    /// The classic NT heap (`_HEAP`).
+    Nt,
+
+    /// The segment heap (`_SEGMENT_HEAP`), used by UWP apps, Edge and some system processes
+    /// since Windows 10.
+    Segment,
+}
+
+/// Represents a heap of the captured process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heap {
+    /// The address of the heap (the handle returned by `HeapCreate`).
+    pub base: u64,
+
+    /// The allocator backing the heap.
+    pub backend: HeapBackend,
+
+    /// The flags the heap was created with (e.g., `HEAP_GROWABLE`), or the `GlobalFlags`
+    /// of a segment heap.
+    pub flags: u32,
+
+    /// The address ranges of the segments of the heap: from `FirstEntry` to `LastValidEntry`
+    /// for an NT heap, the whole page segments for a segment heap.
+    pub segments: Vec<Range<u64>>,
+}
+
+/// Represents a block of a heap, as described by its `_HEAP_ENTRY` header or, for segment
+/// heaps, by its page range descriptor or LFH subsegment.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct HeapEntry {
+    /// The address of the block header.
+    pub header: u64,
+
+    /// The size of the whole block, header included, in bytes.
+    pub size: u64,
+
+    /// The address returned to the caller of `HeapAlloc` (right after the header, if any).
+    pub user_address: u64,
+
+    /// The number of bytes requested by the caller, for busy blocks.
+    pub user_size: Option<u64>,
+
+    /// Indicates whether the block is allocated.
+    pub busy: bool,
+
+    /// The decoded `Flags` of the header, or the `RangeFlags` of a segment heap page range.
+    pub flags: u8,
+
+    /// Indicates whether the block was carved by the low-fragmentation heap out of a
+    /// busy backend block or page range, which is listed right before it.
+    pub lfh: bool,
+}
+
+impl HeapEntry {
+    /// Returns the range of addresses covered by the block, header included.
+    pub fn range(&self) -> Range<u64> {
+        self.header..self.header.saturating_add(self.size)
+    }
+}
+
+/// Describes where an arbitrary address falls within the heaps of the process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapOwner {
+    /// The address of the heap owning the address.
+    pub heap: u64,
+
+    /// The segment of the heap containing the address.
+    pub segment: Range<u64>,
+
+    /// The block containing the address.
+    pub entry: HeapEntry,
+
+    /// Indicates whether the address points into the block header rather than the user data.
+    pub in_header: bool,
+
+    /// The offset of the address from [`HeapEntry::user_address`], for addresses in the user data.
+    pub user_offset: Option<u64>,
+}
+
+/// A segment context of a segment heap, with the geometry of its page segments.
+struct SegContext {
+    /// The address of the `_HEAP_SEG_CONTEXT`.
+    address: u64,
+
+    /// The size of each page segment, in bytes.
+    segment_size: u64,
+
+    /// The shift turning a unit index into an offset in the segment.
+    unit_shift: u32,
+
+    /// The index of the first descriptor past the segment metadata.
+    first_descriptor: u64,
+}
+
+impl UserDump<'_> {
+    /// Locates the heaps of the process through `PEB.ProcessHeaps`.
+    ///
+    /// Both NT heaps and segment heaps are recognized; heaps whose header was not captured are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * A list of [`Heap`], in `ProcessHeaps` order (the default process heap comes first).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for heap in dump.heaps() {
+    ///     println!("{:#x} ({:?}): {} segments", heap.base, heap.backend, heap.segments.len());
+    /// }
+    /// ```
+    pub fn heaps(&self) -> Vec<Heap> {
+        let (Some(offsets), Some(peb)) = (self.struct_offsets(), self.peb_address()) else {
+            return Vec::new();
+        };
+
+        let pointer = self.pointer_size() as u64;
+        let count = offset_va(peb, offsets.peb.number_of_heaps as u64)
+            .and_then(|va| self.read_u32(va))
+            .unwrap_or(0) as usize;
+        let Ok(array) = offset_va(peb, offsets.peb.process_heaps as u64).and_then(|va| self.read_ptr(va)) else {
+            return Vec::new();
+        };
+
+        (0..count.min(MAX_ITEMS) as u64)
+            .filter_map(|index| {
+                offset_va(array, index * pointer)
+                    .and_then(|va| self.read_ptr(va))
+                    .ok()
+            })
+            .filter_map(|base| {
+                self.nt_heap(base, &offsets)
+                    .or_else(|| self.segment_heap(base, &offsets))
+            })
+            .collect()
+    }
+
+    /// Walks the blocks of every segment of a heap.
+    ///
+    /// Uncommitted ranges and pages missing from the dump are skipped. The blocks of the
+    /// low-fragmentation heap (Windows 8 and later) follow the busy backend block holding
+    /// them, flagged with [`HeapEntry::lfh`]; their `user_size` is not recorded.
+    ///
+    /// The blocks of a segment heap are its page ranges and LFH blocks. Page ranges
+    /// holding variable-size (VS) allocations are listed as a whole, since the chunk
+    /// headers inside them are encoded with a key kept in `ntdll`. Large allocations,
+    /// made directly from virtual memory, are not listed for either backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `heap` - The heap to walk, as returned by [`UserDump::heaps`].
+    ///
+    /// # Returns
+    ///
+    /// * A list of [`HeapEntry`], ordered by segment and then by address.
+    pub fn heap_entries(&self, heap: &Heap) -> Vec<HeapEntry> {
+        self.heap_entries_within(heap, &Budget::unlimited())
+            .into_inner()
+    }
+
+    /// Walks the blocks of every segment of a heap, stopping when the budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `heap` - The heap to walk, as returned by [`UserDump::heaps`].
+    /// * `budget` - Limits how long the walk may run.
+    ///
+    /// # Returns
+    ///
+    /// * The blocks found, ordered by segment and then by address, and whether the walk stopped early.
+    pub fn heap_entries_within(&self, heap: &Heap, budget: &Budget) -> Partial<Vec<HeapEntry>> {
+        let mut entries = Vec::new();
+        let Some(offsets) = self.struct_offsets() else {
+            return Partial {
+                value: entries,
+                timed_out: false,
+            };
+        };
+
+        for segment in &heap.segments {
+            let walk = self.heap_segment_entries(heap, segment.clone(), &offsets, budget);
+            entries.extend(walk.value);
+            if walk.timed_out {
+                return Partial {
+                    value: entries,
+                    timed_out: true,
+                };
+            }
+        }
+
+        Partial {
+            value: entries,
+            timed_out: false,
+        }
+    }
+
+    /// Reports which heap block an arbitrary address belongs to.
+    ///
+    /// This answers the usual question when triaging a crash on a corrupted pointer:
+    /// whether it points into a live allocation, a freed block or a block header.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(HeapOwner)` - The heap, segment and block containing `va`.
+    /// * `None` - If `va` is not inside the committed part of a heap segment.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(owner) = dump.heap_owner(0x1f_2a40) {
+    ///     let state = if owner.entry.busy { "busy" } else { "free" };
+    ///     println!("heap {:#x}, {state} block at {:#x} ({} bytes)", owner.heap, owner.entry.header, owner.entry.size);
+    /// }
+    /// ```
+    pub fn heap_owner(&self, va: u64) -> Option<HeapOwner> {
+        let offsets = self.struct_offsets()?;
+        self.heaps()
+            .into_iter()
+            .find_map(|heap| {
+                let segment = heap
+                    .segments
+                    .iter()
+                    .find(|segment| segment.contains(&va))?
+                    .clone();
+                // LFH blocks follow their backend block, so the last block containing `va` is the innermost.
+                let entry = self
+                    .heap_segment_entries(&heap, segment.clone(), &offsets, &Budget::unlimited())
+                    .value
+                    .into_iter()
+                    .rev()
+                    .find(|entry| entry.range().contains(&va))?;
+
+                let in_header = va < entry.user_address;
+                Some(HeapOwner {
+                    heap: heap.base,
+                    segment,
+                    entry,
+                    in_header,
+                    user_offset: (!in_header).then(|| va - entry.user_address),
+                })
+            })
+    }
+
+    /// Reads the header of the heap at `base` and collects its segments.
+    fn nt_heap(&self, base: u64, offsets: &StructOffsets) -> Option<Heap> {
+        let heap = &offsets.heap;
+        if offset_va(base, heap.segment_signature as u64)
+            .and_then(|va| self.read_u32(va))
+            .ok()?
+            != NT_HEAP_SIGNATURE
+        {
+            return None;
+        }
+
+        let flags = offset_va(base, heap.flags as u64)
+            .and_then(|va| self.read_u32(va))
+            .ok()?;
+
+        // The segments are linked through `SegmentListEntry`, starting at `_HEAP.SegmentList`.
+        let head = base.checked_add(heap.segment_list as u64)?;
+        let mut segments = Vec::new();
+        let mut seen = HashSet::new();
+        let mut link = self.read_ptr(head).ok()?;
+        while link != head && looks_like_pointer(link, self) && seen.insert(link) && segments.len() < MAX_ITEMS {
+            let Some(segment) = link.checked_sub(heap.segment_list_entry as u64) else {
+                break;
+            };
+
+            let first = offset_va(segment, heap.first_entry as u64).and_then(|va| self.read_ptr(va));
+            let last = offset_va(segment, heap.last_valid_entry as u64).and_then(|va| self.read_ptr(va));
+            if let (Ok(first), Ok(last)) = (first, last)
+                && first < last
+            {
+                segments.push(first..last);
+            }
+
+            let Ok(next) = self.read_ptr(link) else {
+                break;
+            };
+
+            link = next;
+        }
+
+        Some(Heap {
+            base,
+            backend: HeapBackend::Nt,
+            flags,
+            segments,
+        })
+    }
+
+    /// Reads the header of the segment heap at `base` and collects the page segments of
+    /// both of its segment contexts.
+    fn segment_heap(&self, base: u64, offsets: &StructOffsets) -> Option<Heap> {
+        let layout = &offsets.segment_heap;
+        if offset_va(base, layout.signature as u64)
+            .and_then(|va| self.read_u32(va))
+            .ok()?
+            != SEGMENT_HEAP_SIGNATURE
+        {
+            return None;
+        }
+
+        let flags = offset_va(base, layout.global_flags as u64)
+            .and_then(|va| self.read_u32(va))
+            .ok()?;
+
+        let mut segments = Vec::new();
+        let mut seen = HashSet::new();
+        for context in self.seg_contexts(base, offsets) {
+            // The page segments start with their `ListEntry`, linked from `SegmentListHead`.
+            let Ok(head) = offset_va(context.address, layout.segment_list_head as u64) else {
+                continue;
+            };
+
+            let Ok(mut link) = self.read_ptr(head) else {
+                continue;
+            };
+
+            while link != head && looks_like_pointer(link, self) && seen.insert(link) && segments.len() < MAX_ITEMS {
+                segments.push(link..link.saturating_add(context.segment_size));
+                let Ok(next) = self.read_ptr(link) else {
+                    break;
+                };
+
+                link = next;
+            }
+        }
+
+        segments.sort_by_key(|segment| segment.start);
+        Some(Heap {
+            base,
+            backend: HeapBackend::Segment,
+            flags,
+            segments,
+        })
+    }
+
+    /// Reads the segment contexts of the segment heap at `base`.
+    ///
+    /// A context is only kept if its `Heap` field points back to the heap, which rules
+    /// out layouts not matching the build of the dump.
+    fn seg_contexts(&self, base: u64, offsets: &StructOffsets) -> Vec<SegContext> {
+        let layout = &offsets.segment_heap;
+        (0..2u64)
+            .filter_map(|index| {
+                let address = base.checked_add(layout.seg_contexts as u64 + index * layout.seg_context_size as u64)?;
+                if self
+                    .read_ptr(address.checked_add(layout.context_heap as u64)?)
+                    .ok()?
+                    != base
+                {
+                    return None;
+                }
+
+                let mask = self
+                    .read_ptr(address.checked_add(layout.segment_mask as u64)?)
+                    .ok()?;
+                let shift = self
+                    .read_bytes(address.checked_add(layout.unit_shift as u64)?, 1)
+                    .ok()?[0] as u32;
+                let first_descriptor = self
+                    .read_bytes(address.checked_add(layout.first_descriptor_index as u64)?, 1)
+                    .ok()?[0] as u64;
+
+                // `SegmentMask` clears the offset within a segment, so segments are a power of two.
+                let pointer_mask = u64::MAX >> (64 - self.pointer_size() as u32 * 8);
+                let segment_size = (!mask & pointer_mask).checked_add(1)?;
+                let unit_shift = (12..segment_size.trailing_zeros())
+                    .contains(&shift)
+                    .then_some(shift)?;
+
+                // The descriptors of every unit must fit in the metadata units they describe.
+                let descriptors = (segment_size >> unit_shift) * 4 * self.pointer_size() as u64;
+                (segment_size.is_power_of_two() && descriptors.div_ceil(1 << unit_shift) <= first_descriptor).then_some(SegContext {
+                    address,
+                    segment_size,
+                    unit_shift,
+                    first_descriptor,
+                })
+            })
+            .collect()
+    }
+
+    /// Walks the blocks of a segment with the walker matching the backend of the heap.
+    fn heap_segment_entries(&self, heap: &Heap, segment: Range<u64>, offsets: &StructOffsets, budget: &Budget) -> Partial<Vec<HeapEntry>> {
+        match heap.backend {
+            HeapBackend::Nt => self.segment_entries(heap.base, segment, offsets, budget),
+            HeapBackend::Segment => self.page_segment_entries(heap.base, segment, offsets, budget),
+        }
+    }
+
+    /// Walks the blocks of a segment, from `FirstEntry` up to `LastValidEntry`.
+    ///
+    /// The budget is checked every [`BUDGET_CHECK_INTERVAL`] blocks.
+    fn segment_entries(&self, heap: u64, segment: Range<u64>, offsets: &StructOffsets, budget: &Budget) -> Partial<Vec<HeapEntry>> {
+        // Block sizes are counted in units of the header size.
+        let granularity: u64 = match self.system.processor_architecture {
+            Arch::X86 | Arch::Arm => 8,
+            _ => 16,
+        };
+
+        // Headers are XORed with `_HEAP.Encoding` when `EncodeFlagMask` is set.
+        let mask = offset_va(heap, offsets.heap.encode_flag_mask as u64)
+            .and_then(|va| self.read_u32(va))
+            .unwrap_or(0);
+        let encoding = match mask {
+            0 => None,
+            _ => offset_va(heap, offsets.heap.encoding as u64)
+                .and_then(|va| self.read_bytes(va, granularity as usize))
+                .ok(),
+        };
+
+        let mut entries = Vec::new();
+        let mut address = segment.start;
+        while address < segment.end && entries.len() < MAX_ITEMS {
+            if entries.len() % BUDGET_CHECK_INTERVAL == 0 && budget.is_exhausted() {
+                return Partial {
+                    value: entries,
+                    timed_out: true,
+                };
+            }
+
+            let Ok(mut header) = self.read_bytes(address, granularity as usize) else {
+                match self.next_committed(address, segment.end) {
+                    Some(next) => {
+                        address = next;
+                        continue;
+                    }
+                    None => break,
+                }
+            };
+
+            if let Some(encoding) = &encoding {
+                // Only the size, flags and checksum part of the header is encoded.
+                let encoded = granularity as usize - 8;
+                for (byte, key) in header[encoded..]
+                    .iter_mut()
+                    .zip(&encoding[encoded..])
+                {
+                    *byte ^= key;
+                }
+            }
+
+            // `Size`, `Flags` and `UnusedBytes` sit in the last 8 bytes of the header.
+            let fields = &header[granularity as usize - 8..];
+            let size = u16::from_le_bytes([fields[0], fields[1]]) as u64 * granularity;
+            let flags = fields[2];
+            let unused = fields[7] as u64;
+            if size == 0 {
+                break;
+            }
+
+            let busy = flags & HEAP_ENTRY_BUSY != 0;
+            let entry = HeapEntry {
+                header: address,
+                size,
+                user_address: address + granularity,
+                user_size: (busy && unused <= size).then(|| size.saturating_sub(unused)),
+                busy,
+                flags,
+                lfh: false,
+            };
+
+            entries.push(entry);
+            if busy {
+                entries.extend(self.lfh_entries(&entry, granularity));
+            }
+
+            let Some(next) = address.checked_add(size) else {
+                break;
+            };
+
+            address = next;
+            if flags & HEAP_ENTRY_LAST_ENTRY != 0 {
+                match self.next_committed(address, segment.end) {
+                    Some(next) => address = next,
+                    None => break,
+                }
+            }
+        }
+
+        Partial {
+            value: entries,
+            timed_out: false,
+        }
+    }
+
+    /// Walks the page ranges of a segment heap page segment.
+    ///
+    /// The segment starts with one `_HEAP_PAGE_RANGE_DESCRIPTOR` per unit. The first
+    /// descriptor of each range holds its flags and its size in units; the descriptors
+    /// of the segment's own metadata come before `FirstDescriptorIndex`.
+    fn page_segment_entries(&self, heap: u64, segment: Range<u64>, offsets: &StructOffsets, budget: &Budget) -> Partial<Vec<HeapEntry>> {
+        let mut entries = Vec::new();
+        let Some(context) = self
+            .seg_contexts(heap, offsets)
+            .into_iter()
+            .find(|context| context.segment_size == segment.end - segment.start)
+        else {
+            return Partial {
+                value: entries,
+                timed_out: false,
+            };
+        };
+
+        // `RangeFlags` follows the `TreeNode`, and `UnitSize` is the last byte of the descriptor.
+        let pointer = self.pointer_size() as u64;
+        let descriptor_size = 4 * pointer;
+        let range_flags = 3 * pointer as usize;
+        // A corrupted `SegmentMask` can describe a segment of up to 2^63 bytes, so at most
+        // `MAX_ITEMS` descriptors are read.
+        let units = (context.segment_size >> context.unit_shift).min(MAX_ITEMS as u64);
+        let Ok(descriptors) = self.read_bytes(segment.start, (units * descriptor_size) as usize) else {
+            return Partial {
+                value: entries,
+                timed_out: false,
+            };
+        };
+
+        let mut index = context.first_descriptor;
+        while index < units && entries.len() < MAX_ITEMS {
+            if entries.len() % BUDGET_CHECK_INTERVAL == 0 && budget.is_exhausted() {
+                return Partial {
+                    value: entries,
+                    timed_out: true,
+                };
+            }
+
+            let descriptor = &descriptors[(index * descriptor_size) as usize..][..descriptor_size as usize];
+            let flags = descriptor[range_flags];
+            let unit_size = descriptor[descriptor_size as usize - 1] as u64;
+            if flags & PAGE_RANGE_FLAGS_FIRST == 0 || unit_size == 0 || index + unit_size > units {
+                index += 1;
+                continue;
+            }
+
+            let address = segment.start + (index << context.unit_shift);
+            let busy = flags & PAGE_RANGE_FLAGS_ALLOCATED != 0;
+            let entry = HeapEntry {
+                header: address,
+                size: unit_size << context.unit_shift,
+                user_address: address,
+                user_size: None,
+                busy,
+                flags,
+                lfh: false,
+            };
+
+            entries.push(entry);
+            if busy && flags & PAGE_RANGE_FLAGS_LFH_SUBSEGMENT != 0 {
+                entries.extend(self.lfh_subsegment_entries(&entry));
+            }
+
+            index += unit_size;
+        }
+
+        Partial {
+            value: entries,
+            timed_out: false,
+        }
+    }
+
+    /// Decodes the blocks of a segment heap LFH subsegment starting a page range.
+    ///
+    /// The `_HEAP_LFH_SUBSEGMENT` header holds the number of blocks and a bitmap with two
+    /// bits per block, the low one set for busy blocks. As for the NT heap, the offset of
+    /// the first block and the block size are encoded with a key kept in `ntdll`, so the
+    /// blocks are assumed to start right after the bitmap and to share the rest of the range.
+    /// LFH blocks of the segment heap have no header.
+    fn lfh_subsegment_entries(&self, range: &HeapEntry) -> Vec<HeapEntry> {
+        let pointer = self.pointer_size() as u64;
+        let granularity = 2 * pointer;
+
+        // `FreeCount` and `BlockCount` follow the `ListEntry`, `Owner` and `CommitLock` fields,
+        // and the `BlockBitmap` of 64-bit words follows the encoded offsets.
+        let header = range.header;
+        let (Ok(free), Ok(count)) = (self.read_u16(header + 4 * pointer), self.read_u16(header + 4 * pointer + 2)) else {
+            return Vec::new();
+        };
+
+        let (free, count) = (free as u64, count as u64);
+        let bitmap = header + 4 * pointer + 0x10;
+        let end = header + range.size;
+        if count == 0 || free > count || count > end.saturating_sub(bitmap) / granularity {
+            return Vec::new();
+        }
+
+        let bitmap_len = (2 * count).div_ceil(64) * 8;
+        let first = (bitmap + bitmap_len).next_multiple_of(granularity);
+        let stride = (end.saturating_sub(first) / count) / granularity * granularity;
+        if stride < granularity {
+            return Vec::new();
+        }
+
+        let Ok(bits) = self.read_bytes(bitmap, bitmap_len as usize) else {
+            return Vec::new();
+        };
+
+        (0..count)
+            .map(|index| {
+                let bit = 2 * index;
+                let busy = bits[(bit / 8) as usize] & (1 << (bit % 8)) != 0;
+                let address = first + index * stride;
+                HeapEntry {
+                    header: address,
+                    size: stride,
+                    user_address: address,
+                    user_size: None,
+                    busy,
+                    flags: if busy { HEAP_ENTRY_BUSY } else { 0 },
+                    lfh: true,
+                }
+            })
+            .collect()
+    }
+
+    /// Decodes the LFH blocks held by a busy backend block, if it holds LFH user blocks.
+    ///
+    /// The user blocks start with a `_HEAP_USERDATA_HEADER` followed by the bitmap of the
+    /// busy blocks. The offset of the first block and the stride are encoded with a key
+    /// private to `ntdll`, so they are inferred instead: the blocks start right after the
+    /// bitmap and share the rest of the backend block. The layout is only accepted if
+    /// every block header carries the LFH flag.
+    fn lfh_entries(&self, backend: &HeapEntry, granularity: u64) -> Vec<HeapEntry> {
+        let pointer = self.pointer_size() as u64;
+        let header = backend.user_address;
+
+        // `Signature` and `BusyBitmap` follow three pointer-sized fields on x64 and x86 alike.
+        let (Some(signature), Some(bitmap), Some(end)) = (
+            header.checked_add(2 * pointer + 4),
+            header.checked_add(3 * pointer + if pointer == 8 { 8 } else { 0 }),
+            backend.header.checked_add(backend.size),
+        ) else {
+            return Vec::new();
+        };
+
+        if self.read_u32(signature).ok() != Some(LFH_USERDATA_SIGNATURE) {
+            return Vec::new();
+        }
+
+        let (Ok(count), Ok(buffer)) = (self.read_ptr(bitmap), self.read_ptr(bitmap + pointer)) else {
+            return Vec::new();
+        };
+
+        // `BitmapData` follows the `RTL_BITMAP_EX`, which must point to it.
+        let data = bitmap + 2 * pointer;
+        if buffer != data || count == 0 || count > end.saturating_sub(data) / granularity {
+            return Vec::new();
+        }
+
+        let words = count.div_ceil(pointer * 8);
+        let first = (data + words * pointer).next_multiple_of(granularity);
+        let stride = (end.saturating_sub(first) / count) / granularity * granularity;
+        if stride < granularity {
+            return Vec::new();
+        }
+
+        let Ok(busy) = self.read_bytes(data, (words * pointer) as usize) else {
+            return Vec::new();
+        };
+
+        let mut entries = Vec::new();
+        for index in 0..count {
+            let address = first + index * stride;
+            let Ok(fields) = self.read_bytes(address + granularity - 8, 8) else {
+                return Vec::new();
+            };
+
+            // `UnusedBytes` is not encoded, and flags every LFH block.
+            if fields[7] & HEAP_ENTRY_LFH == 0 {
+                return Vec::new();
+            }
+
+            let is_busy = busy[(index / 8) as usize] & (1 << (index % 8)) != 0;
+            entries.push(HeapEntry {
+                header: address,
+                size: stride,
+                user_address: address + granularity,
+                user_size: None,
+                busy: is_busy,
+                flags: if is_busy { HEAP_ENTRY_BUSY } else { 0 },
+                lfh: true,
+            });
+        }
+
+        entries
+    }
+
+    /// Returns the start of the next captured region after `address`, below `end`.
+    fn next_committed(&self, address: u64, end: u64) -> Option<u64> {
+        self.memorys()
+            .range(address + 1..end)
+            .map(|(_, memory)| memory)
+            .find(|memory| !memory.data.is_empty())
+            .map(|memory| memory.range.start)
+    }
+}
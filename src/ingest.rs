@@ -0,0 +1,363 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender, SyncSender},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::error::UserDmpError;
+use crate::options::ParseOptions;
+use crate::parse::{ModuleOrder, Result, UserDump};
+use crate::triage::Verdict;
+
+/// Options that control how a directory of dumps is watched and parsed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use userdmp::ingest::IngestOptions;
+///
+/// let options = IngestOptions::new().workers(2).poll_interval(Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IngestOptions {
+    /// The number of dumps parsed concurrently.
+    pub workers: usize,
+
+    /// The delay between two scans of the directory.
+    pub poll_interval: Duration,
+
+    /// Whether the dumps already present when the watch starts are parsed.
+    pub include_existing: bool,
+
+    /// The options used to parse every dump.
+    pub parse_options: ParseOptions,
+}
+
+impl Default for IngestOptions {
+    fn default() -> Self {
+        Self {
+            workers: thread::available_parallelism().map_or(1, |count| count.get().min(4)),
+            poll_interval: Duration::from_secs(1),
+            include_existing: true,
+            parse_options: ParseOptions::default(),
+        }
+    }
+}
+
+impl IngestOptions {
+    /// Creates a new [`IngestOptions`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of dumps parsed concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `workers` - The size of the worker pool, at least 1.
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Sets the delay between two scans of the directory.
+    ///
+    /// A new file is parsed once its size did not change between two scans, so
+    /// dumps still being written are not picked up too early.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The delay between two scans.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Sets whether the dumps already present when the watch starts are parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether existing dumps are parsed.
+    pub fn include_existing(mut self, include: bool) -> Self {
+        self.include_existing = include;
+        self
+    }
+
+    /// Sets the options used to parse every dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The [`ParseOptions`] to use.
+    pub fn parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+}
+
+/// A compact description of a parsed dump, independent of the file it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpSummary {
+    /// The path of the dump.
+    pub path: PathBuf,
+
+    /// The size of the file, in bytes.
+    pub file_size: u64,
+
+    /// The classification of the dump.
+    pub verdict: Verdict,
+
+    /// The exception code, if the dump holds an exception.
+    pub exception_code: Option<u32>,
+
+    /// The address of the exception, if the dump holds an exception.
+    pub exception_address: Option<u64>,
+
+    /// The name of the module containing the exception address, if any.
+    pub faulting_module: Option<String>,
+
+    /// The name of the first module in load order, usually the main executable.
+    pub main_module: Option<String>,
+
+    /// The number of threads.
+    pub threads: usize,
+
+    /// The number of modules.
+    pub modules: usize,
+
+    /// The number of handles.
+    pub handles: usize,
+}
+
+impl DumpSummary {
+    /// Summarizes a parsed dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The parsed dump.
+    /// * `path` - The path the dump was read from.
+    pub fn new(dump: &UserDump, path: impl Into<PathBuf>) -> Self {
+        let exception = dump.exception();
+        let module_name = |address: u64| {
+            dump.module_at(address)
+                .and_then(|module| module.name())
+                .map(str::to_string)
+        };
+
+        Self {
+            path: path.into(),
+            file_size: dump.mapped_file.buffer.len() as u64,
+            verdict: dump.verdict(),
+            exception_code: exception.map(|exception| exception.code),
+            exception_address: exception.map(|exception| exception.address),
+            faulting_module: exception.and_then(|exception| module_name(exception.address)),
+            main_module: dump
+                .modules_sorted(ModuleOrder::LoadOrder)
+                .first()
+                .and_then(|module| module.name())
+                .map(str::to_string),
+            threads: dump.threads().len(),
+            modules: dump.modules().len(),
+            handles: dump.handles().len(),
+        }
+    }
+}
+
+/// Reports the outcome of parsing a dump found by a [`DumpWatcher`].
+#[derive(Debug)]
+pub enum IngestEvent {
+    /// The dump was parsed.
+    Parsed(DumpSummary),
+
+    /// The dump could not be parsed.
+    Failed {
+        /// The path of the dump.
+        path: PathBuf,
+
+        /// The error raised while parsing.
+        error: UserDmpError,
+    },
+}
+
+/// Watches a directory for new `.dmp` files and parses them with a pool of workers.
+///
+/// The directory is scanned periodically. Every new dump is handed to a worker once
+/// its size is stable, and the outcome is sent as an [`IngestEvent`] on the channel
+/// returned by [`DumpWatcher::events`]. The job queue is bounded, so the scanner waits
+/// when every worker is busy. Dropping the watcher stops the scanner and the workers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::ingest::{DumpWatcher, IngestEvent, IngestOptions};
+///
+/// let watcher = DumpWatcher::watch("C:\\CrashDumps", IngestOptions::new()).unwrap();
+/// for event in watcher.events() {
+///     match event {
+///         IngestEvent::Parsed(summary) => println!("{}: {}", summary.path.display(), summary.verdict),
+///         IngestEvent::Failed { path, error } => eprintln!("{}: {error}", path.display()),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DumpWatcher {
+    /// The receiving end of the events sent by the workers.
+    events: Receiver<IngestEvent>,
+
+    /// Set to ask the scanner and the workers to stop.
+    stop: Arc<AtomicBool>,
+
+    /// The scanner thread, followed by the workers.
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl DumpWatcher {
+    /// Starts watching a directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory to watch. Subdirectories are not watched.
+    /// * `options` - The options controlling the watch.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the directory can be read.
+    /// * `Err(UserDmpError)` - If the directory cannot be read.
+    pub fn watch(directory: impl AsRef<Path>, options: IngestOptions) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::read_dir(&directory)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let (events_tx, events) = mpsc::channel();
+        let (jobs_tx, jobs_rx) = mpsc::sync_channel::<PathBuf>(options.workers);
+        let jobs_rx = Arc::new(Mutex::new(jobs_rx));
+
+        let mut threads = Vec::with_capacity(options.workers + 1);
+        threads.push(thread::spawn({
+            let stop = Arc::clone(&stop);
+            let options = options.clone();
+            move || scan(&directory, &options, &jobs_tx, &stop)
+        }));
+
+        for _ in 0..options.workers.max(1) {
+            let jobs = Arc::clone(&jobs_rx);
+            let events = events_tx.clone();
+            let parse_options = options.parse_options.clone();
+            threads.push(thread::spawn(move || work(&jobs, &events, &parse_options)));
+        }
+
+        Ok(Self { events, stop, threads })
+    }
+
+    /// Returns the channel on which the outcome of every parsed dump is sent.
+    ///
+    /// The channel is closed once the watcher is stopped and the pending dumps are parsed.
+    pub fn events(&self) -> &Receiver<IngestEvent> {
+        &self.events
+    }
+
+    /// Stops watching and waits for the dumps being parsed.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for DumpWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(scanner) = self.threads.first() {
+            scanner.thread().unpark();
+        }
+
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Scans `directory` until asked to stop, queuing every new dump once its size is stable.
+fn scan(directory: &Path, options: &IngestOptions, jobs: &SyncSender<PathBuf>, stop: &AtomicBool) {
+    let mut queued = HashSet::new();
+    let mut pending = HashMap::<PathBuf, u64>::new();
+
+    if !options.include_existing {
+        queued.extend(
+            list_dumps(directory)
+                .into_iter()
+                .map(|(path, _)| path),
+        );
+    }
+
+    while !stop.load(Ordering::Relaxed) {
+        for (path, size) in list_dumps(directory) {
+            if queued.contains(&path) {
+                continue;
+            }
+
+            // The file is queued once two scans in a row report the same, non-zero size.
+            if size != 0 && pending.get(&path) == Some(&size) {
+                pending.remove(&path);
+                queued.insert(path.clone());
+                if jobs.send(path).is_err() {
+                    return;
+                }
+            } else {
+                pending.insert(path, size);
+            }
+        }
+
+        thread::park_timeout(options.poll_interval);
+    }
+}
+
+/// Parses the queued dumps until the queue is closed.
+fn work(jobs: &Mutex<Receiver<PathBuf>>, events: &Sender<IngestEvent>, options: &ParseOptions) {
+    loop {
+        let Ok(path) = jobs
+            .lock()
+            .map_err(|_| ())
+            .and_then(|jobs| jobs.recv().map_err(|_| ()))
+        else {
+            return;
+        };
+
+        let event = match UserDump::with_options(&path, options.clone()) {
+            Ok(dump) => IngestEvent::Parsed(DumpSummary::new(&dump, path)),
+            Err(error) => IngestEvent::Failed { path, error },
+        };
+
+        if events.send(event).is_err() {
+            return;
+        }
+    }
+}
+
+/// Lists the `.dmp` files of a directory with their size. Unreadable entries are skipped.
+fn list_dumps(directory: &Path) -> Vec<(PathBuf, u64)> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("dmp"))
+        })
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            metadata
+                .is_file()
+                .then(|| (entry.path(), metadata.len()))
+        })
+        .collect()
+}
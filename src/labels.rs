@@ -0,0 +1,215 @@
+use std::{fmt, fs, ops::Range, path::Path, str::FromStr};
+use crate::error::UserDmpError;
+use crate::parse::{Memory, Result, UserDump};
+
+/// A name given by the caller to a range of addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionLabel {
+    /// The labelled addresses.
+    pub range: Range<u64>,
+
+    /// The name shown for the addresses.
+    pub name: String,
+}
+
+/// A set of names for address ranges, supplied by the caller.
+///
+/// Labels carry knowledge the dump lacks, such as the pools of a custom allocator or
+/// the buffers seen in an ETW trace. Once registered with [`UserDump::register_labels`],
+/// they are used by the memory, stack walking, summary and analysis APIs.
+///
+/// The text format holds one label per line: the start and end addresses in hexadecimal,
+/// followed by the name. Empty lines and lines starting with `#` are ignored.
+///
+/// ```text
+/// # allocator pools
+/// 0x1f0000000 0x1f0100000 render pool
+/// 0x1f0100000 0x1f0180000 audio pool
+/// ```
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, labels::LabelMap};
+///
+/// let mut dump = UserDump::new("example.dmp").unwrap();
+/// dump.register_labels(LabelMap::new().label(0x1f0000000..0x1f0100000, "render pool"));
+///
+/// for memory in dump.memorys().values() {
+///     if let Some(label) = memory.label(&dump) {
+///         println!("{:#x}: {label}", memory.start_addr());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelMap {
+    /// The labels, in the order they were added.
+    labels: Vec<RegionLabel>,
+}
+
+impl LabelMap {
+    /// Creates a new, empty [`LabelMap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a label map in the text format described on [`LabelMap`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the label file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Adds a label.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The labelled addresses.
+    /// * `name` - The name shown for the addresses.
+    pub fn label(mut self, range: Range<u64>, name: impl Into<String>) -> Self {
+        self.insert(range, name);
+        self
+    }
+
+    /// Adds a label to an existing map.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The labelled addresses.
+    /// * `name` - The name shown for the addresses.
+    pub fn insert(&mut self, range: Range<u64>, name: impl Into<String>) {
+        self.labels
+            .push(RegionLabel { range, name: name.into() });
+    }
+
+    /// Adds every label of another map.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The labels to add.
+    pub fn extend(&mut self, other: LabelMap) {
+        self.labels.extend(other.labels);
+    }
+
+    /// Returns the labels, in the order they were added.
+    pub fn labels(&self) -> &[RegionLabel] {
+        &self.labels
+    }
+
+    /// Returns true if the map holds no label.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Finds the label of an address.
+    ///
+    /// When labels overlap, the narrowest one wins, and the latest one among labels of
+    /// the same size.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&RegionLabel)` - The label covering the address.
+    /// * `None` - If no label covers the address.
+    pub fn lookup(&self, va: u64) -> Option<&RegionLabel> {
+        self.labels
+            .iter()
+            .rev()
+            .filter(|label| label.range.contains(&va))
+            .min_by_key(|label| label.range.end - label.range.start)
+    }
+}
+
+impl fmt::Display for LabelMap {
+    /// Writes the map in the text format read by its `FromStr` implementation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for label in &self.labels {
+            writeln!(f, "{:#x} {:#x} {}", label.range.start, label.range.end, label.name)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for LabelMap {
+    type Err = UserDmpError;
+
+    /// Parses a map in the text format described on [`LabelMap`].
+    fn from_str(text: &str) -> Result<Self> {
+        let mut map = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || UserDmpError::InvalidLabel(index + 1);
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (Some(start), Some(end), Some(name)) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(invalid());
+            };
+
+            let start = parse_address(start).ok_or_else(invalid)?;
+            let end = parse_address(end)
+                .filter(|&end| end > start)
+                .ok_or_else(invalid)?;
+            map.insert(start..end, name.trim());
+        }
+
+        Ok(map)
+    }
+}
+
+impl Memory<'_> {
+    /// Returns the label registered for the start of the region, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the region belongs to.
+    pub fn label<'d>(&self, dump: &'d UserDump) -> Option<&'d str> {
+        dump.label_at(self.start_addr())
+    }
+}
+
+impl UserDump<'_> {
+    /// Registers labels for address ranges of the process.
+    ///
+    /// Labels registered later take precedence over earlier ones covering the same range.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - The labels to register.
+    pub fn register_labels(&mut self, labels: LabelMap) {
+        self.labels.extend(labels);
+    }
+
+    /// Returns the labels registered with [`UserDump::register_labels`].
+    pub fn labels(&self) -> &LabelMap {
+        &self.labels
+    }
+
+    /// Returns the name of the registered label covering an address, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    pub fn label_at(&self, va: u64) -> Option<&str> {
+        self.labels
+            .lookup(va)
+            .map(|label| label.name.as_str())
+    }
+}
+
+/// Parses an address written in hexadecimal, with or without the `0x` prefix.
+fn parse_address(text: &str) -> Option<u64> {
+    let digits = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+    u64::from_str_radix(&digits.replace('`', ""), 16).ok()
+}
@@ -9,6 +9,146 @@ pub mod data;
 /// The `error` module defines error types used throughout the library.
 pub mod error;
 
+/// The `options` module defines the settings that control how minidump files are parsed.
+pub mod options;
+
+/// The `offsets` module provides the layout of the process structures across Windows builds.
+pub mod offsets;
+
+/// The `diagnostics` module defines the anomalies reported while parsing minidump files.
+pub mod diagnostics;
+
 /// The `parse` module contains the core logic for parsing minidump files.
 pub mod parse;
 pub use parse::*;
+
+/// The `reader` module provides typed reads over the virtual address space captured in the dump.
+pub mod reader;
+
+/// The `pe` module parses the headers of PE images loaded in the captured process
+/// and rebuilds loadable files from them.
+pub mod pe;
+
+/// The `search` module provides scanners over the memory captured in the dump.
+pub mod search;
+
+/// The `codeview` module decodes the CodeView debug records of modules.
+pub mod codeview;
+
+/// The `os` module describes the operating system a dump was captured on.
+pub mod os;
+
+/// The `stackwalk` module reconstructs the call stacks of the captured threads.
+pub mod stackwalk;
+
+/// The `analysis` module flags suspicious thread starts, scheduling anomalies and module origins,
+/// and runs pluggable analysis passes.
+pub mod analysis;
+
+/// The `owned` module provides a minidump type that owns its bytes and can be shared across threads.
+pub mod owned;
+
+/// The `provenance` module maps bytes of the minidump file to the structures and addresses they belong to.
+pub mod provenance;
+
+/// The `handles` module groups the named handles by NT object directory and decodes
+/// job, section and ALPC port handles.
+pub mod handles;
+
+/// The `redact` module writes sanitized copies of a minidump with selected memory scrubbed.
+pub mod redact;
+
+/// The `extract` module writes the in-memory images of modules out of the dump.
+pub mod extract;
+
+/// The `heap` module walks the NT and segment heaps of the process and attributes addresses to heap blocks.
+pub mod heap;
+
+/// The `triage` module classifies dumps into machine-readable verdicts and triage reports.
+pub mod triage;
+
+/// The `peb` module reads the Process Environment Block, the process parameters and the
+/// loader module lists.
+pub mod peb;
+
+/// The `baseline` module learns the expected state of a process from healthy dumps and
+/// scores new dumps against it.
+pub mod baseline;
+
+/// The `ingest` module watches a directory for new dumps and parses them with a worker pool.
+#[cfg(not(target_family = "wasm"))]
+pub mod ingest;
+
+/// The `version` module decodes the version information recorded for each module.
+pub mod version;
+
+/// The `seh` module walks the SEH chains of 32-bit threads and checks where their handlers point.
+pub mod seh;
+
+/// The `wow64` module reads the 32-bit state of WOW64 processes captured in x64 dumps.
+pub mod wow64;
+
+/// The `budget` module bounds long-running operations with deadlines and cancellation tokens.
+pub mod budget;
+
+/// The `summary` module condenses a dump into a one-screen overview, rendered as text or JSON.
+pub mod summary;
+
+/// The `labels` module lets callers name address ranges, so their own knowledge of the
+/// process shows up in the crate output.
+pub mod labels;
+
+/// The `fuzz` module exposes a single entry point running every parser over untrusted input.
+pub mod fuzz;
+
+/// The `pointer` module provides the address canonicality, alignment and pointer-sanity
+/// checks shared by the analyses.
+pub mod pointer;
+
+/// The `report` module renders dumps as structured JSON reports for triage pipelines.
+pub mod report;
+
+/// The `exception` module names exception codes and decodes the parameters of access violations.
+pub mod exception;
+
+/// The `sxs` module resolves side-by-side assemblies and tells how the loader found each module.
+pub mod sxs;
+
+/// The `codepage` module decodes ANSI strings in the code page of the captured process.
+pub mod codepage;
+
+/// The `correlation` module indexes which modules appear together with which crash signatures across dumps.
+pub mod correlation;
+
+/// The `token` module decodes the access tokens captured in the `TokenStream`.
+pub mod token;
+
+/// The `performance` module decodes the process and system memory counters recorded at dump time.
+pub mod performance;
+
+/// The `cpu` module decodes the processor vendor and feature bits stored with the system information.
+pub mod cpu;
+
+/// The `memory` module provides iterator adapters filtering memory regions by protection, state and type.
+pub mod memory;
+
+/// The `address_space` module indexes the regions and modules of a dump for fast address attribution.
+pub mod address_space;
+
+/// The `secrets` module scans the captured memory for credentials and keys, reporting redacted previews.
+pub mod secrets;
+
+/// The `elf` module converts dumps into Linux ELF core files for debuggers that do not read minidumps.
+pub mod elf;
+
+/// The `raw` module exports the captured memory as flat raw images with a JSON layout sidecar.
+pub mod raw;
+
+/// The `disasm` module disassembles x86 and x64 code from the captured memory.
+#[cfg(feature = "iced-x86")]
+pub mod disasm;
+
+/// The `symsrv` module downloads PDBs and binaries from symbol servers to symbolize addresses
+/// and map them to source lines.
+#[cfg(feature = "symsrv")]
+pub mod symsrv;
@@ -13,6 +13,15 @@ pub struct MappingFile<'a> {
     pub address: *mut c_void,
 }
 
+// SAFETY: the mapping is read-only and never mutated after creation: `buffer` is a shared
+// view of it and `address` is only used by `Drop` to release it, which takes `&mut self`.
+// Mapped views and leaked buffers may be released from any thread.
+unsafe impl Send for MappingFile<'_> {}
+
+// SAFETY: see `Send`; `&MappingFile` only exposes `buffer`, and reads of immutable memory
+// are safe to perform concurrently.
+unsafe impl Sync for MappingFile<'_> {}
+
 impl<'a> MappingFile<'a> {
     /// Creates a new `MappingFile` instance by mapping the contents of a file into memory.
     ///
@@ -43,6 +52,21 @@ impl<'a> MappingFile<'a> {
         Ok(Self { buffer, address })
     }
 
+    /// Creates a `MappingFile` over bytes that are already in memory.
+    ///
+    /// Nothing is unmapped when the returned value is dropped; the caller keeps
+    /// ownership of `buffer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The contents of a minidump file.
+    pub fn from_slice(buffer: &'a [u8]) -> Self {
+        Self {
+            buffer,
+            address: std::ptr::null_mut(),
+        }
+    }
+
     /// Creates a cursor for the memory-mapped file buffer.
     ///
     /// # Returns
@@ -97,12 +121,20 @@ impl Drop for MappingFile<'_> {
                     libc::munmap(self.address, self.buffer.len());
                 }
             }
+
+            #[cfg(not(any(windows, unix)))]
+            {
+                // SAFETY: the buffer was leaked from a boxed slice of this length by `map_file`.
+                unsafe {
+                    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(self.address.cast::<u8>(), self.buffer.len())));
+                }
+            }
         }
     }
 }
 
 mod map {
-    use std::{ffi::c_void, ptr, slice};
+    use std::ffi::c_void;
     use super::{File, UserDmpError};
 
     /// Maps a file into memory and retrieves its memory buffer and base address (Windows).
@@ -135,7 +167,7 @@ mod map {
     /// ```
     #[cfg(windows)]
     pub fn map_file(file: File) -> Result<(&'static [u8], *mut c_void), UserDmpError> {
-        use std::os::windows::io::AsRawHandle;
+        use std::{os::windows::io::AsRawHandle, ptr, slice};
         use windows_sys::Win32::{
             Foundation::CloseHandle,
             System::Memory::{CreateFileMappingA, FILE_MAP_READ, MapViewOfFile, PAGE_READONLY},
@@ -199,7 +231,7 @@ mod map {
     /// ```
     #[cfg(unix)]
     pub fn map_file(file: File) -> Result<(&'static [u8], *mut c_void), UserDmpError> {
-        use std::os::unix::io::AsRawFd;
+        use std::{os::unix::io::AsRawFd, ptr, slice};
         use libc::{MAP_FAILED, MAP_SHARED, PROT_READ, mmap};
 
         // Get the raw file descriptor.
@@ -219,4 +251,30 @@ mod map {
         // Return the memory-mapped buffer and its base address.
         unsafe { Ok((slice::from_raw_parts(base_address as *const u8, size), base_address)) }
     }
+
+    /// Reads a file into memory on platforms without memory mapping (e.g., WASI).
+    ///
+    /// The buffer is leaked so it can be borrowed like a mapped view, and freed when the
+    /// [`super::MappingFile`] is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - A `File` instance representing the file to be read.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// * A slice of the file contents.
+    /// * The base address of the buffer holding them.
+    #[cfg(not(any(windows, unix)))]
+    pub fn map_file(mut file: File) -> Result<(&'static [u8], *mut c_void), UserDmpError> {
+        use std::io::Read;
+
+        let mut buffer = Vec::new();
+        file.read_to_end(&mut buffer)?;
+
+        let buffer = Box::leak(buffer.into_boxed_slice());
+        let address = buffer.as_mut_ptr().cast::<c_void>();
+        Ok((buffer, address))
+    }
 }
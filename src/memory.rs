@@ -0,0 +1,450 @@
+use core::fmt;
+use core::ops::{BitAnd, BitOr};
+use std::{collections::btree_map::Values, iter::Filter};
+use crate::parse::{Memory, Memorys};
+
+/// Writes the names of the flags set in `bits`, separated by `|`.
+///
+/// Bits without a name are written as a trailing hexadecimal value, and an
+/// empty set is written as `0`.
+pub(crate) fn write_flags(f: &mut fmt::Formatter<'_>, bits: u32, names: &[(u32, &str)]) -> fmt::Result {
+    let mut remaining = bits;
+    let mut first = true;
+    for (flag, name) in names {
+        if bits & flag != 0 {
+            if !first {
+                f.write_str(" | ")?;
+            }
+
+            f.write_str(name)?;
+            remaining &= !flag;
+            first = false;
+        }
+    }
+
+    match (remaining, first) {
+        (0, false) => Ok(()),
+        (_, true) => write!(f, "{remaining:#x}"),
+        (_, false) => write!(f, " | {remaining:#x}"),
+    }
+}
+
+/// The protection of a memory region (`PAGE_*` flags).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, memory::MemoryProtection};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// for memory in dump.memorys().values() {
+///     if memory.protect.is_executable() && memory.protect.contains(MemoryProtection::GUARD) {
+///         println!("{:#x} {}", memory.start_addr(), memory.protect);
+///     }
+/// }
+/// ```
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MemoryProtection(u32);
+
+impl MemoryProtection {
+    /// `PAGE_EXECUTE`: execute access.
+    pub const EXECUTE: Self = Self(0x10);
+    /// `PAGE_EXECUTE_READ`: execute and read access.
+    pub const EXECUTE_READ: Self = Self(0x20);
+    /// `PAGE_EXECUTE_READWRITE`: execute, read and write access.
+    pub const EXECUTE_READWRITE: Self = Self(0x40);
+    /// `PAGE_EXECUTE_WRITECOPY`: execute, read and copy-on-write access.
+    pub const EXECUTE_WRITECOPY: Self = Self(0x80);
+    /// `PAGE_GUARD`: the first access raises a guard page exception.
+    pub const GUARD: Self = Self(0x100);
+    /// The names of the flags, in display order.
+    const NAMES: [(u32, &str); 11] = [
+        (0x01, "PAGE_NOACCESS"),
+        (0x02, "PAGE_READONLY"),
+        (0x04, "PAGE_READWRITE"),
+        (0x08, "PAGE_WRITECOPY"),
+        (0x10, "PAGE_EXECUTE"),
+        (0x20, "PAGE_EXECUTE_READ"),
+        (0x40, "PAGE_EXECUTE_READWRITE"),
+        (0x80, "PAGE_EXECUTE_WRITECOPY"),
+        (0x100, "PAGE_GUARD"),
+        (0x200, "PAGE_NOCACHE"),
+        (0x400, "PAGE_WRITECOMBINE"),
+    ];
+    /// `PAGE_NOACCESS`: any access raises an access violation.
+    pub const NOACCESS: Self = Self(0x01);
+    /// `PAGE_NOCACHE`: the pages are not cached.
+    pub const NOCACHE: Self = Self(0x200);
+    /// `PAGE_READONLY`: read-only access.
+    pub const READONLY: Self = Self(0x02);
+    /// `PAGE_READWRITE`: read and write access.
+    pub const READWRITE: Self = Self(0x04);
+    /// `PAGE_WRITECOMBINE`: the pages use write-combined caching.
+    pub const WRITECOMBINE: Self = Self(0x400);
+    /// `PAGE_WRITECOPY`: read and copy-on-write access.
+    pub const WRITECOPY: Self = Self(0x08);
+
+    /// Returns the raw `PAGE_*` value.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether every flag of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns whether no flag is set, which is the case of free and reserved regions.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether the pages can be read.
+    ///
+    /// Execute-only pages (`PAGE_EXECUTE`) and guard pages are not readable.
+    pub const fn is_readable(self) -> bool {
+        self.0 & (0x02 | 0x04 | 0x08 | 0x20 | 0x40 | 0x80) != 0 && !self.is_guard()
+    }
+
+    /// Returns whether the pages can be written, including copy-on-write.
+    pub const fn is_writable(self) -> bool {
+        self.0 & (0x04 | 0x08 | 0x40 | 0x80) != 0
+    }
+
+    /// Returns whether the pages can be executed.
+    pub const fn is_executable(self) -> bool {
+        self.0 & (0x10 | 0x20 | 0x40 | 0x80) != 0
+    }
+
+    /// Returns whether the pages are copy-on-write.
+    pub const fn is_copy_on_write(self) -> bool {
+        self.0 & (0x08 | 0x80) != 0
+    }
+
+    /// Returns whether the pages are guard pages.
+    pub const fn is_guard(self) -> bool {
+        self.contains(Self::GUARD)
+    }
+}
+
+impl From<u32> for MemoryProtection {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<MemoryProtection> for u32 {
+    fn from(protection: MemoryProtection) -> Self {
+        protection.0
+    }
+}
+
+impl From<MemoryProtection> for u64 {
+    fn from(protection: MemoryProtection) -> Self {
+        protection.0.into()
+    }
+}
+
+impl BitOr for MemoryProtection {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemoryProtection {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Display for MemoryProtection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flags(f, self.0, &Self::NAMES)
+    }
+}
+
+/// The state of a memory region (`MEM_COMMIT`, `MEM_RESERVE` or `MEM_FREE`).
+///
+/// Regions only known from the `MemoryListStream` or `Memory64ListStream` have
+/// an empty state, since those streams carry no metadata.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MemoryState(u32);
+
+impl MemoryState {
+    /// `MEM_COMMIT`: the pages are backed by physical memory or the page file.
+    pub const COMMIT: Self = Self(0x1_000);
+    /// `MEM_FREE`: the pages are free.
+    pub const FREE: Self = Self(0x10_000);
+    /// The names of the flags, in display order.
+    const NAMES: [(u32, &str); 3] = [(0x1_000, "MEM_COMMIT"), (0x2_000, "MEM_RESERVE"), (0x10_000, "MEM_FREE")];
+    /// `MEM_RESERVE`: the pages are reserved but not committed.
+    pub const RESERVE: Self = Self(0x2_000);
+
+    /// Returns the raw `MEM_*` value.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether every flag of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether no flag is set, meaning the state is unknown.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether the region is committed.
+    pub const fn is_committed(self) -> bool {
+        self.contains(Self::COMMIT)
+    }
+
+    /// Returns whether the region is reserved.
+    pub const fn is_reserved(self) -> bool {
+        self.contains(Self::RESERVE)
+    }
+
+    /// Returns whether the region is free.
+    pub const fn is_free(self) -> bool {
+        self.contains(Self::FREE)
+    }
+}
+
+impl From<u32> for MemoryState {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<MemoryState> for u32 {
+    fn from(state: MemoryState) -> Self {
+        state.0
+    }
+}
+
+impl From<MemoryState> for u64 {
+    fn from(state: MemoryState) -> Self {
+        state.0.into()
+    }
+}
+
+impl BitOr for MemoryState {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemoryState {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Display for MemoryState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flags(f, self.0, &Self::NAMES)
+    }
+}
+
+/// The type of a memory region (`MEM_PRIVATE`, `MEM_MAPPED` or `MEM_IMAGE`).
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MemoryType(u32);
+
+impl MemoryType {
+    /// `MEM_IMAGE`: the pages are mapped into the view of an image section.
+    pub const IMAGE: Self = Self(0x1_000_000);
+    /// `MEM_MAPPED`: the pages are mapped into the view of a data section.
+    pub const MAPPED: Self = Self(0x40_000);
+    /// The names of the flags, in display order.
+    const NAMES: [(u32, &str); 3] = [(0x20_000, "MEM_PRIVATE"), (0x40_000, "MEM_MAPPED"), (0x1_000_000, "MEM_IMAGE")];
+    /// `MEM_PRIVATE`: the pages are private to the process.
+    pub const PRIVATE: Self = Self(0x20_000);
+
+    /// Returns the raw `MEM_*` value.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether every flag of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether no flag is set, meaning the type is unknown.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether the region is private to the process.
+    pub const fn is_private(self) -> bool {
+        self.contains(Self::PRIVATE)
+    }
+
+    /// Returns whether the region is mapped from a data file or section.
+    pub const fn is_mapped(self) -> bool {
+        self.contains(Self::MAPPED)
+    }
+
+    /// Returns whether the region is mapped from an image file.
+    pub const fn is_image(self) -> bool {
+        self.contains(Self::IMAGE)
+    }
+}
+
+impl From<u32> for MemoryType {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<MemoryType> for u32 {
+    fn from(type_: MemoryType) -> Self {
+        type_.0
+    }
+}
+
+impl From<MemoryType> for u64 {
+    fn from(type_: MemoryType) -> Self {
+        type_.0.into()
+    }
+}
+
+impl BitOr for MemoryType {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemoryType {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Display for MemoryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flags(f, self.0, &Self::NAMES)
+    }
+}
+
+/// The predicate applied by the adapters of [`MemoryFilter`].
+pub type MemoryPredicate<'m, 'a> = fn(&&'m Memory<'a>) -> bool;
+
+/// An iterator over the regions kept by a [`MemoryFilter`] adapter.
+pub type Filtered<'m, 'a, I> = Regions<Filter<I, MemoryPredicate<'m, 'a>>>;
+
+/// An iterator over memory regions, returned by the adapters of [`MemoryFilter`] so they can be chained.
+#[derive(Debug, Clone)]
+pub struct Regions<I>(I);
+
+impl<'m, 'a: 'm, I: Iterator<Item = &'m Memory<'a>>> Iterator for Regions<I> {
+    type Item = &'m Memory<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Iterator adapters selecting memory regions by protection, state and type.
+///
+/// Implemented for the map returned by [`crate::UserDump::memorys`] and for the
+/// iterators returned by the adapters themselves, so filters can be chained.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, memory::MemoryFilter};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// for memory in dump.memorys().executable().writable().private() {
+///     println!("RWX private region at {:#x} ({} bytes)", memory.start_addr(), memory.len());
+/// }
+/// ```
+pub trait MemoryFilter<'m, 'a: 'm>: Sized {
+    /// The iterator over the regions before filtering.
+    type Iter: Iterator<Item = &'m Memory<'a>>;
+
+    /// Returns the regions before filtering.
+    fn regions(self) -> Self::Iter;
+
+    /// Keeps the regions matching a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns whether a region is kept.
+    fn matching(self, predicate: MemoryPredicate<'m, 'a>) -> Filtered<'m, 'a, Self::Iter> {
+        Regions(self.regions().filter(predicate))
+    }
+
+    /// Keeps the regions whose protection allows execution (`PAGE_EXECUTE*`).
+    fn executable(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.protect.is_executable())
+    }
+
+    /// Keeps the regions whose protection allows writing, including copy-on-write.
+    fn writable(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.protect.is_writable())
+    }
+
+    /// Keeps the private regions (`MEM_PRIVATE`).
+    fn private(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.type_.is_private())
+    }
+
+    /// Keeps the regions mapped from an image file (`MEM_IMAGE`).
+    fn image(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.type_.is_image())
+    }
+
+    /// Keeps the regions mapped from a data file or section (`MEM_MAPPED`).
+    fn mapped(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.type_.is_mapped())
+    }
+
+    /// Keeps the committed regions (`MEM_COMMIT`).
+    fn committed(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| memory.state.is_committed())
+    }
+
+    /// Keeps the regions whose bytes were captured in the dump.
+    fn captured(self) -> Filtered<'m, 'a, Self::Iter> {
+        self.matching(|memory| !memory.data.is_empty())
+    }
+}
+
+impl<'m, 'a: 'm> MemoryFilter<'m, 'a> for &'m Memorys<'a> {
+    type Iter = Values<'m, u64, Memory<'a>>;
+
+    fn regions(self) -> Self::Iter {
+        self.values()
+    }
+}
+
+impl<'m, 'a: 'm, I: Iterator<Item = &'m Memory<'a>>> MemoryFilter<'m, 'a> for Regions<I> {
+    type Iter = I;
+
+    fn regions(self) -> Self::Iter {
+        self.0
+    }
+}
@@ -0,0 +1,479 @@
+use crate::parse::{Arch, UserDump};
+
+/// Field offsets of the Thread Environment Block (`TEB`).
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct TebOffsets {
+    /// Offset of `NtTib.StackBase`, the upper bound of the stack.
+    pub stack_base: u32,
+
+    /// Offset of `NtTib.StackLimit`, the lower bound of the committed stack.
+    pub stack_limit: u32,
+
+    /// Offset of `NtTib.Self`, the linear address of the TEB.
+    pub self_: u32,
+
+    /// Offset of `ClientId` (process ID followed by thread ID).
+    pub client_id: u32,
+
+    /// Offset of `ProcessEnvironmentBlock`.
+    pub peb: u32,
+
+    /// Offset of `LastErrorValue`.
+    pub last_error_value: u32,
+}
+
+/// Field offsets of the Process Environment Block (`PEB`).
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct PebOffsets {
+    /// Offset of `BeingDebugged`.
+    pub being_debugged: u32,
+
+    /// Offset of `ImageBaseAddress`.
+    pub image_base_address: u32,
+
+    /// Offset of `Ldr`, a pointer to `PEB_LDR_DATA`.
+    pub ldr: u32,
+
+    /// Offset of `ProcessParameters`, a pointer to `RTL_USER_PROCESS_PARAMETERS`.
+    pub process_parameters: u32,
+
+    /// Offset of `ProcessHeap`.
+    pub process_heap: u32,
+
+    /// Offset of `AnsiCodePageData`, a pointer to the NLS table of the ANSI code page.
+    pub ansi_code_page_data: u32,
+
+    /// Offset of `NumberOfHeaps`.
+    pub number_of_heaps: u32,
+
+    /// Offset of `ProcessHeaps`, a pointer to the array of heap handles.
+    pub process_heaps: u32,
+
+    /// Offset of `SessionId`.
+    pub session_id: u32,
+
+    /// Offset of `ActivationContextData`, the activation context built from the manifest of the executable.
+    pub activation_context_data: u32,
+
+    /// Offset of `SystemDefaultActivationContextData`, the activation context of the system-wide assemblies.
+    pub system_default_activation_context_data: u32,
+}
+
+/// Field offsets of `RTL_USER_PROCESS_PARAMETERS`.
+///
+/// Every field except `environment` and `environment_size` is a `UNICODE_STRING`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct ProcessParametersOffsets {
+    /// Offset of `CurrentDirectory.DosPath`.
+    pub current_directory: u32,
+
+    /// Offset of `DllPath`.
+    pub dll_path: u32,
+
+    /// Offset of `ImagePathName`.
+    pub image_path_name: u32,
+
+    /// Offset of `CommandLine`.
+    pub command_line: u32,
+
+    /// Offset of `Environment`, a pointer to the environment block.
+    pub environment: u32,
+
+    /// Offset of `WindowTitle`.
+    pub window_title: u32,
+
+    /// Offset of `DesktopInfo`.
+    pub desktop_info: u32,
+
+    /// Offset of `EnvironmentSize`.
+    pub environment_size: u32,
+}
+
+/// Field offsets of `PEB_LDR_DATA` and `LDR_DATA_TABLE_ENTRY`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct LdrOffsets {
+    /// Offset of `PEB_LDR_DATA.InLoadOrderModuleList`.
+    pub in_load_order_module_list: u32,
+
+    /// Offset of `PEB_LDR_DATA.InMemoryOrderModuleList`.
+    pub in_memory_order_module_list: u32,
+
+    /// Offset of `PEB_LDR_DATA.InInitializationOrderModuleList`.
+    pub in_initialization_order_module_list: u32,
+
+    /// Offset of `LDR_DATA_TABLE_ENTRY.DllBase`.
+    pub dll_base: u32,
+
+    /// Offset of `LDR_DATA_TABLE_ENTRY.EntryPoint`.
+    pub entry_point: u32,
+
+    /// Offset of `LDR_DATA_TABLE_ENTRY.SizeOfImage`.
+    pub size_of_image: u32,
+
+    /// Offset of `LDR_DATA_TABLE_ENTRY.FullDllName`.
+    pub full_dll_name: u32,
+
+    /// Offset of `LDR_DATA_TABLE_ENTRY.BaseDllName`.
+    pub base_dll_name: u32,
+}
+
+/// Field offsets of the NT heap (`_HEAP`).
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct HeapOffsets {
+    /// Offset of `SegmentSignature` (`0xFFEEFFEE` for NT heaps).
+    pub segment_signature: u32,
+
+    /// Offset of `Flags`.
+    pub flags: u32,
+
+    /// Offset of `ForceFlags`.
+    pub force_flags: u32,
+
+    /// Offset of `EncodeFlagMask`.
+    pub encode_flag_mask: u32,
+
+    /// Offset of `Encoding`, the key used to encode the heap entry headers.
+    pub encoding: u32,
+
+    /// Offset of `VirtualAllocdBlocks`.
+    pub virtual_allocd_blocks: u32,
+
+    /// Offset of `SegmentList`.
+    pub segment_list: u32,
+
+    /// Offset of `_HEAP_SEGMENT.SegmentListEntry`, linking the segments of a heap.
+    pub segment_list_entry: u32,
+
+    /// Offset of `_HEAP_SEGMENT.FirstEntry`.
+    pub first_entry: u32,
+
+    /// Offset of `_HEAP_SEGMENT.LastValidEntry`.
+    pub last_valid_entry: u32,
+}
+
+/// Field offsets of the segment heap (`_SEGMENT_HEAP`) and its segment contexts (`_HEAP_SEG_CONTEXT`).
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct SegmentHeapOffsets {
+    /// Offset of `Signature` (`0xDDEEDDEE` for segment heaps).
+    pub signature: u32,
+
+    /// Offset of `GlobalFlags`.
+    pub global_flags: u32,
+
+    /// Offset of `SegContexts`, the two segment contexts of the heap.
+    pub seg_contexts: u32,
+
+    /// Size of a `_HEAP_SEG_CONTEXT`.
+    pub seg_context_size: u32,
+
+    /// Offset of `_HEAP_SEG_CONTEXT.SegmentMask`.
+    pub segment_mask: u32,
+
+    /// Offset of `_HEAP_SEG_CONTEXT.UnitShift`.
+    pub unit_shift: u32,
+
+    /// Offset of `_HEAP_SEG_CONTEXT.FirstDescriptorIndex`.
+    pub first_descriptor_index: u32,
+
+    /// Offset of `_HEAP_SEG_CONTEXT.Heap`, pointing back to the segment heap.
+    pub context_heap: u32,
+
+    /// Offset of `_HEAP_SEG_CONTEXT.SegmentListHead`.
+    pub segment_list_head: u32,
+}
+
+/// Describes the layout of the process structures walked by the library for
+/// one architecture and range of Windows builds.
+///
+/// The built-in table covers x86 and x64 from Windows Vista onward; dumps from
+/// older builds use the oldest entry on a best-effort basis. Unusual builds can
+/// supply their own layout through [`crate::options::ParseOptions::struct_offsets`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, Arch, offsets::StructOffsets, options::ParseOptions};
+///
+/// let mut offsets = StructOffsets::lookup(Arch::X64, 19045).unwrap();
+/// offsets.heap.segment_list = 0x128;
+///
+/// let options = ParseOptions::new().struct_offsets(offsets);
+/// let dump = UserDump::with_options("example.dmp", options).unwrap();
+/// ```
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub struct StructOffsets {
+    /// The architecture the layout applies to.
+    pub arch: Arch,
+
+    /// The first build number the layout applies to.
+    pub min_build: u32,
+
+    /// The layout of the TEB.
+    pub teb: TebOffsets,
+
+    /// The layout of the PEB.
+    pub peb: PebOffsets,
+
+    /// The layout of the process parameters.
+    pub process_parameters: ProcessParametersOffsets,
+
+    /// The layout of the loader data.
+    pub ldr: LdrOffsets,
+
+    /// The layout of the NT heap.
+    pub heap: HeapOffsets,
+
+    /// The layout of the segment heap.
+    pub segment_heap: SegmentHeapOffsets,
+}
+
+/// Layout shared by every x64 build, except for the heap.
+const TEB_X64: TebOffsets = TebOffsets {
+    stack_base: 0x08,
+    stack_limit: 0x10,
+    self_: 0x30,
+    client_id: 0x40,
+    peb: 0x60,
+    last_error_value: 0x68,
+};
+
+const PEB_X64: PebOffsets = PebOffsets {
+    being_debugged: 0x02,
+    image_base_address: 0x10,
+    ldr: 0x18,
+    process_parameters: 0x20,
+    process_heap: 0x30,
+    ansi_code_page_data: 0xA0,
+    number_of_heaps: 0xE8,
+    process_heaps: 0xF0,
+    session_id: 0x2C0,
+    activation_context_data: 0x2F8,
+    system_default_activation_context_data: 0x308,
+};
+
+const PROCESS_PARAMETERS_X64: ProcessParametersOffsets = ProcessParametersOffsets {
+    current_directory: 0x38,
+    dll_path: 0x50,
+    image_path_name: 0x60,
+    command_line: 0x70,
+    environment: 0x80,
+    window_title: 0xB0,
+    desktop_info: 0xC0,
+    environment_size: 0x3F0,
+};
+
+const LDR_X64: LdrOffsets = LdrOffsets {
+    in_load_order_module_list: 0x10,
+    in_memory_order_module_list: 0x20,
+    in_initialization_order_module_list: 0x30,
+    dll_base: 0x30,
+    entry_point: 0x38,
+    size_of_image: 0x40,
+    full_dll_name: 0x48,
+    base_dll_name: 0x58,
+};
+
+/// Segment heaps only exist from Windows 10 onward; older builds never pass the signature check.
+const SEGMENT_HEAP_X64: SegmentHeapOffsets = SegmentHeapOffsets {
+    signature: 0x10,
+    global_flags: 0x14,
+    seg_contexts: 0x100,
+    seg_context_size: 0xC0,
+    segment_mask: 0x00,
+    unit_shift: 0x08,
+    first_descriptor_index: 0x0A,
+    context_heap: 0x38,
+    segment_list_head: 0x48,
+};
+
+/// Layout shared by every x86 build, except for the heap.
+const TEB_X86: TebOffsets = TebOffsets {
+    stack_base: 0x04,
+    stack_limit: 0x08,
+    self_: 0x18,
+    client_id: 0x20,
+    peb: 0x30,
+    last_error_value: 0x34,
+};
+
+const PEB_X86: PebOffsets = PebOffsets {
+    being_debugged: 0x02,
+    image_base_address: 0x08,
+    ldr: 0x0C,
+    process_parameters: 0x10,
+    process_heap: 0x18,
+    ansi_code_page_data: 0x58,
+    number_of_heaps: 0x88,
+    process_heaps: 0x90,
+    session_id: 0x1D4,
+    activation_context_data: 0x1F8,
+    system_default_activation_context_data: 0x200,
+};
+
+const PROCESS_PARAMETERS_X86: ProcessParametersOffsets = ProcessParametersOffsets {
+    current_directory: 0x24,
+    dll_path: 0x30,
+    image_path_name: 0x38,
+    command_line: 0x40,
+    environment: 0x48,
+    window_title: 0x70,
+    desktop_info: 0x78,
+    environment_size: 0x290,
+};
+
+const LDR_X86: LdrOffsets = LdrOffsets {
+    in_load_order_module_list: 0x0C,
+    in_memory_order_module_list: 0x14,
+    in_initialization_order_module_list: 0x1C,
+    dll_base: 0x18,
+    entry_point: 0x1C,
+    size_of_image: 0x20,
+    full_dll_name: 0x24,
+    base_dll_name: 0x2C,
+};
+
+const SEGMENT_HEAP_X86: SegmentHeapOffsets = SegmentHeapOffsets {
+    signature: 0x08,
+    global_flags: 0x0C,
+    seg_contexts: 0xC0,
+    seg_context_size: 0x80,
+    segment_mask: 0x00,
+    unit_shift: 0x04,
+    first_descriptor_index: 0x06,
+    context_heap: 0x24,
+    segment_list_head: 0x2C,
+};
+
+/// The built-in layouts, ordered by architecture and ascending build number.
+const OFFSETS: [StructOffsets; 4] = [
+    // Windows Vista and 7.
+    StructOffsets {
+        arch: Arch::X64,
+        min_build: 6000,
+        teb: TEB_X64,
+        peb: PEB_X64,
+        process_parameters: PROCESS_PARAMETERS_X64,
+        ldr: LDR_X64,
+        heap: HeapOffsets {
+            segment_signature: 0x10,
+            flags: 0x70,
+            force_flags: 0x74,
+            encode_flag_mask: 0x7C,
+            encoding: 0x80,
+            virtual_allocd_blocks: 0x118,
+            segment_list: 0x128,
+            segment_list_entry: 0x18,
+            first_entry: 0x40,
+            last_valid_entry: 0x48,
+        },
+        segment_heap: SEGMENT_HEAP_X64,
+    },
+    // Windows 8 and later.
+    StructOffsets {
+        arch: Arch::X64,
+        min_build: 9200,
+        teb: TEB_X64,
+        peb: PEB_X64,
+        process_parameters: PROCESS_PARAMETERS_X64,
+        ldr: LDR_X64,
+        heap: HeapOffsets {
+            segment_signature: 0x10,
+            flags: 0x70,
+            force_flags: 0x74,
+            encode_flag_mask: 0x7C,
+            encoding: 0x80,
+            virtual_allocd_blocks: 0x110,
+            segment_list: 0x120,
+            segment_list_entry: 0x18,
+            first_entry: 0x40,
+            last_valid_entry: 0x48,
+        },
+        segment_heap: SEGMENT_HEAP_X64,
+    },
+    // Windows Vista and 7.
+    StructOffsets {
+        arch: Arch::X86,
+        min_build: 6000,
+        teb: TEB_X86,
+        peb: PEB_X86,
+        process_parameters: PROCESS_PARAMETERS_X86,
+        ldr: LDR_X86,
+        heap: HeapOffsets {
+            segment_signature: 0x08,
+            flags: 0x40,
+            force_flags: 0x44,
+            encode_flag_mask: 0x4C,
+            encoding: 0x50,
+            virtual_allocd_blocks: 0xA0,
+            segment_list: 0xA8,
+            segment_list_entry: 0x10,
+            first_entry: 0x24,
+            last_valid_entry: 0x28,
+        },
+        segment_heap: SEGMENT_HEAP_X86,
+    },
+    // Windows 8 and later.
+    StructOffsets {
+        arch: Arch::X86,
+        min_build: 9200,
+        teb: TEB_X86,
+        peb: PEB_X86,
+        process_parameters: PROCESS_PARAMETERS_X86,
+        ldr: LDR_X86,
+        heap: HeapOffsets {
+            segment_signature: 0x08,
+            flags: 0x40,
+            force_flags: 0x44,
+            encode_flag_mask: 0x4C,
+            encoding: 0x50,
+            virtual_allocd_blocks: 0x9C,
+            segment_list: 0xA4,
+            segment_list_entry: 0x10,
+            first_entry: 0x24,
+            last_valid_entry: 0x28,
+        },
+        segment_heap: SEGMENT_HEAP_X86,
+    },
+];
+
+impl StructOffsets {
+    /// Returns the built-in layout for an architecture and Windows build number.
+    ///
+    /// # Arguments
+    ///
+    /// * `arch` - The architecture of the captured process.
+    /// * `build` - The build number of the operating system.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(StructOffsets)` - The layout of the newest entry not newer than `build`,
+    ///   or the oldest entry for builds predating the table.
+    /// * `None` - If the architecture is not covered by the table.
+    pub fn lookup(arch: Arch, build: u32) -> Option<Self> {
+        let mut entries = OFFSETS
+            .iter()
+            .filter(|offsets| offsets.arch == arch);
+        let oldest = entries.clone().next().copied();
+        entries
+            .rfind(|offsets| offsets.min_build <= build)
+            .copied()
+            .or(oldest)
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns the layout of the process structures for the captured process.
+    ///
+    /// The layout supplied through [`crate::options::ParseOptions::struct_offsets`]
+    /// takes precedence over the built-in table, which is keyed by the build number
+    /// of the `SystemInfoStream`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(StructOffsets)` - The layout to use when walking the TEB, PEB and heaps.
+    /// * `None` - If no layout is known for the architecture of the dump.
+    pub fn struct_offsets(&self) -> Option<StructOffsets> {
+        self.struct_offsets
+            .or_else(|| StructOffsets::lookup(self.system.processor_architecture, self.system.build_number))
+    }
+}
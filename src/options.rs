@@ -0,0 +1,235 @@
+use std::collections::BTreeSet;
+use crate::codepage::CodePage;
+use crate::data::MINIDUMP_STREAM_TYPE;
+use crate::offsets::StructOffsets;
+
+/// Defines how the parser behaves when the stream directory lists the same
+/// stream type more than once.
+///
+/// Well-formed minidumps contain at most one entry per stream type, so duplicates
+/// usually indicate a corrupt or deliberately crafted file. Every duplicate is
+/// reported through [`crate::UserDump::diagnostics`] regardless of the policy.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+pub enum DuplicateStreamPolicy {
+    /// Only the first entry of each stream type (in directory order) is parsed.
+    #[default]
+    FirstWins,
+
+    /// Every entry is parsed and the last one in directory order replaces the previous ones.
+    LastWins,
+
+    /// Every entry is parsed and the results are merged.
+    ///
+    /// List streams (modules, threads, handles and memory) are combined, with later
+    /// entries replacing earlier ones on key collisions. Streams that describe a
+    /// single object (such as `SystemInfoStream` or `ExceptionStream`) keep the first entry.
+    CollectAll,
+}
+
+/// Defines how the parser handles module and memory region entries whose range
+/// is empty or overflows the address space.
+///
+/// Skipped entries are reported through [`crate::UserDump::diagnostics`].
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+pub enum InvalidRangePolicy {
+    /// Parsing fails with `UserDmpError::InvalidRange`.
+    #[default]
+    Fail,
+
+    /// The entry is left out and parsing continues with the next one.
+    Skip,
+}
+
+/// Defines how the parser reacts to a stream it cannot parse.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// A malformed stream aborts parsing with its error.
+    #[default]
+    Strict,
+
+    /// A malformed stream is skipped and reported through [`crate::UserDump::diagnostics`],
+    /// so truncated dumps still yield whatever is recoverable.
+    ///
+    /// The data of the skipped stream is left empty, as if the stream was absent.
+    Lenient,
+}
+
+/// Defines how reads of virtual memory behave when part of the requested range
+/// was not captured in the dump.
+///
+/// The policy applies to [`crate::UserDump::read_range`] and
+/// [`crate::UserDump::read_range_with`]. Typed reads (`read_pod`, `read_u32`, ...)
+/// always fail on missing bytes, since a partially read structure is meaningless.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+pub enum UnmappedReadPolicy {
+    /// The read fails with `UserDmpError::AddressNotFound` if any byte is missing.
+    #[default]
+    Fail,
+
+    /// Missing bytes are returned as zeros and reported as gaps.
+    ZeroFill,
+
+    /// Only the bytes preceding the first gap are returned, and every gap of the
+    /// requested range is reported.
+    Partial,
+}
+
+/// Options that control how a minidump file is parsed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, options::{ParseOptions, DuplicateStreamPolicy}};
+///
+/// let options = ParseOptions::new().duplicate_streams(DuplicateStreamPolicy::LastWins);
+/// let dump = UserDump::with_options("example.dmp", options).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Whether a malformed stream aborts parsing or is skipped.
+    pub mode: ParseMode,
+
+    /// The policy applied to duplicate stream directory entries.
+    pub duplicate_streams: DuplicateStreamPolicy,
+
+    /// The default policy applied to reads spanning bytes missing from the dump.
+    pub unmapped_reads: UnmappedReadPolicy,
+
+    /// The policy applied to module and memory entries with an invalid range.
+    pub invalid_ranges: InvalidRangePolicy,
+
+    /// The layout of the process structures, overriding the built-in table.
+    pub struct_offsets: Option<StructOffsets>,
+
+    /// The code page of ANSI strings, overriding the one of the captured process.
+    pub code_page: Option<CodePage>,
+
+    /// The stream types to parse, or `None` to parse every stream.
+    pub streams: Option<BTreeSet<MINIDUMP_STREAM_TYPE>>,
+}
+
+impl ParseOptions {
+    /// Creates a new [`ParseOptions`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether a malformed stream aborts parsing or is skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The [`ParseMode`] to apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, options::{ParseMode, ParseOptions}};
+    ///
+    /// let options = ParseOptions::new().mode(ParseMode::Lenient);
+    /// let dump = UserDump::with_options("truncated.dmp", options).unwrap();
+    /// for diagnostic in dump.diagnostics() {
+    ///     println!("{diagnostic}");
+    /// }
+    /// ```
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the policy applied to duplicate stream directory entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`DuplicateStreamPolicy`] to apply.
+    pub fn duplicate_streams(mut self, policy: DuplicateStreamPolicy) -> Self {
+        self.duplicate_streams = policy;
+        self
+    }
+
+    /// Sets the default policy applied to reads spanning bytes missing from the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`UnmappedReadPolicy`] to apply.
+    pub fn unmapped_reads(mut self, policy: UnmappedReadPolicy) -> Self {
+        self.unmapped_reads = policy;
+        self
+    }
+
+    /// Sets the policy applied to module and memory entries with an invalid range.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`InvalidRangePolicy`] to apply.
+    pub fn invalid_ranges(mut self, policy: InvalidRangePolicy) -> Self {
+        self.invalid_ranges = policy;
+        self
+    }
+
+    /// Overrides the layout of the process structures (TEB, PEB, loader data and heaps).
+    ///
+    /// Useful for builds missing from the built-in table of [`StructOffsets`].
+    ///
+    /// # Arguments
+    ///
+    /// * `offsets` - The [`StructOffsets`] to use instead of the built-in layout.
+    pub fn struct_offsets(mut self, offsets: StructOffsets) -> Self {
+        self.struct_offsets = Some(offsets);
+        self
+    }
+
+    /// Restricts parsing to the given stream types.
+    ///
+    /// The other streams are left out of the parsed data, which saves time and memory
+    /// when only metadata is needed. They stay listed in the stream directory and can be
+    /// parsed later with [`crate::UserDump::reparse_stream`]. The `SystemInfoStream` is
+    /// always parsed, since decoding thread contexts and tokens depends on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - The stream types to parse.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, data::MINIDUMP_STREAM_TYPE, options::ParseOptions};
+    ///
+    /// let options = ParseOptions::new().streams([MINIDUMP_STREAM_TYPE::ModuleListStream]);
+    /// let dump = UserDump::with_options("example.dmp", options).unwrap();
+    /// for module in dump.modules().values() {
+    ///     println!("{}", module.path.display());
+    /// }
+    /// ```
+    pub fn streams(mut self, streams: impl IntoIterator<Item = MINIDUMP_STREAM_TYPE>) -> Self {
+        self.streams = Some(streams.into_iter().collect());
+        self
+    }
+
+    /// Returns whether the given raw stream type is selected for parsing.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_type` - The raw stream type of a directory entry.
+    pub fn parses(&self, stream_type: u32) -> bool {
+        let Some(streams) = &self.streams else {
+            return true;
+        };
+
+        match MINIDUMP_STREAM_TYPE::try_from(stream_type) {
+            Ok(MINIDUMP_STREAM_TYPE::SystemInfoStream) => true,
+            Ok(stream_type) => streams.contains(&stream_type),
+            Err(_) => false,
+        }
+    }
+
+    /// Sets the code page used to decode ANSI strings, instead of the ANSI code page
+    /// of the captured process.
+    ///
+    /// # Arguments
+    ///
+    /// * `code_page` - The [`CodePage`] of the strings.
+    pub fn code_page(mut self, code_page: CodePage) -> Self {
+        self.code_page = Some(code_page);
+        self
+    }
+}
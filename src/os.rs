@@ -0,0 +1,294 @@
+use std::fmt;
+use crate::parse::{Arch, System, UserDump};
+use crate::data::*;
+
+/// Represents the operating system family a dump was captured on.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OsFamily {
+    /// Microsoft Windows (NT based).
+    Windows,
+
+    /// Linux, as written by Breakpad/Crashpad.
+    Linux,
+
+    /// Android, as written by Breakpad/Crashpad.
+    Android,
+
+    /// macOS, as written by Breakpad/Crashpad.
+    MacOs,
+
+    /// iOS, as written by Breakpad/Crashpad.
+    Ios,
+
+    /// Solaris, as written by Breakpad.
+    Solaris,
+
+    /// Fuchsia, as written by Crashpad.
+    Fuchsia,
+
+    /// An unrecognized platform identifier.
+    Unknown(u32),
+}
+
+impl From<u32> for OsFamily {
+    fn from(platform_id: u32) -> Self {
+        match platform_id {
+            VER_PLATFORM_WIN32_NT => OsFamily::Windows,
+            MD_OS_LINUX => OsFamily::Linux,
+            MD_OS_ANDROID => OsFamily::Android,
+            MD_OS_MAC_OS_X => OsFamily::MacOs,
+            MD_OS_IOS => OsFamily::Ios,
+            MD_OS_SOLARIS => OsFamily::Solaris,
+            MD_OS_FUCHSIA => OsFamily::Fuchsia,
+            _ => OsFamily::Unknown(platform_id),
+        }
+    }
+}
+
+impl fmt::Display for OsFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OsFamily::Windows => write!(f, "Windows"),
+            OsFamily::Linux => write!(f, "Linux"),
+            OsFamily::Android => write!(f, "Android"),
+            OsFamily::MacOs => write!(f, "macOS"),
+            OsFamily::Ios => write!(f, "iOS"),
+            OsFamily::Solaris => write!(f, "Solaris"),
+            OsFamily::Fuchsia => write!(f, "Fuchsia"),
+            OsFamily::Unknown(platform_id) => write!(f, "Unknown ({platform_id:#x})"),
+        }
+    }
+}
+
+/// Describes the operating system a dump was captured on, independently of the dump producer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OsInfo {
+    /// The operating system family.
+    pub family: OsFamily,
+
+    /// A human-readable name of the operating system (e.g., `Windows` or `Ubuntu 22.04.3 LTS`).
+    pub name: String,
+
+    /// The major version number.
+    pub major_version: u32,
+
+    /// The minor version number.
+    pub minor_version: u32,
+
+    /// The build number.
+    pub build_number: u32,
+
+    /// The processor architecture of the captured process.
+    pub arch: Arch,
+}
+
+impl OsInfo {
+    /// Returns the version as a `major.minor.build` string.
+    pub fn version(&self) -> String {
+        format!("{}.{}.{}", self.major_version, self.minor_version, self.build_number)
+    }
+}
+
+impl fmt::Display for OsInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} ({:?})", self.name, self.version(), self.arch)
+    }
+}
+
+/// Release names of Windows 10, indexed by build number.
+const WINDOWS_10_RELEASES: [(u32, &str); 14] = [
+    (10240, "1507"),
+    (10586, "1511"),
+    (14393, "1607"),
+    (15063, "1703"),
+    (16299, "1709"),
+    (17134, "1803"),
+    (17763, "1809"),
+    (18362, "1903"),
+    (18363, "1909"),
+    (19041, "2004"),
+    (19042, "20H2"),
+    (19043, "21H1"),
+    (19044, "21H2"),
+    (19045, "22H2"),
+];
+
+/// Release names of Windows 11, indexed by build number.
+const WINDOWS_11_RELEASES: [(u32, &str); 5] = [(22000, "21H2"), (22621, "22H2"), (22631, "23H2"), (26100, "24H2"), (26200, "25H2")];
+
+/// Names of the Windows Server releases sharing the 10.0 version, indexed by build number.
+const WINDOWS_SERVER_RELEASES: [(u32, &str); 5] = [
+    (14393, "Windows Server 2016"),
+    (17763, "Windows Server 2019"),
+    (20348, "Windows Server 2022"),
+    (25398, "Windows Server 23H2"),
+    (26100, "Windows Server 2025"),
+];
+
+/// Finds the name of a build in a release table.
+fn release_name(releases: &[(u32, &'static str)], build: u32) -> Option<&'static str> {
+    releases
+        .iter()
+        .find(|(number, _)| *number == build)
+        .map(|(_, name)| *name)
+}
+
+impl System {
+    /// Returns the marketing name of the Windows release (e.g., `Windows 11 22H2`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The product name, followed by the release name when the build is known.
+    /// * `None` - If the dump was not captured on Windows or the version is not recognized.
+    pub fn windows_release(&self) -> Option<String> {
+        if self.platform_id != VER_PLATFORM_WIN32_NT {
+            return None;
+        }
+
+        let server = self.product_type != VER_NT_WORKSTATION;
+        let name = match (self.major_version, self.minor_version, server) {
+            (10, 0, true) => {
+                return Some(
+                    release_name(&WINDOWS_SERVER_RELEASES, self.build_number)
+                        .unwrap_or("Windows Server")
+                        .to_string(),
+                );
+            }
+            (10, 0, false) if self.build_number >= 22000 => {
+                return Some(match release_name(&WINDOWS_11_RELEASES, self.build_number) {
+                    Some(release) => format!("Windows 11 {release}"),
+                    None => "Windows 11".to_string(),
+                });
+            }
+            (10, 0, false) => {
+                return Some(match release_name(&WINDOWS_10_RELEASES, self.build_number) {
+                    Some(release) => format!("Windows 10 {release}"),
+                    None => "Windows 10".to_string(),
+                });
+            }
+            (6, 3, false) => "Windows 8.1",
+            (6, 3, true) => "Windows Server 2012 R2",
+            (6, 2, false) => "Windows 8",
+            (6, 2, true) => "Windows Server 2012",
+            (6, 1, false) => "Windows 7",
+            (6, 1, true) => "Windows Server 2008 R2",
+            (6, 0, false) => "Windows Vista",
+            (6, 0, true) => "Windows Server 2008",
+            (5, 2, false) => "Windows XP Professional x64",
+            (5, 2, true) => "Windows Server 2003",
+            (5, 1, _) => "Windows XP",
+            (5, 0, _) => "Windows 2000",
+            _ => return None,
+        };
+
+        Some(name.to_string())
+    }
+
+    /// Returns the name of the product type (`Workstation`, `Domain Controller` or `Server`).
+    pub fn product_type_name(&self) -> Option<&'static str> {
+        match self.product_type {
+            VER_NT_WORKSTATION => Some("Workstation"),
+            VER_NT_DOMAIN_CONTROLLER => Some("Domain Controller"),
+            VER_NT_SERVER => Some("Server"),
+            _ => None,
+        }
+    }
+
+    /// Returns a human-readable description of the operating system.
+    ///
+    /// Combines the release name, the service pack, the build number and the product
+    /// type, e.g. `Windows 11 22H2 Build 22621 (Workstation)` or
+    /// `Windows 7 Service Pack 1 Build 7601 (Workstation)`. Dumps of other platforms
+    /// are described by their family and version.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// println!("Captured on {}", dump.system.os_description());
+    /// ```
+    pub fn os_description(&self) -> String {
+        let Some(release) = self.windows_release() else {
+            return format!(
+                "{} {}.{}.{}",
+                OsFamily::from(self.platform_id),
+                self.major_version,
+                self.minor_version,
+                self.build_number
+            );
+        };
+
+        let mut description = release;
+        if let Some(csd_version) = &self.csd_version {
+            description.push(' ');
+            description.push_str(csd_version);
+        }
+
+        description.push_str(&format!(" Build {}", self.build_number));
+        if let Some(product_type) = self.product_type_name() {
+            description.push_str(&format!(" ({product_type})"));
+        }
+
+        description
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns a platform-independent description of the operating system.
+    ///
+    /// The information comes from the `SystemInfoStream`, refined with the
+    /// distribution name from the Breakpad `lsb-release` stream on Linux dumps.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, os::OsFamily};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let os = dump.os_info();
+    /// if os.family == OsFamily::Windows {
+    ///     println!("Captured on {os}");
+    /// }
+    /// ```
+    pub fn os_info(&self) -> OsInfo {
+        let family = OsFamily::from(self.system.platform_id);
+        let name = match family {
+            OsFamily::Linux | OsFamily::Android => self.lsb_description(),
+            _ => None,
+        };
+
+        OsInfo {
+            family,
+            name: name.unwrap_or_else(|| family.to_string()),
+            major_version: self.system.major_version,
+            minor_version: self.system.minor_version,
+            build_number: self.system.build_number,
+            arch: self.system.processor_architecture,
+        }
+    }
+
+    /// Returns the distribution description found in the Breakpad `lsb-release` stream.
+    fn lsb_description(&self) -> Option<String> {
+        let data = self.stream_data(MD_LINUX_LSB_RELEASE)?;
+        let text = String::from_utf8_lossy(data);
+
+        let value = |key: &str| {
+            text.lines()
+                .filter_map(|line| line.split_once('='))
+                .find(|(name, _)| name.trim() == key)
+                .map(|(_, value)| {
+                    value
+                        .trim()
+                        .trim_matches('"')
+                        .to_string()
+                })
+                .filter(|value| !value.is_empty())
+        };
+
+        value("DISTRIB_DESCRIPTION")
+            .or_else(|| value("PRETTY_NAME"))
+            .or_else(|| value("DISTRIB_ID"))
+    }
+}
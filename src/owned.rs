@@ -0,0 +1,159 @@
+use std::{
+    fs,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+    sync::Arc,
+};
+use crate::data::MINIDUMP_STREAM_TYPE;
+use crate::mapper::MappingFile;
+use crate::options::ParseOptions;
+use crate::parse::{Result, UserDump};
+
+/// A parsed minidump that owns its bytes.
+///
+/// [`UserDump`] borrows the memory mapping of the file, which ties every value
+/// derived from it to the `'a` lifetime. `OwnedUserDump` instead keeps the contents
+/// of the file in a reference-counted buffer, so it is `'static`, `Send` and `Sync`
+/// and can be stored in long-lived structures or shared between threads.
+///
+/// The parsed dump is reached through [`OwnedUserDump::dump`], whose lifetime is
+/// bound to the `OwnedUserDump` itself.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::{sync::Arc, thread};
+/// use userdmp::owned::OwnedUserDump;
+///
+/// let dump = Arc::new(OwnedUserDump::new("example.dmp").unwrap());
+/// let worker = {
+///     let dump = Arc::clone(&dump);
+///     thread::spawn(move || dump.dump().modules().len())
+/// };
+///
+/// println!("{} modules", worker.join().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct OwnedUserDump {
+    /// The parsed dump, borrowing from `data`.
+    ///
+    /// Declared before `data` so that it is dropped first.
+    dump: UserDump<'static>,
+
+    /// The contents of the minidump file.
+    data: Arc<[u8]>,
+}
+
+impl OwnedUserDump {
+    /// Reads a minidump file into memory and parses it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the minidump file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the file is read and parsed successfully.
+    /// * `Err(UserDmpError)` - If the file cannot be read or parsing fails.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_options(path, ParseOptions::default())
+    }
+
+    /// Reads a minidump file into memory and parses it using custom [`ParseOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the minidump file.
+    /// * `options` - The options controlling how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the file is read and parsed successfully.
+    /// * `Err(UserDmpError)` - If the file cannot be read or parsing fails.
+    pub fn with_options(path: impl AsRef<Path>, options: ParseOptions) -> Result<Self> {
+        Self::from_data(fs::read(path)?.into(), options)
+    }
+
+    /// Reads a minidump from any seekable reader and parses it.
+    ///
+    /// The whole minidump is read into memory, starting from the beginning of the
+    /// reader regardless of its current position. This allows parsing dumps coming
+    /// from network streams, archives or embedded resources without a file on disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the minidump.
+    /// * `options` - The options controlling how the data is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the data is read and parsed successfully.
+    /// * `Err(UserDmpError)` - If reading fails or parsing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::{fs::File, io::BufReader};
+    /// use userdmp::{owned::OwnedUserDump, options::ParseOptions};
+    ///
+    /// let reader = BufReader::new(File::open("example.dmp").unwrap());
+    /// let dump = OwnedUserDump::from_reader(reader, ParseOptions::default()).unwrap();
+    /// println!("{} threads", dump.dump().threads().len());
+    /// ```
+    pub fn from_reader(mut reader: impl Read + Seek, options: ParseOptions) -> Result<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+
+        let mut data = Vec::with_capacity(len as usize);
+        reader.read_to_end(&mut data)?;
+        Self::from_data(data.into(), options)
+    }
+
+    /// Parses a minidump held in a shared buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The contents of a minidump file.
+    /// * `options` - The options controlling how the data is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the data is parsed successfully.
+    /// * `Err(UserDmpError)` - If parsing fails.
+    pub fn from_data(data: Arc<[u8]>, options: ParseOptions) -> Result<Self> {
+        // SAFETY: the buffer of an `Arc<[u8]>` never moves and is freed only when the last
+        // reference is dropped. `self.data` holds one for as long as `self.dump` exists, and
+        // the `'static` lifetime never escapes: `dump()` shortens it to the borrow of `self`.
+        let buffer = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+        let dump = UserDump::parse(MappingFile::from_slice(buffer), &options)?;
+        Ok(Self { dump, data })
+    }
+
+    /// Returns the parsed dump.
+    pub fn dump(&self) -> &UserDump<'_> {
+        &self.dump
+    }
+
+    /// Returns the contents of the minidump file.
+    pub fn data(&self) -> &Arc<[u8]> {
+        &self.data
+    }
+
+    /// Parses a single stream again and replaces the data previously derived from it.
+    ///
+    /// See [`UserDump::reparse_stream`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_type` - The type of the stream to parse again.
+    pub fn reparse_stream(&mut self, stream_type: MINIDUMP_STREAM_TYPE) -> Result<()> {
+        self.dump.reparse_stream(stream_type)
+    }
+}
+
+/// Fails to compile if the dump types stop being shareable across threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<UserDump<'static>>();
+    assert_send_sync::<OwnedUserDump>();
+};
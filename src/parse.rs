@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashSet},
     io::{self, Cursor, Seek},
     path::Path,
     ptr::{self},
@@ -7,28 +7,64 @@ use std::{
 use binrw::BinRead;
 use crate::mapper::MappingFile;
 use crate::error::UserDmpError;
+use crate::diagnostics::Diagnostic;
+use crate::offsets::StructOffsets;
+use crate::options::{DuplicateStreamPolicy, InvalidRangePolicy, ParseMode, ParseOptions, UnmappedReadPolicy};
+use crate::stackwalk::{CodeRange, FunctionTable};
+use crate::labels::LabelMap;
+use crate::codepage::CodePage;
+use crate::token::Token;
+use crate::cpu::CpuInfo;
+use crate::memory::{MemoryProtection, MemoryState, MemoryType};
+use crate::performance::{SystemMemoryInfo, VmCounters};
+use crate::version::VersionInfo;
+use crate::reader::offset_va;
 use crate::data::{
     MINIDUMP_STREAM_TYPE::{self, *},
     *,
 };
 
 /// Represents the modules in a minidump file, mapped by their starting memory address.
+///
+/// Iteration always yields modules in ascending base address order.
 pub type Modules<'a> = BTreeMap<u64, Module<'a>>;
 
 /// Represents the threads in a minidump file, mapped by their thread IDs.
+///
+/// Iteration always yields threads in ascending thread ID order.
 pub type Threads = BTreeMap<u32, Thread>;
 
 /// Represents the handles in a minidump file, mapped by their handle values.
+///
+/// Iteration always yields handles in ascending handle value order.
 pub type Handles = BTreeMap<u64, Handle>;
 
 /// Represents memory regions in a minidump file, mapped by their base addresses.
+///
+/// Iteration always yields regions in ascending base address order.
 pub type Memorys<'a> = BTreeMap<u64, Memory<'a>>;
 
 // Type of error
 pub type Result<T> = std::result::Result<T, UserDmpError>;
 
+/// Defines the order in which modules are listed by [`UserDump::modules_sorted`].
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+pub enum ModuleOrder {
+    /// Ascending base address, the order of [`UserDump::modules`].
+    #[default]
+    BaseAddress,
+
+    /// Case-insensitive file name, then base address for modules sharing a name.
+    Name,
+
+    /// The order in which modules appear in the `ModuleListStream`, which
+    /// follows the load order recorded by the dump producer.
+    LoadOrder,
+}
+
 /// Represents the processor architecture of the captured process.
-#[derive(Copy, Debug, Clone, Default)]
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Arch {
     // 64-bit architecture
     #[default]
@@ -36,6 +72,44 @@ pub enum Arch {
 
     // 32-bit architecture
     X86,
+
+    // 64-bit ARM architecture (AArch64)
+    Arm64,
+
+    // 32-bit ARM architecture
+    Arm,
+
+    // Intel Itanium architecture
+    Ia64,
+
+    // Architecture not recognized by this crate, with its raw identifier
+    Unknown(u16),
+}
+
+impl From<u16> for Arch {
+    fn from(arch: u16) -> Self {
+        match arch {
+            ARCH_X64 => Arch::X64,
+            ARCH_X86 => Arch::X86,
+            ARCH_ARM64 => Arch::Arm64,
+            ARCH_ARM => Arch::Arm,
+            ARCH_IA64 => Arch::Ia64,
+            _ => Arch::Unknown(arch),
+        }
+    }
+}
+
+impl From<Arch> for u16 {
+    fn from(arch: Arch) -> Self {
+        match arch {
+            Arch::X64 => ARCH_X64,
+            Arch::X86 => ARCH_X86,
+            Arch::Arm64 => ARCH_ARM64,
+            Arch::Arm => ARCH_ARM,
+            Arch::Ia64 => ARCH_IA64,
+            Arch::Unknown(arch) => arch,
+        }
+    }
 }
 
 /// Trait to represent the parsing of generic streams in a minidump file.
@@ -57,6 +131,24 @@ pub trait MinidumpStream<'a> {
 }
 
 /// Represents a parsed minidump file, containing metadata, modules, and threads.
+///
+/// The dump is `Send` and `Sync`: once parsed it is never mutated through a shared
+/// reference, so analyses can borrow it from several threads at once.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::thread;
+/// use userdmp::UserDump;
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let (hooks, injections) = thread::scope(|scope| {
+///     let hooks = scope.spawn(|| dump.hooks().len());
+///     let injections = scope.spawn(|| dump.injected_regions().len());
+///     (hooks.join().unwrap(), injections.join().unwrap())
+/// });
+/// println!("{hooks} hooks, {injections} injected regions");
+/// ```
 #[derive(Debug)]
 pub struct UserDump<'a> {
     /// Indicates that it is the ID of the thread directly related to the exception.
@@ -65,6 +157,12 @@ pub struct UserDump<'a> {
     // System information on the dump
     pub system: System,
 
+    /// Miscellaneous process and system information, if present in the dump.
+    misc_info: Option<MiscInfo>,
+
+    /// The exception that triggered the dump, if present in the dump.
+    exception: Option<Exception>,
+
     /// The list of modules in the captured process.
     modules: Modules<'a>,
 
@@ -77,6 +175,48 @@ pub struct UserDump<'a> {
     /// The list of handles in the captured process.
     handles: Handles,
 
+    /// The handle operations traced by Application Verifier, in stream order.
+    handle_operations: Vec<HandleOperation>,
+
+    /// The access tokens of the captured process.
+    tokens: Vec<Token>,
+
+    /// The dynamic function tables registered by the process.
+    function_tables: Vec<FunctionTable>,
+
+    /// The virtual memory counters of the process, if present in the dump.
+    vm_counters: Option<VmCounters>,
+
+    /// The memory state of the system, if present in the dump.
+    system_memory: Option<SystemMemoryInfo>,
+
+    /// The anomalies found while parsing the dump.
+    diagnostics: Vec<Diagnostic>,
+
+    /// The raw type of the stream each entry of `diagnostics` was reported for.
+    diagnostic_sources: Vec<u32>,
+
+    /// The stream directory of the dump, in file order.
+    directory: Vec<MINIDUMP_DIRECTORY>,
+
+    /// The default policy applied to reads spanning bytes missing from the dump.
+    pub(crate) unmapped_reads: UnmappedReadPolicy,
+
+    /// The policy applied to module and memory entries with an invalid range.
+    pub(crate) invalid_ranges: InvalidRangePolicy,
+
+    /// The layout of the process structures supplied by the user, if any.
+    pub(crate) struct_offsets: Option<StructOffsets>,
+
+    /// The code page of ANSI strings supplied by the user, if any.
+    pub(crate) code_page: Option<CodePage>,
+
+    /// The code ranges registered with custom unwind rules, used by the stack walker.
+    pub(crate) code_ranges: Vec<CodeRange>,
+
+    /// The labels registered by the user for address ranges of the process.
+    pub(crate) labels: LabelMap,
+
     /// Mapped file information.
     pub mapped_file: MappingFile<'a>,
 }
@@ -104,9 +244,76 @@ impl<'a> UserDump<'a> {
     /// }
     /// ```
     pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_options(path, ParseOptions::default())
+    }
+
+    /// Creates a new [`UserDump`] by parsing a minidump file from the given path
+    /// using custom [`ParseOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the minidump file.
+    /// * `options` - The options controlling how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the file is parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, options::{ParseOptions, DuplicateStreamPolicy}};
+    ///
+    /// let options = ParseOptions::new().duplicate_streams(DuplicateStreamPolicy::CollectAll);
+    /// let dump = UserDump::with_options("example.dmp", options).unwrap();
+    /// ```
+    pub fn with_options(path: impl AsRef<Path>, options: ParseOptions) -> Result<Self> {
         // Mapping the file in memory to the target environment (Windows or Linux).
         let mapped_file = MappingFile::new(path)?;
-        Self::parse(mapped_file)
+        Self::parse(mapped_file, &options)
+    }
+
+    /// Creates a new [`UserDump`] by parsing a minidump that is already in memory.
+    ///
+    /// The dump borrows `data`, so no copy is made. To parse from a reader, or to get
+    /// a dump that owns its bytes, use [`crate::owned::OwnedUserDump::from_reader`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The contents of a minidump file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the data is parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let data = std::fs::read("example.dmp").unwrap();
+    /// let dump = UserDump::from_bytes(&data).unwrap();
+    /// ```
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self> {
+        Self::from_bytes_with_options(data, ParseOptions::default())
+    }
+
+    /// Creates a new [`UserDump`] by parsing a minidump that is already in memory
+    /// using custom [`ParseOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The contents of a minidump file.
+    /// * `options` - The options controlling how the data is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the data is parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    pub fn from_bytes_with_options(data: &'a [u8], options: ParseOptions) -> Result<Self> {
+        Self::parse(MappingFile::from_slice(data), &options)
     }
 
     /// Returns a reference to the list of threads in the parsed minidump.
@@ -142,10 +349,115 @@ impl<'a> UserDump<'a> {
     ///     );
     /// }
     /// ```
-    pub fn modules(&self) -> &Modules {
+    pub fn modules(&self) -> &Modules<'a> {
         &self.modules
     }
 
+    /// Returns the module whose address range contains a virtual address.
+    ///
+    /// The lookup is a range query on the module map, so it runs in logarithmic time.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Module)` - The module whose image contains `va`.
+    /// * `None` - If the address does not belong to any loaded module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(module) = dump.module_at(0x7ffa_1234_5678) {
+    ///     println!("{}+{:#x}", module.name().unwrap_or("Unknown"), 0x7ffa_1234_5678 - module.start_addr());
+    /// }
+    /// ```
+    pub fn module_at(&self, va: u64) -> Option<&Module<'a>> {
+        self.modules
+            .range(..=va)
+            .next_back()
+            .map(|(_, module)| module)
+            .filter(|module| module.range.contains(&va))
+    }
+
+    /// Returns the module with the given file name, compared case-insensitively.
+    ///
+    /// Only the file name is compared, so `"KERNEL32.dll"` matches a module loaded
+    /// from `C:\Windows\System32\kernel32.dll`. When several modules share the
+    /// name, the one with the lowest base address is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The file name of the module, without directory.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Module)` - The first module with that name.
+    /// * `None` - If no loaded module has that name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(ntdll) = dump.module_by_name("ntdll.dll") {
+    ///     println!("ntdll.dll loaded at {:#x}", ntdll.start_addr());
+    /// }
+    /// ```
+    pub fn module_by_name(&self, name: &str) -> Option<&Module<'a>> {
+        self.modules.values().find(|module| {
+            module
+                .name()
+                .is_some_and(|module_name| module_name.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Returns the modules of the parsed minidump in the requested order.
+    ///
+    /// Every order is total, so the result is identical across runs and can be
+    /// used to produce stable reports and diffs.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The [`ModuleOrder`] to apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, ModuleOrder};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules_sorted(ModuleOrder::Name) {
+    ///     println!("{}", module.name().unwrap_or("Unknown"));
+    /// }
+    /// ```
+    pub fn modules_sorted(&self, order: ModuleOrder) -> Vec<&Module<'a>> {
+        let mut modules = self
+            .modules
+            .values()
+            .collect::<Vec<&Module<'a>>>();
+        match order {
+            ModuleOrder::BaseAddress => {}
+            ModuleOrder::Name => modules.sort_by_cached_key(|module| {
+                (
+                    module
+                        .name()
+                        .unwrap_or("")
+                        .to_lowercase(),
+                    module.range.start,
+                )
+            }),
+            ModuleOrder::LoadOrder => modules.sort_by_key(|module| (module.load_order, module.range.start)),
+        }
+
+        modules
+    }
+
     /// Returns a reference to the list of memory in the parsed minidump
     ///
     /// # Example
@@ -162,10 +474,43 @@ impl<'a> UserDump<'a> {
     ///     );
     /// }
     /// ```
-    pub fn memorys(&self) -> &Memorys {
+    pub fn memorys(&self) -> &Memorys<'a> {
         &self.memorys
     }
 
+    /// Returns the memory region containing a virtual address.
+    ///
+    /// The lookup is a range query on the region map, so it runs in logarithmic time.
+    /// Regions that only carry metadata are returned as well; check [`Memory::data`]
+    /// to know whether the bytes were captured.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Memory)` - The region whose range contains `va`.
+    /// * `None` - If no region of the dump contains the address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(memory) = dump.memory_at(0x7ff6_1234_5678) {
+    ///     println!("{:#x} - {:#x} {}", memory.start_addr(), memory.end_addr(), memory.state());
+    /// }
+    /// ```
+    pub fn memory_at(&self, va: u64) -> Option<&Memory<'a>> {
+        self.memorys
+            .range(..=va)
+            .next_back()
+            .map(|(_, memory)| memory)
+            .filter(|memory| memory.range.contains(&va))
+    }
+
     /// Returns a reference to the list of handles in the parsed minidump.
     ///
     /// # Example
@@ -189,138 +534,717 @@ impl<'a> UserDump<'a> {
         &self.handles
     }
 
-    /// Parses a specific stream type from a minidump file using the `MinidumpStream` trait.
+    /// Returns the handle operations recorded in the `HandleOperationListStream`.
     ///
-    /// # Type Parameters
+    /// The stream is only written when Application Verifier handle tracing was enabled
+    /// for the process, so the list is usually empty. Operations are listed in stream order.
     ///
-    /// * `S` - The stream type to parse. Must implement the `MinidumpStream` trait.
+    /// # Example
     ///
-    /// # Arguments
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
     ///
-    /// * `cursor` - A mutable reference to a cursor positioned within the minidump file.
-    ///   The cursor provides access to the binary data of the stream to be parsed.
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for operation in dump.handle_operations() {
+    ///     println!("{:?} {:#x} by thread {}", operation.operation, operation.handle, operation.thread_id);
+    /// }
+    /// ```
+    pub fn handle_operations(&self) -> &[HandleOperation] {
+        &self.handle_operations
+    }
+
+    /// Returns the access tokens recorded in the `TokenStream`.
     ///
-    /// # Returns
+    /// The stream is only written with `MiniDumpWithTokenInformation`. The first token
+    /// is usually the primary token of the process, followed by the impersonation
+    /// tokens of its threads.
     ///
-    /// * `Ok(S::Output)` - The parsed result for the specific stream type.
-    /// * `Err(UserDmpError)` - An error indicating that the parsing failed.
-    fn parse_stream<S>(cursor: &mut Cursor<&'a [u8]>) -> Result<S::Output>
-    where
-        S: MinidumpStream<'a>,
-    {
-        S::parse(cursor)
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for token in dump.tokens() {
+    ///     println!("user: {:?}, integrity: {:?}", token.user().map(|sid| sid.to_string()), token.integrity_level());
+    ///     for privilege in token.enabled_privileges() {
+    ///         println!("  {}", privilege.name().unwrap_or("unknown"));
+    ///     }
+    /// }
+    /// ```
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
     }
 
-    /// Parses a minidump file into a [`UserDump`] structure.
+    /// Returns the dynamic function tables recorded in the `FunctionTableStream`.
     ///
-    /// # Arguments
+    /// The stack walker uses them to unwind through code generated at runtime, after
+    /// the ranges registered with [`UserDump::register_code_range`].
     ///
-    /// * `mapped_file` - The memory-mapped minidump file.
+    /// # Example
     ///
-    /// # Returns
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
     ///
-    /// * `Ok(Self)` - If the file is parsed successfully.
-    /// * `Err(UserDmpError)` - If the file format is invalid or if parsing fails.
-    fn parse(mapped_file: MappingFile<'a>) -> Result<Self> {
-        // Creates a cursor to navigate the mapped file.
-        let mut cursor = mapped_file.cursor();
-
-        // Reads minidump header.
-        let header = MINIDUMP_HEADER::read(&mut cursor)?;
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for table in dump.function_tables() {
+    ///     println!("{:#x}..{:#x}: {} functions", table.minimum_address, table.maximum_address, table.functions().len());
+    /// }
+    /// ```
+    pub fn function_tables(&self) -> &[FunctionTable] {
+        &self.function_tables
+    }
 
-        // Does the file provided have a minidump signature?
-        if header.Signature != MINIDUMP_SIGNATURE {
-            return Err(UserDmpError::InvalidSignature);
-        }
+    /// Returns the virtual memory counters recorded in the `ProcessVmCountersStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(counters) = dump.vm_counters() {
+    ///     println!("working set: {:?}, private: {:?}", counters.working_set_size, counters.private_usage);
+    /// }
+    /// ```
+    pub fn vm_counters(&self) -> Option<&VmCounters> {
+        self.vm_counters.as_ref()
+    }
 
-        // Checks if at least one of the bits defined in DUMP_FLAGS (0x001f_ffff)
-        // is present in the Flags field of the header. These bits correspond to:
-        // - 0x00000001: Includes data sections of loaded modules (MiniDumpWithDataSegs).
-        // - 0x00000002: Includes the full memory of the process (MiniDumpWithFullMemory).
-        // - 0x00000004: Includes handle information (MiniDumpWithHandleData).
-        // - 0x00000008: Filters out unused memory (MiniDumpFilterMemory).
-        // - 0x00000010: Adds directly referenced memory (MiniDumpScanMemory).
-        // - 0x00000020: Includes unloaded modules (MiniDumpWithUnloadedModules).
-        // - 0x00000040: Includes indirectly referenced memory (MiniDumpWithIndirectlyReferencedMemory).
-        // - 0x00000200: Adds private read/write memory regions (MiniDumpWithPrivateReadWriteMemory).
-        // - 0x00000800: Adds detailed memory information (MiniDumpWithFullMemoryInfo).
-        // - 0x00001000: Includes detailed thread information (MiniDumpWithThreadInfo).
-        // - 0x00002000: Includes code segments from modules (MiniDumpWithCodeSegs).
-        if (header.Flags & DUMP_FLAGS) != 0 {
-            return Err(UserDmpError::InvalidFlags(header.Flags));
-        }
+    /// Returns the system memory state recorded in the `SystemMemoryInfoStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(memory) = dump.system_memory() {
+    ///     println!("commit: {:#x} / {:#x}", memory.committed, memory.commit_limit);
+    /// }
+    /// ```
+    pub fn system_memory(&self) -> Option<&SystemMemoryInfo> {
+        self.system_memory.as_ref()
+    }
 
-        // Seeks to the stream directory.
-        cursor.seek(io::SeekFrom::Start(header.StreamDirectoryRva.into()))?;
+    /// Returns the miscellaneous information recorded in the `MiscInfoStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(misc) = dump.misc_info() {
+    ///     println!("PID: {:?}, Build: {:?}", misc.process_id, misc.build_string);
+    /// }
+    /// ```
+    pub fn misc_info(&self) -> Option<&MiscInfo> {
+        self.misc_info.as_ref()
+    }
 
-        // Collects all valid streams from the stream directory.
-        let mut streams = (0..header.NumberOfStreams)
-            .filter_map(|_| {
-                let stream = MINIDUMP_DIRECTORY::read(&mut cursor).ok()?;
-                (stream.StreamType != UnusedStream as u32).then_some(stream)
+    /// Returns the exception that triggered the dump.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Exception)` - If the dump has an `ExceptionStream`.
+    /// * `None` - If the dump was written without an exception (e.g., a manual snapshot).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(exception) = dump.exception() {
+    ///     println!("{:#x} at {:#x}", exception.code, exception.address);
+    /// }
+    /// ```
+    pub fn exception(&self) -> Option<&Exception> {
+        self.exception.as_ref()
+    }
+
+    /// Returns the entries of the stream directory, in file order.
+    ///
+    /// Entries of type `UnusedStream` are not included.
+    pub fn streams(&self) -> &[MINIDUMP_DIRECTORY] {
+        &self.directory
+    }
+
+    /// Returns the raw bytes of the first stream of the given type.
+    ///
+    /// This gives access to streams the library does not parse itself
+    /// (e.g., the Breakpad `/proc` streams of Linux dumps).
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_type` - The raw stream type to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&[u8])` - The contents of the stream.
+    /// * `None` - If the stream is not present or lies outside the file.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, data::MINIDUMP_STREAM_TYPE};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(data) = dump.stream_data(MINIDUMP_STREAM_TYPE::CommentStreamA as u32) {
+    ///     println!("{}", String::from_utf8_lossy(data));
+    /// }
+    /// ```
+    pub fn stream_data(&self, stream_type: u32) -> Option<&'a [u8]> {
+        let stream = self
+            .directory
+            .iter()
+            .find(|stream| stream.StreamType == stream_type)?;
+        let start = stream.Location.RVA as usize;
+        self.mapped_file
+            .buffer
+            .get(start..start.checked_add(stream.Location.DataSize as usize)?)
+    }
+
+    /// Parses a single stream again and replaces the data previously derived from it.
+    ///
+    /// This allows retrying a stream that failed or was skipped during the initial
+    /// parse without reopening the file. Dependent data is refreshed as well: threads
+    /// get their names and start addresses attached again, and memory regions are
+    /// merged again from both memory streams. The diagnostics reported for the stream
+    /// are replaced by the ones of the new parse.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_type` - The type of the stream to parse again. The first entry
+    ///   of that type in the stream directory is used.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the stream was parsed and its data replaced.
+    /// * `Err(UserDmpError::StreamNotFound)` - If the dump does not contain the stream.
+    /// * `Err(UserDmpError::UnsupportedStream)` - If the library does not parse this stream type.
+    /// * `Err(UserDmpError)` - If the stream is malformed. The previous data is kept in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, data::MINIDUMP_STREAM_TYPE};
+    ///
+    /// let mut dump = UserDump::new("example.dmp").unwrap();
+    /// dump.reparse_stream(MINIDUMP_STREAM_TYPE::HandleDataStream).unwrap();
+    /// ```
+    pub fn reparse_stream(&mut self, stream_type: MINIDUMP_STREAM_TYPE) -> Result<()> {
+        let mut cursor = self
+            .stream_cursor(stream_type)
+            .ok_or(UserDmpError::StreamNotFound(stream_type))?;
+
+        // Diagnostics are collected apart, so a failed parse keeps the previous ones.
+        let mut diagnostics = Vec::new();
+        match stream_type {
+            SystemInfoStream => self.system = Self::parse_stream::<System>(&mut cursor)?,
+            MiscInfoStream => self.misc_info = Some(Self::parse_stream::<MiscInfo>(&mut cursor)?),
+            FunctionTableStream => self.function_tables = Self::parse_stream::<FunctionTable>(&mut cursor)?,
+            ProcessVmCountersStream => self.vm_counters = Some(Self::parse_stream::<VmCounters>(&mut cursor)?),
+            SystemMemoryInfoStream => self.system_memory = Some(Self::parse_stream::<SystemMemoryInfo>(&mut cursor)?),
+            ExceptionStream => {
+                let exception = Self::parse_stream::<Exception>(&mut cursor)?;
+                self.exception_thread_id = Some(exception.thread_id);
+                self.exception = Some(exception);
+            }
+            ModuleListStream => self.modules = Module::parse_list(&mut cursor, self.invalid_ranges, &mut diagnostics)?,
+            HandleDataStream => self.handles = Handle::parse_list(&mut cursor, &mut diagnostics)?,
+            HandleOperationListStream => self.handle_operations = HandleOperation::parse_list(&mut cursor, self.stream_len(stream_type))?,
+            TokenStream => {
+                let mut tokens = Self::parse_stream::<Token>(&mut cursor)?;
+                for token in &mut tokens {
+                    token.decode(self.system.processor_architecture);
+                }
+
+                self.tokens = tokens;
+            }
+            ThreadListStream => {
+                let mut threads = Thread::parse(&mut cursor, &Some(self.system.processor_architecture), &mut diagnostics)?;
+
+                // Threads are created bare, the optional thread streams are attached again.
+                if let Some(names) = self
+                    .stream_cursor(ThreadNamesStream)
+                    .and_then(|mut cursor| Thread::parse_names(&mut cursor, &mut Vec::new()).ok())
+                {
+                    Thread::attach_names(&mut threads, names);
+                }
+
+                if let Some(infos) = self
+                    .stream_cursor(ThreadInfoListStream)
+                    .and_then(|mut cursor| Thread::parse_info(&mut cursor, self.stream_len(ThreadInfoListStream)).ok())
+                {
+                    Thread::attach_info(&mut threads, infos);
+                }
+
+                self.threads = threads;
+                self.attribute_stacks();
+            }
+            ThreadNamesStream => {
+                let names = Thread::parse_names(&mut cursor, &mut diagnostics)?;
+                for thread in self.threads.values_mut() {
+                    thread.name = None;
+                }
+
+                Thread::attach_names(&mut self.threads, names);
+            }
+            ThreadInfoListStream => {
+                let infos = Thread::parse_info(&mut cursor, self.stream_len(stream_type))?;
+                for thread in self.threads.values_mut() {
+                    thread.info = None;
+                }
+
+                Thread::attach_info(&mut self.threads, infos);
+            }
+            MemoryInfoListStream | MemoryListStream | Memory64ListStream => {
+                // Regions are the merge of every memory stream, so all of them are parsed again.
+                let memory_info = match self.stream_cursor(MemoryInfoListStream) {
+                    Some(mut cursor) => Memory::parser_memory_info(&mut cursor, self.invalid_ranges, &mut diagnostics)?,
+                    None => Memorys::new(),
+                };
+
+                let mut captured = match self.stream_cursor(Memory64ListStream) {
+                    Some(mut cursor) => Memory::parser_memory64_list(&mut cursor)?,
+                    None => Memorys::new(),
+                };
+
+                if let Some(mut cursor) = self.stream_cursor(MemoryListStream) {
+                    for (address, memory) in Memory::parser_memory_list(&mut cursor)? {
+                        captured
+                            .entry(address)
+                            .or_insert(memory);
+                    }
+                }
+
+                Memory::check_overlaps(&memory_info, &mut diagnostics);
+                Memory::check_overlaps(&captured, &mut diagnostics);
+                self.memorys = Memory::merge_memory(memory_info, captured)?;
+                self.attribute_stacks();
+                self.replace_diagnostics(&[MemoryInfoListStream, MemoryListStream, Memory64ListStream], diagnostics);
+                return Ok(());
+            }
+            _ => return Err(UserDmpError::UnsupportedStream(stream_type)),
+        }
+
+        self.replace_diagnostics(&[stream_type], diagnostics);
+        Ok(())
+    }
+
+    /// Replaces the diagnostics reported for the given streams with the ones of a new parse.
+    ///
+    /// Diagnostics about the stream directory itself (duplicate or unknown streams) are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_types` - The streams that were parsed again. The new diagnostics are attributed to the first one.
+    /// * `diagnostics` - The diagnostics reported by the new parse.
+    fn replace_diagnostics(&mut self, stream_types: &[MINIDUMP_STREAM_TYPE], diagnostics: Vec<Diagnostic>) {
+        let (kept, sources): (Vec<Diagnostic>, Vec<u32>) = self
+            .diagnostics
+            .drain(..)
+            .zip(self.diagnostic_sources.drain(..))
+            .filter(|(diagnostic, source)| {
+                matches!(diagnostic, Diagnostic::DuplicateStream { .. } | Diagnostic::UnknownStream { .. })
+                    || !stream_types
+                        .iter()
+                        .any(|stream_type| *stream_type as u32 == *source)
             })
+            .unzip();
+
+        self.diagnostics = kept;
+        self.diagnostic_sources = sources;
+        self.diagnostic_sources
+            .resize(self.diagnostics.len() + diagnostics.len(), stream_types[0] as u32);
+        self.diagnostics.extend(diagnostics);
+    }
+
+    /// Returns a cursor positioned at the first stream of the given type.
+    fn stream_cursor(&self, stream_type: MINIDUMP_STREAM_TYPE) -> Option<Cursor<&'a [u8]>> {
+        let stream = self
+            .directory
+            .iter()
+            .find(|stream| stream.StreamType == stream_type as u32)?;
+        let mut cursor = self.mapped_file.cursor();
+        cursor.set_position(stream.Location.RVA.into());
+        Some(cursor)
+    }
+
+    /// Returns the size of the first stream of the given type, or 0 if the dump does not contain it.
+    fn stream_len(&self, stream_type: MINIDUMP_STREAM_TYPE) -> u64 {
+        self.directory
+            .iter()
+            .find(|stream| stream.StreamType == stream_type as u32)
+            .map_or(0, |stream| stream.Location.DataSize.into())
+    }
+
+    /// Returns the anomalies found while parsing the minidump.
+    ///
+    /// An empty list means the dump was parsed without any irregularity.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for diagnostic in dump.diagnostics() {
+    ///     println!("{diagnostic}");
+    /// }
+    /// ```
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Parses a specific stream type from a minidump file using the `MinidumpStream` trait.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - The stream type to parse. Must implement the `MinidumpStream` trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - A mutable reference to a cursor positioned within the minidump file.
+    ///   The cursor provides access to the binary data of the stream to be parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(S::Output)` - The parsed result for the specific stream type.
+    /// * `Err(UserDmpError)` - An error indicating that the parsing failed.
+    fn parse_stream<S>(cursor: &mut Cursor<&'a [u8]>) -> Result<S::Output>
+    where
+        S: MinidumpStream<'a>,
+    {
+        S::parse(cursor)
+    }
+
+    /// Parses a minidump file into a [`UserDump`] structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapped_file` - The memory-mapped minidump file.
+    /// * `options` - The options controlling how the file is parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If the file is parsed successfully.
+    /// * `Err(UserDmpError)` - If the file format is invalid or if parsing fails.
+    pub(crate) fn parse(mapped_file: MappingFile<'a>, options: &ParseOptions) -> Result<Self> {
+        // Creates a cursor to navigate the mapped file.
+        let mut cursor = mapped_file.cursor();
+
+        // Reads minidump header.
+        let header = MINIDUMP_HEADER::read(&mut cursor)?;
+
+        // Does the file provided have a minidump signature?
+        if header.Signature != MINIDUMP_SIGNATURE {
+            return Err(UserDmpError::InvalidSignature);
+        }
+
+        // Checks if at least one of the bits defined in DUMP_FLAGS (0x001f_ffff)
+        // is present in the Flags field of the header. These bits correspond to:
+        // - 0x00000001: Includes data sections of loaded modules (MiniDumpWithDataSegs).
+        // - 0x00000002: Includes the full memory of the process (MiniDumpWithFullMemory).
+        // - 0x00000004: Includes handle information (MiniDumpWithHandleData).
+        // - 0x00000008: Filters out unused memory (MiniDumpFilterMemory).
+        // - 0x00000010: Adds directly referenced memory (MiniDumpScanMemory).
+        // - 0x00000020: Includes unloaded modules (MiniDumpWithUnloadedModules).
+        // - 0x00000040: Includes indirectly referenced memory (MiniDumpWithIndirectlyReferencedMemory).
+        // - 0x00000200: Adds private read/write memory regions (MiniDumpWithPrivateReadWriteMemory).
+        // - 0x00000800: Adds detailed memory information (MiniDumpWithFullMemoryInfo).
+        // - 0x00001000: Includes detailed thread information (MiniDumpWithThreadInfo).
+        // - 0x00002000: Includes code segments from modules (MiniDumpWithCodeSegs).
+        if (header.Flags & DUMP_FLAGS) != 0 {
+            return Err(UserDmpError::InvalidFlags(header.Flags));
+        }
+
+        // Seeks to the stream directory.
+        cursor.seek(io::SeekFrom::Start(header.StreamDirectoryRva.into()))?;
+
+        // Collects all valid streams from the stream directory, up to the end of the file.
+        let mut streams = (0..header.NumberOfStreams)
+            .map_while(|_| MINIDUMP_DIRECTORY::read(&mut cursor).ok())
+            .filter(|stream| stream.StreamType != UnusedStream as u32)
             .collect::<Vec<MINIDUMP_DIRECTORY>>();
 
+        // Keeps the directory in its original order.
+        let directory = streams.clone();
+
         // Sort streams by their StreamType in descending order to ensure
         // that higher priority or dependent streams are processed first.
         streams.sort_by_key(|stream| std::cmp::Reverse(stream.StreamType));
 
         let mut system = System::default();
+        let mut misc_info = None;
+        let mut vm_counters = None;
+        let mut system_memory = None;
         let mut modules = Modules::new();
         let mut threads = Threads::new();
         let mut memory_info = Memorys::new();
         let mut memory64 = Memorys::new();
+        let mut memory_list = Memorys::new();
         let mut handles = Handles::new();
-        let mut exception_thread_id = None;
+        let mut handle_operations = Vec::new();
+        let mut tokens = Vec::new();
+        let mut function_tables = Vec::new();
+        let mut thread_names = BTreeMap::new();
+        let mut thread_infos = BTreeMap::new();
+        let mut exception = None;
+        let mut diagnostics = Vec::new();
+        let mut diagnostic_sources = Vec::new();
+        let mut seen = HashSet::new();
 
         // Processes each stream based on its type.
         for stream in &streams {
+            // Leaves out the streams the caller did not select.
+            if !options.parses(stream.StreamType) {
+                continue;
+            }
+
+            // Detects stream types listed more than once in the directory.
+            let duplicate = !seen.insert(stream.StreamType);
+            if duplicate {
+                diagnostics.push(Diagnostic::DuplicateStream {
+                    stream_type: stream.StreamType,
+                    rva: stream.Location.RVA,
+                });
+
+                if options.duplicate_streams == DuplicateStreamPolicy::FirstWins {
+                    diagnostic_sources.resize(diagnostics.len(), stream.StreamType);
+                    continue;
+                }
+            }
+
+            // Only list streams are merged, everything else keeps the previous result.
+            let merge = duplicate && options.duplicate_streams == DuplicateStreamPolicy::CollectAll;
+
             // Seeks to the stream data.
             cursor.seek(io::SeekFrom::Start(stream.Location.RVA.into()))?;
 
-            match MINIDUMP_STREAM_TYPE::try_from(stream.StreamType) {
-                Ok(SystemInfoStream) => system = Self::parse_stream::<System>(&mut cursor)?,
-                Ok(ModuleListStream) => modules = Self::parse_stream::<Module>(&mut cursor)?,
-                Ok(HandleDataStream) => handles = Self::parse_stream::<Handle>(&mut cursor)?,
-                Ok(ExceptionStream) => exception_thread_id = Some(Self::parser_exception(&mut cursor)?),
-                Ok(ThreadListStream) => threads = Thread::parse(&mut cursor, &Some(system.processor_architecture))?,
-                Ok(MemoryInfoListStream) => memory_info = Memory::parser_memory_info(&mut cursor)?,
-                Ok(Memory64ListStream) => memory64 = Memory::parser_memory64_list(&mut cursor)?,
-                _ => {}
+            // Parses the stream; in lenient mode a malformed stream is recorded and skipped.
+            let mut parse_one = || -> Result<()> {
+                match MINIDUMP_STREAM_TYPE::try_from(stream.StreamType) {
+                    Ok(SystemInfoStream) if !merge => system = Self::parse_stream::<System>(&mut cursor)?,
+                    Ok(MiscInfoStream) if !merge => misc_info = Some(Self::parse_stream::<MiscInfo>(&mut cursor)?),
+                    Ok(ProcessVmCountersStream) if !merge => vm_counters = Some(Self::parse_stream::<VmCounters>(&mut cursor)?),
+                    Ok(SystemMemoryInfoStream) if !merge => system_memory = Some(Self::parse_stream::<SystemMemoryInfo>(&mut cursor)?),
+                    Ok(ExceptionStream) if !merge => exception = Some(Self::parse_stream::<Exception>(&mut cursor)?),
+                    Ok(ModuleListStream) => {
+                        let parsed = Module::parse_list(&mut cursor, options.invalid_ranges, &mut diagnostics)?;
+                        Self::collect(&mut modules, parsed, merge)
+                    }
+                    Ok(HandleDataStream) => Self::collect(&mut handles, Handle::parse_list(&mut cursor, &mut diagnostics)?, merge),
+                    Ok(HandleOperationListStream) => {
+                        let parsed = HandleOperation::parse_list(&mut cursor, stream.Location.DataSize.into())?;
+                        if merge {
+                            handle_operations.extend(parsed);
+                        } else {
+                            handle_operations = parsed;
+                        }
+                    }
+                    Ok(ThreadListStream) => {
+                        let parsed = Thread::parse(&mut cursor, &Some(system.processor_architecture), &mut diagnostics)?;
+                        Self::collect(&mut threads, parsed, merge)
+                    }
+                    Ok(TokenStream) => {
+                        let parsed = Self::parse_stream::<Token>(&mut cursor)?;
+                        if merge {
+                            tokens.extend(parsed);
+                        } else {
+                            tokens = parsed;
+                        }
+                    }
+                    Ok(FunctionTableStream) => {
+                        let parsed = Self::parse_stream::<FunctionTable>(&mut cursor)?;
+                        if merge {
+                            function_tables.extend(parsed);
+                        } else {
+                            function_tables = parsed;
+                        }
+                    }
+                    Ok(ThreadInfoListStream) => {
+                        let parsed = Thread::parse_info(&mut cursor, stream.Location.DataSize.into())?;
+                        Self::collect(&mut thread_infos, parsed, merge)
+                    }
+                    Ok(ThreadNamesStream) => Self::collect(&mut thread_names, Thread::parse_names(&mut cursor, &mut diagnostics)?, merge),
+                    Ok(MemoryInfoListStream) => {
+                        let parsed = Memory::parser_memory_info(&mut cursor, options.invalid_ranges, &mut diagnostics)?;
+                        Self::collect(&mut memory_info, parsed, merge)
+                    }
+                    Ok(Memory64ListStream) => Self::collect(&mut memory64, Memory::parser_memory64_list(&mut cursor)?, merge),
+                    Ok(MemoryListStream) => Self::collect(&mut memory_list, Memory::parser_memory_list(&mut cursor)?, merge),
+                    Err(_) if stream.StreamType <= LastReservedStream as u32 => {
+                        diagnostics.push(Diagnostic::UnknownStream {
+                            stream_type: stream.StreamType,
+                            rva: stream.Location.RVA,
+                        });
+                    }
+                    _ => {}
+                }
+
+                Ok(())
+            };
+
+            if let Err(error) = parse_one() {
+                if options.mode == ParseMode::Strict {
+                    return Err(error);
+                }
+
+                diagnostics.push(Diagnostic::MalformedStream {
+                    stream_type: stream.StreamType,
+                    rva: stream.Location.RVA,
+                    error: error.to_string(),
+                });
             }
+
+            diagnostic_sources.resize(diagnostics.len(), stream.StreamType);
+        }
+
+        // Tokens are decoded once the pointer size is known from the SystemInfoStream.
+        for token in &mut tokens {
+            token.decode(system.processor_architecture);
         }
 
-        // Merges two maps of memory regions into a single map.
+        // Attaches the names and the additional information to their threads.
+        Thread::attach_names(&mut threads, thread_names);
+        Thread::attach_info(&mut threads, thread_infos);
+
+        // Full memory dumps use the Memory64ListStream, others the MemoryListStream; the
+        // 64-bit list wins when a region is listed in both.
+        for (address, memory) in memory_list {
+            memory64
+                .entry(address)
+                .or_insert(memory);
+        }
+
+        // Reports regions overlapping within the same stream before they are merged.
+        Memory::check_overlaps(&memory_info, &mut diagnostics);
+        diagnostic_sources.resize(diagnostics.len(), MemoryInfoListStream as u32);
+        Memory::check_overlaps(&memory64, &mut diagnostics);
+        diagnostic_sources.resize(diagnostics.len(), Memory64ListStream as u32);
+
+        // Merges the region attributes and the captured bytes into a single map.
         let memorys = Memory::merge_memory(memory_info, memory64)?;
 
-        // Returns the parsed UserDump.
-        Ok(Self {
-            exception_thread_id,
+        let mut dump = Self {
+            exception_thread_id: exception
+                .as_ref()
+                .map(|exception| exception.thread_id),
             system,
+            misc_info,
+            exception,
             modules,
             threads,
             memorys,
             handles,
+            handle_operations,
+            tokens,
+            function_tables,
+            vm_counters,
+            system_memory,
+            diagnostics,
+            diagnostic_sources,
+            directory,
+            unmapped_reads: options.unmapped_reads,
+            invalid_ranges: options.invalid_ranges,
+            struct_offsets: options.struct_offsets,
+            code_page: options.code_page.clone(),
+            code_ranges: Vec::new(),
+            labels: LabelMap::new(),
             mapped_file,
-        })
+        };
+
+        // Tags the regions holding thread stacks with their owning thread.
+        dump.attribute_stacks();
+
+        // Returns the parsed UserDump.
+        Ok(dump)
     }
 
-    /// Parses the exception information from the `ExceptionStream`.
+    /// Returns the bounds of a thread's stack.
+    ///
+    /// The bounds are read from the `NT_TIB` at the start of the thread's TEB
+    /// (`StackLimit..StackBase`) and extended with the stack memory captured for the thread.
+    /// When the TEB is not present in the dump, or its layout is unknown (see
+    /// [`UserDump::struct_offsets`]), only the captured stack memory is used.
     ///
     /// # Arguments
     ///
-    /// * `cursor` - Cursor positioned at the exception stream.
+    /// * `thread` - The thread whose stack bounds are requested.
     ///
     /// # Returns
     ///
-    /// * `Ok(u32)` - The thread ID associated with the exception.
-    /// * `Err(UserDmpError)` - If an error occurs during parsing.
-    fn parser_exception(cursor: &mut Cursor<&'a [u8]>) -> Result<u32> {
-        // Reads the exception stream.
-        let exception = MINIDUMP_EXCEPTION_STREAM::read(cursor)?;
+    /// * A `Range<u64>` covering the committed stack of the thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     println!("Thread {tid}: stack {:x?}", dump.stack_bounds(thread));
+    /// }
+    /// ```
+    pub fn stack_bounds(&self, thread: &Thread) -> std::ops::Range<u64> {
+        let Some(offsets) = self.struct_offsets() else {
+            return thread.stack.clone();
+        };
+
+        // A TEB near the top of the address space falls back to the captured stack memory.
+        let stack_base = offset_va(thread.teb, offsets.teb.stack_base as u64).and_then(|va| self.read_ptr(va));
+        let stack_limit = offset_va(thread.teb, offsets.teb.stack_limit as u64).and_then(|va| self.read_ptr(va));
+
+        match (stack_base, stack_limit) {
+            (Ok(base), Ok(limit)) if limit < base => {
+                if thread.stack.is_empty() {
+                    limit..base
+                } else {
+                    limit.min(thread.stack.start)..base.max(thread.stack.end)
+                }
+            }
+            _ => thread.stack.clone(),
+        }
+    }
+
+    /// Tags every memory region overlapping a thread's stack with the owning thread ID.
+    fn attribute_stacks(&mut self) {
+        for memory in self.memorys.values_mut() {
+            memory.owner = None;
+        }
 
-        // Returns the associated thread ID.
-        Ok(exception.ThreadId)
+        let stacks = self
+            .threads
+            .values()
+            .map(|thread| (thread.thread_id, self.stack_bounds(thread)))
+            .filter(|(_, bounds)| !bounds.is_empty())
+            .collect::<Vec<_>>();
+
+        for (thread_id, bounds) in stacks {
+            for memory in self.memorys.values_mut() {
+                if memory.range.start < bounds.end && bounds.start < memory.range.end {
+                    memory.owner = Some(MemoryOwner::ThreadStack(thread_id));
+                }
+            }
+        }
+    }
+
+    /// Stores the result of a list stream, either replacing or extending the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The map holding the previously parsed entries.
+    /// * `parsed` - The entries parsed from the current stream.
+    /// * `merge` - Whether `parsed` should be merged into `target` instead of replacing it.
+    fn collect<K: Ord, V>(target: &mut BTreeMap<K, V>, parsed: BTreeMap<K, V>, merge: bool) {
+        if merge {
+            target.extend(parsed);
+        } else {
+            *target = parsed;
+        }
     }
 
     /// Extracts raw data from a [`MINIDUMP_LOCATION_DESCRIPTOR`].
@@ -333,20 +1257,146 @@ impl<'a> UserDump<'a> {
     /// # Returns
     ///
     /// * `Ok(&'a [u8])` - A slice containing the raw data.
-    /// * `Err(io::Error)` - If the data extraction fails.
-    fn extract_raw_data(cursor: &Cursor<&'a [u8]>, location: MINIDUMP_LOCATION_DESCRIPTOR) -> io::Result<&'a [u8]> {
-        // Reads the RVA.
-        let rva = location.RVA;
+    /// * `Err(UserDmpError::TruncatedStream)` - If the data extends past the end of the file.
+    fn extract_raw_data(cursor: &Cursor<&'a [u8]>, location: MINIDUMP_LOCATION_DESCRIPTOR) -> Result<&'a [u8]> {
+        Self::file_slice(cursor, location.RVA.into(), location.DataSize.into())
+    }
 
-        // Reads the size of the data.
-        let size = location.DataSize;
+    /// Returns the bytes of the file at the given offset, checking they lie within the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor over the whole file.
+    /// * `rva` - The offset of the data in the file.
+    /// * `size` - The size of the data, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&'a [u8])` - A slice containing the data.
+    /// * `Err(UserDmpError::TruncatedStream)` - If the data extends past the end of the file.
+    pub(crate) fn file_slice(cursor: &Cursor<&'a [u8]>, rva: u64, size: u64) -> Result<&'a [u8]> {
+        let data: &'a [u8] = cursor.get_ref();
+        usize::try_from(rva)
+            .ok()
+            .zip(usize::try_from(size).ok())
+            .and_then(|(start, len)| data.get(start..start.checked_add(len)?))
+            .ok_or(UserDmpError::TruncatedStream { rva, size })
+    }
+
+    /// Returns how many entries of a list stream with a declared stride can be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_len` - The size of the stream, in bytes.
+    /// * `size_of_header` - The size of the list header declared in the stream.
+    /// * `size_of_entry` - The stride of the entries declared in the stream.
+    /// * `number_of_entries` - The number of entries declared in the stream.
+    /// * `min_size` - The size of the structure read for each entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of entries, capped to the ones fitting in the stream.
+    /// * `Err(UserDmpError::InvalidEntrySize)` - If the stride is smaller than the entry structure.
+    pub(crate) fn entry_count(stream_len: u64, size_of_header: u32, size_of_entry: u32, number_of_entries: u32, min_size: usize) -> Result<u64> {
+        // A stride smaller than the entry would read the same bytes over and over.
+        if (size_of_entry as usize) < min_size {
+            return Err(UserDmpError::InvalidEntrySize {
+                size: size_of_entry,
+                expected: min_size,
+            });
+        }
+
+        let capacity = stream_len.saturating_sub(size_of_header.into()) / u64::from(size_of_entry);
+        Ok(capacity.min(number_of_entries.into()))
+    }
+
+    /// Reads a `MINIDUMP_STRING` at the given offset.
+    ///
+    /// A string extending past the end of the file is cut at the end of the file and
+    /// reported as a [`Diagnostic::TruncatedString`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor over the whole file.
+    /// * `rva` - The offset of the string in the file.
+    /// * `diagnostics` - Receives the truncated strings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string converted to UTF-8, without its null terminator.
+    /// * `Err(UserDmpError)` - If the length of the string cannot be read.
+    fn read_string(cursor: &mut Cursor<&[u8]>, rva: u64, diagnostics: &mut Vec<Diagnostic>) -> Result<String> {
+        // Reads the length of the string, in bytes.
+        cursor.seek(io::SeekFrom::Start(rva))?;
+        let length = u32::read_le(cursor)?;
+
+        // Keeps the part of the buffer present in the file.
+        let data = *cursor.get_ref();
+        let start = cursor.position() as usize;
+        let available = data.len().saturating_sub(start);
+        let buffer = data
+            .get(start..start + available.min(length as usize))
+            .unwrap_or_default();
+
+        if buffer.len() < length as usize {
+            diagnostics.push(Diagnostic::TruncatedString { rva, length });
+        }
+
+        let units = buffer
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|unit| u16::from_le_bytes(*unit))
+            .collect::<Vec<u16>>();
+
+        Ok(String::from_utf16_lossy(&units)
+            .trim_end_matches('\0')
+            .to_string())
+    }
+
+    /// Reads the `MINIDUMP_STRING`s at several RVAs, in parallel with the `rayon` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor over the minidump file.
+    /// * `rvas` - The RVAs of the strings, `None` for absent strings.
+    /// * `diagnostics` - Receives the truncated strings, in the order of `rvas`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Option<String>>)` - One string per RVA, `None` where the RVA was `None`.
+    /// * `Err(UserDmpError)` - If a string length cannot be read.
+    fn read_strings(cursor: &Cursor<&[u8]>, rvas: &[Option<u64>], diagnostics: &mut Vec<Diagnostic>) -> Result<Vec<Option<String>>> {
+        let data = *cursor.get_ref();
+        let read = |rva: &Option<u64>| {
+            let mut truncated = Vec::new();
+            let string = rva
+                .map(|rva| UserDump::read_string(&mut Cursor::new(data), rva, &mut truncated))
+                .transpose()?;
+            Ok((string, truncated))
+        };
 
-        // Splits the slice at the RVA.
-        let slice = cursor.get_ref();
-        let (_, tail) = slice.split_at(rva as usize);
+        #[cfg(feature = "rayon")]
+        let strings = {
+            use rayon::prelude::*;
+            rvas.par_iter()
+                .map(read)
+                .collect::<Result<Vec<_>>>()?
+        };
 
-        // Returns the extracted slice.
-        Ok(&tail[..size as usize])
+        #[cfg(not(feature = "rayon"))]
+        let strings = rvas
+            .iter()
+            .map(read)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(strings
+            .into_iter()
+            .map(|(string, truncated)| {
+                diagnostics.extend(truncated);
+                string
+            })
+            .collect())
     }
 }
 
@@ -354,7 +1404,8 @@ impl<'a> UserDump<'a> {
 /// The [`System`] struct contains details about the processor architecture,
 /// operating system version, and other general system information useful
 /// for analyzing the minidump.
-#[derive(Copy, Debug, Clone, Default)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct System {
     /// The processor architecture captured in the minidump (e.g., x86 or x64).
     pub processor_architecture: Arch,
@@ -382,56 +1433,351 @@ pub struct System {
 
     /// The platform identifier of the operating system.
     pub platform_id: u32,
+
+    /// The latest service pack installed on the system (e.g., `Service Pack 1`), if any.
+    pub csd_version: Option<String>,
+
+    /// The processor information (vendor and feature bits).
+    pub cpu: CpuInfo,
+}
+
+impl MinidumpStream<'_> for System {
+    type Output = System;
+
+    /// Parses the system information from the `SystemInfoStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the system info stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Modules<'a>)` - If the system are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the system info stream.
+        let system_info = MINIDUMP_SYSTEM_INFO::read(cursor)?;
+
+        // Converts MINIDUMP_SYSTEM_INFO into System.
+        let mut system = System::from(system_info);
+
+        // Resolves the service pack string; an unreadable one is treated as absent.
+        if system_info.CSDVersionRva != 0 {
+            cursor.set_position(system_info.CSDVersionRva.into());
+            system.csd_version = MINIDUMP_STRING::read(cursor)
+                .ok()
+                .map(|string| {
+                    String::from_utf16_lossy(&string.Buffer)
+                        .trim_end_matches('\0')
+                        .to_string()
+                })
+                .filter(|csd_version| !csd_version.is_empty());
+        }
+
+        Ok(system)
+    }
+}
+
+impl From<MINIDUMP_SYSTEM_INFO> for System {
+    /// Converts a `MINIDUMP_SYSTEM_INFO` structure into a `System` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `info` - A [`MINIDUMP_SYSTEM_INFO`] instance containing the raw data
+    ///   extracted from the system information stream.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`System`] instance populated with data from the [`MINIDUMP_SYSTEM_INFO`].
+    fn from(info: MINIDUMP_SYSTEM_INFO) -> Self {
+        Self {
+            processor_architecture: Arch::from(info.ProcessorArchitecture),
+            processor_level: info.ProcessorLevel,
+            processor_revision: info.ProcessorRevision,
+            number_of_processors: info.NumberOfProcessors,
+            product_type: info.ProductType,
+            major_version: info.MajorVersion,
+            minor_version: info.MinorVersion,
+            build_number: info.BuildNumber,
+            platform_id: info.PlatformId,
+            csd_version: None,
+            cpu: CpuInfo::new(info.Cpu, Arch::from(info.ProcessorArchitecture)),
+        }
+    }
+}
+
+/// Represents the time zone settings captured in the minidump.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimeZone {
+    /// The time zone identifier (e.g., `TIME_ZONE_ID_DAYLIGHT`).
+    pub id: u32,
+
+    /// The bias for local time translation, in minutes.
+    pub bias: i32,
+
+    /// The description for standard time.
+    pub standard_name: String,
+
+    /// The additional bias used during standard time, in minutes.
+    pub standard_bias: i32,
+
+    /// The description for daylight saving time.
+    pub daylight_name: String,
+
+    /// The additional bias used during daylight saving time, in minutes.
+    pub daylight_bias: i32,
+}
+
+/// Represents the miscellaneous information captured in the `MiscInfoStream`.
+///
+/// Each field is only present when the corresponding flag is set in the stream,
+/// which depends on the `MINIDUMP_MISC_INFO` revision written by the dump producer.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MiscInfo {
+    /// The identifier of the captured process.
+    pub process_id: Option<u32>,
+
+    /// The creation time of the process, represented as a 32-bit UNIX time value.
+    pub process_create_time: Option<u32>,
+
+    /// The time the process has executed in user mode, in seconds.
+    pub process_user_time: Option<u32>,
+
+    /// The time the process has executed in kernel mode, in seconds.
+    pub process_kernel_time: Option<u32>,
+
+    /// The maximum clock frequency of the processor, in MHz.
+    pub processor_max_mhz: Option<u32>,
+
+    /// The current clock frequency of the processor, in MHz.
+    pub processor_current_mhz: Option<u32>,
+
+    /// The limit on the clock frequency of the processor, in MHz.
+    pub processor_mhz_limit: Option<u32>,
+
+    /// The integrity level of the process.
+    pub process_integrity_level: Option<u32>,
+
+    /// The execute flags of the process.
+    pub process_execute_flags: Option<u32>,
+
+    /// Indicates whether the process was a protected process.
+    pub protected_process: Option<bool>,
+
+    /// The time zone settings of the system.
+    pub time_zone: Option<TimeZone>,
+
+    /// The build string of the operating system.
+    pub build_string: Option<String>,
+
+    /// The build string of the debugger engine that wrote the dump.
+    pub dbg_build_string: Option<String>,
+
+    /// The process cookie.
+    pub process_cookie: Option<u32>,
+}
+
+impl MinidumpStream<'_> for MiscInfo {
+    type Output = MiscInfo;
+
+    /// Parses the miscellaneous information from the `MiscInfoStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the misc info stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MiscInfo)` - If the misc info is parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the misc info stream.
+        let misc_info = MINIDUMP_MISC_INFO_N::read(cursor)?;
+
+        // Converts MINIDUMP_MISC_INFO_N into MiscInfo.
+        Ok(MiscInfo::from(misc_info))
+    }
+}
+
+impl From<MINIDUMP_MISC_INFO_N> for MiscInfo {
+    /// Converts a `MINIDUMP_MISC_INFO_N` structure into a `MiscInfo` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `info` - A [`MINIDUMP_MISC_INFO_N`] instance containing the raw data
+    ///   extracted from the misc info stream.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`MiscInfo`] instance with the fields flagged as valid.
+    fn from(info: MINIDUMP_MISC_INFO_N) -> Self {
+        let has = |flag: u32| info.Flags1 & flag != 0;
+        let string = |buffer: &[u16]| {
+            let len = buffer
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(buffer.len());
+            String::from_utf16_lossy(&buffer[..len])
+        };
+
+        let times = has(MINIDUMP_MISC1_PROCESS_TIMES);
+        let power = has(MINIDUMP_MISC1_PROCESSOR_POWER_INFO) && info.SizeOfInfo >= MINIDUMP_MISC_INFO_2_SIZE;
+        let misc3 = info.SizeOfInfo >= MINIDUMP_MISC_INFO_3_SIZE;
+        let misc4 = info.SizeOfInfo >= MINIDUMP_MISC_INFO_4_SIZE;
+        let misc5 = info.SizeOfInfo >= MINIDUMP_MISC_INFO_5_SIZE;
+
+        Self {
+            process_id: has(MINIDUMP_MISC1_PROCESS_ID).then_some(info.ProcessId),
+            process_create_time: times.then_some(info.ProcessCreateTime),
+            process_user_time: times.then_some(info.ProcessUserTime),
+            process_kernel_time: times.then_some(info.ProcessKernelTime),
+            processor_max_mhz: power.then_some(info.ProcessorMaxMhz),
+            processor_current_mhz: power.then_some(info.ProcessorCurrentMhz),
+            processor_mhz_limit: power.then_some(info.ProcessorMhzLimit),
+            process_integrity_level: (misc3 && has(MINIDUMP_MISC3_PROCESS_INTEGRITY)).then_some(info.ProcessIntegrityLevel),
+            process_execute_flags: (misc3 && has(MINIDUMP_MISC3_PROCESS_EXECUTE_FLAGS)).then_some(info.ProcessExecuteFlags),
+            protected_process: (misc3 && has(MINIDUMP_MISC3_PROTECTED_PROCESS)).then_some(info.ProtectedProcess != 0),
+            time_zone: (misc3 && has(MINIDUMP_MISC3_TIMEZONE)).then(|| TimeZone {
+                id: info.TimeZoneId,
+                bias: info.TimeZone.Bias,
+                standard_name: string(&info.TimeZone.StandardName),
+                standard_bias: info.TimeZone.StandardBias,
+                daylight_name: string(&info.TimeZone.DaylightName),
+                daylight_bias: info.TimeZone.DaylightBias,
+            }),
+            build_string: (misc4 && has(MINIDUMP_MISC4_BUILDSTRING)).then(|| string(&info.BuildString)),
+            dbg_build_string: (misc4 && has(MINIDUMP_MISC4_BUILDSTRING)).then(|| string(&info.DbgBldStr)),
+            process_cookie: (misc5 && has(MINIDUMP_MISC5_PROCESS_COOKIE)).then_some(info.ProcessCookie),
+        }
+    }
+}
+
+/// Represents the exception captured in the `ExceptionStream`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Exception {
+    /// The identifier of the thread that caused the exception.
+    pub thread_id: u32,
+
+    /// The reason the exception occurred (e.g., `0xC0000005` for an access violation).
+    pub code: u32,
+
+    /// The exception flags (`EXCEPTION_NONCONTINUABLE` for noncontinuable exceptions).
+    pub flags: u32,
+
+    /// The address of a nested exception record in the captured process, if any.
+    pub record: u64,
+
+    /// The address where the exception occurred.
+    pub address: u64,
+
+    /// The number of valid parameters, as declared by the record.
+    number_parameters: u32,
+
+    /// The parameter array, of which only the first `number_parameters` entries are valid.
+    information: [u64; EXCEPTION_MAXIMUM_PARAMETERS],
+}
+
+impl Exception {
+    /// Returns the parameters describing the exception.
+    ///
+    /// Only the entries declared by `NumberParameters` are returned, so stale values left
+    /// in the rest of the fixed-size array are never exposed. A count above
+    /// `EXCEPTION_MAXIMUM_PARAMETERS` is clamped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(exception) = dump.exception() {
+    ///     // For an access violation: [0] is read (0), write (1) or DEP (8), [1] is the address.
+    ///     if let [kind, address] = exception.parameters() {
+    ///         println!("access {kind} at {address:#x}");
+    ///     }
+    /// }
+    /// ```
+    pub fn parameters(&self) -> &[u64] {
+        &self.information[..self.parameter_count()]
+    }
+
+    /// Returns a single parameter of the exception.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The value of the parameter.
+    /// * `None` - If `index` is not below the number of valid parameters.
+    pub fn parameter(&self, index: usize) -> Option<u64> {
+        self.parameters().get(index).copied()
+    }
+
+    /// Returns the number of valid parameters.
+    pub fn parameter_count(&self) -> usize {
+        (self.number_parameters as usize).min(EXCEPTION_MAXIMUM_PARAMETERS)
+    }
+
+    /// Returns true if the exception cannot be continued.
+    pub fn is_noncontinuable(&self) -> bool {
+        self.flags & EXCEPTION_NONCONTINUABLE != 0
+    }
+}
+
+impl std::ops::Index<usize> for Exception {
+    type Output = u64;
+
+    /// Returns a valid parameter of the exception.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below the number of valid parameters.
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.parameters()[index]
+    }
 }
 
-impl MinidumpStream<'_> for System {
-    type Output = System;
+impl MinidumpStream<'_> for Exception {
+    type Output = Exception;
 
-    /// Parses the system information from the `SystemInfoStream`.
+    /// Parses the exception information from the `ExceptionStream`.
     ///
     /// # Arguments
     ///
-    /// * `cursor` - Cursor positioned at the system info stream.
+    /// * `cursor` - Cursor positioned at the exception stream.
     ///
     /// # Returns
     ///
-    /// * `Ok(Modules<'a>)` - If the system are parsed successfully.
+    /// * `Ok(Exception)` - If the exception is parsed successfully.
     /// * `Err(UserDmpError)` - If an error occurs during parsing.
     fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
-        // Reads the system info stream.
-        let system_info = MINIDUMP_SYSTEM_INFO::read(cursor)?;
-
-        // Converts MINIDUMP_SYSTEM_INFO into System.
-        Ok(System::from(system_info))
+        // Reads the exception stream.
+        let stream = MINIDUMP_EXCEPTION_STREAM::read(cursor)?;
+        Ok(Exception::from_record(stream.ThreadId, &stream.ExceptionRecord))
     }
 }
 
-impl From<MINIDUMP_SYSTEM_INFO> for System {
-    /// Converts a `MINIDUMP_SYSTEM_INFO` structure into a `System` instance.
-    ///
-    /// # Parameters
-    ///
-    /// * `info` - A [`MINIDUMP_SYSTEM_INFO`] instance containing the raw data
-    ///   extracted from the system information stream.
+impl Exception {
+    /// Creates an [`Exception`] from an exception record raised on a thread.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// * A new [`System`] instance populated with data from the [`MINIDUMP_SYSTEM_INFO`].
-    fn from(info: MINIDUMP_SYSTEM_INFO) -> Self {
+    /// * `thread_id` - The identifier of the thread that raised the exception.
+    /// * `record` - The exception record.
+    pub(crate) fn from_record(thread_id: u32, record: &MINIDUMP_EXCEPTION) -> Self {
         Self {
-            processor_architecture: match info.ProcessorArchitecture {
-                ARCH_X64 => Arch::X64,
-                ARCH_X86 => Arch::X86,
-                _ => panic!("Unsupported architecture: {:x}", info.ProcessorArchitecture),
-            },
-            processor_level: info.ProcessorLevel,
-            processor_revision: info.ProcessorRevision,
-            number_of_processors: info.NumberOfProcessors,
-            product_type: info.ProductType,
-            major_version: info.MajorVersion,
-            minor_version: info.MinorVersion,
-            build_number: info.BuildNumber,
-            platform_id: info.PlatformId,
+            thread_id,
+            code: record.ExceptionCode,
+            flags: record.ExceptionFlags,
+            record: record.ExceptionRecord,
+            address: record.ExceptionAddress,
+            number_parameters: record.NumberParameters,
+            information: record.ExceptionInformation,
         }
     }
 }
@@ -439,6 +1785,7 @@ impl From<MINIDUMP_SYSTEM_INFO> for System {
 /// Represents a module loaded in a process, including its memory range, checksum, path,
 /// timestamp, and additional records like CodeView (CV) and miscellaneous (MISC) information.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Module<'a> {
     /// The memory range of the module.
     pub range: std::ops::Range<u64>,
@@ -457,6 +1804,12 @@ pub struct Module<'a> {
 
     /// The miscellaneous (MISC) record, often containing additional debug metadata.
     pub misc_record: &'a [u8],
+
+    /// The position of the module in the `ModuleListStream`.
+    load_order: usize,
+
+    /// The fixed version information of the module, when recorded.
+    version_info: Option<VersionInfo>,
 }
 
 impl<'a> Module<'a> {
@@ -469,39 +1822,59 @@ impl<'a> Module<'a> {
     ///
     /// # Returns
     ///
-    /// * A new `Module` instance initialized with the provided data.
-    ///
-    /// # Panics
-    ///
-    /// * This function will panic if the memory range of the module is invalid (e.g., start >= end).
-    pub fn new(module: &MINIDUMP_MODULE, name: String, cv_record: &'a [u8], misc_record: &'a [u8]) -> Self {
-        let range = std::ops::Range {
-            start: module.BaseOfImage,
-            end: module.BaseOfImage + module.SizeOfImage as u64,
-        };
-
-        if range.is_empty() {
-            panic!("Problem building the memory range")
-        }
+    /// * `Ok(Module)` - A new `Module` instance initialized with the provided data.
+    /// * `Err(UserDmpError::InvalidRange)` - If the range of the module is empty or overflows.
+    pub fn new(module: &MINIDUMP_MODULE, name: String, cv_record: &'a [u8], misc_record: &'a [u8]) -> Result<Self> {
+        let start = module.BaseOfImage;
+        let size = module.SizeOfImage as u64;
+        let end = start
+            .checked_add(size)
+            .filter(|end| *end > start)
+            .ok_or(UserDmpError::InvalidRange { start, size })?;
 
-        Self {
-            range,
+        Ok(Self {
+            range: start..end,
             checksum: module.CheckSum,
             path: name.into(),
             time_date_stamp: module.TimeDateStamp,
             cv_record,
             misc_record,
-        }
+            load_order: 0,
+            version_info: VersionInfo::from_fixed_file_info(&module.VersionInfo),
+        })
+    }
+
+    /// Returns the position of the module in the `ModuleListStream`, which
+    /// follows the load order recorded by the dump producer.
+    pub fn load_order(&self) -> usize {
+        self.load_order
+    }
+
+    /// Returns the fixed version information recorded for the module (`VS_FIXEDFILEINFO`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&VersionInfo)` - The version information.
+    /// * `None` - If the dump producer recorded none, as for images without a version resource.
+    pub fn version_info(&self) -> Option<&VersionInfo> {
+        self.version_info.as_ref()
     }
 
     /// Returns the name of the module file, if available.
     ///
+    /// Both `\` and `/` are treated as separators, so Windows paths are split
+    /// correctly regardless of the host platform.
+    ///
     /// # Returns
     ///
     /// * An `Option<&str>` containing the file name, or `None` if the path is invalid or
     ///   not UTF-8 encoded.
     pub fn name(&self) -> Option<&str> {
-        self.path.file_name()?.to_str()
+        self.path
+            .to_str()?
+            .rsplit(['\\', '/'])
+            .next()
+            .filter(|name| !name.is_empty())
     }
 
     /// Returns the starting memory address of the module.
@@ -551,34 +1924,102 @@ impl<'a> MinidumpStream<'a> for Module<'a> {
     /// * `Ok(Modules<'a>)` - If the modules are parsed successfully.
     /// * `Err(UserDmpError)` - If an error occurs during parsing.
     fn parse(cursor: &mut Cursor<&'a [u8]>) -> Result<Modules<'a>> {
+        Module::parse_list(cursor, InvalidRangePolicy::Fail, &mut Vec::new())
+    }
+}
+
+impl<'a> Module<'a> {
+    /// Parses the modules from the `ModuleListStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the module list stream.
+    /// * `policy` - How modules with an invalid range are handled.
+    /// * `diagnostics` - Receives the modules skipped under [`InvalidRangePolicy::Skip`] and the truncated debug records.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Modules<'a>)` - If the modules are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse_list(cursor: &mut Cursor<&'a [u8]>, policy: InvalidRangePolicy, diagnostics: &mut Vec<Diagnostic>) -> Result<Modules<'a>> {
         // Reads the module list stream.
         let module_list = MINIDUMP_MODULE_LIST::read(cursor)?;
 
-        // Parses each module entry in the list.
-        let modules = module_list
+        // Reads the module names.
+        let rvas = module_list
             .Modules
             .iter()
-            .map(|module| {
-                // Seeks to the module name.
-                cursor.seek(io::SeekFrom::Start(module.ModuleNameRva.into()))?;
+            .map(|module| Some(module.ModuleNameRva.into()))
+            .collect::<Vec<_>>();
+        let names = UserDump::read_strings(cursor, &rvas, diagnostics)?;
 
-                // reading the structure MINIDUMP_STRING
-                let string = MINIDUMP_STRING::read(cursor)?;
-
-                // Converts the name to UTF-8.
-                let module_name = String::from_utf16_lossy(&string.Buffer)
-                    .trim_end_matches('\0')
-                    .to_string();
+        // Parses each module entry in the list.
+        let mut modules = Modules::new();
+        for (index, (module, module_name)) in module_list
+            .Modules
+            .iter()
+            .zip(names)
+            .enumerate()
+        {
+            let module_name = module_name.unwrap_or_default();
+
+            // Extracts the CodeView record, when present.
+            let cv_record = Module::extract_record(cursor, module.CvRecord, diagnostics);
+
+            // Extracts the MISC record, when present.
+            let misc_record = Module::extract_record(cursor, module.MiscRecord, diagnostics);
+
+            // Creates a new Module.
+            let mut module = match Module::new(module, module_name, cv_record, misc_record) {
+                Ok(module) => module,
+                Err(UserDmpError::InvalidRange { start, size }) if policy == InvalidRangePolicy::Skip => {
+                    diagnostics.push(Diagnostic::InvalidRange {
+                        stream_type: ModuleListStream as u32,
+                        start,
+                        size,
+                    });
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
 
-                // Creates a new Module.
-                let module = Module::new(module, module_name, &[], &[]);
-                Ok((module.range.start, module))
-            })
-            .collect::<Result<Modules>>()?;
+            module.load_order = index;
+            modules.insert(module.range.start, module);
+        }
 
         // Returns the parsed modules.
         Ok(modules)
     }
+
+    /// Extracts a debug record of a module, such as its CodeView or MISC record.
+    ///
+    /// A record extending past the end of the file is reported as a
+    /// [`Diagnostic::TruncatedRecord`] and replaced by an empty record,
+    /// so the rest of the module list is still parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor over the whole file.
+    /// * `location` - The descriptor of the record.
+    /// * `diagnostics` - Receives the truncated records.
+    ///
+    /// # Returns
+    ///
+    /// * The bytes of the record, or an empty slice if it is absent or truncated.
+    fn extract_record(cursor: &Cursor<&'a [u8]>, location: MINIDUMP_LOCATION_DESCRIPTOR, diagnostics: &mut Vec<Diagnostic>) -> &'a [u8] {
+        if location.DataSize == 0 {
+            return &[];
+        }
+
+        UserDump::extract_raw_data(cursor, location).unwrap_or_else(|_| {
+            diagnostics.push(Diagnostic::TruncatedRecord {
+                rva: location.RVA.into(),
+                size: location.DataSize.into(),
+            });
+
+            &[]
+        })
+    }
 }
 
 /// Represents the processor context of a thread captured in the minidump.
@@ -592,6 +2033,171 @@ pub enum ThreadContext {
 
     /// Represents the 32-bit processor context (`CONTEXT_X86`) for the thread.
     X86(Box<CONTEXT_X86>),
+
+    /// The context of a thread whose architecture has no supported context layout.
+    ///
+    /// The remaining thread metadata is still available, only the registers are not decoded.
+    Unsupported(Arch),
+}
+
+impl ThreadContext {
+    /// Returns the instruction pointer (`RIP` or `EIP`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The instruction pointer, zero-extended for 32-bit contexts.
+    /// * `None` - If the context layout is not supported.
+    pub fn instruction_pointer(&self) -> Option<u64> {
+        match self {
+            ThreadContext::X64(context) => Some(context.Rip),
+            ThreadContext::X86(context) => Some(context.Eip.into()),
+            ThreadContext::Unsupported(_) => None,
+        }
+    }
+
+    /// Returns the stack pointer (`RSP` or `ESP`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The stack pointer, zero-extended for 32-bit contexts.
+    /// * `None` - If the context layout is not supported.
+    pub fn stack_pointer(&self) -> Option<u64> {
+        match self {
+            ThreadContext::X64(context) => Some(context.Rsp),
+            ThreadContext::X86(context) => Some(context.Esp.into()),
+            ThreadContext::Unsupported(_) => None,
+        }
+    }
+
+    /// Returns the frame pointer (`RBP` or `EBP`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The frame pointer, zero-extended for 32-bit contexts.
+    /// * `None` - If the context layout is not supported.
+    pub fn frame_pointer(&self) -> Option<u64> {
+        match self {
+            ThreadContext::X64(context) => Some(context.Rbp),
+            ThreadContext::X86(context) => Some(context.Ebp.into()),
+            ThreadContext::Unsupported(_) => None,
+        }
+    }
+
+    /// Returns a register by name (e.g., `"rax"` or `"eip"`), ignoring case.
+    ///
+    /// The names are those of the architecture of the context, as listed by
+    /// [`ThreadContext::registers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the register.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The value of the register, zero-extended for 32-bit contexts.
+    /// * `None` - If the context has no register with this name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     println!("{tid}: rax = {:x?}", thread.context().get_register("rax"));
+    /// }
+    /// ```
+    pub fn get_register(&self, name: &str) -> Option<u64> {
+        self.registers()
+            .into_iter()
+            .find(|(register, _)| register.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the general-purpose, control and segment registers, with their names.
+    ///
+    /// # Returns
+    ///
+    /// * A list of `(name, value)` pairs in the order debuggers usually display them, empty
+    ///   if the context layout is not supported.
+    pub fn registers(&self) -> Vec<(&'static str, u64)> {
+        match self {
+            ThreadContext::X64(c) => vec![
+                ("rax", c.Rax),
+                ("rbx", c.Rbx),
+                ("rcx", c.Rcx),
+                ("rdx", c.Rdx),
+                ("rsi", c.Rsi),
+                ("rdi", c.Rdi),
+                ("rip", c.Rip),
+                ("rsp", c.Rsp),
+                ("rbp", c.Rbp),
+                ("r8", c.R8),
+                ("r9", c.R9),
+                ("r10", c.R10),
+                ("r11", c.R11),
+                ("r12", c.R12),
+                ("r13", c.R13),
+                ("r14", c.R14),
+                ("r15", c.R15),
+                ("eflags", c.EFlags.into()),
+                ("cs", c.SegCs.into()),
+                ("ds", c.SegDs.into()),
+                ("es", c.SegEs.into()),
+                ("fs", c.SegFs.into()),
+                ("gs", c.SegGs.into()),
+                ("ss", c.SegSs.into()),
+                ("dr0", c.Dr0),
+                ("dr1", c.Dr1),
+                ("dr2", c.Dr2),
+                ("dr3", c.Dr3),
+                ("dr6", c.Dr6),
+                ("dr7", c.Dr7),
+            ],
+            ThreadContext::X86(c) => vec![
+                ("eax", c.Eax.into()),
+                ("ebx", c.Ebx.into()),
+                ("ecx", c.Ecx.into()),
+                ("edx", c.Edx.into()),
+                ("esi", c.Esi.into()),
+                ("edi", c.Edi.into()),
+                ("eip", c.Eip.into()),
+                ("esp", c.Esp.into()),
+                ("ebp", c.Ebp.into()),
+                ("eflags", c.EFlags.into()),
+                ("cs", c.SegCs.into()),
+                ("ds", c.SegDs.into()),
+                ("es", c.SegEs.into()),
+                ("fs", c.SegFs.into()),
+                ("gs", c.SegGs.into()),
+                ("ss", c.SegSs.into()),
+                ("dr0", c.Dr0.into()),
+                ("dr1", c.Dr1.into()),
+                ("dr2", c.Dr2.into()),
+                ("dr3", c.Dr3.into()),
+                ("dr6", c.Dr6.into()),
+                ("dr7", c.Dr7.into()),
+            ],
+            ThreadContext::Unsupported(_) => Vec::new(),
+        }
+    }
+}
+
+/// Serializes the context as a map of register names to values (see [`ThreadContext::registers`]),
+/// empty if the context layout is not supported.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ThreadContext {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let registers = self.registers();
+        let mut map = serializer.serialize_map(Some(registers.len()))?;
+        for (name, value) in registers {
+            map.serialize_entry(name, &value)?;
+        }
+
+        map.end()
+    }
 }
 
 /// Represents a thread in the process, as captured in the minidump file.
@@ -599,6 +2205,7 @@ pub enum ThreadContext {
 /// The `Thread` struct contains metadata about the thread, such as its ID,
 /// priority, and execution context.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Thread {
     /// The unique identifier (ID) of the thread.
     pub thread_id: u32,
@@ -615,6 +2222,15 @@ pub struct Thread {
     /// The address of the Thread Environment Block (TEB), containing per-thread information.
     pub teb: u64,
 
+    /// The range of the stack memory captured for the thread.
+    pub stack: std::ops::Range<u64>,
+
+    /// The description of the thread, as set by `SetThreadDescription`.
+    name: Option<String>,
+
+    /// The additional information of the thread, as recorded in the `ThreadInfoListStream`.
+    info: Option<ThreadInfo>,
+
     /// The execution context of the thread, including register states.
     context: ThreadContext,
 }
@@ -631,12 +2247,21 @@ impl Thread {
     ///
     /// * A new `Thread` instance initialized with the provided data.
     fn new(thread: &MINIDUMP_THREAD, context: ThreadContext) -> Self {
+        // A stack range that overflows the address space is left empty.
+        let start = thread.Stack.StartOfMemoryRange;
+        let end = start
+            .checked_add(thread.Stack.Memory.DataSize as u64)
+            .unwrap_or(start);
+
         Self {
             thread_id: thread.ThreadId,
             suspend_count: thread.SuspendCount,
             priority_class: thread.PriorityClass,
             priority: thread.Priority,
             teb: thread.Teb,
+            stack: start..end,
+            name: None,
+            info: None,
             context,
         }
     }
@@ -646,56 +2271,334 @@ impl Thread {
         &self.context
     }
 
-    /// Parses the list of threads from the `ThreadListStream`.
+    /// Returns the name of the thread, if one was recorded in the `ThreadNamesStream`.
+    ///
+    /// # Returns
+    ///
+    /// * An `Option<&str>` containing the thread description, or `None` if unavailable.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the start address of the thread, if one was recorded in the `ThreadInfoListStream`.
+    ///
+    /// # Returns
+    ///
+    /// * An `Option<u64>` containing the start address, or `None` if unavailable.
+    pub fn start_address(&self) -> Option<u64> {
+        self.info
+            .as_ref()
+            .map(|info| info.start_address)
+            .filter(|&address| address != 0)
+    }
+
+    /// Returns the additional information of the thread, if recorded in the `ThreadInfoListStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     if let Some(info) = thread.info() {
+    ///         println!("{tid}: user {:?}, kernel {:?}, affinity {:#x}", info.user_time(), info.kernel_time(), info.affinity);
+    ///     }
+    /// }
+    /// ```
+    pub fn info(&self) -> Option<&ThreadInfo> {
+        self.info.as_ref()
+    }
+
+    /// Attaches the names parsed from the `ThreadNamesStream` to their threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The threads to update.
+    /// * `names` - The thread names indexed by thread ID. Empty names are ignored.
+    fn attach_names(threads: &mut Threads, names: BTreeMap<u32, String>) {
+        for (thread_id, name) in names
+            .into_iter()
+            .filter(|(_, name)| !name.is_empty())
+        {
+            if let Some(thread) = threads.get_mut(&thread_id) {
+                thread.name = Some(name);
+            }
+        }
+    }
+
+    /// Attaches the entries parsed from the `ThreadInfoListStream` to their threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The threads to update.
+    /// * `infos` - The thread information indexed by thread ID.
+    fn attach_info(threads: &mut Threads, infos: BTreeMap<u32, MINIDUMP_THREAD_INFO>) {
+        for (thread_id, info) in infos {
+            if let Some(thread) = threads.get_mut(&thread_id) {
+                thread.info = Some(ThreadInfo::from(info));
+            }
+        }
+    }
+
+    /// Parses the entries of the `ThreadInfoListStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the thread info list stream.
+    /// * `stream_len` - The size of the stream, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BTreeMap<u32, MINIDUMP_THREAD_INFO>)` - The thread information indexed by thread ID.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse_info(cursor: &mut Cursor<&[u8]>, stream_len: u64) -> Result<BTreeMap<u32, MINIDUMP_THREAD_INFO>> {
+        // Reads the thread info list header.
+        let start = cursor.position();
+        let info_list = MINIDUMP_THREAD_INFO_LIST::read(cursor)?;
+        let count = UserDump::entry_count(
+            stream_len,
+            info_list.SizeOfHeader,
+            info_list.SizeOfEntry,
+            info_list.NumberOfEntries,
+            size_of::<MINIDUMP_THREAD_INFO>(),
+        )?;
+
+        // Entries are read at their declared stride, which may grow in future versions.
+        let infos = (0..count)
+            .map(|index| {
+                let offset = start + info_list.SizeOfHeader as u64 + index * info_list.SizeOfEntry as u64;
+                cursor.seek(io::SeekFrom::Start(offset))?;
+
+                let info = MINIDUMP_THREAD_INFO::read(cursor)?;
+                Ok((info.ThreadId, info))
+            })
+            .collect::<Result<BTreeMap<u32, MINIDUMP_THREAD_INFO>>>()?;
+
+        Ok(infos)
+    }
+
+    /// Parses the thread names from the `ThreadNamesStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the thread names stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BTreeMap<u32, String>)` - The thread names indexed by thread ID.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse_names(cursor: &mut Cursor<&[u8]>, diagnostics: &mut Vec<Diagnostic>) -> Result<BTreeMap<u32, String>> {
+        // Reads the thread name list stream.
+        let name_list = MINIDUMP_THREAD_NAME_LIST::read(cursor)?;
+
+        // Parses each thread name entry in the list.
+        let names = name_list
+            .ThreadNames
+            .iter()
+            .map(|thread_name| {
+                // Reads the thread name.
+                let name = UserDump::read_string(cursor, thread_name.RvaOfThreadName, diagnostics)?;
+                Ok((thread_name.ThreadId, name))
+            })
+            .collect::<Result<BTreeMap<u32, String>>>()?;
+
+        Ok(names)
+    }
+
+    /// Parses the list of threads from the `ThreadListStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the thread list stream.
+    /// * `arch` - An optional `Arch` parameter that specifies the architecture (e.g., `X64` or `X86`).
+    ///   This is used to correctly parse the thread context based on the architecture.
+    ///
+    /// * `diagnostics` - Receives the thread IDs listed more than once and the stacks with an overflowing range.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Threads)` - If the threads are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&[u8]>, arch: &Option<Arch>, diagnostics: &mut Vec<Diagnostic>) -> Result<Threads> {
+        // Reads the thread list stream.
+        let thread_list = MINIDUMP_THREAD_LIST::read(cursor)?;
+
+        // Parses each thread entry in the list.
+        let mut threads = Threads::new();
+        for thread in thread_list.Threads.iter() {
+            // Extracts the thread context.
+            let context_slice = UserDump::extract_raw_data(cursor, thread.ThreadContext)?;
+            let context = match arch.ok_or(UserDmpError::InvalidContext)? {
+                Arch::X64 if context_slice.len() >= size_of::<CONTEXT_X64>() => unsafe {
+                    let ctx = ptr::read_unaligned(context_slice.as_ptr() as *const CONTEXT_X64);
+                    ThreadContext::X64(Box::new(ctx))
+                },
+                Arch::X86 if context_slice.len() >= size_of::<CONTEXT_X86>() => unsafe {
+                    let ctx = ptr::read_unaligned(context_slice.as_ptr() as *const CONTEXT_X86);
+                    ThreadContext::X86(Box::new(ctx))
+                },
+
+                // A context smaller than the structure would be read past its end.
+                Arch::X64 | Arch::X86 => return Err(UserDmpError::InvalidContext),
+                arch => ThreadContext::Unsupported(arch),
+            };
+
+            // Creates a new Thread; the last entry of a repeated thread ID wins.
+            let stack = thread.Stack;
+            let thread = Thread::new(thread, context);
+            if thread.stack.is_empty() && stack.Memory.DataSize != 0 {
+                diagnostics.push(Diagnostic::InvalidRange {
+                    stream_type: ThreadListStream as u32,
+                    start: stack.StartOfMemoryRange,
+                    size: stack.Memory.DataSize.into(),
+                });
+            }
+
+            let thread_id = thread.thread_id;
+            if threads
+                .insert(thread_id, thread)
+                .is_some()
+            {
+                diagnostics.push(Diagnostic::DuplicateThread { thread_id });
+            }
+        }
+
+        Ok(threads)
+    }
+}
+
+/// Number of 100-nanosecond intervals between January 1, 1601 and the UNIX epoch.
+const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+
+/// Represents the additional information of a thread captured in the `ThreadInfoListStream`.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ThreadInfo {
+    /// The flags describing the state of the thread in the dump (`MINIDUMP_THREAD_INFO_*`).
+    pub dump_flags: u32,
+
+    /// The `HRESULT` of the failure to retrieve the thread state, if any.
+    pub dump_error: u32,
+
+    /// The exit status of the thread, `STILL_ACTIVE` (0x103) while running.
+    pub exit_status: u32,
+
+    /// The creation time of the thread, as a `FILETIME`.
+    pub create_time: u64,
+
+    /// The exit time of the thread, as a `FILETIME`, zero while running.
+    pub exit_time: u64,
+
+    /// The time spent in kernel mode, in 100-nanosecond intervals.
+    pub kernel_time_100ns: u64,
+
+    /// The time spent in user mode, in 100-nanosecond intervals.
+    pub user_time_100ns: u64,
+
+    /// The start address of the thread.
+    pub start_address: u64,
+
+    /// The processor affinity mask of the thread.
+    pub affinity: u64,
+}
+
+impl ThreadInfo {
+    /// Returns the creation time of the thread.
+    pub fn created(&self) -> Option<std::time::SystemTime> {
+        Self::system_time(self.create_time)
+    }
+
+    /// Returns the exit time of the thread, if it had exited when the dump was written.
+    pub fn exited(&self) -> Option<std::time::SystemTime> {
+        Self::system_time(self.exit_time)
+    }
+
+    /// Returns the time the thread spent in kernel mode.
+    pub fn kernel_time(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(
+            self.kernel_time_100ns
+                .saturating_mul(100),
+        )
+    }
+
+    /// Returns the time the thread spent in user mode.
+    pub fn user_time(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.user_time_100ns.saturating_mul(100))
+    }
+
+    /// Returns true if the thread raised the exception that triggered the dump.
+    pub fn is_error_thread(&self) -> bool {
+        self.dump_flags & MINIDUMP_THREAD_INFO_ERROR_THREAD != 0
+    }
+
+    /// Returns true if the thread was writing the dump.
+    pub fn is_writing_thread(&self) -> bool {
+        self.dump_flags & MINIDUMP_THREAD_INFO_WRITING_THREAD != 0
+    }
+
+    /// Returns true if the thread had exited when the dump was written.
+    pub fn is_exited(&self) -> bool {
+        self.dump_flags & MINIDUMP_THREAD_INFO_EXITED_THREAD != 0
+    }
+
+    /// Returns true if the dump writer failed to retrieve the context or the TEB of the thread.
+    pub fn is_incomplete(&self) -> bool {
+        self.dump_flags & (MINIDUMP_THREAD_INFO_INVALID_INFO | MINIDUMP_THREAD_INFO_INVALID_CONTEXT | MINIDUMP_THREAD_INFO_INVALID_TEB) != 0
+    }
+
+    /// Converts a `FILETIME` into a `SystemTime`, `None` for zero or pre-1970 times.
+    fn system_time(filetime: u64) -> Option<std::time::SystemTime> {
+        let since_epoch = filetime.checked_sub(FILETIME_UNIX_EPOCH)?;
+        (filetime != 0).then(|| std::time::UNIX_EPOCH + std::time::Duration::from_nanos(since_epoch.saturating_mul(100)))
+    }
+}
+
+impl From<MINIDUMP_THREAD_INFO> for ThreadInfo {
+    /// Converts a `MINIDUMP_THREAD_INFO` structure into a `ThreadInfo` instance.
     ///
-    /// # Arguments
+    /// # Parameters
     ///
-    /// * `cursor` - Cursor positioned at the thread list stream.
-    /// * `arch` - An optional `Arch` parameter that specifies the architecture (e.g., `X64` or `X86`).
-    ///            This is used to correctly parse the thread context based on the architecture.
+    /// * `info` - A [`MINIDUMP_THREAD_INFO`] instance read from the thread info list stream.
     ///
     /// # Returns
     ///
-    /// * `Ok(Threads)` - If the threads are parsed successfully.
-    /// * `Err(UserDmpError)` - If an error occurs during parsing.
-    fn parse(cursor: &mut Cursor<&[u8]>, arch: &Option<Arch>) -> Result<Threads> {
-        // Reads the thread list stream.
-        let thread_list = MINIDUMP_THREAD_LIST::read(cursor)?;
+    /// * A new [`ThreadInfo`] instance populated with data from the [`MINIDUMP_THREAD_INFO`].
+    fn from(info: MINIDUMP_THREAD_INFO) -> Self {
+        Self {
+            dump_flags: info.DumpFlags,
+            dump_error: info.DumpError,
+            exit_status: info.ExitStatus,
+            create_time: info.CreateTime,
+            exit_time: info.ExitTime,
+            kernel_time_100ns: info.KernelTime,
+            user_time_100ns: info.UserTime,
+            start_address: info.StartAddress,
+            affinity: info.Affinity,
+        }
+    }
+}
 
-        // Parses each thread entry in the list.
-        let threads = thread_list
-            .Threads
-            .iter()
-            .map(|thread| {
-                // Extracts the thread context.
-                let context_slice = UserDump::extract_raw_data(cursor, thread.ThreadContext)?;
-                let context = arch
-                    .as_ref()
-                    .map(|arch| match arch {
-                        Arch::X64 => unsafe {
-                            let ctx = ptr::read_unaligned(context_slice.as_ptr() as *const CONTEXT_X64);
-                            ThreadContext::X64(Box::new(ctx))
-                        },
-                        Arch::X86 => unsafe {
-                            let ctx = ptr::read_unaligned(context_slice.as_ptr() as *const CONTEXT_X86);
-                            ThreadContext::X86(Box::new(ctx))
-                        },
-                    })
-                    .ok_or(UserDmpError::InvalidContext)?;
-
-                // Creates a new Thread.
-                let thread = Thread::new(thread, context);
-                Ok((thread.thread_id, thread))
-            })
-            .collect::<Result<Threads>>()?;
+/// Identifies what a memory region is used for in the captured process.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MemoryOwner {
+    /// The region holds the stack of the thread with the given ID.
+    ThreadStack(u32),
+}
 
-        Ok(threads)
+impl std::fmt::Display for MemoryOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemoryOwner::ThreadStack(thread_id) => write!(f, "Stack (thread {thread_id})"),
+        }
     }
 }
 
 /// Represents a memory region in a minidump file, providing metadata about its state,
 /// protection level, allocation base, and type.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Memory<'a> {
     /// The range of memory addresses for this region.
     pub range: std::ops::Range<u64>,
@@ -704,22 +2607,25 @@ pub struct Memory<'a> {
     pub allocation_base: u64,
 
     /// The protection attributes applied at the time of memory allocation.
-    pub allocation_protect: u32,
+    pub allocation_protect: MemoryProtection,
 
     /// The current state of the memory region, indicating if it's committed,
     /// reserved, or free (e.g., `MEM_COMMIT` or `MEM_FREE`).
-    pub state: u32,
+    pub state: MemoryState,
 
     /// The protection level of the memory region (e.g., `PAGE_READWRITE`).
-    pub protect: u32,
+    pub protect: MemoryProtection,
 
     /// The type of memory region (e.g., private, mapped, or image).
-    pub type_: u32,
+    pub type_: MemoryType,
 
     /// The raw bytes of the memory region, as extracted from the minidump file.
     /// This data represents the actual content of the memory in this region
     /// and can be used for further analysis or reconstruction.
     pub data: &'a [u8],
+
+    /// What the region is used for, when it could be attributed.
+    owner: Option<MemoryOwner>,
 }
 
 impl<'a> Memory<'a> {
@@ -731,76 +2637,77 @@ impl<'a> Memory<'a> {
     ///
     /// # Returns
     ///
-    /// * A `Memory` instance initialized with the provided data.
-    ///
-    /// # Panics
-    ///
-    /// * This function will panic if the memory range is invalid (e.g., `start >= end`).
-    fn new(memory: &MINIDUMP_MEMORY_INFO) -> Self {
-        let range = std::ops::Range {
-            start: memory.BaseAddress,
-            end: memory.BaseAddress + memory.RegionSize,
-        };
-
-        if range.is_empty() {
-            panic!("Problem building the memory range")
-        }
+    /// * `Ok(Memory)` - A `Memory` instance initialized with the provided data.
+    /// * `Err(UserDmpError::InvalidRange)` - If the range of the region is empty or overflows.
+    fn new(memory: &MINIDUMP_MEMORY_INFO) -> Result<Self> {
+        let start = memory.BaseAddress;
+        let size = memory.RegionSize;
+        let end = start
+            .checked_add(size)
+            .filter(|end| *end > start)
+            .ok_or(UserDmpError::InvalidRange { start, size })?;
 
-        Self {
-            range,
+        Ok(Self {
+            range: start..end,
             allocation_base: memory.AllocationBase,
-            allocation_protect: memory.AllocationProtect,
-            state: memory.State,
-            protect: memory.Protect,
-            type_: memory.Type,
+            allocation_protect: memory.AllocationProtect.into(),
+            state: memory.State.into(),
+            protect: memory.Protect.into(),
+            type_: memory.Type.into(),
             ..Default::default()
-        }
+        })
     }
 
-    /// Returns a textual description of the current memory state.
+    /// Returns the current state of the memory region.
     ///
-    /// The possible states are:
-    /// - `MEM_COMMIT` (0x1000): Memory is committed and backed by physical storage or the page file.
-    /// - `MEM_RESERVE` (0x2000): Memory is reserved but not yet committed.
-    /// - `MEM_FREE` (0x10000): Memory is free and available for allocation.
-    /// - `MEM_RESET` (0x8000): Memory has been reset to a clean state.
-    /// - `MEM_TOP_DOWN` (0x100000): Allocation was made top-down from high memory addresses.
+    /// The state displays as its `MEM_*` name (e.g., `MEM_COMMIT`).
     ///
     /// # Returns
     ///
-    /// * A `&str` describing the state of the memory.
-    pub fn state(&self) -> &str {
-        if self.state == 0x10_000 {
-            return "";
-        }
-
-        match self.state {
-            0x1_000 => "MEM_COMMIT",
-            0x2_000 => "MEM_RESERVE",
-            0x10_000 => "MEM_FREE",
-            0x8_000 => "MEM_RESET",
-            0x100_000 => "MEM_TOP_DOWN",
-            _ => "UNKNOWN",
-        }
+    /// * A [`MemoryState`] describing whether the region is committed, reserved or free.
+    pub fn state(&self) -> MemoryState {
+        self.state
     }
 
-    /// Returns a textual description of the memory type.
+    /// Returns the type of the memory region.
     ///
-    /// The possible types are:
-    /// - `MEM_PRIVATE` (0x20000): Memory is private to the process.
-    /// - `MEM_MAPPED` (0x40000): Memory is mapped to a file.
-    /// - `MEM_IMAGE` (0x1000000): Memory is associated with an executable image.
+    /// The type displays as its `MEM_*` name (e.g., `MEM_IMAGE`).
     ///
     /// # Returns
     ///
-    /// * A `&str` describing the type of the memory region.
-    pub fn type_memory(&self) -> &str {
-        match self.type_ {
-            0x20_000 => "MEM_PRIVATE",
-            0x40_000 => "MEM_MAPPED",
-            0x1_000_000 => "MEM_IMAGE",
-            _ => "UNKNOWN",
-        }
+    /// * A [`MemoryType`] describing whether the region is private, mapped or an image.
+    pub fn type_memory(&self) -> MemoryType {
+        self.type_
+    }
+
+    /// Returns the raw `MEM_*` state of the memory region.
+    pub fn raw_state(&self) -> u32 {
+        self.state.bits()
+    }
+
+    /// Returns the raw `PAGE_*` protection of the memory region.
+    pub fn raw_protect(&self) -> u32 {
+        self.protect.bits()
+    }
+
+    /// Returns the raw `PAGE_*` protection applied at the time of allocation.
+    pub fn raw_allocation_protect(&self) -> u32 {
+        self.allocation_protect.bits()
+    }
+
+    /// Returns the raw `MEM_*` type of the memory region.
+    pub fn raw_type(&self) -> u32 {
+        self.type_.bits()
+    }
+
+    /// Returns what the memory region is used for, when it could be attributed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(MemoryOwner::ThreadStack(tid))` - If the region holds the stack of thread `tid`.
+    /// * `None` - If the region could not be attributed.
+    pub fn owner(&self) -> Option<MemoryOwner> {
+        self.owner
     }
 
     /// Returns the starting address of the memory region.
@@ -835,24 +2742,99 @@ impl<'a> Memory<'a> {
         self.len() == 0
     }
 
-    /// Merges two maps of memory regions into a single map.
+    /// Merges the region attributes with the captured bytes into a single map.
+    ///
+    /// Captured ranges inherit the attributes (state, protection, type) of the
+    /// `MemoryInfoListStream` region they start in. The parts of those regions that
+    /// were not captured, common in dumps written without full memory, are kept as
+    /// regions without data, so every address of the process still maps to its attributes.
     ///
     /// # Arguments
     ///
     /// * `memory_info` - Memory regions parsed from the `MemoryInfoListStream`.
-    /// * `memory64` - Memory regions parsed from the `Memory64ListStream`.
+    /// * `captured` - Memory regions parsed from the `Memory64ListStream` or the `MemoryListStream`.
     ///
     /// # Returns
     ///
     /// * `Ok(Memorys<'a>)` - The combined map of memory regions.
     /// * `Err(UserDmpError)` - If merging fails.
-    fn merge_memory(mut memory_info: Memorys<'a>, memory64: Memorys<'a>) -> Result<Memorys<'a>> {
-        // Insert memory64 regions into memory_info.
-        for (address, memory) in memory64 {
-            memory_info.insert(address, memory);
+    fn merge_memory(memory_info: Memorys<'a>, captured: Memorys<'a>) -> Result<Memorys<'a>> {
+        let mut memorys = Memorys::new();
+        for (address, mut memory) in captured {
+            let info = memory_info
+                .range(..=address)
+                .next_back()
+                .map(|(_, info)| info)
+                .filter(|info| info.range.contains(&address));
+
+            if let Some(info) = info {
+                memory.allocation_base = info.allocation_base;
+                memory.allocation_protect = info.allocation_protect;
+                memory.state = info.state;
+                memory.protect = info.protect;
+                memory.type_ = info.type_;
+            }
+
+            memorys.insert(address, memory);
+        }
+
+        for info in memory_info.into_values() {
+            // Collects the parts of the region not covered by a captured range.
+            let mut gaps = Vec::new();
+            let mut cursor = memorys
+                .range(..info.range.start)
+                .next_back()
+                .map_or(info.range.start, |(_, memory)| {
+                    memory
+                        .range
+                        .end
+                        .clamp(info.range.start, info.range.end)
+                });
+
+            for memory in memorys
+                .range(info.range.clone())
+                .map(|(_, memory)| memory)
+            {
+                if memory.range.start > cursor {
+                    gaps.push(cursor..memory.range.start);
+                }
+
+                cursor = cursor.max(memory.range.end);
+            }
+
+            if cursor < info.range.end {
+                gaps.push(cursor..info.range.end);
+            }
+
+            for gap in gaps {
+                memorys.insert(gap.start, Memory { range: gap, ..info.clone() });
+            }
         }
 
-        Ok(memory_info)
+        Ok(memorys)
+    }
+
+    /// Reports the regions of a map that start inside the region preceding them.
+    ///
+    /// # Arguments
+    ///
+    /// * `memorys` - The regions of a memory stream, indexed by their base address.
+    /// * `diagnostics` - Receives the overlapping pairs.
+    fn check_overlaps(memorys: &Memorys<'a>, diagnostics: &mut Vec<Diagnostic>) {
+        let mut previous: Option<&std::ops::Range<u64>> = None;
+        for memory in memorys.values() {
+            if let Some(first) = previous.filter(|first| memory.range.start < first.end) {
+                diagnostics.push(Diagnostic::OverlappingMemory {
+                    first: first.clone(),
+                    second: memory.range.clone(),
+                });
+            }
+
+            // Keeps the region reaching furthest, so nested regions are all reported.
+            if previous.is_none_or(|first| memory.range.end > first.end) {
+                previous = Some(&memory.range);
+            }
+        }
     }
 
     /// Parses memory information from the `MemoryInfoListStream`.
@@ -860,25 +2842,76 @@ impl<'a> Memory<'a> {
     /// # Arguments
     ///
     /// * `cursor` - Cursor positioned at the memory info list stream.
+    /// * `policy` - How regions with an invalid range are handled.
+    /// * `diagnostics` - Receives the regions skipped under [`InvalidRangePolicy::Skip`].
     ///
     /// # Returns
     ///
     /// * `Ok(Memorys<'a>)` - A map of memory regions indexed by their base address.
     /// * `Err(UserDmpError)` - If an error occurs during parsing.
-    fn parser_memory_info(cursor: &mut Cursor<&'a [u8]>) -> Result<Memorys<'a>> {
+    fn parser_memory_info(cursor: &mut Cursor<&'a [u8]>, policy: InvalidRangePolicy, diagnostics: &mut Vec<Diagnostic>) -> Result<Memorys<'a>> {
         // Reads the memory info list stream.
         let memory_info_list = MINIDUMP_MEMORY_INFO_LIST::read(cursor)?;
 
         // Parses each memory region in the list.
-        let memorys = memory_info_list
-            .Entries
-            .iter()
-            .map(|memory| {
-                let memory_block = Memory::new(memory);
+        let mut memorys = Memorys::new();
+        for memory in memory_info_list.Entries.iter() {
+            match Memory::new(memory) {
+                Ok(memory_block) => {
+                    memorys.insert(memory.BaseAddress, memory_block);
+                }
+                Err(UserDmpError::InvalidRange { start, size }) if policy == InvalidRangePolicy::Skip => {
+                    diagnostics.push(Diagnostic::InvalidRange {
+                        stream_type: MemoryInfoListStream as u32,
+                        start,
+                        size,
+                    });
+                }
+                Err(error) => return Err(error),
+            }
+        }
 
-                Ok((memory.BaseAddress, memory_block))
-            })
-            .collect::<Result<Memorys>>()?;
+        Ok(memorys)
+    }
+
+    /// Parses memory information from the `MemoryListStream`.
+    ///
+    /// Dumps written without full memory (e.g., by Task Manager) list their captured
+    /// ranges in this stream, each with its own location in the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the memory list stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Memorys<'a>)` - A map of memory regions indexed by their base address.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parser_memory_list(cursor: &mut Cursor<&'a [u8]>) -> Result<Memorys<'a>> {
+        let memory_list = MINIDUMP_MEMORY_LIST::read(cursor)?;
+
+        let mut memorys = Memorys::new();
+        for descriptor in memory_list.MemoryRanges.iter() {
+            let data = UserDump::extract_raw_data(cursor, descriptor.Memory)?;
+            let start = descriptor.StartOfMemoryRange;
+            let size = data.len() as u64;
+            let end = start
+                .checked_add(size)
+                .ok_or(UserDmpError::InvalidRange { start, size })?;
+
+            let memory = Memory {
+                range: start..end,
+                allocation_base: 0,
+                allocation_protect: MemoryProtection::default(),
+                state: MemoryState::default(),
+                protect: MemoryProtection::default(),
+                type_: MemoryType::default(),
+                data,
+                owner: None,
+            };
+
+            memorys.insert(start, memory);
+        }
 
         Ok(memorys)
     }
@@ -902,35 +2935,34 @@ impl<'a> Memory<'a> {
 
         // Iterate over the memory descriptors in the list.
         for memory_descriptor in memory64_list.Ranges.iter() {
+            let start = memory_descriptor.StartOfMemoryRange;
+            let size = memory_descriptor.DataSize;
             let range = std::ops::Range {
-                start: memory_descriptor.StartOfMemoryRange,
-                end: memory_descriptor.StartOfMemoryRange + memory_descriptor.DataSize,
+                start,
+                end: start
+                    .checked_add(size)
+                    .ok_or(UserDmpError::InvalidRange { start, size })?,
             };
 
-            // Seek to the data for the current memory descriptor.
-            cursor.seek(io::SeekFrom::Start(current_rva))?;
-
-            // Read the memory data.
-            let data = {
-                let data_slice = &cursor.get_ref()[(current_rva as usize)..];
-                &data_slice[..(memory_descriptor.DataSize as usize)]
-            };
+            // Read the memory data, which must lie within the file.
+            let data = UserDump::file_slice(cursor, current_rva, size)?;
 
             // Create a Memory instance.
             let memory = Memory {
                 range,
                 allocation_base: 0,
-                allocation_protect: 0,
-                state: 0,
-                protect: 0,
-                type_: 0,
+                allocation_protect: MemoryProtection::default(),
+                state: MemoryState::default(),
+                protect: MemoryProtection::default(),
+                type_: MemoryType::default(),
                 data,
+                owner: None,
             };
 
             memorys.insert(memory_descriptor.StartOfMemoryRange, memory);
 
             // Update the current RVA for the next memory block.
-            current_rva += memory_descriptor.DataSize;
+            current_rva += size;
         }
 
         Ok(memorys)
@@ -940,6 +2972,7 @@ impl<'a> Memory<'a> {
 /// Represents a handle in a minidump file, providing metadata about its type,
 /// object name, attributes, and granted access rights.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Handle {
     /// The unique identifier (handle value) for this object.
     pub handle: u64,
@@ -955,6 +2988,9 @@ pub struct Handle {
 
     /// The access rights granted to this handle.
     pub granted_access: u32,
+
+    /// The object-specific information blocks, present in `MINIDUMP_HANDLE_DESCRIPTOR_2` entries.
+    object_info: Vec<HandleObjectInfo>,
 }
 
 impl Handle {
@@ -976,6 +3012,7 @@ impl Handle {
             object_name,
             attributes: handle.Attributes,
             granted_access: handle.GrantedAccess,
+            object_info: Vec::new(),
         }
     }
 
@@ -1005,6 +3042,130 @@ impl Handle {
     pub fn object_name(&self) -> Option<&str> {
         self.object_name.as_deref()
     }
+
+    /// Returns the object-specific information blocks of the handle, in chain order.
+    ///
+    /// Only dumps written with `MiniDumpWithHandleData` on systems producing
+    /// `MINIDUMP_HANDLE_DESCRIPTOR_2` entries carry these blocks; the list is empty otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for handle in dump.handles().values() {
+    ///     for info in handle.object_info() {
+    ///         println!("{} {:?} ({} bytes)", handle.handle(), info.info_type, info.data.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn object_info(&self) -> &[HandleObjectInfo] {
+        &self.object_info
+    }
+
+    /// Parses the chain of `MINIDUMP_HANDLE_OBJECT_INFORMATION` blocks starting at an RVA.
+    ///
+    /// The chain stops at the first RVA already visited, so a malformed dump cannot loop forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor over the minidump file.
+    /// * `rva` - The RVA of the first block, as found in `ObjectInfoRva`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<HandleObjectInfo>)` - The blocks of the chain.
+    /// * `Err(UserDmpError)` - If a block lies outside the file.
+    fn parse_object_info(cursor: &mut Cursor<&[u8]>, rva: u32) -> Result<Vec<HandleObjectInfo>> {
+        let mut infos = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next = rva;
+
+        while next != 0 && visited.insert(next) {
+            // Reads the header of the block.
+            cursor.seek(io::SeekFrom::Start(next.into()))?;
+            let header = MINIDUMP_HANDLE_OBJECT_INFORMATION::read(cursor)?;
+
+            // The information immediately follows the header.
+            let data = UserDump::file_slice(cursor, cursor.position(), header.SizeOfInfo.into())?;
+
+            infos.push(HandleObjectInfo {
+                info_type: HandleObjectInfoType::from(header.InfoType),
+                data: data.to_vec(),
+            });
+
+            next = header.NextInfoRva;
+        }
+
+        Ok(infos)
+    }
+}
+
+/// Identifies the layout of a [`HandleObjectInfo`] block (`MINIDUMP_HANDLE_OBJECT_INFORMATION_TYPE`).
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum HandleObjectInfoType {
+    // No object-specific information
+    #[default]
+    None,
+
+    // Thread object information (`MiniThreadInformation1`)
+    Thread,
+
+    // Mutant object information (`MiniMutantInformation1`)
+    Mutant,
+
+    // Mutant owner information (`MiniMutantInformation2`)
+    Mutant2,
+
+    // Process object information (`MiniProcessInformation1`)
+    Process,
+
+    // Extended process object information (`MiniProcessInformation2`)
+    Process2,
+
+    // Event object information (`MiniEventInformation1`)
+    Event,
+
+    // Section object information (`MiniSectionInformation1`)
+    Section,
+
+    // Semaphore object information (`MiniSemaphoreInformation1`)
+    Semaphore,
+
+    // Information type not recognized by this crate, with its raw identifier
+    Unknown(u32),
+}
+
+impl From<u32> for HandleObjectInfoType {
+    fn from(info_type: u32) -> Self {
+        match info_type {
+            MINI_HANDLE_OBJECT_INFORMATION_NONE => HandleObjectInfoType::None,
+            MINI_THREAD_INFORMATION_1 => HandleObjectInfoType::Thread,
+            MINI_MUTANT_INFORMATION_1 => HandleObjectInfoType::Mutant,
+            MINI_MUTANT_INFORMATION_2 => HandleObjectInfoType::Mutant2,
+            MINI_PROCESS_INFORMATION_1 => HandleObjectInfoType::Process,
+            MINI_PROCESS_INFORMATION_2 => HandleObjectInfoType::Process2,
+            MINI_EVENT_INFORMATION_1 => HandleObjectInfoType::Event,
+            MINI_SECTION_INFORMATION_1 => HandleObjectInfoType::Section,
+            MINI_SEMAPHORE_INFORMATION_1 => HandleObjectInfoType::Semaphore,
+            _ => HandleObjectInfoType::Unknown(info_type),
+        }
+    }
+}
+
+/// Represents an object-specific information block attached to a handle.
+///
+/// The layout of `data` depends on `info_type` and on the operating system that wrote the dump.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandleObjectInfo {
+    /// The type of information held in the block.
+    pub info_type: HandleObjectInfoType,
+
+    /// The raw information, following the `MINIDUMP_HANDLE_OBJECT_INFORMATION` header.
+    pub data: Vec<u8>,
 }
 
 impl<'a> MinidumpStream<'a> for Handle {
@@ -1021,50 +3182,53 @@ impl<'a> MinidumpStream<'a> for Handle {
     /// * `Ok(Handles)` - If the handles are parsed successfully.
     /// * `Err(UserDmpError)` - If an error occurs during parsing.
     fn parse(cursor: &mut Cursor<&'a [u8]>) -> Result<Self::Output> {
+        Handle::parse_list(cursor, &mut Vec::new())
+    }
+}
+
+impl Handle {
+    /// Parses the list of handles from the `HandleDataStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the handle list stream.
+    /// * `diagnostics` - Receives the truncated type and object names.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Handles)` - If the handles are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse_list(cursor: &mut Cursor<&[u8]>, diagnostics: &mut Vec<Diagnostic>) -> Result<Handles> {
         // Reads the handle list stream.
         let handle_data = MINIDUMP_HANDLE_DATA_STREAM::read(cursor)?;
 
+        // Reads the type and object names, interleaved.
+        let rvas = handle_data
+            .Handles
+            .iter()
+            .flat_map(|handle| [handle.TypeNameRva, handle.ObjectNameRva])
+            .map(|rva| (rva != 0).then_some(rva.into()))
+            .collect::<Vec<_>>();
+        let mut names = UserDump::read_strings(cursor, &rvas, diagnostics)?.into_iter();
+
         // Parses each handle entry in the list.
         let handles = handle_data
             .Handles
             .iter()
             .map(|handle| {
-                let type_name = if handle.TypeNameRva != 0 {
-                    // Seeks to the type name.
-                    cursor.seek(io::SeekFrom::Start(handle.TypeNameRva.into()))?;
-
-                    // reading the structure MINIDUMP_STRING
-                    let string = MINIDUMP_STRING::read(cursor)?;
-
-                    // Converts the name to UTF-8.
-                    let name = String::from_utf16_lossy(&string.Buffer)
-                        .trim_end_matches('\0')
-                        .to_string();
+                let type_name = names.next().flatten();
+                let object_name = names.next().flatten();
 
-                    Some(name)
+                // Follows the object information chain of MINIDUMP_HANDLE_DESCRIPTOR_2 entries.
+                let object_info = if handle.ObjectInfoRva != 0 {
+                    Handle::parse_object_info(cursor, handle.ObjectInfoRva)?
                 } else {
-                    None
-                };
-
-                let object_name = if handle.ObjectNameRva != 0 {
-                    // Seeks to the object name.
-                    cursor.seek(io::SeekFrom::Start(handle.ObjectNameRva.into()))?;
-
-                    // reading the structure MINIDUMP_STRING
-                    let string = MINIDUMP_STRING::read(cursor)?;
-
-                    // Converts the name to UTF-8.
-                    let name = String::from_utf16_lossy(&string.Buffer)
-                        .trim_end_matches('\0')
-                        .to_string();
-
-                    Some(name)
-                } else {
-                    None
+                    Vec::new()
                 };
 
                 // Creates a new Handle.
-                let handle = Handle::new(type_name, object_name, handle);
+                let mut handle = Handle::new(type_name, object_name, handle);
+                handle.object_info = object_info;
                 Ok((handle.handle, handle))
             })
             .collect::<Result<Handles>>()?;
@@ -1072,3 +3236,266 @@ impl<'a> MinidumpStream<'a> for Handle {
         Ok(handles)
     }
 }
+
+/// Identifies the kind of a [`HandleOperation`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum HandleOperationType {
+    // The handle was opened
+    Open,
+
+    // The handle was closed
+    Close,
+
+    // The handle was referenced while invalid
+    BadReference,
+
+    // Operation not recognized by this crate, with its raw identifier
+    Unknown(u32),
+}
+
+impl From<u32> for HandleOperationType {
+    fn from(operation: u32) -> Self {
+        match operation {
+            OPERATION_DB_OPEN => HandleOperationType::Open,
+            OPERATION_DB_CLOSE => HandleOperationType::Close,
+            OPERATION_DB_BAD_REF => HandleOperationType::BadReference,
+            _ => HandleOperationType::Unknown(operation),
+        }
+    }
+}
+
+/// Represents a handle operation traced by Application Verifier, with the call stack
+/// that performed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandleOperation {
+    /// The handle value the operation applies to.
+    pub handle: u64,
+
+    /// The identifier of the process that performed the operation.
+    pub process_id: u32,
+
+    /// The identifier of the thread that performed the operation.
+    pub thread_id: u32,
+
+    /// The kind of operation.
+    pub operation: HandleOperationType,
+
+    /// The return addresses of the call stack, innermost first.
+    backtrace: Vec<u64>,
+}
+
+impl HandleOperation {
+    /// Returns the return addresses of the call stack that performed the operation, innermost first.
+    ///
+    /// Only the `Depth` recorded entries are returned, clamped to `AVRF_MAX_TRACES`.
+    pub fn backtrace(&self) -> &[u64] {
+        &self.backtrace
+    }
+
+    /// Parses the handle operations from the `HandleOperationListStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the handle operation list stream.
+    /// * `stream_len` - The size of the stream, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<HandleOperation>)` - The operations, in stream order.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse_list(cursor: &mut Cursor<&[u8]>, stream_len: u64) -> Result<Vec<HandleOperation>> {
+        // Reads the handle operation list header.
+        let start = cursor.position();
+        let operation_list = MINIDUMP_HANDLE_OPERATION_LIST::read(cursor)?;
+        let count = UserDump::entry_count(
+            stream_len,
+            operation_list.SizeOfHeader,
+            operation_list.SizeOfEntry,
+            operation_list.NumberOfEntries,
+            size_of::<AVRF_HANDLE_OPERATION>(),
+        )?;
+
+        // Entries are read at their declared stride, which may grow in future versions.
+        let operations = (0..count)
+            .map(|index| {
+                let offset = start + operation_list.SizeOfHeader as u64 + index * operation_list.SizeOfEntry as u64;
+                cursor.seek(io::SeekFrom::Start(offset))?;
+
+                let operation = AVRF_HANDLE_OPERATION::read(cursor)?;
+                Ok(HandleOperation::from(operation))
+            })
+            .collect::<Result<Vec<HandleOperation>>>()?;
+
+        Ok(operations)
+    }
+}
+
+impl From<AVRF_HANDLE_OPERATION> for HandleOperation {
+    /// Converts an `AVRF_HANDLE_OPERATION` structure into a `HandleOperation` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `operation` - An [`AVRF_HANDLE_OPERATION`] instance read from the handle operation list stream.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`HandleOperation`] instance with the valid part of the backtrace.
+    fn from(operation: AVRF_HANDLE_OPERATION) -> Self {
+        let trace = operation.BackTraceInformation;
+        let depth = (trace.Depth as usize).min(AVRF_MAX_TRACES);
+
+        Self {
+            handle: operation.Handle,
+            process_id: operation.ProcessId,
+            thread_id: operation.ThreadId,
+            operation: HandleOperationType::from(operation.OperationType),
+            backtrace: trace.ReturnAddresses[..depth].to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuzz::parse_all;
+
+    /// Size of the `MINIDUMP_HEADER`.
+    const HEADER_SIZE: usize = 32;
+
+    /// Lays out a minidump holding the given streams, in order, right after the directory.
+    fn minidump(streams: &[(MINIDUMP_STREAM_TYPE, Vec<u8>)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&MINIDUMP_SIGNATURE.to_le_bytes());
+        data.extend_from_slice(&0xA793u32.to_le_bytes());
+        data.extend_from_slice(&(streams.len() as u32).to_le_bytes());
+        data.extend_from_slice(&(HEADER_SIZE as u32).to_le_bytes());
+        data.resize(HEADER_SIZE, 0);
+
+        let mut rva = HEADER_SIZE + streams.len() * 12;
+        for (stream_type, bytes) in streams {
+            data.extend_from_slice(&(*stream_type as u32).to_le_bytes());
+            data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            data.extend_from_slice(&(rva as u32).to_le_bytes());
+            rva += bytes.len();
+        }
+
+        for (_, bytes) in streams {
+            data.extend_from_slice(bytes);
+        }
+
+        data
+    }
+
+    /// Builds a `MINIDUMP_MEMORY64_LIST` with a single range.
+    fn memory64_list(base_rva: u64, start: u64, size: u64) -> Vec<u8> {
+        [1, base_rva, start, size]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    /// Builds a `MINIDUMP_SYSTEM_INFO` for an x64 processor.
+    fn system_info() -> Vec<u8> {
+        let mut bytes = vec![0; 56];
+        bytes[..2].copy_from_slice(&9u16.to_le_bytes());
+        bytes
+    }
+
+    /// Builds a `MINIDUMP_THREAD_LIST` with a single thread whose context is at `rva`.
+    fn thread_list(context_size: u32, context_rva: u32) -> Vec<u8> {
+        let mut bytes = vec![0; 4 + 48];
+        bytes[..4].copy_from_slice(&1u32.to_le_bytes());
+        bytes[4..8].copy_from_slice(&0x42u32.to_le_bytes());
+        bytes[44..48].copy_from_slice(&context_size.to_le_bytes());
+        bytes[48..52].copy_from_slice(&context_rva.to_le_bytes());
+        bytes
+    }
+
+    /// Asserts that every parser survives the input, whatever the dump-level result.
+    fn assert_no_panic(data: &[u8]) {
+        assert!(!matches!(parse_all(data), Err(UserDmpError::Panic(_))));
+    }
+
+    #[test]
+    fn memory64_within_file() {
+        let data = minidump(&[(Memory64ListStream, memory64_list(0, 0x1000, 0x10))]);
+        let dump = UserDump::from_bytes(&data).unwrap();
+        assert_eq!(dump.read_bytes(0x1000, 4).unwrap(), b"MDMP");
+    }
+
+    #[test]
+    fn memory64_base_rva_past_end() {
+        let data = minidump(&[(Memory64ListStream, memory64_list(0x10_0000, 0x1000, 0x10))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::TruncatedStream { rva: 0x10_0000, .. })));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn memory64_data_past_end() {
+        let data = minidump(&[(Memory64ListStream, memory64_list(0, 0x1000, 0x10_0000))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::TruncatedStream { size: 0x10_0000, .. })));
+
+        let data = minidump(&[(Memory64ListStream, memory64_list(u64::MAX, 0x1000, 0x10))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::TruncatedStream { .. })));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn memory64_range_overflow() {
+        let data = minidump(&[(Memory64ListStream, memory64_list(0, u64::MAX, 0x10))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::InvalidRange { .. })));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn memory_list_data_past_end() {
+        let mut list = 1u32.to_le_bytes().to_vec();
+        list.extend_from_slice(&0x1000u64.to_le_bytes());
+        list.extend_from_slice(&0x10u32.to_le_bytes());
+        list.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let data = minidump(&[(MemoryListStream, list)]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::TruncatedStream { .. })));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn thread_context_past_end() {
+        let data = minidump(&[(SystemInfoStream, system_info()), (ThreadListStream, thread_list(0x4D0, 0x10_0000))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::TruncatedStream { rva: 0x10_0000, .. })));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn thread_context_too_small() {
+        let data = minidump(&[(SystemInfoStream, system_info()), (ThreadListStream, thread_list(0x10, 0))]);
+        assert!(matches!(UserDump::from_bytes(&data), Err(UserDmpError::InvalidContext)));
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn truncated_directory() {
+        let mut data = minidump(&[(Memory64ListStream, memory64_list(0, 0x1000, 0x10))]);
+        data[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        data.truncate(HEADER_SIZE + 6);
+
+        let dump = UserDump::from_bytes(&data).unwrap();
+        assert!(dump.memorys().is_empty());
+        assert_no_panic(&data);
+    }
+
+    #[test]
+    fn lenient_truncated_stream() {
+        let data = minidump(&[(Memory64ListStream, memory64_list(0, 0x1000, 0x10_0000))]);
+        let options = ParseOptions::new().mode(ParseMode::Lenient);
+        let dump = UserDump::from_bytes_with_options(&data, options).unwrap();
+        assert!(dump.memorys().is_empty());
+        assert!(
+            dump.diagnostics()
+                .iter()
+                .any(|diagnostic| matches!(diagnostic, Diagnostic::MalformedStream { .. }))
+        );
+    }
+}
@@ -0,0 +1,716 @@
+use std::{fmt, io::Cursor, ops::Range};
+use binrw::BinRead;
+use crate::error::UserDmpError;
+use crate::parse::{Memory, Module, Result, UserDump};
+use crate::data::*;
+
+/// The `rebuild` module turns in-memory images back into loadable PE files.
+pub mod rebuild;
+
+/// Maximum number of entries read from each table of an export directory.
+const MAX_EXPORTS: u32 = 0x10000;
+
+/// Maximum number of import descriptors read from an import directory.
+const MAX_IMPORT_DESCRIPTORS: usize = 0x1000;
+
+/// Maximum number of thunks read for a single imported DLL.
+const MAX_IMPORT_THUNKS: u64 = 0x10000;
+
+/// Represents a section of a PE image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The name of the section (e.g., `.text`).
+    pub name: String,
+
+    /// The address of the section when loaded into memory, relative to the image base.
+    pub virtual_address: u32,
+
+    /// The size of the section when loaded into memory, in bytes.
+    pub virtual_size: u32,
+
+    /// The file offset of the section's raw data.
+    pub pointer_to_raw_data: u32,
+
+    /// The size of the section's raw data in the file, in bytes.
+    pub size_of_raw_data: u32,
+
+    /// The characteristics of the section (e.g., `IMAGE_SCN_MEM_EXECUTE`).
+    pub characteristics: u32,
+}
+
+impl Section {
+    /// Returns the range of virtual addresses covered by the section.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base address of the image the section belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * A `Range<u64>` with the virtual addresses of the section.
+    pub fn range(&self, base: u64) -> Range<u64> {
+        let start = base.saturating_add(self.virtual_address.into());
+        start
+            ..start.saturating_add(
+                self.virtual_size
+                    .max(self.size_of_raw_data)
+                    .into(),
+            )
+    }
+
+    /// Returns true if the given relative virtual address falls within the section.
+    pub fn contains_rva(&self, rva: u32) -> bool {
+        (self.virtual_address
+            ..self.virtual_address.saturating_add(
+                self.virtual_size
+                    .max(self.size_of_raw_data),
+            ))
+            .contains(&rva)
+    }
+
+    /// Returns true if the section is marked as executable.
+    pub fn is_executable(&self) -> bool {
+        self.characteristics & 0x2000_0000 != 0
+    }
+
+    /// Returns true if the section is marked as writable.
+    pub fn is_writable(&self) -> bool {
+        self.characteristics & 0x8000_0000 != 0
+    }
+}
+
+impl From<&IMAGE_SECTION_HEADER> for Section {
+    fn from(header: &IMAGE_SECTION_HEADER) -> Self {
+        let len = header
+            .Name
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(header.Name.len());
+        Self {
+            name: String::from_utf8_lossy(&header.Name[..len]).into_owned(),
+            virtual_address: header.VirtualAddress,
+            virtual_size: header.VirtualSize,
+            pointer_to_raw_data: header.PointerToRawData,
+            size_of_raw_data: header.SizeOfRawData,
+            characteristics: header.Characteristics,
+        }
+    }
+}
+
+/// Represents the headers of a PE image loaded in the captured process.
+#[derive(Debug, Clone)]
+pub struct PeHeaders {
+    /// The address the image is loaded at.
+    pub base: u64,
+
+    /// The machine type of the image (e.g., `0x8664` for x64).
+    pub machine: u16,
+
+    /// The link timestamp of the image, in time_t format.
+    pub time_date_stamp: u32,
+
+    /// The characteristics of the image.
+    pub characteristics: u16,
+
+    /// Indicates whether the image uses the 64-bit (PE32+) optional header.
+    pub is_64bit: bool,
+
+    /// The entry point of the image, relative to the image base.
+    pub entry_point: u32,
+
+    /// The preferred base address of the image.
+    pub image_base: u64,
+
+    /// The size of the image when loaded in memory, in bytes.
+    pub size_of_image: u32,
+
+    /// The combined size of all headers, in bytes.
+    pub size_of_headers: u32,
+
+    /// The alignment of sections loaded in memory, in bytes.
+    pub section_alignment: u32,
+
+    /// The alignment of section data in the image file, in bytes.
+    pub file_alignment: u32,
+
+    /// The checksum of the image.
+    pub checksum: u32,
+
+    /// The DLL characteristics of the image (e.g., `IMAGE_DLLCHARACTERISTICS_NX_COMPAT`).
+    pub dll_characteristics: u16,
+
+    /// The data directories of the image.
+    pub data_directories: Vec<IMAGE_DATA_DIRECTORY>,
+
+    /// The sections of the image.
+    pub sections: Vec<Section>,
+}
+
+impl PeHeaders {
+    /// Parses the PE headers from the first bytes of an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The bytes of the image, starting at the MS-DOS header and
+    ///   covering at least the section table.
+    /// * `base` - The address the image is loaded at.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PeHeaders)` - If the headers are parsed successfully.
+    /// * `Err(UserDmpError::InvalidPeHeader)` - If the headers are malformed.
+    pub fn parse(data: &[u8], base: u64) -> Result<Self> {
+        let invalid = |_| UserDmpError::InvalidPeHeader(base);
+        let mut cursor = Cursor::new(data);
+
+        // Reads the MS-DOS header.
+        let dos_header = IMAGE_DOS_HEADER::read(&mut cursor).map_err(invalid)?;
+        if dos_header.e_magic != IMAGE_DOS_SIGNATURE {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        // Reads the NT signature and file header.
+        cursor.set_position(dos_header.e_lfanew.into());
+        let signature = u32::read_le(&mut cursor).map_err(invalid)?;
+        if signature != IMAGE_NT_SIGNATURE {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        let file_header = IMAGE_FILE_HEADER::read(&mut cursor).map_err(invalid)?;
+        let optional_offset = cursor.position();
+
+        // Reads the optional header, whose layout depends on its magic value.
+        let magic = u16::read_le(&mut cursor).map_err(invalid)?;
+        cursor.set_position(optional_offset);
+        let mut headers = match magic {
+            IMAGE_NT_OPTIONAL_HDR64_MAGIC => {
+                let optional = IMAGE_OPTIONAL_HEADER64::read(&mut cursor).map_err(invalid)?;
+                Self {
+                    base,
+                    machine: file_header.Machine,
+                    time_date_stamp: file_header.TimeDateStamp,
+                    characteristics: file_header.Characteristics,
+                    is_64bit: true,
+                    entry_point: optional.AddressOfEntryPoint,
+                    image_base: optional.ImageBase,
+                    size_of_image: optional.SizeOfImage,
+                    size_of_headers: optional.SizeOfHeaders,
+                    section_alignment: optional.SectionAlignment,
+                    file_alignment: optional.FileAlignment,
+                    checksum: optional.CheckSum,
+                    dll_characteristics: optional.DllCharacteristics,
+                    data_directories: optional.DataDirectory[..(optional.NumberOfRvaAndSizes as usize).min(IMAGE_NUMBEROF_DIRECTORY_ENTRIES)]
+                        .to_vec(),
+                    sections: Vec::new(),
+                }
+            }
+            IMAGE_NT_OPTIONAL_HDR32_MAGIC => {
+                let optional = IMAGE_OPTIONAL_HEADER32::read(&mut cursor).map_err(invalid)?;
+                Self {
+                    base,
+                    machine: file_header.Machine,
+                    time_date_stamp: file_header.TimeDateStamp,
+                    characteristics: file_header.Characteristics,
+                    is_64bit: false,
+                    entry_point: optional.AddressOfEntryPoint,
+                    image_base: optional.ImageBase.into(),
+                    size_of_image: optional.SizeOfImage,
+                    size_of_headers: optional.SizeOfHeaders,
+                    section_alignment: optional.SectionAlignment,
+                    file_alignment: optional.FileAlignment,
+                    checksum: optional.CheckSum,
+                    dll_characteristics: optional.DllCharacteristics,
+                    data_directories: optional.DataDirectory[..(optional.NumberOfRvaAndSizes as usize).min(IMAGE_NUMBEROF_DIRECTORY_ENTRIES)]
+                        .to_vec(),
+                    sections: Vec::new(),
+                }
+            }
+            _ => return Err(UserDmpError::InvalidPeHeader(base)),
+        };
+
+        // Reads the section table, which follows the optional header.
+        cursor.set_position(optional_offset + file_header.SizeOfOptionalHeader as u64);
+        headers.sections = (0..file_header.NumberOfSections)
+            .map(|_| IMAGE_SECTION_HEADER::read(&mut cursor).map(|section| Section::from(&section)))
+            .collect::<binrw::BinResult<Vec<Section>>>()
+            .map_err(invalid)?;
+
+        Ok(headers)
+    }
+
+    /// Returns the section containing the given relative virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The address to look up, relative to the image base.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Section)` - The section containing `rva`.
+    /// * `None` - If `rva` does not belong to any section.
+    pub fn section_at(&self, rva: u32) -> Option<&Section> {
+        self.sections
+            .iter()
+            .find(|section| section.contains_rva(rva))
+    }
+
+    /// Returns the data directory at the given index, if present and non-empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the directory (e.g., `IMAGE_DIRECTORY_ENTRY_EXCEPTION`).
+    pub fn data_directory(&self, index: usize) -> Option<IMAGE_DATA_DIRECTORY> {
+        self.data_directories
+            .get(index)
+            .copied()
+            .filter(|directory| directory.VirtualAddress != 0 && directory.Size != 0)
+    }
+}
+
+/// Represents a function or variable exported by a PE image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    /// The exported name, or `None` for exports only reachable by ordinal.
+    pub name: Option<String>,
+
+    /// The ordinal of the export.
+    pub ordinal: u32,
+
+    /// The virtual address of the export in the captured process.
+    pub address: u64,
+
+    /// The target of a forwarded export (e.g., `NTDLL.RtlAllocateHeap`).
+    pub forwarder: Option<String>,
+}
+
+/// Represents a function or variable imported by a PE image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// The name of the DLL the import is requested from (e.g., `KERNEL32.dll`).
+    pub module: String,
+
+    /// The imported name, or `None` for imports by ordinal.
+    pub name: Option<String>,
+
+    /// The ordinal of the import, for imports by ordinal.
+    pub ordinal: Option<u16>,
+
+    /// The address of the import address table slot in the captured process.
+    pub slot: u64,
+
+    /// The address stored in the slot, the function the loader resolved the import to.
+    pub address: u64,
+}
+
+/// Describes which module and sections back a `MEM_IMAGE` memory region.
+#[derive(Debug, Clone)]
+pub struct ImageRegion<'d, 'a> {
+    /// The module owning the region.
+    pub module: &'d Module<'a>,
+
+    /// The names of the sections overlapping the region.
+    ///
+    /// The pseudo-section `headers` is used for the pages holding the PE headers.
+    pub sections: Vec<String>,
+}
+
+impl fmt::Display for ImageRegion<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.module
+                .name()
+                .unwrap_or("<unknown>")
+        )?;
+        for section in &self.sections {
+            write!(f, " {section}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Reads and parses the PE headers of the image loaded at `base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address the image is loaded at (usually a module's start address).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PeHeaders)` - If the headers are present in the dump and well formed.
+    /// * `Err(UserDmpError)` - If the headers were not captured or are malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     if let Ok(headers) = dump.pe_headers(module.start_addr()) {
+    ///         for section in &headers.sections {
+    ///             println!("{} {}", module.name().unwrap_or(""), section.name);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn pe_headers(&self, base: u64) -> Result<PeHeaders> {
+        // Locates the NT headers and the sizes needed to reach the end of the section table.
+        let at = |offset: u64| {
+            base.checked_add(offset)
+                .ok_or(UserDmpError::InvalidPeHeader(base))
+        };
+
+        let nt_offset = self.read_u32(at(0x3C)?)? as u64;
+        let file_header = nt_offset + 4;
+        let number_of_sections = self.read_u16(at(file_header + 2)?)? as u64;
+        let size_of_optional_header = self.read_u16(at(file_header + 16)?)? as u64;
+        let size = file_header + 20 + size_of_optional_header + number_of_sections * 40;
+
+        // Rejects header sizes that cannot belong to a real image.
+        if size > 0x10_0000 {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        let data = self.read_bytes(base, size as usize)?;
+        PeHeaders::parse(&data, base)
+    }
+
+    /// Reads the export table of the image loaded at `base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address the image is loaded at (usually a module's start address).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Export>)` - The exports, in export address table order. Empty if the image exports nothing.
+    /// * `Err(UserDmpError)` - If the headers or the export directory were not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let kernel32 = dump.modules().values().find(|m| m.name() == Some("kernel32.dll")).unwrap();
+    /// for export in dump.exports(kernel32.start_addr()).unwrap() {
+    ///     println!("{:#x} {}", export.address, export.name.as_deref().unwrap_or("<ordinal>"));
+    /// }
+    /// ```
+    pub fn exports(&self, base: u64) -> Result<Vec<Export>> {
+        let headers = self.pe_headers(base)?;
+        let Some(directory) = headers.data_directory(IMAGE_DIRECTORY_ENTRY_EXPORT) else {
+            return Ok(Vec::new());
+        };
+
+        // Every address below is `base` plus a 32-bit RVA, so this check keeps them from overflowing.
+        if u64::MAX - base < u32::MAX as u64 {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        let invalid = |_| UserDmpError::InvalidPeHeader(base);
+        let data = self.read_bytes(base + directory.VirtualAddress as u64, size_of::<IMAGE_EXPORT_DIRECTORY>())?;
+        let export_directory = IMAGE_EXPORT_DIRECTORY::read(&mut Cursor::new(&data)).map_err(invalid)?;
+
+        // Reads the three export tables.
+        let table = |rva: u32, count: u32, width: usize| self.read_bytes(base + rva as u64, count.min(MAX_EXPORTS) as usize * width);
+        let functions = table(export_directory.AddressOfFunctions, export_directory.NumberOfFunctions, 4)?;
+        let names = table(export_directory.AddressOfNames, export_directory.NumberOfNames, 4)?;
+        let ordinals = table(export_directory.AddressOfNameOrdinals, export_directory.NumberOfNames, 2)?;
+
+        let functions = functions
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|rva| u32::from_le_bytes(*rva));
+        let names = names
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|rva| u32::from_le_bytes(*rva));
+        let ordinals = ordinals
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|ordinal| u16::from_le_bytes(*ordinal));
+
+        let mut exports = functions
+            .enumerate()
+            .map(|(index, rva)| {
+                // Exports pointing inside the export directory are forwarder strings.
+                let forwarded = directory
+                    .VirtualAddress
+                    .checked_add(directory.Size)
+                    .is_some_and(|end| (directory.VirtualAddress..end).contains(&rva));
+                Export {
+                    name: None,
+                    ordinal: export_directory
+                        .Base
+                        .wrapping_add(index as u32),
+                    address: base + rva as u64,
+                    forwarder: forwarded
+                        .then(|| self.read_ansi(base + rva as u64))
+                        .flatten(),
+                }
+            })
+            .collect::<Vec<Export>>();
+
+        // Attaches the names through the ordinal table, which indexes the export address table.
+        for (name, index) in names.zip(ordinals) {
+            if let Some(export) = exports.get_mut(index as usize) {
+                export.name = self.read_ansi(base + name as u64);
+            }
+        }
+
+        // Drops the unused slots of the export address table.
+        exports.retain(|export| export.address != base);
+        Ok(exports)
+    }
+
+    /// Reads the import table of the image loaded at `base`, with the addresses the loader resolved.
+    ///
+    /// Descriptors or thunks that were not captured end the walk of their table, so
+    /// the imports read before them are still returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address the image is loaded at (usually a module's start address).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Import>)` - The imports, in import directory order. Empty if the image imports nothing.
+    /// * `Err(UserDmpError)` - If the headers were not captured or are malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let main = dump.modules().values().next().unwrap();
+    /// for import in dump.imports(main.start_addr()).unwrap() {
+    ///     println!("{}!{} -> {:#x}", import.module, import.name.as_deref().unwrap_or("<ordinal>"), import.address);
+    /// }
+    /// ```
+    pub fn imports(&self, base: u64) -> Result<Vec<Import>> {
+        let headers = self.pe_headers(base)?;
+        let Some(directory) = headers.data_directory(IMAGE_DIRECTORY_ENTRY_IMPORT) else {
+            return Ok(Vec::new());
+        };
+
+        let (width, ordinal_flag) = match headers.is_64bit {
+            true => (8, IMAGE_ORDINAL_FLAG64),
+            false => (4, IMAGE_ORDINAL_FLAG32),
+        };
+
+        let thunk = |va: u64| match width {
+            8 => self.read_u64(va),
+            _ => self.read_u32(va).map(u64::from),
+        };
+
+        // Addresses are checked, since the RVAs come from the dump and the image may sit at the top of the address space.
+        let at = |rva: u64| base.checked_add(rva);
+
+        let mut imports = Vec::new();
+        let count = directory.Size as usize / size_of::<IMAGE_IMPORT_DESCRIPTOR>();
+        for index in 0..count.min(MAX_IMPORT_DESCRIPTORS) {
+            let Some(va) = at(directory.VirtualAddress as u64 + (index * size_of::<IMAGE_IMPORT_DESCRIPTOR>()) as u64) else {
+                break;
+            };
+
+            let Ok(data) = self.read_bytes(va, size_of::<IMAGE_IMPORT_DESCRIPTOR>()) else {
+                break;
+            };
+
+            let Ok(descriptor) = IMAGE_IMPORT_DESCRIPTOR::read(&mut Cursor::new(&data)) else {
+                break;
+            };
+
+            // The table ends with a zeroed descriptor.
+            if descriptor.Name == 0 || descriptor.FirstThunk == 0 {
+                break;
+            }
+
+            let module = at(descriptor.Name as u64)
+                .and_then(|va| self.read_ansi(va))
+                .unwrap_or_default();
+
+            // Names come from the lookup table, since the address table was overwritten by the loader.
+            let lookup = match descriptor.OriginalFirstThunk {
+                0 => descriptor.FirstThunk,
+                rva => rva,
+            };
+
+            for index in 0..MAX_IMPORT_THUNKS {
+                let offset = index * width;
+                let (Some(slot), Some(entry_va)) = (at(descriptor.FirstThunk as u64 + offset), at(lookup as u64 + offset)) else {
+                    break;
+                };
+
+                let (Ok(entry), Ok(address)) = (thunk(entry_va), thunk(slot)) else {
+                    break;
+                };
+
+                if entry == 0 {
+                    break;
+                }
+
+                // Without a lookup table the entries are resolved addresses, not names.
+                let (name, ordinal) = if lookup == descriptor.FirstThunk {
+                    (None, None)
+                } else if entry & ordinal_flag != 0 {
+                    (None, Some(entry as u16))
+                } else {
+                    (at((entry & 0x7FFF_FFFF) + 2).and_then(|va| self.read_ansi(va)), None)
+                };
+
+                imports.push(Import {
+                    module: module.clone(),
+                    name,
+                    ordinal,
+                    slot,
+                    address,
+                });
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// Returns the address of the export named `name` in the image loaded at `base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address the image is loaded at.
+    /// * `name` - The exported name, compared case-sensitively.
+    pub fn export_address(&self, base: u64, name: &str) -> Option<u64> {
+        self.exports(base)
+            .ok()?
+            .into_iter()
+            .find(|export| export.name.as_deref() == Some(name))
+            .map(|export| export.address)
+    }
+
+    /// Names an address after the module and the closest export preceding it.
+    ///
+    /// The module is named without its extension, as debuggers do, so an address inside
+    /// `BaseThreadInitThunk` is named `kernel32!BaseThreadInitThunk+0x14`. Addresses with no
+    /// named export before them are given as an offset from the module base (`app.exe+0x1234`).
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to name.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The name of the address.
+    /// * `None` - If the address does not belong to any loaded module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(exception) = dump.exception() {
+    ///     println!("{}", dump.symbolize(exception.address).unwrap_or_default());
+    /// }
+    /// ```
+    pub fn symbolize(&self, va: u64) -> Option<String> {
+        let module = self.module_at(va)?;
+        let name = module.name().unwrap_or("<unknown>");
+        let export = self
+            .exports(module.start_addr())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|export| export.address <= va && export.forwarder.is_none())
+            .filter_map(|export| Some((export.name?, export.address)))
+            .max_by_key(|(_, address)| *address);
+
+        Some(match export {
+            Some((symbol, address)) => {
+                let stem = name
+                    .rsplit_once('.')
+                    .map_or(name, |(stem, _)| stem);
+                match va - address {
+                    0 => format!("{stem}!{symbol}"),
+                    offset => format!("{stem}!{symbol}+{offset:#x}"),
+                }
+            }
+            None => format!("{name}+{:#x}", va - module.start_addr()),
+        })
+    }
+
+    /// Reads a NUL-terminated ANSI string of at most 512 bytes, as found in export tables.
+    fn read_ansi(&self, va: u64) -> Option<String> {
+        match self
+            .read_terminated::<1>(va, 512)
+            .ok()?
+        {
+            (bytes, true) => Some(String::from_utf8_lossy(&bytes).into_owned()),
+            (_, false) => None,
+        }
+    }
+
+    /// Maps a `MEM_IMAGE` memory region back to the module and PE sections that own it.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The memory region to annotate.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ImageRegion)` - The owning module and the sections overlapping the region.
+    /// * `None` - If the region is not an image region or no module contains it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for memory in dump.memorys().values() {
+    ///     match dump.annotate_image_region(memory) {
+    ///         Some(image) => println!("{:#x} {}", memory.start_addr(), image),
+    ///         None => println!("{:#x} {}", memory.start_addr(), memory.type_memory()),
+    ///     }
+    /// }
+    /// ```
+    pub fn annotate_image_region(&self, region: &Memory) -> Option<ImageRegion<'_, 'a>> {
+        if !region.type_.is_image() {
+            return None;
+        }
+
+        let module = self.module_at(region.range.start)?;
+
+        // Section names are best effort: the headers may not have been captured.
+        let sections = match self.pe_headers(module.start_addr()) {
+            Ok(headers) => {
+                let mut sections = Vec::new();
+                if region.range.start < module.start_addr() + headers.size_of_headers as u64 {
+                    sections.push(String::from("headers"));
+                }
+
+                sections.extend(
+                    headers
+                        .sections
+                        .iter()
+                        .filter(|section| {
+                            let range = section.range(module.start_addr());
+                            range.start < region.range.end && region.range.start < range.end
+                        })
+                        .map(|section| section.name.clone()),
+                );
+
+                sections
+            }
+            Err(_) => Vec::new(),
+        };
+
+        Some(ImageRegion { module, sections })
+    }
+}
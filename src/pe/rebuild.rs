@@ -0,0 +1,218 @@
+use std::{fs, ops::Range, path::Path};
+use crate::data::*;
+use crate::error::UserDmpError;
+use crate::options::UnmappedReadPolicy;
+use crate::parse::{Result, UserDump};
+
+/// Largest `SizeOfImage` accepted when rebuilding an image.
+const MAX_IMAGE_SIZE: u32 = 0x4000_0000;
+
+/// File alignment used when the headers declare an invalid one.
+const DEFAULT_FILE_ALIGNMENT: u32 = 0x200;
+
+/// Size of an `IMAGE_SECTION_HEADER`.
+const SECTION_HEADER_SIZE: usize = 40;
+
+/// Options that control how an in-memory image is rebuilt into a PE file.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, pe::rebuild::RebuildOptions};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let module = dump.module_by_name("payload.dll").unwrap();
+/// let options = RebuildOptions::new().neutralize_relocations(true);
+/// dump.rebuild_pe(module.start_addr(), &options).unwrap().save("payload.dll").unwrap();
+/// ```
+#[derive(Copy, Debug, Clone, Default)]
+pub struct RebuildOptions {
+    /// Strips the base relocations so the image only loads at the address it was captured at.
+    pub neutralize_relocations: bool,
+}
+
+impl RebuildOptions {
+    /// Creates a new [`RebuildOptions`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the base relocations are stripped from the rebuilt image.
+    ///
+    /// The captured image already has its relocations applied for the address it was
+    /// loaded at. Stripping them (and clearing `DYNAMIC_BASE`) keeps loaders and
+    /// disassemblers from applying them a second time.
+    ///
+    /// # Arguments
+    ///
+    /// * `neutralize` - Whether the relocations are stripped.
+    pub fn neutralize_relocations(mut self, neutralize: bool) -> Self {
+        self.neutralize_relocations = neutralize;
+        self
+    }
+}
+
+/// A PE file rebuilt from an image captured in the dump.
+#[derive(Debug, Clone)]
+pub struct RebuiltPe {
+    /// The contents of the PE file.
+    pub data: Vec<u8>,
+
+    /// The virtual address ranges of the image that were missing from the dump and zero-filled.
+    pub gaps: Vec<Range<u64>>,
+}
+
+impl RebuiltPe {
+    /// Returns true if every byte of the image was present in the dump.
+    pub fn is_complete(&self) -> bool {
+        self.gaps.is_empty()
+    }
+
+    /// Writes the PE file to disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, &self.data)?;
+        Ok(())
+    }
+}
+
+impl UserDump<'_> {
+    /// Rebuilds a loadable PE file from the image mapped at `base`.
+    ///
+    /// The headers are parsed from the dump, each section is moved from its virtual
+    /// offset to a raw offset aligned on `FileAlignment`, and the section table is
+    /// updated to match. `ImageBase` is set to `base`, since the captured code already
+    /// has its relocations applied for that address.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address the image is mapped at (a module base or a manually mapped image).
+    /// * `options` - The options controlling the rebuild.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RebuiltPe)` - The rebuilt file. Pages missing from the dump are zero-filled.
+    /// * `Err(UserDmpError)` - If the headers were not captured or are malformed.
+    pub fn rebuild_pe(&self, base: u64, options: &RebuildOptions) -> Result<RebuiltPe> {
+        let headers = self.pe_headers(base)?;
+        if headers.size_of_image == 0 || headers.size_of_image > MAX_IMAGE_SIZE {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        let image = self.read_range_with(base, headers.size_of_image as usize, UnmappedReadPolicy::ZeroFill)?;
+        let image_len = image.data.len() as u32;
+        let alignment = match headers.file_alignment {
+            alignment if alignment.is_power_of_two() => alignment,
+            _ => DEFAULT_FILE_ALIGNMENT,
+        };
+
+        // The headers keep their offset, padded to the file alignment.
+        let size_of_headers = headers.size_of_headers.min(image_len);
+        let mut data = image.data[..size_of_headers as usize].to_vec();
+        data.resize(align_up(size_of_headers, alignment) as usize, 0);
+
+        // Sections are laid out one after the other, in section table order.
+        let mut raw = Vec::with_capacity(headers.sections.len());
+        for section in &headers.sections {
+            let start = section.virtual_address.min(image_len);
+            let size = match section.virtual_size {
+                0 => section.size_of_raw_data,
+                size => size,
+            };
+
+            let end = start
+                .saturating_add(size)
+                .min(image_len);
+            let pointer = data.len() as u32;
+            let size_of_raw_data = align_up(end - start, alignment);
+
+            data.extend_from_slice(&image.data[start as usize..end as usize]);
+            data.resize((pointer + size_of_raw_data) as usize, 0);
+            raw.push((pointer, size_of_raw_data));
+        }
+
+        // Locates the headers to patch in the output.
+        let nt_headers = read_u32(&data, 0x3C).ok_or(UserDmpError::InvalidPeHeader(base))? as usize;
+        let file_header = nt_headers + 4;
+        let optional_header = file_header + 20;
+        let size_of_optional_header = read_u16(&data, file_header + 16).ok_or(UserDmpError::InvalidPeHeader(base))? as usize;
+        let section_table = optional_header + size_of_optional_header;
+        if section_table + raw.len() * SECTION_HEADER_SIZE > size_of_headers as usize {
+            return Err(UserDmpError::InvalidPeHeader(base));
+        }
+
+        for (index, (pointer, size)) in raw.into_iter().enumerate() {
+            let entry = section_table + index * SECTION_HEADER_SIZE;
+            write_u32(&mut data, entry + 16, size);
+            write_u32(&mut data, entry + 20, pointer);
+        }
+
+        // The data directories follow the Windows-specific fields of the optional header.
+        let directories = if headers.is_64bit {
+            write_u64(&mut data, optional_header + 24, base);
+            optional_header + 112
+        } else {
+            write_u32(&mut data, optional_header + 28, base as u32);
+            optional_header + 96
+        };
+
+        if options.neutralize_relocations {
+            let characteristics = headers.characteristics | IMAGE_FILE_RELOCS_STRIPPED;
+            write_u16(&mut data, file_header + 18, characteristics);
+
+            let dll_characteristics = optional_header + 70;
+            if let Some(flags) = read_u16(&data, dll_characteristics) {
+                write_u16(&mut data, dll_characteristics, flags & !IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE);
+            }
+
+            if headers.data_directories.len() > IMAGE_DIRECTORY_ENTRY_BASERELOC {
+                let entry = directories + IMAGE_DIRECTORY_ENTRY_BASERELOC * 8;
+                write_u32(&mut data, entry, 0);
+                write_u32(&mut data, entry + 4, 0);
+            }
+        }
+
+        Ok(RebuiltPe { data, gaps: image.gaps })
+    }
+}
+
+/// Rounds `value` up to a multiple of `alignment`, which must be a power of two.
+fn align_up(value: u32, alignment: u32) -> u32 {
+    value.saturating_add(alignment - 1) & !(alignment - 1)
+}
+
+/// Reads a little-endian `u16` from a buffer.
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a little-endian `u32` from a buffer.
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Writes a little-endian `u16` into a buffer, ignoring writes past its end.
+fn write_u16(data: &mut [u8], offset: usize, value: u16) {
+    if let Some(bytes) = data.get_mut(offset..offset + 2) {
+        bytes.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Writes a little-endian `u32` into a buffer, ignoring writes past its end.
+fn write_u32(data: &mut [u8], offset: usize, value: u32) {
+    if let Some(bytes) = data.get_mut(offset..offset + 4) {
+        bytes.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Writes a little-endian `u64` into a buffer, ignoring writes past its end.
+fn write_u64(data: &mut [u8], offset: usize, value: u64) {
+    if let Some(bytes) = data.get_mut(offset..offset + 8) {
+        bytes.copy_from_slice(&value.to_le_bytes());
+    }
+}
@@ -0,0 +1,784 @@
+use std::{collections::HashSet, fmt};
+use crate::offsets::StructOffsets;
+use crate::parse::{Arch, UserDump};
+use crate::pointer::{is_aligned, looks_like_pointer};
+use crate::reader::{MAX_STRING_LEN, decode_utf16, offset_va};
+
+/// Distance between the 64-bit TEB of a WOW64 thread and its 32-bit TEB.
+const WOW64_TEB32_OFFSET: u64 = 0x2000;
+
+/// Upper bound on the size of an environment block, to survive a corrupted `EnvironmentSize`.
+const MAX_ENVIRONMENT_SIZE: u64 = 0x10_0000;
+
+/// Upper bound on the number of loader entries followed, to survive corrupted lists.
+const MAX_LOADER_ENTRIES: usize = 0x1_0000;
+
+/// Identifies one of the three module lists of `PEB_LDR_DATA`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoaderList {
+    /// `InLoadOrderModuleList`, in the order the modules were loaded.
+    LoadOrder,
+
+    /// `InMemoryOrderModuleList`, in the order of their base addresses.
+    MemoryOrder,
+
+    /// `InInitializationOrderModuleList`, in the order their entry points ran.
+    InitializationOrder,
+}
+
+/// Represents a module linked in the loader lists of the PEB (`LDR_DATA_TABLE_ENTRY`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoaderModule {
+    /// The address of the `LDR_DATA_TABLE_ENTRY`.
+    pub entry: u64,
+
+    /// The base address of the module (`DllBase`).
+    pub base: u64,
+
+    /// The size of the image, in bytes.
+    pub size: u32,
+
+    /// The address of the entry point of the module, or zero.
+    pub entry_point: u64,
+
+    /// The full path of the module (`FullDllName`), when captured.
+    pub full_name: Option<String>,
+
+    /// The file name of the module (`BaseDllName`), when captured.
+    pub base_name: Option<String>,
+
+    /// Indicates whether the entry comes from the 32-bit loader of a WOW64 process.
+    pub wow64: bool,
+}
+
+/// Describes a module found in only one of the `ModuleListStream` and the PEB loader list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleListDiff {
+    /// The module is in the `ModuleListStream` but not linked in the loader list,
+    /// which is how DLLs hide themselves by unlinking their `LDR_DATA_TABLE_ENTRY`.
+    NotInLoader {
+        /// The base address of the module.
+        base: u64,
+
+        /// The file name of the module, as recorded in the stream.
+        name: Option<String>,
+    },
+
+    /// The module is linked in the loader list but missing from the `ModuleListStream`.
+    NotInStream {
+        /// The base address of the module.
+        base: u64,
+
+        /// The file name of the module, as recorded in the loader entry.
+        name: Option<String>,
+    },
+}
+
+impl fmt::Display for ModuleListDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModuleListDiff::NotInLoader { base, name } => {
+                write!(f, "{} at {base:#x} is not linked in the loader list", name.as_deref().unwrap_or("module"))
+            }
+            ModuleListDiff::NotInStream { base, name } => {
+                write!(f, "{} at {base:#x} is missing from the module list stream", name.as_deref().unwrap_or("module"))
+            }
+        }
+    }
+}
+
+/// The Terminal Services session of a process, with its window station and desktop.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInfo {
+    /// The identifier of the session, `0` for the session hosting the services.
+    pub session_id: Option<u32>,
+
+    /// The name of the window station (e.g., `WinSta0` or `Service-0x0-3e7$`).
+    pub window_station: Option<String>,
+
+    /// The name of the desktop (e.g., `Default` or `Winlogon`).
+    pub desktop: Option<String>,
+}
+
+impl SessionInfo {
+    /// Returns true if the process runs in session 0, which hosts the services.
+    pub fn is_service_session(&self) -> bool {
+        self.session_id == Some(0)
+    }
+
+    /// Returns true if the process is attached to `WinSta0`, the only window station
+    /// that can interact with the user.
+    pub fn is_interactive(&self) -> bool {
+        self.window_station
+            .as_deref()
+            .is_some_and(|station| station.eq_ignore_ascii_case("WinSta0"))
+    }
+
+    /// Completes the missing fields with those of `other`.
+    fn or(self, other: SessionInfo) -> SessionInfo {
+        SessionInfo {
+            session_id: self.session_id.or(other.session_id),
+            window_station: self
+                .window_station
+                .or(other.window_station),
+            desktop: self.desktop.or(other.desktop),
+        }
+    }
+}
+
+impl fmt::Display for SessionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.session_id {
+            Some(session_id) => write!(f, "session {session_id}")?,
+            None => f.write_str("session ?")?,
+        }
+
+        write!(
+            f,
+            " {}\\{}",
+            self.window_station
+                .as_deref()
+                .unwrap_or("?"),
+            self.desktop.as_deref().unwrap_or("?")
+        )
+    }
+}
+
+/// Gives access to the Process Environment Block of the captured process and to its
+/// `RTL_USER_PROCESS_PARAMETERS`.
+///
+/// For WOW64 processes the native 64-bit PEB is used, and the 32-bit PEB serves as a
+/// fallback when the 64-bit process parameters were not captured.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::UserDump;
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// if let Some(process) = dump.process() {
+///     println!("Command line: {}", process.command_line().unwrap_or_default());
+///     println!("Image path: {}", process.image_path().unwrap_or_default());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Process<'d, 'a> {
+    /// The dump the process was captured in.
+    dump: &'d UserDump<'a>,
+
+    /// The address of the native PEB.
+    peb: u64,
+
+    /// The layout of the native process structures.
+    offsets: StructOffsets,
+
+    /// The address and layout of the 32-bit PEB, for WOW64 processes.
+    peb32: Option<(u64, StructOffsets)>,
+}
+
+impl<'d, 'a> Process<'d, 'a> {
+    /// Returns the address of the native PEB.
+    pub fn peb(&self) -> u64 {
+        self.peb
+    }
+
+    /// Returns the address of the 32-bit PEB of a WOW64 process.
+    pub fn peb32(&self) -> Option<u64> {
+        self.peb32.map(|(peb, _)| peb)
+    }
+
+    /// Returns true if the process is a 32-bit process running under WOW64.
+    pub fn is_wow64(&self) -> bool {
+        self.peb32.is_some()
+    }
+
+    /// Returns `PEB.BeingDebugged`.
+    pub fn being_debugged(&self) -> Option<bool> {
+        offset_va(self.peb, self.offsets.peb.being_debugged as u64)
+            .and_then(|va| self.dump.read_u8(va))
+            .ok()
+            .map(|value| value != 0)
+    }
+
+    /// Returns `PEB.ImageBaseAddress`, the address the main executable is loaded at.
+    pub fn image_base(&self) -> Option<u64> {
+        offset_va(self.peb, self.offsets.peb.image_base_address as u64)
+            .and_then(|va| self.dump.read_ptr(va))
+            .ok()
+    }
+
+    /// Returns `PEB.SessionId`, the Terminal Services session of the process.
+    pub fn session_id(&self) -> Option<u32> {
+        offset_va(self.peb, self.offsets.peb.session_id as u64)
+            .and_then(|va| self.dump.read_u32(va))
+            .ok()
+    }
+
+    /// Returns `PEB.AnsiCodePageData`, the address of the NLS table of the ANSI code page.
+    pub fn ansi_code_page_data(&self) -> Option<u64> {
+        self.peb_pointer(|offsets| offsets.peb.ansi_code_page_data)
+    }
+
+    /// Returns `PEB.ActivationContextData`, the activation context built from the
+    /// manifest of the executable.
+    pub fn activation_context_data(&self) -> Option<u64> {
+        self.peb_pointer(|offsets| offsets.peb.activation_context_data)
+    }
+
+    /// Returns `PEB.SystemDefaultActivationContextData`, the activation context of the
+    /// assemblies every process of the system binds to.
+    pub fn system_default_activation_context_data(&self) -> Option<u64> {
+        self.peb_pointer(|offsets| {
+            offsets
+                .peb
+                .system_default_activation_context_data
+        })
+    }
+
+    /// Returns the address of the native `RTL_USER_PROCESS_PARAMETERS`.
+    pub fn process_parameters(&self) -> Option<u64> {
+        offset_va(self.peb, self.offsets.peb.process_parameters as u64)
+            .and_then(|va| self.dump.read_ptr(va))
+            .ok()
+            .filter(|&parameters| parameters != 0)
+    }
+
+    /// Returns the command line of the process.
+    pub fn command_line(&self) -> Option<String> {
+        self.parameter_string(|offsets| offsets.process_parameters.command_line)
+    }
+
+    /// Returns the full path of the main executable.
+    pub fn image_path(&self) -> Option<String> {
+        self.parameter_string(|offsets| {
+            offsets
+                .process_parameters
+                .image_path_name
+        })
+    }
+
+    /// Returns the current directory of the process.
+    pub fn current_directory(&self) -> Option<String> {
+        self.parameter_string(|offsets| {
+            offsets
+                .process_parameters
+                .current_directory
+        })
+    }
+
+    /// Returns the window title passed to the process at creation.
+    pub fn window_title(&self) -> Option<String> {
+        self.parameter_string(|offsets| offsets.process_parameters.window_title)
+    }
+
+    /// Returns the DLL search path of the process.
+    pub fn dll_path(&self) -> Option<String> {
+        self.parameter_string(|offsets| offsets.process_parameters.dll_path)
+    }
+
+    /// Returns the window station and desktop of the process (e.g., `WinSta0\Default`).
+    pub fn desktop_info(&self) -> Option<String> {
+        self.parameter_string(|offsets| offsets.process_parameters.desktop_info)
+    }
+
+    /// Returns the window station of the process, the part of
+    /// [`Process::desktop_info`] before the backslash.
+    pub fn window_station(&self) -> Option<String> {
+        let info = self.desktop_info()?;
+        let (station, _) = info.split_once('\\')?;
+        (!station.is_empty()).then(|| station.to_string())
+    }
+
+    /// Returns the desktop of the process, the part of [`Process::desktop_info`] after
+    /// the backslash, or all of it when it names no window station.
+    pub fn desktop(&self) -> Option<String> {
+        let info = self.desktop_info()?;
+        let desktop = info
+            .split_once('\\')
+            .map_or(info.as_str(), |(_, desktop)| desktop);
+        (!desktop.is_empty()).then(|| desktop.to_string())
+    }
+
+    /// Returns the session, window station and desktop of the process.
+    ///
+    /// The values come from the PEB and the process parameters. Those that were not
+    /// captured are recovered from the names of the window station, desktop and
+    /// session-scoped object handles, when the dump holds the handle list.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(process) = dump.process() {
+    ///     let session = process.session();
+    ///     if session.is_service_session() {
+    ///         println!("service process ({session})");
+    ///     }
+    /// }
+    /// ```
+    pub fn session(&self) -> SessionInfo {
+        let parameters = SessionInfo {
+            session_id: self.session_id(),
+            window_station: self.window_station(),
+            desktop: self.desktop(),
+        };
+
+        parameters.or(self.dump.session_from_handles())
+    }
+
+    /// Walks one of the module lists of `PEB.Ldr`.
+    ///
+    /// For WOW64 processes the list of the 32-bit loader follows the list of the native
+    /// loader, which only holds the 64-bit modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list to walk.
+    ///
+    /// # Returns
+    ///
+    /// * The modules in list order. The walk stops at the first entry missing from the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, peb::LoaderList};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(process) = dump.process() {
+    ///     for module in process.loader_modules(LoaderList::LoadOrder) {
+    ///         println!("{:#x} {}", module.base, module.full_name.unwrap_or_default());
+    ///     }
+    /// }
+    /// ```
+    pub fn loader_modules(&self, list: LoaderList) -> Vec<LoaderModule> {
+        let mut modules = self.walk_loader(self.peb, &self.offsets, self.dump.pointer_size() as u64, list, false);
+        if let Some((peb, offsets)) = &self.peb32 {
+            modules.extend(self.walk_loader(*peb, offsets, 4, list, true));
+        }
+
+        modules
+    }
+
+    /// Walks a loader list of the PEB at `peb`, whose pointers are `pointer` bytes wide.
+    fn walk_loader(&self, peb: u64, offsets: &StructOffsets, pointer: u64, list: LoaderList, wow64: bool) -> Vec<LoaderModule> {
+        let read_ptr = |va: u64| match pointer {
+            4 => self.dump.read_u32(va).map(u64::from),
+            _ => self.dump.read_u64(va),
+        };
+
+        // Fields are read through checked addresses, since the links come from the dump.
+        let read_field = |va: u64, offset: u32| offset_va(va, offset as u64).and_then(read_ptr);
+
+        let ldr = &offsets.ldr;
+        let head_offset = match list {
+            LoaderList::LoadOrder => ldr.in_load_order_module_list,
+            LoaderList::MemoryOrder => ldr.in_memory_order_module_list,
+            LoaderList::InitializationOrder => ldr.in_initialization_order_module_list,
+        };
+
+        // The links of an entry are laid out like the list heads of `PEB_LDR_DATA`.
+        let link_offset = (head_offset - ldr.in_load_order_module_list) as u64;
+        let Ok(data) = read_field(peb, offsets.peb.ldr) else {
+            return Vec::new();
+        };
+
+        let Ok(head) = offset_va(data, head_offset as u64) else {
+            return Vec::new();
+        };
+
+        let mut modules = Vec::new();
+        let mut seen = HashSet::new();
+        let mut link = read_ptr(head).unwrap_or(head);
+        while link != head
+            && is_aligned(link, pointer)
+            && looks_like_pointer(link, self.dump)
+            && seen.insert(link)
+            && modules.len() < MAX_LOADER_ENTRIES
+        {
+            let Some(entry) = link.checked_sub(link_offset) else {
+                break;
+            };
+
+            let Ok(base) = read_field(entry, ldr.dll_base) else {
+                break;
+            };
+
+            modules.push(LoaderModule {
+                entry,
+                base,
+                size: offset_va(entry, ldr.size_of_image as u64)
+                    .and_then(|va| self.dump.read_u32(va))
+                    .unwrap_or(0),
+                entry_point: read_field(entry, ldr.entry_point).unwrap_or(0),
+                full_name: offset_va(entry, ldr.full_dll_name as u64)
+                    .ok()
+                    .and_then(|va| self.unicode_string(va, pointer)),
+                base_name: offset_va(entry, ldr.base_dll_name as u64)
+                    .ok()
+                    .and_then(|va| self.unicode_string(va, pointer)),
+                wow64,
+            });
+
+            let Ok(next) = read_ptr(link) else {
+                break;
+            };
+
+            link = next;
+        }
+
+        modules
+    }
+
+    /// Reads a `UNICODE_STRING` whose buffer pointer is `pointer` bytes wide.
+    fn unicode_string(&self, va: u64, pointer: u64) -> Option<String> {
+        if pointer as usize == self.dump.pointer_size() {
+            return self.dump.read_unicode_string(va).ok();
+        }
+
+        // `UNICODE_STRING32`: Length, MaximumLength and a 32-bit Buffer pointer.
+        let length = self.dump.read_u16(va).ok()? as usize & !1;
+        let buffer = self
+            .dump
+            .read_u32(offset_va(va, 4).ok()?)
+            .ok()? as u64;
+        Some(decode_utf16(
+            &self
+                .dump
+                .read_bytes(buffer, length)
+                .ok()?,
+        ))
+    }
+
+    /// Parses the environment block of the process.
+    ///
+    /// Entries are yielded in block order. Entries that do not contain `=` are skipped,
+    /// while the per-drive current directories (e.g., `=C:=C:\Windows`) are kept with
+    /// their leading `=` in the name. Parsing stops at the end of the block or at the
+    /// first page missing from the dump.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over `(name, value)` pairs, empty if the block was not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(process) = dump.process() {
+    ///     for (name, value) in process.environment() {
+    ///         println!("{name}={value}");
+    ///     }
+    /// }
+    /// ```
+    pub fn environment(&self) -> impl Iterator<Item = (String, String)> + use<> {
+        let mut variables = Vec::new();
+        if let Some((mut address, size)) = self.environment_block() {
+            let end = address.saturating_add(size.min(MAX_ENVIRONMENT_SIZE));
+            while address < end {
+                let Ok((bytes, _)) = self
+                    .dump
+                    .read_terminated::<2>(address, MAX_STRING_LEN)
+                else {
+                    break;
+                };
+
+                if bytes.is_empty() {
+                    break;
+                }
+
+                let Ok(next) = offset_va(address, bytes.len() as u64 + 2) else {
+                    break;
+                };
+
+                address = next;
+                let entry = decode_utf16(&bytes);
+
+                // The name of the per-drive directories starts with `=`, so the separator is searched after it.
+                if let Some(separator) = entry
+                    .get(1..)
+                    .and_then(|rest| rest.find('='))
+                {
+                    let (name, value) = entry.split_at(separator + 1);
+                    variables.push((name.to_string(), value[1..].to_string()));
+                }
+            }
+        }
+
+        variables.into_iter()
+    }
+
+    /// Looks up an environment variable, ignoring case like Windows does.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable (e.g., `PATH`).
+    pub fn environment_variable(&self, name: &str) -> Option<String> {
+        self.environment()
+            .find(|(variable, _)| variable.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the address and size of the environment block, falling back to the
+    /// 32-bit process parameters of WOW64 processes.
+    ///
+    /// The size is `MAX_ENVIRONMENT_SIZE` when `EnvironmentSize` is not available.
+    fn environment_block(&self) -> Option<(u64, u64)> {
+        let size = |value: u64| if value == 0 { MAX_ENVIRONMENT_SIZE } else { value };
+        let native = self
+            .process_parameters()
+            .and_then(|parameters| {
+                let fields = &self.offsets.process_parameters;
+                let block = offset_va(parameters, fields.environment as u64)
+                    .and_then(|va| self.dump.read_ptr(va))
+                    .ok()?;
+                let length = offset_va(parameters, fields.environment_size as u64)
+                    .and_then(|va| self.dump.read_ptr(va))
+                    .unwrap_or(0);
+                (block != 0).then(|| (block, size(length)))
+            });
+
+        native.or_else(|| {
+            let (peb, offsets) = self.peb32?;
+            let fields = &offsets.process_parameters;
+            let parameters = offset_va(peb, offsets.peb.process_parameters as u64)
+                .and_then(|va| self.dump.read_u32(va))
+                .ok()? as u64;
+            let block = offset_va(parameters, fields.environment as u64)
+                .and_then(|va| self.dump.read_u32(va))
+                .ok()? as u64;
+            let length = offset_va(parameters, fields.environment_size as u64)
+                .and_then(|va| self.dump.read_u32(va))
+                .unwrap_or(0) as u64;
+            (block != 0).then(|| (block, size(length)))
+        })
+    }
+
+    /// Reads a non-null pointer of the PEB, falling back to the 32-bit PEB of WOW64 processes.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Selects the offset of the pointer in the PEB.
+    fn peb_pointer(&self, field: impl Fn(&StructOffsets) -> u32) -> Option<u64> {
+        let native = offset_va(self.peb, field(&self.offsets) as u64)
+            .and_then(|va| self.dump.read_ptr(va))
+            .ok()
+            .filter(|&pointer| pointer != 0);
+
+        native.or_else(|| {
+            let (peb, offsets) = self.peb32?;
+            offset_va(peb, field(&offsets) as u64)
+                .and_then(|va| self.dump.read_u32(va))
+                .ok()
+                .filter(|&pointer| pointer != 0)
+                .map(u64::from)
+        })
+    }
+
+    /// Reads a `UNICODE_STRING` of the process parameters, falling back to the 32-bit
+    /// parameters of WOW64 processes.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Selects the offset of the string in `RTL_USER_PROCESS_PARAMETERS`.
+    fn parameter_string(&self, field: impl Fn(&StructOffsets) -> u32) -> Option<String> {
+        let native = self
+            .process_parameters()
+            .and_then(|parameters| {
+                offset_va(parameters, field(&self.offsets) as u64)
+                    .and_then(|va| self.dump.read_unicode_string(va))
+                    .ok()
+            });
+
+        native.or_else(|| {
+            let (peb, offsets) = self.peb32?;
+            let parameters = offset_va(peb, offsets.peb.process_parameters as u64)
+                .and_then(|va| self.dump.read_u32(va))
+                .ok()? as u64;
+            self.unicode_string(offset_va(parameters, field(&offsets) as u64).ok()?, 4)
+        })
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Locates the Process Environment Block of the captured process.
+    ///
+    /// The PEB is found through the TEB of the first thread whose TEB was captured.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Process)` - If the PEB address could be read and the structure layout is known.
+    /// * `None` - If no TEB is present in the dump or the architecture is not supported.
+    pub fn process(&self) -> Option<Process<'_, 'a>> {
+        let offsets = self.struct_offsets()?;
+        let peb = self.peb_address()?;
+        Some(Process {
+            dump: self,
+            peb,
+            offsets,
+            peb32: self.peb32(),
+        })
+    }
+
+    /// Returns the session, window station and desktop of the captured process.
+    ///
+    /// Uses [`Process::session`] when the PEB was captured, and the names of the
+    /// handles of the process otherwise.
+    pub fn session(&self) -> SessionInfo {
+        match self.process() {
+            Some(process) => process.session(),
+            None => self.session_from_handles(),
+        }
+    }
+
+    /// Recovers the session, window station and desktop from the names of the handles.
+    ///
+    /// Window stations are named `\Sessions\<id>\Windows\WindowStations\<name>`, or
+    /// `\Windows\WindowStations\<name>` in session 0, desktops are named `\<name>`, and
+    /// other objects of a session live under `\Sessions\<id>`.
+    fn session_from_handles(&self) -> SessionInfo {
+        let mut session = SessionInfo::default();
+        for handle in self.handles().values() {
+            let (Some(type_name), Some(name)) = (handle.type_name(), handle.object_name()) else {
+                continue;
+            };
+
+            match type_name {
+                "WindowStation" if session.window_station.is_none() => {
+                    session.window_station = name
+                        .rsplit('\\')
+                        .next()
+                        .filter(|station| !station.is_empty())
+                        .map(str::to_string);
+                    if name.starts_with("\\Windows\\WindowStations\\") {
+                        session.session_id.get_or_insert(0);
+                    }
+                }
+                "Desktop" if session.desktop.is_none() => {
+                    session.desktop = Some(
+                        name.trim_start_matches('\\')
+                            .to_string(),
+                    )
+                    .filter(|desktop| !desktop.is_empty());
+                }
+                _ => {}
+            }
+
+            if let Some(rest) = name.strip_prefix("\\Sessions\\")
+                && let Some(Ok(id)) = rest
+                    .split('\\')
+                    .next()
+                    .map(str::parse::<u32>)
+            {
+                session.session_id.get_or_insert(id);
+            }
+        }
+
+        session
+    }
+
+    /// Compares the `ModuleListStream` with the load order list of `PEB.Ldr`.
+    ///
+    /// A module the stream reports but the loader list does not is the classic sign of
+    /// a DLL that unlinked itself to hide from enumeration. Modules are matched by base
+    /// address; the 32-bit loader list is included for WOW64 processes.
+    ///
+    /// # Returns
+    ///
+    /// * The modules found in only one of the lists, ordered by base address. Empty
+    ///   when the PEB or the loader data was not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for diff in dump.diff_module_lists() {
+    ///     println!("{diff}");
+    /// }
+    /// ```
+    pub fn diff_module_lists(&self) -> Vec<ModuleListDiff> {
+        let Some(process) = self.process() else {
+            return Vec::new();
+        };
+
+        let loader = process.loader_modules(LoaderList::LoadOrder);
+        if loader.is_empty() {
+            return Vec::new();
+        }
+
+        let mut diffs = self
+            .modules()
+            .values()
+            .filter(|module| {
+                !loader
+                    .iter()
+                    .any(|entry| entry.base == module.start_addr())
+            })
+            .map(|module| ModuleListDiff::NotInLoader {
+                base: module.start_addr(),
+                name: module.name().map(str::to_string),
+            })
+            .collect::<Vec<ModuleListDiff>>();
+
+        diffs.extend(
+            loader
+                .into_iter()
+                .filter(|entry| !self.modules().contains_key(&entry.base))
+                .map(|entry| ModuleListDiff::NotInStream {
+                    base: entry.base,
+                    name: entry.base_name,
+                }),
+        );
+
+        diffs.sort_by_key(|diff| match diff {
+            ModuleListDiff::NotInLoader { base, .. } | ModuleListDiff::NotInStream { base, .. } => *base,
+        });
+
+        diffs
+    }
+
+    /// Returns the address of the Process Environment Block, read from the TEB of the first thread.
+    pub(crate) fn peb_address(&self) -> Option<u64> {
+        let offsets = self.struct_offsets()?;
+        self.threads()
+            .values()
+            .find_map(|thread| {
+                offset_va(thread.teb, offsets.teb.peb as u64)
+                    .and_then(|va| self.read_ptr(va))
+                    .ok()
+            })
+            .filter(|&peb| peb != 0)
+    }
+
+    /// Returns the address and layout of the 32-bit PEB of a WOW64 process.
+    ///
+    /// WOW64 threads have a 32-bit TEB right after their 64-bit TEB, recognized by its
+    /// `NtTib.Self` field pointing to itself.
+    pub(crate) fn peb32(&self) -> Option<(u64, StructOffsets)> {
+        if self.system.processor_architecture != Arch::X64 {
+            return None;
+        }
+
+        let offsets = StructOffsets::lookup(Arch::X86, self.system.build_number)?;
+        self.threads()
+            .values()
+            .find_map(|thread| {
+                let teb32 = thread
+                    .teb
+                    .checked_add(WOW64_TEB32_OFFSET)?;
+                let this = offset_va(teb32, offsets.teb.self_ as u64)
+                    .and_then(|va| self.read_u32(va))
+                    .ok()? as u64;
+                let peb = offset_va(teb32, offsets.teb.peb as u64)
+                    .and_then(|va| self.read_u32(va))
+                    .ok()? as u64;
+                (this == teb32 && peb != 0).then_some((peb, offsets))
+            })
+    }
+}
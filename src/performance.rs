@@ -0,0 +1,319 @@
+use std::io::Cursor;
+use binrw::BinRead;
+use crate::data::{
+    MINIDUMP_PROCESS_VM_COUNTERS, MINIDUMP_PROCESS_VM_COUNTERS_2, MINIDUMP_PROCESS_VM_COUNTERS_EX, MINIDUMP_PROCESS_VM_COUNTERS_EX2,
+    MINIDUMP_PROCESS_VM_COUNTERS_JOB, MINIDUMP_PROCESS_VM_COUNTERS_VIRTUALSIZE, MINIDUMP_STREAM_TYPE::HandleDataStream,
+    MINIDUMP_SYSTEM_MEMORY_INFO_1,
+};
+use crate::parse::{MinidumpStream, Result, UserDump};
+
+/// The virtual memory counters of the process, read from the `ProcessVmCountersStream`.
+///
+/// Revision 1 of the stream carries no flags and always holds the basic counters and
+/// the private usage. Revision 2 flags each group of counters, and the groups not
+/// flagged are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VmCounters {
+    /// The number of page faults of the process.
+    pub page_fault_count: Option<u32>,
+
+    /// The peak working set size, in bytes.
+    pub peak_working_set_size: Option<u64>,
+
+    /// The working set size, in bytes.
+    pub working_set_size: Option<u64>,
+
+    /// The peak paged pool usage, in bytes.
+    pub peak_paged_pool_usage: Option<u64>,
+
+    /// The paged pool usage, in bytes.
+    pub paged_pool_usage: Option<u64>,
+
+    /// The peak nonpaged pool usage, in bytes.
+    pub peak_non_paged_pool_usage: Option<u64>,
+
+    /// The nonpaged pool usage, in bytes.
+    pub non_paged_pool_usage: Option<u64>,
+
+    /// The commit charge of the process, in bytes.
+    pub pagefile_usage: Option<u64>,
+
+    /// The peak commit charge of the process, in bytes.
+    pub peak_pagefile_usage: Option<u64>,
+
+    /// The peak virtual size, in bytes.
+    pub peak_virtual_size: Option<u64>,
+
+    /// The virtual size, in bytes.
+    pub virtual_size: Option<u64>,
+
+    /// The private memory usage, in bytes.
+    pub private_usage: Option<u64>,
+
+    /// The private working set size, in bytes.
+    pub private_working_set_size: Option<u64>,
+
+    /// The shared commit usage, in bytes.
+    pub shared_commit_usage: Option<u64>,
+
+    /// The commit usage and limits of the job the process belongs to.
+    pub job: Option<JobCommit>,
+}
+
+/// The commit counters of the job object the process belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct JobCommit {
+    /// The shared commit usage of the job, in bytes.
+    pub shared_commit_usage: u64,
+
+    /// The private commit usage of the job, in bytes.
+    pub private_commit_usage: u64,
+
+    /// The peak private commit usage of the job, in bytes.
+    pub peak_private_commit_usage: u64,
+
+    /// The private commit limit of the job, in bytes.
+    pub private_commit_limit: u64,
+
+    /// The total commit limit of the job, in bytes.
+    pub total_commit_limit: u64,
+}
+
+impl MinidumpStream<'_> for VmCounters {
+    type Output = VmCounters;
+
+    /// Parses the counters from the `ProcessVmCountersStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the process VM counters stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VmCounters)` - If the counters are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the process VM counters stream.
+        let counters = MINIDUMP_PROCESS_VM_COUNTERS_2::read(cursor)?;
+
+        // Converts MINIDUMP_PROCESS_VM_COUNTERS_2 into VmCounters.
+        Ok(VmCounters::from(counters))
+    }
+}
+
+impl From<MINIDUMP_PROCESS_VM_COUNTERS_2> for VmCounters {
+    /// Converts a `MINIDUMP_PROCESS_VM_COUNTERS_2` structure into a `VmCounters` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `counters` - A [`MINIDUMP_PROCESS_VM_COUNTERS_2`] instance containing the raw data
+    ///   extracted from the process VM counters stream.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`VmCounters`] instance with the counters flagged as valid.
+    fn from(counters: MINIDUMP_PROCESS_VM_COUNTERS_2) -> Self {
+        // Revision 1 has no flags, its counters are always valid.
+        let flags = if counters.Revision >= 2 {
+            counters.Flags
+        } else {
+            MINIDUMP_PROCESS_VM_COUNTERS | MINIDUMP_PROCESS_VM_COUNTERS_EX
+        };
+
+        let has = |flag: u16| flags & flag != 0;
+        let basic = has(MINIDUMP_PROCESS_VM_COUNTERS);
+        let virtual_size = has(MINIDUMP_PROCESS_VM_COUNTERS_VIRTUALSIZE);
+        let ex2 = has(MINIDUMP_PROCESS_VM_COUNTERS_EX2);
+
+        Self {
+            page_fault_count: basic.then_some(counters.PageFaultCount),
+            peak_working_set_size: basic.then_some(counters.PeakWorkingSetSize),
+            working_set_size: basic.then_some(counters.WorkingSetSize),
+            peak_paged_pool_usage: basic.then_some(counters.QuotaPeakPagedPoolUsage),
+            paged_pool_usage: basic.then_some(counters.QuotaPagedPoolUsage),
+            peak_non_paged_pool_usage: basic.then_some(counters.QuotaPeakNonPagedPoolUsage),
+            non_paged_pool_usage: basic.then_some(counters.QuotaNonPagedPoolUsage),
+            pagefile_usage: basic.then_some(counters.PagefileUsage),
+            peak_pagefile_usage: basic.then_some(counters.PeakPagefileUsage),
+            peak_virtual_size: virtual_size.then_some(counters.PeakVirtualSize),
+            virtual_size: virtual_size.then_some(counters.VirtualSize),
+            private_usage: has(MINIDUMP_PROCESS_VM_COUNTERS_EX).then_some(counters.PrivateUsage),
+            private_working_set_size: ex2.then_some(counters.PrivateWorkingSetSize),
+            shared_commit_usage: ex2.then_some(counters.SharedCommitUsage),
+            job: has(MINIDUMP_PROCESS_VM_COUNTERS_JOB).then_some(JobCommit {
+                shared_commit_usage: counters.JobSharedCommitUsage,
+                private_commit_usage: counters.JobPrivateCommitUsage,
+                peak_private_commit_usage: counters.JobPeakPrivateCommitUsage,
+                private_commit_limit: counters.JobPrivateCommitLimit,
+                total_commit_limit: counters.JobTotalCommitLimit,
+            }),
+        }
+    }
+}
+
+/// The memory state of the whole system, read from the `SystemMemoryInfoStream`.
+///
+/// Page counts are converted to bytes using the page size recorded in the same stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SystemMemoryInfo {
+    /// The size of a page, in bytes.
+    pub page_size: u32,
+
+    /// The number of processors.
+    pub number_of_processors: u32,
+
+    /// The amount of physical memory, in bytes.
+    pub physical_memory: u64,
+
+    /// The amount of available physical memory, in bytes.
+    pub available_memory: u64,
+
+    /// The committed memory of the system, in bytes.
+    pub committed: u64,
+
+    /// The commit limit of the system, in bytes.
+    pub commit_limit: u64,
+
+    /// The peak committed memory of the system, in bytes.
+    pub peak_commitment: u64,
+
+    /// The current size of the file cache, in bytes.
+    pub file_cache_size: u64,
+
+    /// The peak size of the file cache, in bytes.
+    pub file_cache_peak_size: u64,
+}
+
+impl SystemMemoryInfo {
+    /// Returns the share of the commit limit in use, between `0.0` and `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The committed memory divided by the commit limit.
+    /// * `None` - If the commit limit is zero.
+    pub fn commit_ratio(&self) -> Option<f64> {
+        (self.commit_limit != 0).then(|| self.committed as f64 / self.commit_limit as f64)
+    }
+
+    /// Returns the share of physical memory still available, between `0.0` and `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The available memory divided by the physical memory.
+    /// * `None` - If the physical memory is zero.
+    pub fn available_ratio(&self) -> Option<f64> {
+        (self.physical_memory != 0).then(|| self.available_memory as f64 / self.physical_memory as f64)
+    }
+}
+
+impl MinidumpStream<'_> for SystemMemoryInfo {
+    type Output = SystemMemoryInfo;
+
+    /// Parses the system memory state from the `SystemMemoryInfoStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the system memory info stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SystemMemoryInfo)` - If the information is parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the system memory info stream.
+        let info = MINIDUMP_SYSTEM_MEMORY_INFO_1::read(cursor)?;
+
+        // Converts MINIDUMP_SYSTEM_MEMORY_INFO_1 into SystemMemoryInfo.
+        Ok(SystemMemoryInfo::from(info))
+    }
+}
+
+impl From<MINIDUMP_SYSTEM_MEMORY_INFO_1> for SystemMemoryInfo {
+    /// Converts a `MINIDUMP_SYSTEM_MEMORY_INFO_1` structure into a `SystemMemoryInfo` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `info` - A [`MINIDUMP_SYSTEM_MEMORY_INFO_1`] instance containing the raw data
+    ///   extracted from the system memory info stream.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`SystemMemoryInfo`] instance with the page counts converted to bytes.
+    fn from(info: MINIDUMP_SYSTEM_MEMORY_INFO_1) -> Self {
+        let page_size = info.BasicInfo.PageSize;
+        let bytes = |pages: u64| pages.saturating_mul(page_size.into());
+
+        Self {
+            page_size,
+            number_of_processors: info.BasicInfo.NumberOfProcessors,
+            physical_memory: bytes(
+                info.BasicInfo
+                    .NumberOfPhysicalPages
+                    .into(),
+            ),
+            available_memory: bytes(info.BasicPerfInfo.AvailablePages),
+            committed: bytes(info.BasicPerfInfo.CommittedPages),
+            commit_limit: bytes(info.BasicPerfInfo.CommitLimit),
+            peak_commitment: bytes(info.BasicPerfInfo.PeakCommitment),
+            file_cache_size: info.FileCacheInfo.CurrentSize,
+            file_cache_peak_size: info.FileCacheInfo.PeakSize,
+        }
+    }
+}
+
+/// The performance counters of a dump, gathered in one place for memory-leak triage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PerformanceInfo {
+    /// The virtual memory counters of the process, if the `ProcessVmCountersStream` is present.
+    pub process: Option<VmCounters>,
+
+    /// The memory state of the system, if the `SystemMemoryInfoStream` is present.
+    pub system: Option<SystemMemoryInfo>,
+
+    /// The number of open handles, if the `HandleDataStream` is present.
+    pub handle_count: Option<usize>,
+}
+
+impl UserDump<'_> {
+    /// Returns the performance counters recorded at dump time.
+    ///
+    /// Combines the process counters (commit, working set, page faults), the number
+    /// of open handles and the memory pressure of the system.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(PerformanceInfo)` - If either the process or the system counters are present.
+    /// * `None` - If the dump holds neither stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(perf) = dump.performance_info() {
+    ///     let commit = perf.process.and_then(|process| process.private_usage);
+    ///     let pressure = perf.system.and_then(|system| system.commit_ratio());
+    ///     println!("private: {commit:?}, handles: {:?}, system commit: {pressure:?}", perf.handle_count);
+    /// }
+    /// ```
+    pub fn performance_info(&self) -> Option<PerformanceInfo> {
+        if self.vm_counters().is_none() && self.system_memory().is_none() {
+            return None;
+        }
+
+        Some(PerformanceInfo {
+            process: self.vm_counters().copied(),
+            system: self.system_memory().copied(),
+            handle_count: self
+                .streams()
+                .iter()
+                .any(|stream| stream.StreamType == HandleDataStream as u32)
+                .then(|| self.handles().len()),
+        })
+    }
+}
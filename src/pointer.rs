@@ -0,0 +1,89 @@
+use crate::parse::UserDump;
+
+/// Returns true if `va` is a canonical x64 address, i.e. bits 48 to 63 are copies of bit 47.
+///
+/// Non-canonical values fault when dereferenced, so they are never valid pointers.
+///
+/// # Arguments
+///
+/// * `va` - The value to check.
+pub fn is_canonical_x64(va: u64) -> bool {
+    matches!(va >> 47, 0 | 0x1_FFFF)
+}
+
+/// Returns true if `va` is a multiple of `alignment`.
+///
+/// # Arguments
+///
+/// * `va` - The value to check.
+/// * `alignment` - The alignment, in bytes. An alignment of `0` is never satisfied.
+pub fn is_aligned(va: u64, alignment: u64) -> bool {
+    alignment != 0 && va.is_multiple_of(alignment)
+}
+
+/// Rounds `va` down to a multiple of `alignment`, which must be a power of two.
+///
+/// # Arguments
+///
+/// * `va` - The value to round.
+/// * `alignment` - The alignment, in bytes.
+pub fn align_down(va: u64, alignment: u64) -> u64 {
+    va & !(alignment - 1)
+}
+
+/// Rounds `va` up to a multiple of `alignment`, which must be a power of two.
+///
+/// # Arguments
+///
+/// * `va` - The value to round.
+/// * `alignment` - The alignment, in bytes.
+///
+/// # Returns
+///
+/// * `Some(u64)` - The rounded value.
+/// * `None` - If the rounded value does not fit in 64 bits.
+pub fn align_up(va: u64, alignment: u64) -> Option<u64> {
+    va.checked_add(alignment - 1)
+        .map(|va| align_down(va, alignment))
+}
+
+/// Returns true if `va` could be a pointer of the captured process.
+///
+/// The value must be non-zero, fit in a pointer of the dump's architecture (and be
+/// canonical on 64-bit dumps), and point into committed memory captured in the dump.
+/// It does not tell data from code pointers, nor check alignment; combine it with
+/// [`is_aligned`] for pointers to structures.
+///
+/// # Arguments
+///
+/// * `va` - The value to check.
+/// * `dump` - The dump of the process.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, pointer::looks_like_pointer};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let thread = dump.threads().values().next().unwrap();
+/// let stack = dump.read_bytes(thread.stack.start, (thread.stack.end - thread.stack.start) as usize).unwrap();
+/// let pointers = stack
+///     .as_chunks::<8>()
+///     .0
+///     .iter()
+///     .map(|chunk| u64::from_le_bytes(*chunk))
+///     .filter(|&value| looks_like_pointer(value, &dump))
+///     .count();
+/// ```
+pub fn looks_like_pointer(va: u64, dump: &UserDump) -> bool {
+    let fits = match dump.pointer_size() {
+        4 => va <= u32::MAX as u64,
+        _ => is_canonical_x64(va),
+    };
+
+    va != 0
+        && fits
+        && dump
+            .memory_at(va)
+            .is_some_and(|memory| memory.state.is_committed() || !memory.data.is_empty())
+}
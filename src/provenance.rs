@@ -0,0 +1,173 @@
+use std::{fmt, ops::Range};
+use binrw::BinRead;
+use crate::parse::UserDump;
+use crate::data::*;
+
+/// Size of the `MINIDUMP_HEADER` at the start of every minidump file.
+const HEADER_SIZE: u64 = 32;
+
+/// Size of a `MINIDUMP_DIRECTORY` entry.
+const DIRECTORY_ENTRY_SIZE: u64 = 12;
+
+/// Describes what a byte of the minidump file belongs to.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum FileOrigin {
+    /// The byte belongs to the `MINIDUMP_HEADER`.
+    Header,
+
+    /// The byte belongs to the stream directory.
+    Directory {
+        /// The index of the directory entry holding the byte.
+        index: usize,
+    },
+
+    /// The byte belongs to the memory captured from the process.
+    Memory {
+        /// The virtual address the byte was captured from.
+        address: u64,
+    },
+
+    /// The byte belongs to the data of a stream.
+    Stream {
+        /// The raw type of the stream.
+        stream_type: u32,
+
+        /// The offset of the byte from the start of the stream.
+        offset: u64,
+    },
+}
+
+impl fmt::Display for FileOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileOrigin::Header => write!(f, "header"),
+            FileOrigin::Directory { index } => write!(f, "directory entry {index}"),
+            FileOrigin::Memory { address } => write!(f, "memory at {address:#x}"),
+            FileOrigin::Stream { stream_type, offset } => match MINIDUMP_STREAM_TYPE::try_from(*stream_type) {
+                Ok(known) => write!(f, "{known:?}+{offset:#x}"),
+                Err(_) => write!(f, "stream {stream_type:#x}+{offset:#x}"),
+            },
+        }
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns the offset in the minidump file of the byte captured at a virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The file offset holding the byte.
+    /// * `None` - If the byte was not captured in the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(offset) = dump.va_to_file_offset(0x7ff6_1234_0000) {
+    ///     println!("Open the dump in a hex editor at {offset:#x}");
+    /// }
+    /// ```
+    pub fn va_to_file_offset(&self, va: u64) -> Option<u64> {
+        let memory = self
+            .memory_at(va)
+            .filter(|memory| va - memory.range.start < memory.data.len() as u64)?;
+        let base = self.file_offset_of(memory.data)?;
+        Some(base + (va - memory.range.start))
+    }
+
+    /// Reports what a byte of the minidump file belongs to.
+    ///
+    /// Captured memory takes precedence over the stream describing it, so offsets inside
+    /// the memory of the process are reported as [`FileOrigin::Memory`] with their virtual address.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset in the minidump file.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(FileOrigin)` - The structure owning the byte.
+    /// * `None` - If the offset is past the end of the file or in data not referenced
+    ///   by the header, the directory, a stream or a memory region (e.g., padding or
+    ///   strings referenced from within streams).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// match dump.file_offset_origin(0x1F40) {
+    ///     Some(origin) => println!("0x1f40: {origin}"),
+    ///     None => println!("0x1f40: unreferenced"),
+    /// }
+    /// ```
+    pub fn file_offset_origin(&self, offset: u64) -> Option<FileOrigin> {
+        if offset >= self.mapped_file.buffer.len() as u64 {
+            return None;
+        }
+
+        if offset < HEADER_SIZE {
+            return Some(FileOrigin::Header);
+        }
+
+        // Captured memory, mapped back to the virtual address it was read from.
+        let memory = self
+            .memorys()
+            .values()
+            .find_map(|memory| {
+                let base = self.file_offset_of(memory.data)?;
+                (base..base + memory.data.len() as u64)
+                    .contains(&offset)
+                    .then(|| FileOrigin::Memory {
+                        address: memory.range.start + (offset - base),
+                    })
+            });
+
+        if memory.is_some() {
+            return memory;
+        }
+
+        // The stream directory, located through the header.
+        if let Ok(header) = MINIDUMP_HEADER::read(&mut self.mapped_file.cursor()) {
+            let start = header.StreamDirectoryRva as u64;
+            let directory = start..start + header.NumberOfStreams as u64 * DIRECTORY_ENTRY_SIZE;
+            if directory.contains(&offset) {
+                return Some(FileOrigin::Directory {
+                    index: ((offset - start) / DIRECTORY_ENTRY_SIZE) as usize,
+                });
+            }
+        }
+
+        self.streams()
+            .iter()
+            .find_map(|stream| {
+                let range = stream_range(stream);
+                range
+                    .contains(&offset)
+                    .then(|| FileOrigin::Stream {
+                        stream_type: stream.StreamType,
+                        offset: offset - range.start,
+                    })
+            })
+    }
+
+    /// Returns the file offset of a slice borrowed from the mapped minidump.
+    pub(crate) fn file_offset_of(&self, data: &[u8]) -> Option<u64> {
+        let buffer = self.mapped_file.buffer.as_ptr_range();
+        let start = data.as_ptr();
+        (buffer.contains(&start) || (data.is_empty() && start == buffer.end)).then(|| start as u64 - buffer.start as u64)
+    }
+}
+
+/// Returns the range of file offsets covered by a stream.
+fn stream_range(stream: &MINIDUMP_DIRECTORY) -> Range<u64> {
+    let start = stream.Location.RVA as u64;
+    start..start + stream.Location.DataSize as u64
+}
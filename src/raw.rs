@@ -0,0 +1,279 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+use crate::memory::{MemoryProtection, MemoryState, MemoryType};
+use crate::parse::{Result, UserDump};
+use crate::report::{json_array, json_hex, json_object, json_string};
+
+/// Defines how the captured regions are laid out in a raw memory image.
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RawLayout {
+    /// The captured regions are written back to back in address order, so the image only
+    /// holds captured bytes. The sidecar is needed to map offsets back to addresses.
+    #[default]
+    Sparse,
+
+    /// Each region is written at its address minus the base of the image, so file offsets
+    /// translate to addresses without the sidecar. The gaps are left as holes, which take
+    /// no disk space on file systems supporting sparse files.
+    Padded,
+}
+
+impl RawLayout {
+    /// Returns the name of the layout, as written in the sidecar.
+    fn name(&self) -> &'static str {
+        match self {
+            RawLayout::Sparse => "sparse",
+            RawLayout::Padded => "padded",
+        }
+    }
+}
+
+/// Options that control how [`UserDump::export_raw`] writes a raw memory image.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, raw::{RawExportOptions, RawLayout}};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let options = RawExportOptions::new().layout(RawLayout::Padded);
+/// dump.export_raw("example.raw", &options).unwrap();
+/// ```
+#[derive(Copy, Debug, Clone)]
+pub struct RawExportOptions {
+    /// The layout of the image.
+    pub layout: RawLayout,
+
+    /// The address written at offset 0 of a [`RawLayout::Padded`] image.
+    pub base: u64,
+
+    /// Whether the JSON layout sidecar is written next to the image.
+    pub sidecar: bool,
+}
+
+impl Default for RawExportOptions {
+    fn default() -> Self {
+        Self {
+            layout: RawLayout::default(),
+            base: 0,
+            sidecar: true,
+        }
+    }
+}
+
+impl RawExportOptions {
+    /// Creates a new [`RawExportOptions`] with the default settings: a sparse image and its sidecar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the layout of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The [`RawLayout`] to apply.
+    pub fn layout(mut self, layout: RawLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the address written at offset 0 of a [`RawLayout::Padded`] image.
+    ///
+    /// The default of 0 makes file offsets equal to addresses, as tools reading a raw
+    /// layer expect. Regions below the base are left out of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The address at offset 0.
+    pub fn base(mut self, base: u64) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Sets whether the JSON layout sidecar is written next to the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidecar` - Whether the sidecar is written.
+    pub fn sidecar(mut self, sidecar: bool) -> Self {
+        self.sidecar = sidecar;
+        self
+    }
+}
+
+/// Describes where a captured region was written in a raw memory image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RawRegion {
+    /// The address of the region.
+    pub address: u64,
+
+    /// The number of captured bytes written.
+    pub size: u64,
+
+    /// The offset of the region in the image.
+    pub offset: u64,
+
+    /// The protection of the region.
+    pub protect: MemoryProtection,
+
+    /// The state of the region.
+    pub state: MemoryState,
+
+    /// The type of the region.
+    pub type_: MemoryType,
+}
+
+/// Describes a raw memory image written by [`UserDump::export_raw`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RawExport {
+    /// The layout of the image.
+    pub layout: RawLayout,
+
+    /// The address at offset 0 of a padded image, 0 for a sparse image.
+    pub base: u64,
+
+    /// The size of the image, in bytes.
+    pub size: u64,
+
+    /// The regions written, in address order.
+    pub regions: Vec<RawRegion>,
+
+    /// The path of the JSON sidecar, if one was written.
+    pub sidecar: Option<PathBuf>,
+}
+
+impl RawExport {
+    /// Returns the offset in the image holding an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to locate.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` - The offset of the byte at `va`.
+    /// * `None` - If the byte at `va` was not written to the image.
+    pub fn offset_of(&self, va: u64) -> Option<u64> {
+        let index = self
+            .regions
+            .partition_point(|region| region.address <= va)
+            .checked_sub(1)?;
+        let region = &self.regions[index];
+        (va - region.address < region.size).then(|| region.offset + (va - region.address))
+    }
+
+    /// Renders the layout as the JSON document written to the sidecar.
+    ///
+    /// Addresses are hexadecimal strings, offsets and sizes are numbers.
+    pub fn to_json(&self) -> String {
+        let regions = self.regions.iter().map(|region| {
+            json_object([
+                ("address", json_hex(region.address)),
+                ("size", region.size.to_string()),
+                ("offset", region.offset.to_string()),
+                ("protect", json_string(&region.protect.to_string())),
+                ("state", json_string(&region.state.to_string())),
+                ("type", json_string(&region.type_.to_string())),
+            ])
+        });
+
+        json_object([
+            ("layout", json_string(self.layout.name())),
+            ("base", json_hex(self.base)),
+            ("size", self.size.to_string()),
+            ("regions", json_array(regions)),
+        ])
+    }
+}
+
+impl UserDump<'_> {
+    /// Writes the captured memory as a flat raw image, for tools that expect raw memory
+    /// rather than minidumps (e.g., Volatility raw layers or carving tools).
+    ///
+    /// Unless disabled in the options, a JSON sidecar describing the offset, address and
+    /// attributes of each region is written next to the image, at `<path>.json`.
+    /// Regions whose content was not captured are left out of both.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the image to create.
+    /// * `options` - The layout of the image and whether the sidecar is written.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RawExport)` - The layout of the written image.
+    /// * `Err(UserDmpError)` - If a file cannot be created or written.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, raw::RawExportOptions};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let export = dump.export_raw("example.raw", &RawExportOptions::new()).unwrap();
+    /// println!("{} regions, {} bytes", export.regions.len(), export.size);
+    /// ```
+    pub fn export_raw(&self, path: impl AsRef<Path>, options: &RawExportOptions) -> Result<RawExport> {
+        let path = path.as_ref();
+        let base = match options.layout {
+            RawLayout::Sparse => 0,
+            RawLayout::Padded => options.base,
+        };
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut regions = Vec::new();
+        let mut size = 0;
+        for memory in self
+            .memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty() && memory.range.start >= base)
+        {
+            let offset = match options.layout {
+                RawLayout::Sparse => size,
+                RawLayout::Padded => {
+                    // Seeking past the end leaves a hole instead of writing the zeros.
+                    let offset = memory.range.start - base;
+                    writer.seek(SeekFrom::Start(offset))?;
+                    offset
+                }
+            };
+
+            writer.write_all(memory.data)?;
+            size = offset + memory.data.len() as u64;
+            regions.push(RawRegion {
+                address: memory.range.start,
+                size: memory.data.len() as u64,
+                offset,
+                protect: memory.protect,
+                state: memory.state,
+                type_: memory.type_,
+            });
+        }
+
+        writer.flush()?;
+
+        let mut export = RawExport {
+            layout: options.layout,
+            base,
+            size,
+            regions,
+            sidecar: None,
+        };
+
+        if options.sidecar {
+            let mut sidecar = path.as_os_str().to_owned();
+            sidecar.push(".json");
+            let sidecar = PathBuf::from(sidecar);
+            std::fs::write(&sidecar, export.to_json())?;
+            export.sidecar = Some(sidecar);
+        }
+
+        Ok(export)
+    }
+}
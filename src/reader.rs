@@ -0,0 +1,542 @@
+use std::ops::Range;
+use bytemuck::AnyBitPattern;
+use crate::codepage::CodePage;
+use crate::error::UserDmpError;
+use crate::options::UnmappedReadPolicy;
+use crate::parse::{Arch, Memory, Result, UserDump};
+
+/// Maximum number of characters read by the NUL-terminated string readers.
+pub const MAX_STRING_LEN: usize = 0x8000;
+
+/// Represents the result of a read performed under an [`UnmappedReadPolicy`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeRead {
+    /// The bytes read. Its length depends on the policy used for the read.
+    pub data: Vec<u8>,
+
+    /// The address ranges of the request that were not captured in the dump, in ascending order.
+    pub gaps: Vec<Range<u64>>,
+}
+
+impl RangeRead {
+    /// Returns true if every requested byte was captured in the dump.
+    pub fn is_complete(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+/// A piece of a virtual address range, as returned by [`UserDump::spans`].
+pub(crate) enum Span<'a> {
+    /// Bytes captured in the dump.
+    Captured(&'a [u8]),
+
+    /// An address range missing from the dump.
+    Missing(Range<u64>),
+}
+
+impl<'a> UserDump<'a> {
+    /// Returns the memory region that holds captured bytes for the given virtual address.
+    ///
+    /// Regions that only carry metadata (e.g. entries coming from `MemoryInfoListStream`
+    /// without backing data) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to look up.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Memory)` - The region whose captured data contains `va`.
+    /// * `None` - If no captured bytes are available for the address.
+    fn backing_region(&self, va: u64) -> Option<&Memory<'a>> {
+        self.memorys()
+            .range(..=va)
+            .rev()
+            .map(|(_, memory)| memory)
+            .find(|memory| !memory.data.is_empty())
+            .filter(|memory| va - memory.range.start < memory.data.len() as u64)
+    }
+
+    /// Checks that every byte of a range was captured in the dump, without reading it.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the range.
+    /// * `len` - The size of the range, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the whole range is present in the dump.
+    /// * `Err(UserDmpError::AddressNotFound)` - If any byte of the range is missing or the range overflows.
+    fn ensure_captured(&self, va: u64, len: usize) -> Result<()> {
+        let end = offset_va(va, len as u64)?;
+        let mut address = va;
+        while address < end {
+            let memory = self
+                .backing_region(address)
+                .ok_or(UserDmpError::AddressNotFound(address))?;
+            address = memory
+                .range
+                .start
+                .saturating_add(memory.data.len() as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the bytes at a virtual address of the captured process into `buffer`.
+    ///
+    /// The read may span several adjacent memory regions, as long as every byte
+    /// in the requested range was captured in the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to start reading from.
+    /// * `buffer` - The buffer to fill with the bytes read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the whole buffer was filled.
+    /// * `Err(UserDmpError::AddressNotFound)` - If any byte of the range is not present in the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let mut buffer = [0u8; 2];
+    /// dump.read_virtual(0x7ff6_1234_0000, &mut buffer).unwrap();
+    /// assert_eq!(&buffer, b"MZ");
+    /// ```
+    pub fn read_virtual(&self, va: u64, buffer: &mut [u8]) -> Result<()> {
+        let mut offset = 0;
+        while offset < buffer.len() {
+            let address = va
+                .checked_add(offset as u64)
+                .ok_or(UserDmpError::AddressNotFound(va))?;
+
+            let memory = self
+                .backing_region(address)
+                .ok_or(UserDmpError::AddressNotFound(address))?;
+
+            // Copies as much as the current region can provide.
+            let start = (address - memory.range.start) as usize;
+            let available = &memory.data[start..];
+            let count = available
+                .len()
+                .min(buffer.len() - offset);
+            buffer[offset..offset + count].copy_from_slice(&available[..count]);
+            offset += count;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `len` bytes at a virtual address of the captured process.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to start reading from.
+    /// * `len` - The number of bytes to read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The bytes read.
+    /// * `Err(UserDmpError::AddressNotFound)` - If any byte of the range is not present in the dump.
+    pub fn read_bytes(&self, va: u64, len: usize) -> Result<Vec<u8>> {
+        // Lengths often come from the dump itself, so nothing is allocated before the range is known to be captured.
+        self.ensure_captured(va, len)?;
+
+        let mut buffer = vec![0; len];
+        self.read_virtual(va, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Reads `len` bytes at a virtual address, using the default [`UnmappedReadPolicy`]
+    /// configured through [`crate::options::ParseOptions::unmapped_reads`].
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to start reading from.
+    /// * `len` - The number of bytes to read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RangeRead)` - The bytes read and the gaps found in the range.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the policy is [`UnmappedReadPolicy::Fail`]
+    ///   and any byte of the range is not present in the dump.
+    pub fn read_range(&self, va: u64, len: usize) -> Result<RangeRead> {
+        self.read_range_with(va, len, self.unmapped_reads)
+    }
+
+    /// Reads `len` bytes at a virtual address, using the given [`UnmappedReadPolicy`].
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address to start reading from.
+    /// * `len` - The number of bytes to read.
+    /// * `policy` - How bytes missing from the dump are handled.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RangeRead)` - The bytes read and the gaps found in the range.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the policy is [`UnmappedReadPolicy::Fail`]
+    ///   and any byte of the range is not present in the dump.
+    ///
+    /// Memory is only allocated for the bytes returned. With [`UnmappedReadPolicy::ZeroFill`]
+    /// that is the whole of `len`, even if little of it was captured, so lengths coming from
+    /// the dump itself should be bounded by the caller.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, options::UnmappedReadPolicy};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let read = dump.read_range_with(0x7ff6_1234_0000, 0x2000, UnmappedReadPolicy::ZeroFill).unwrap();
+    /// for gap in &read.gaps {
+    ///     println!("missing {:#x}..{:#x}", gap.start, gap.end);
+    /// }
+    /// ```
+    pub fn read_range_with(&self, va: u64, len: usize, policy: UnmappedReadPolicy) -> Result<RangeRead> {
+        let end = va.saturating_add(len as u64);
+        let mut read = RangeRead::default();
+        for span in self.spans(va, end) {
+            match span {
+                Span::Captured(bytes) => {
+                    // Partial reads stop at the first gap, the later gaps are still reported.
+                    if policy != UnmappedReadPolicy::Partial || read.gaps.is_empty() {
+                        read.data.extend_from_slice(bytes);
+                    }
+                }
+                Span::Missing(gap) => {
+                    match policy {
+                        UnmappedReadPolicy::Fail => return Err(UserDmpError::AddressNotFound(gap.start)),
+                        UnmappedReadPolicy::ZeroFill => read
+                            .data
+                            .resize(read.data.len() + (gap.end - gap.start) as usize, 0),
+                        UnmappedReadPolicy::Partial => {}
+                    }
+
+                    read.gaps.push(gap);
+                }
+            }
+        }
+
+        Ok(read)
+    }
+
+    /// Splits a virtual address range into the slices captured in the dump and the gaps between them.
+    ///
+    /// Nothing is copied, so arbitrarily large ranges can be walked.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The start of the range.
+    /// * `end` - The end of the range, exclusive.
+    pub(crate) fn spans(&self, va: u64, end: u64) -> impl Iterator<Item = Span<'a>> + '_ {
+        let mut address = va;
+        std::iter::from_fn(move || {
+            if address >= end {
+                return None;
+            }
+
+            match self.backing_region(address) {
+                Some(memory) => {
+                    // Takes as much as the current region can provide.
+                    let start = (address - memory.range.start) as usize;
+                    let count = (memory.data.len() - start).min((end - address) as usize);
+                    address += count as u64;
+                    Some(Span::Captured(&memory.data[start..start + count]))
+                }
+                None => {
+                    // The gap ends at the next region holding captured bytes, or at the end of the range.
+                    let next = self
+                        .memorys()
+                        .range(address..end)
+                        .map(|(_, memory)| memory)
+                        .find(|memory| !memory.data.is_empty())
+                        .map_or(end, |memory| memory.range.start);
+
+                    let gap = address..next;
+                    address = next;
+                    Some(Span::Missing(gap))
+                }
+            }
+        })
+    }
+
+    /// Reads a plain-old-data value of type `T` at a virtual address.
+    ///
+    /// The value is read unaligned, so `va` does not need to respect the alignment of `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the value.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The value read.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the value is not fully present in the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// #[derive(Clone, Copy, bytemuck::Zeroable, bytemuck::AnyBitPattern)]
+    /// #[repr(C)]
+    /// struct ListEntry {
+    ///     flink: u64,
+    ///     blink: u64,
+    /// }
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let entry = dump.read_pod::<ListEntry>(0x7ff6_1234_0000).unwrap();
+    /// println!("Flink: {:#x}", entry.flink);
+    /// ```
+    pub fn read_pod<T: AnyBitPattern>(&self, va: u64) -> Result<T> {
+        let bytes = self.read_bytes(va, size_of::<T>())?;
+        Ok(bytemuck::pod_read_unaligned(&bytes))
+    }
+
+    /// Reads a `u8` at a virtual address.
+    pub fn read_u8(&self, va: u64) -> Result<u8> {
+        self.read_pod(va)
+    }
+
+    /// Reads a little-endian `u16` at a virtual address.
+    pub fn read_u16(&self, va: u64) -> Result<u16> {
+        self.read_pod::<u16>(va)
+            .map(u16::from_le)
+    }
+
+    /// Reads a little-endian `u32` at a virtual address.
+    pub fn read_u32(&self, va: u64) -> Result<u32> {
+        self.read_pod::<u32>(va)
+            .map(u32::from_le)
+    }
+
+    /// Reads a little-endian `u64` at a virtual address.
+    pub fn read_u64(&self, va: u64) -> Result<u64> {
+        self.read_pod::<u64>(va)
+            .map(u64::from_le)
+    }
+
+    /// Reads a pointer at a virtual address.
+    ///
+    /// The pointer width follows the architecture of the captured process:
+    /// 4 bytes for [`Arch::X86`] and [`Arch::Arm`], 8 bytes otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the pointer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The pointer value, zero-extended to 64 bits.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the pointer is not fully present in the dump.
+    pub fn read_ptr(&self, va: u64) -> Result<u64> {
+        match self.system.processor_architecture {
+            Arch::X86 | Arch::Arm => self.read_u32(va).map(u64::from),
+            _ => self.read_u64(va),
+        }
+    }
+
+    /// Reads a NUL-terminated single-byte string at a virtual address.
+    ///
+    /// The bytes are decoded as UTF-8, replacing invalid sequences. Strings longer than
+    /// [`MAX_STRING_LEN`] characters are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the first character.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string, without its terminator.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the dump ends before the terminator.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// println!("{}", dump.read_cstring(0x7ff6_1234_5000).unwrap());
+    /// ```
+    pub fn read_cstring(&self, va: u64) -> Result<String> {
+        let (bytes, _) = self.read_terminated::<1>(va, MAX_STRING_LEN)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Reads a NUL-terminated ANSI string at a virtual address, decoded with
+    /// [`UserDump::code_page`].
+    ///
+    /// Strings longer than [`MAX_STRING_LEN`] bytes are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the first character.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string, without its terminator.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the dump ends before the terminator.
+    pub fn read_ansi_string(&self, va: u64) -> Result<String> {
+        self.read_cstring_with(va, &self.code_page())
+    }
+
+    /// Reads a NUL-terminated single-byte or double-byte string at a virtual address,
+    /// decoded with the given code page.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the first character.
+    /// * `code_page` - The code page of the string.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string, without its terminator.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the dump ends before the terminator.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, codepage::CodePage};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// println!("{}", dump.read_cstring_with(0x7ff6_1234_5000, &CodePage::latin1()).unwrap());
+    /// ```
+    pub fn read_cstring_with(&self, va: u64, code_page: &CodePage) -> Result<String> {
+        let (bytes, _) = self.read_terminated::<1>(va, MAX_STRING_LEN)?;
+        Ok(code_page.decode(&bytes))
+    }
+
+    /// Reads a NUL-terminated UTF-16LE string at a virtual address.
+    ///
+    /// Unpaired surrogates are replaced. Strings longer than [`MAX_STRING_LEN`]
+    /// characters are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the first character.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string, without its terminator.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the dump ends before the terminator.
+    pub fn read_wstring(&self, va: u64) -> Result<String> {
+        let (bytes, _) = self.read_terminated::<2>(va, MAX_STRING_LEN)?;
+        Ok(decode_utf16(&bytes))
+    }
+
+    /// Reads a `UNICODE_STRING` structure at a virtual address and the text it points to.
+    ///
+    /// The layout follows the architecture of the captured process: the buffer pointer
+    /// is at offset 4 on 32-bit processes and at offset 8 on 64-bit processes.
+    ///
+    /// # Arguments
+    ///
+    /// * `va` - The virtual address of the `UNICODE_STRING`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The `Length` bytes of the buffer, decoded as UTF-16LE.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the structure or its buffer is not present in the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let image_path = dump.read_unicode_string(0x1f_2060).unwrap();
+    /// ```
+    pub fn read_unicode_string(&self, va: u64) -> Result<String> {
+        let length = self.read_u16(va)? as usize & !1;
+        let buffer = self.read_ptr(offset_va(va, self.pointer_size() as u64)?)?;
+        if length == 0 {
+            return Ok(String::new());
+        }
+
+        Ok(decode_utf16(&self.read_bytes(buffer, length)?))
+    }
+
+    /// Reads units of `N` bytes at a virtual address until a zero unit or `max_units` units.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<u8>, bool))` - The bytes before the terminator, and whether the terminator was found.
+    /// * `Err(UserDmpError::AddressNotFound)` - If the dump ends before the terminator or the limit.
+    pub(crate) fn read_terminated<const N: usize>(&self, va: u64, max_units: usize) -> Result<(Vec<u8>, bool)> {
+        let mut bytes = Vec::new();
+        let mut address = va;
+        while bytes.len() < max_units * N {
+            let memory = self
+                .backing_region(address)
+                .ok_or(UserDmpError::AddressNotFound(address))?;
+            let available = &memory.data[(address - memory.range.start) as usize..];
+            let wanted = (max_units * N - bytes.len()).min(available.len());
+            let (units, _) = available[..wanted].as_chunks::<N>();
+            if units.is_empty() {
+                // A unit straddles two regions.
+                let unit = self.read_bytes(address, N)?;
+                if unit.iter().all(|&byte| byte == 0) {
+                    return Ok((bytes, true));
+                }
+
+                bytes.extend_from_slice(&unit);
+                address += N as u64;
+                continue;
+            }
+
+            if let Some(end) = units
+                .iter()
+                .position(|unit| unit.iter().all(|&byte| byte == 0))
+            {
+                bytes.extend(units[..end].iter().flatten());
+                return Ok((bytes, true));
+            }
+
+            bytes.extend(units.iter().flatten());
+            address += (units.len() * N) as u64;
+        }
+
+        Ok((bytes, false))
+    }
+
+    /// Returns the pointer size of the captured process, in bytes.
+    pub fn pointer_size(&self) -> usize {
+        match self.system.processor_architecture {
+            Arch::X86 | Arch::Arm => 4,
+            _ => 8,
+        }
+    }
+}
+
+/// Returns the address of a field at `offset` bytes from `va`.
+///
+/// Pointers read from the dump are untrusted, so the addition is checked.
+///
+/// # Returns
+///
+/// * `Ok(u64)` - The address of the field.
+/// * `Err(UserDmpError::AddressNotFound)` - If the address overflows.
+pub(crate) fn offset_va(va: u64, offset: u64) -> Result<u64> {
+    va.checked_add(offset)
+        .ok_or(UserDmpError::AddressNotFound(va))
+}
+
+/// Decodes UTF-16LE bytes, replacing unpaired surrogates. A trailing odd byte is ignored.
+pub(crate) fn decode_utf16(bytes: &[u8]) -> String {
+    let units = bytes
+        .as_chunks::<2>()
+        .0
+        .iter()
+        .map(|unit| u16::from_le_bytes(*unit));
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
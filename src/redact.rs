@@ -0,0 +1,180 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::Path,
+};
+use crate::parse::{Result, UserDump};
+
+/// Builds a sanitized copy of a minidump with selected memory scrubbed.
+///
+/// Every byte of captured memory falling into a redacted range is replaced by zero
+/// in the output. The rest of the file, including every stream, is copied byte for
+/// byte, so the result is still a valid minidump with the same layout.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, redact::Redactor};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let scrubbed = Redactor::new(&dump)
+///     .text("hunter2")
+///     .range(0x1f_0000..0x1f_1000)
+///     .save("sanitized.dmp")
+///     .unwrap();
+///
+/// println!("{scrubbed} bytes scrubbed");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redactor<'d, 'a> {
+    /// The dump being redacted.
+    dump: &'d UserDump<'a>,
+
+    /// The virtual address ranges to scrub.
+    ranges: Vec<Range<u64>>,
+}
+
+impl<'d, 'a> Redactor<'d, 'a> {
+    /// Creates a new [`Redactor`] for a dump, with nothing selected yet.
+    pub fn new(dump: &'d UserDump<'a>) -> Self {
+        Self { dump, ranges: Vec::new() }
+    }
+
+    /// Scrubs a range of virtual addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The virtual addresses to scrub. Bytes not captured in the dump are ignored.
+    pub fn range(mut self, range: Range<u64>) -> Self {
+        if !range.is_empty() {
+            self.ranges.push(range);
+        }
+
+        self
+    }
+
+    /// Scrubs several ranges of virtual addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges` - The virtual addresses to scrub.
+    pub fn ranges(self, ranges: &[Range<u64>]) -> Self {
+        ranges
+            .iter()
+            .cloned()
+            .fold(self, Self::range)
+    }
+
+    /// Scrubs every occurrence of a byte pattern in the captured memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The exact bytes to scrub.
+    pub fn pattern(mut self, pattern: &[u8]) -> Self {
+        let dump = self.dump;
+        self.ranges.extend(
+            dump.search_bytes(pattern)
+                .map(|found| found.address..found.address + found.len as u64),
+        );
+        self
+    }
+
+    /// Scrubs every occurrence of a string, in both its ASCII and UTF-16LE encodings
+    /// and ignoring the case of ASCII letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to scrub (e.g., a password or a token).
+    pub fn text(mut self, text: &str) -> Self {
+        let dump = self.dump;
+        self.ranges.extend(
+            dump.search_str_ignore_case(text)
+                .map(|found| found.address..found.address + found.len as u64),
+        );
+        self
+    }
+
+    /// Returns the ranges of virtual addresses selected so far.
+    pub fn selected(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    /// Writes the sanitized minidump.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the sanitized minidump.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of bytes scrubbed.
+    /// * `Err(UserDmpError)` - If writing fails.
+    pub fn write_to(&self, mut writer: impl Write) -> Result<usize> {
+        let mut output = self.dump.mapped_file.buffer.to_vec();
+        let mut scrubbed = 0;
+
+        for memory in self.dump.memorys().values() {
+            let Some(base) = self.dump.file_offset_of(memory.data) else {
+                continue;
+            };
+
+            let captured = memory.range.start..memory.range.start + memory.data.len() as u64;
+            for range in &self.ranges {
+                let start = range.start.max(captured.start);
+                let end = range.end.min(captured.end);
+                if start >= end {
+                    continue;
+                }
+
+                let offset = (base + (start - captured.start)) as usize;
+                let len = (end - start) as usize;
+                let bytes = &mut output[offset..offset + len];
+                scrubbed += bytes
+                    .iter()
+                    .filter(|byte| **byte != 0)
+                    .count();
+                bytes.fill(0);
+            }
+        }
+
+        writer.write_all(&output)?;
+        writer.flush()?;
+        Ok(scrubbed)
+    }
+
+    /// Writes the sanitized minidump to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of bytes scrubbed.
+    /// * `Err(UserDmpError)` - If the file cannot be created or written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<usize> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl UserDump<'_> {
+    /// Writes a copy of the minidump with the given ranges of memory scrubbed.
+    ///
+    /// This is a shortcut for [`Redactor`]; use it directly to scrub patterns or strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges` - The virtual addresses to scrub.
+    /// * `writer` - The destination of the sanitized minidump.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of bytes scrubbed.
+    /// * `Err(UserDmpError)` - If writing fails.
+    pub fn redact(&self, ranges: &[Range<u64>], writer: impl Write) -> Result<usize> {
+        Redactor::new(self)
+            .ranges(ranges)
+            .write_to(writer)
+    }
+}
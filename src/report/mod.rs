@@ -0,0 +1,623 @@
+use std::fmt::{self, Write};
+use crate::parse::{ModuleOrder, Thread, UserDump};
+use self::render::{JsonRenderer, ReportRenderer};
+
+/// The `render` module turns reports into text, JSON, Markdown or HTML, and lets callers add their own formats.
+pub mod render;
+
+/// Default number of frames reported for the top of each stack.
+const DEFAULT_STACK_DEPTH: usize = 8;
+
+/// Selects the sections of a report.
+///
+/// Every section is included by default.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::report::ReportOptions;
+///
+/// let options = ReportOptions::new().handles(false).stack_depth(4);
+/// ```
+#[derive(Copy, Debug, Clone)]
+pub struct ReportOptions {
+    /// Whether the operating system and processor are reported.
+    pub system: bool,
+
+    /// Whether the exception is reported.
+    pub exception: bool,
+
+    /// Whether the modules are reported.
+    pub modules: bool,
+
+    /// Whether the threads and the top of their stacks are reported.
+    pub threads: bool,
+
+    /// Whether the handles are reported.
+    pub handles: bool,
+
+    /// Whether the executable memory outside of any image is reported.
+    pub suspicious_memory: bool,
+
+    /// The maximum number of frames reported for each thread.
+    pub stack_depth: usize,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            system: true,
+            exception: true,
+            modules: true,
+            threads: true,
+            handles: true,
+            suspicious_memory: true,
+            stack_depth: DEFAULT_STACK_DEPTH,
+        }
+    }
+}
+
+impl ReportOptions {
+    /// Creates a new [`ReportOptions`] including every section.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the operating system and processor are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn system(mut self, include: bool) -> Self {
+        self.system = include;
+        self
+    }
+
+    /// Sets whether the exception is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn exception(mut self, include: bool) -> Self {
+        self.exception = include;
+        self
+    }
+
+    /// Sets whether the modules are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn modules(mut self, include: bool) -> Self {
+        self.modules = include;
+        self
+    }
+
+    /// Sets whether the threads and the top of their stacks are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn threads(mut self, include: bool) -> Self {
+        self.threads = include;
+        self
+    }
+
+    /// Sets whether the handles are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn handles(mut self, include: bool) -> Self {
+        self.handles = include;
+        self
+    }
+
+    /// Sets whether the executable memory outside of any image is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether the section is included.
+    pub fn suspicious_memory(mut self, include: bool) -> Self {
+        self.suspicious_memory = include;
+        self
+    }
+
+    /// Sets the maximum number of frames reported for each thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of frames, from the innermost one.
+    pub fn stack_depth(mut self, depth: usize) -> Self {
+        self.stack_depth = depth;
+        self
+    }
+}
+
+/// A value of a [`Report`], independent of the output format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportValue {
+    /// A missing value.
+    Null,
+
+    /// A flag.
+    Bool(bool),
+
+    /// A count, size or identifier.
+    Number(u64),
+
+    /// An address, bit mask or code, written in hexadecimal.
+    Hex(u64),
+
+    /// A string.
+    Text(String),
+
+    /// An ordered list of values.
+    List(Vec<ReportValue>),
+
+    /// Named fields, in order.
+    Record(Vec<(String, ReportValue)>),
+}
+
+impl ReportValue {
+    /// Returns true for the values holding other values (lists and records).
+    pub fn is_nested(&self) -> bool {
+        matches!(self, ReportValue::List(_) | ReportValue::Record(_))
+    }
+}
+
+impl fmt::Display for ReportValue {
+    /// Writes scalar values as text; lists and records are summarized by their length.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportValue::Null => f.write_str("-"),
+            ReportValue::Bool(value) => write!(f, "{value}"),
+            ReportValue::Number(value) => write!(f, "{value}"),
+            ReportValue::Hex(value) => write!(f, "{value:#x}"),
+            ReportValue::Text(value) => f.write_str(value),
+            ReportValue::List(values) => write!(f, "[{} items]", values.len()),
+            ReportValue::Record(fields) => write!(f, "{{{} fields}}", fields.len()),
+        }
+    }
+}
+
+impl From<&str> for ReportValue {
+    fn from(value: &str) -> Self {
+        ReportValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for ReportValue {
+    fn from(value: String) -> Self {
+        ReportValue::Text(value)
+    }
+}
+
+impl<T: Into<ReportValue>> From<Option<T>> for ReportValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(ReportValue::Null, Into::into)
+    }
+}
+
+/// A section of a [`Report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSection {
+    /// The stable, machine-readable name of the section (e.g., `suspicious_memory`).
+    pub key: String,
+
+    /// The human-readable title of the section (e.g., `Suspicious memory`).
+    pub title: String,
+
+    /// The content of the section.
+    pub value: ReportValue,
+}
+
+/// A report on a dump, normalized so that every output format renders the same content.
+///
+/// Build it with [`UserDump::report`] and render it with a [`ReportRenderer`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The sections, in the order they are rendered.
+    pub sections: Vec<ReportSection>,
+}
+
+impl Report {
+    /// Returns the section with the given key, if present.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The machine-readable name of the section.
+    pub fn section(&self, key: &str) -> Option<&ReportSection> {
+        self.sections
+            .iter()
+            .find(|section| section.key == key)
+    }
+
+    /// Appends a section.
+    fn push(&mut self, key: &str, title: &str, value: ReportValue) {
+        self.sections.push(ReportSection {
+            key: key.to_string(),
+            title: title.to_string(),
+            value,
+        });
+    }
+}
+
+impl UserDump<'_> {
+    /// Builds a report on the dump, holding the sections selected in `options`:
+    /// `system`, `exception`, `modules`, `threads`, `handles` and `suspicious_memory`.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The sections to include.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, report::{ReportOptions, render::{MarkdownRenderer, ReportRenderer}}};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let report = dump.report(&ReportOptions::new().handles(false));
+    /// println!("{}", MarkdownRenderer.render(&report));
+    /// ```
+    pub fn report(&self, options: &ReportOptions) -> Report {
+        let mut report = Report::default();
+        if options.system {
+            report.push("system", "System", self.system_section());
+        }
+
+        if options.exception {
+            report.push("exception", "Exception", self.exception_section());
+        }
+
+        if options.modules {
+            report.push("modules", "Modules", self.modules_section());
+        }
+
+        if options.threads {
+            let threads = self
+                .threads()
+                .values()
+                .map(|thread| self.thread_record(thread, options.stack_depth))
+                .collect();
+            report.push("threads", "Threads", ReportValue::List(threads));
+        }
+
+        if options.handles {
+            report.push("handles", "Handles", self.handles_section());
+        }
+
+        if options.suspicious_memory {
+            report.push("suspicious_memory", "Suspicious memory", self.suspicious_memory_section());
+        }
+
+        report
+    }
+
+    /// Builds a report on the dump and renders it.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The sections to include.
+    /// * `renderer` - The output format.
+    pub fn render_report(&self, options: &ReportOptions, renderer: &dyn ReportRenderer) -> String {
+        renderer.render(&self.report(options))
+    }
+
+    /// Renders the dump as a single JSON object, for SIEM and triage pipelines.
+    ///
+    /// The object holds one key per section selected in `options`. Addresses are written
+    /// as hexadecimal strings, since JSON numbers lose precision above 2^53.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The sections to include.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, report::ReportOptions};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let json = dump.to_json_report(&ReportOptions::new().handles(false));
+    /// std::fs::write("report.json", json).unwrap();
+    /// ```
+    pub fn to_json_report(&self, options: &ReportOptions) -> String {
+        self.render_report(options, &JsonRenderer)
+    }
+
+    /// Describes the operating system and the processor.
+    fn system_section(&self) -> ReportValue {
+        let os = self.os_info();
+        record([
+            ("os", os.name.as_str().into()),
+            ("version", os.version().into()),
+            ("arch", format!("{:?}", os.arch).into()),
+            ("processors", ReportValue::Number(self.system.number_of_processors.into())),
+            (
+                "process_id",
+                self.misc_info()
+                    .and_then(|misc| misc.process_id)
+                    .map(|pid| ReportValue::Number(pid.into()))
+                    .into(),
+            ),
+        ])
+    }
+
+    /// Describes the exception, [`ReportValue::Null`] if the dump holds none.
+    fn exception_section(&self) -> ReportValue {
+        let Some(exception) = self.exception() else {
+            return ReportValue::Null;
+        };
+
+        record([
+            ("code", format!("{:#010x}", exception.code).into()),
+            (
+                "name",
+                exception
+                    .exception_code()
+                    .description()
+                    .into(),
+            ),
+            ("address", ReportValue::Hex(exception.address)),
+            ("thread_id", ReportValue::Number(exception.thread_id.into())),
+            ("location", self.location(exception.address).into()),
+            (
+                "access_violation",
+                exception
+                    .access_violation()
+                    .map(|violation| {
+                        record([
+                            ("access", violation.kind.to_string().into()),
+                            ("address", ReportValue::Hex(violation.address)),
+                        ])
+                    })
+                    .into(),
+            ),
+            (
+                "exploitability",
+                self.exploitability()
+                    .map(|assessment| {
+                        record([
+                            ("rating", assessment.rating.as_str().into()),
+                            ("classification", assessment.classification.into()),
+                        ])
+                    })
+                    .into(),
+            ),
+            (
+                "parameters",
+                ReportValue::List(
+                    exception
+                        .parameters()
+                        .iter()
+                        .map(|&parameter| ReportValue::Hex(parameter))
+                        .collect(),
+                ),
+            ),
+            (
+                "nested",
+                ReportValue::List(
+                    exception
+                        .nested(self)
+                        .iter()
+                        .map(|nested| {
+                            record([
+                                ("code", format!("{:#010x}", nested.code).into()),
+                                (
+                                    "name",
+                                    nested
+                                        .exception_code()
+                                        .description()
+                                        .into(),
+                                ),
+                                ("address", ReportValue::Hex(nested.address)),
+                                ("location", self.location(nested.address).into()),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    /// Lists the modules, in load order.
+    fn modules_section(&self) -> ReportValue {
+        ReportValue::List(
+            self.modules_sorted(ModuleOrder::LoadOrder)
+                .into_iter()
+                .map(|module| {
+                    record([
+                        ("base", ReportValue::Hex(module.start_addr())),
+                        ("size", ReportValue::Number(module.len())),
+                        (
+                            "path",
+                            module
+                                .path
+                                .to_string_lossy()
+                                .into_owned()
+                                .into(),
+                        ),
+                        ("time_date_stamp", ReportValue::Number(module.time_date_stamp.into())),
+                        ("checksum", ReportValue::Number(module.checksum.into())),
+                        ("file_version", module.file_version_string().into()),
+                        (
+                            "file_flags",
+                            module
+                                .file_flags()
+                                .map(|flags| flags.to_string())
+                                .into(),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Describes a thread and the top of its stack.
+    fn thread_record(&self, thread: &Thread, depth: usize) -> ReportValue {
+        // Falls back on the instruction pointer alone when the stack cannot be walked.
+        let frames = match self.stack_trace(thread) {
+            Ok(frames) => frames
+                .iter()
+                .map(|frame| frame.instruction_pointer)
+                .take(depth)
+                .collect(),
+            Err(_) => thread
+                .context()
+                .instruction_pointer()
+                .into_iter()
+                .take(depth)
+                .collect::<Vec<u64>>(),
+        };
+
+        record([
+            ("id", ReportValue::Number(thread.thread_id.into())),
+            ("name", thread.name().into()),
+            ("teb", ReportValue::Hex(thread.teb)),
+            ("stack_start", ReportValue::Hex(thread.stack.start)),
+            ("stack_end", ReportValue::Hex(thread.stack.end)),
+            (
+                "frames",
+                ReportValue::List(
+                    frames
+                        .into_iter()
+                        .map(|address| record([("address", ReportValue::Hex(address)), ("location", self.location(address).into())]))
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    /// Lists the handles.
+    fn handles_section(&self) -> ReportValue {
+        ReportValue::List(
+            self.handles()
+                .values()
+                .map(|handle| {
+                    record([
+                        ("handle", ReportValue::Hex(handle.handle)),
+                        ("type", handle.type_name().into()),
+                        ("name", handle.object_name().into()),
+                        ("granted_access", ReportValue::Hex(handle.granted_access.into())),
+                        (
+                            "access_rights",
+                            handle
+                                .access_rights()
+                                .join(" | ")
+                                .into(),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Lists the committed executable regions that do not belong to an image.
+    fn suspicious_memory_section(&self) -> ReportValue {
+        let regions = self
+            .memorys()
+            .values()
+            .filter(|memory| {
+                memory.state.is_committed()
+                    && !memory.type_.is_image()
+                    && memory.protect.is_executable()
+                    && self
+                        .module_at(memory.range.start)
+                        .is_none()
+            });
+
+        ReportValue::List(
+            regions
+                .map(|memory| {
+                    let reason = match memory.protect.is_writable() {
+                        false => "executable memory outside of any image",
+                        true => "writable and executable memory outside of any image",
+                    };
+
+                    record([
+                        ("base", ReportValue::Hex(memory.range.start)),
+                        ("size", ReportValue::Number(memory.len())),
+                        ("allocation_base", ReportValue::Hex(memory.allocation_base)),
+                        ("protect", ReportValue::Hex(memory.protect.into())),
+                        ("type", ReportValue::Hex(memory.type_.into())),
+                        ("reason", reason.into()),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Describes an address as `module+offset`, or by its registered label.
+    fn location(&self, address: u64) -> Option<String> {
+        match self.module_at(address) {
+            Some(module) => Some(format!("{}+{:#x}", module.name().unwrap_or("<unknown>"), address - module.start_addr())),
+            None => self
+                .label_at(address)
+                .map(str::to_string),
+        }
+    }
+}
+
+/// Builds a [`ReportValue::Record`] from its fields.
+fn record<'k>(fields: impl IntoIterator<Item = (&'k str, ReportValue)>) -> ReportValue {
+    ReportValue::Record(
+        fields
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+/// Writes `"key":value` pairs as a JSON object. Values must already be JSON.
+pub(crate) fn json_object<'k>(fields: impl IntoIterator<Item = (&'k str, String)>) -> String {
+    let fields = fields
+        .into_iter()
+        .map(|(key, value)| format!("{}:{value}", json_string(key)))
+        .collect::<Vec<_>>();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Writes values as a JSON array. Values must already be JSON.
+pub(crate) fn json_array(values: impl IntoIterator<Item = String>) -> String {
+    format!(
+        "[{}]",
+        values
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+/// Writes an address as a hexadecimal JSON string.
+pub(crate) fn json_hex(value: u64) -> String {
+    json_string(&format!("{value:#x}"))
+}
+
+/// Formats a value as JSON, or `null` if missing.
+pub(crate) fn json_option(value: Option<impl fmt::Display>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+/// Quotes and escapes a string as a JSON string.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
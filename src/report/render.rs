@@ -0,0 +1,354 @@
+use std::fmt::Write;
+use crate::report::{Report, ReportValue, json_array, json_hex, json_object, json_string};
+
+/// Turns a [`Report`] into an output format.
+///
+/// Implement it to add formats such as JUnit XML or chat messages without
+/// rebuilding the report: every renderer receives the same normalized content.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, report::{Report, ReportOptions, render::ReportRenderer}};
+///
+/// struct SectionList;
+///
+/// impl ReportRenderer for SectionList {
+///     fn render(&self, report: &Report) -> String {
+///         report
+///             .sections
+///             .iter()
+///             .map(|section| format!("{}\n", section.title))
+///             .collect()
+///     }
+/// }
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// print!("{}", dump.render_report(&ReportOptions::new(), &SectionList));
+/// ```
+pub trait ReportRenderer {
+    /// Renders the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The report to render.
+    fn render(&self, report: &Report) -> String;
+}
+
+/// Renders a report as a single JSON object with one key per section.
+///
+/// Addresses are written as hexadecimal strings, since JSON numbers lose precision above 2^53.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct JsonRenderer;
+
+impl ReportRenderer for JsonRenderer {
+    fn render(&self, report: &Report) -> String {
+        json_object(
+            report
+                .sections
+                .iter()
+                .map(|section| (section.key.as_str(), json_value(&section.value))),
+        )
+    }
+}
+
+/// Renders a report as indented plain text, for terminals and logs.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct TextRenderer;
+
+impl ReportRenderer for TextRenderer {
+    fn render(&self, report: &Report) -> String {
+        let mut text = String::new();
+        for section in &report.sections {
+            let _ = writeln!(text, "{}", section.title);
+            text_value(&mut text, &section.value, 1);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// Renders a report as Markdown, for issue trackers and chat tools.
+///
+/// Lists of flat records become tables; nested content becomes bullet lists.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct MarkdownRenderer;
+
+impl ReportRenderer for MarkdownRenderer {
+    fn render(&self, report: &Report) -> String {
+        let mut markdown = String::new();
+        for section in &report.sections {
+            let _ = writeln!(markdown, "## {}\n", markdown_escape(&section.title));
+            match &section.value {
+                ReportValue::List(values) if is_table(values) => markdown_table(&mut markdown, values),
+                value => markdown_value(&mut markdown, value, 0),
+            }
+
+            markdown.push('\n');
+        }
+
+        markdown
+    }
+}
+
+/// Renders a report as a standalone HTML document.
+///
+/// Lists of flat records become tables; nested content becomes nested lists.
+#[derive(Copy, Debug, Clone, Default)]
+pub struct HtmlRenderer;
+
+impl ReportRenderer for HtmlRenderer {
+    fn render(&self, report: &Report) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>userdmp report</title></head>\n<body>\n");
+        for section in &report.sections {
+            let _ = writeln!(html, "<h2>{}</h2>", html_escape(&section.title));
+            html_value(&mut html, &section.value);
+            html.push('\n');
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// Writes a value as JSON.
+fn json_value(value: &ReportValue) -> String {
+    match value {
+        ReportValue::Null => "null".to_string(),
+        ReportValue::Bool(value) => value.to_string(),
+        ReportValue::Number(value) => value.to_string(),
+        ReportValue::Hex(value) => json_hex(*value),
+        ReportValue::Text(value) => json_string(value),
+        ReportValue::List(values) => json_array(values.iter().map(json_value)),
+        ReportValue::Record(fields) => json_object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.as_str(), json_value(value))),
+        ),
+    }
+}
+
+/// Writes a value as indented text, scalar fields of records on a single line.
+fn text_value(text: &mut String, value: &ReportValue, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match value {
+        ReportValue::List(values) if values.is_empty() => {
+            let _ = writeln!(text, "{indent}(none)");
+        }
+        ReportValue::List(values) => {
+            for value in values {
+                match value {
+                    ReportValue::Record(fields) => {
+                        let _ = writeln!(text, "{indent}- {}", inline_fields(fields));
+                        for (key, value) in fields
+                            .iter()
+                            .filter(|(_, value)| value.is_nested())
+                        {
+                            let _ = writeln!(text, "{indent}  {key}:");
+                            text_value(text, value, depth + 2);
+                        }
+                    }
+                    value if value.is_nested() => text_value(text, value, depth + 1),
+                    value => {
+                        let _ = writeln!(text, "{indent}- {value}");
+                    }
+                }
+            }
+        }
+        ReportValue::Record(fields) => {
+            for (key, value) in fields {
+                if value.is_nested() {
+                    let _ = writeln!(text, "{indent}{key}:");
+                    text_value(text, value, depth + 1);
+                } else {
+                    let _ = writeln!(text, "{indent}{key}: {value}");
+                }
+            }
+        }
+        value => {
+            let _ = writeln!(text, "{indent}{value}");
+        }
+    }
+}
+
+/// Writes the scalar fields of a record as `key: value` pairs on a single line.
+fn inline_fields(fields: &[(String, ReportValue)]) -> String {
+    fields
+        .iter()
+        .filter(|(_, value)| !value.is_nested())
+        .map(|(key, value)| format!("{key}: {value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns true if the values are records without nested values, sharing the keys of the first one.
+fn is_table(values: &[ReportValue]) -> bool {
+    let Some(ReportValue::Record(first)) = values.first() else {
+        return false;
+    };
+
+    values.iter().all(|value| match value {
+        ReportValue::Record(fields) => {
+            fields.len() == first.len()
+                && fields
+                    .iter()
+                    .zip(first)
+                    .all(|((key, value), (first_key, _))| key == first_key && !value.is_nested())
+        }
+        _ => false,
+    })
+}
+
+/// Writes records sharing the same keys as a Markdown table.
+fn markdown_table(markdown: &mut String, values: &[ReportValue]) {
+    let rows = values
+        .iter()
+        .filter_map(|value| match value {
+            ReportValue::Record(fields) => Some(fields),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let Some(header) = rows.first() else {
+        return;
+    };
+
+    let keys = header
+        .iter()
+        .map(|(key, _)| markdown_escape(key))
+        .collect::<Vec<_>>();
+    let _ = writeln!(markdown, "| {} |", keys.join(" | "));
+    let _ = writeln!(markdown, "|{}", " --- |".repeat(keys.len()));
+    for fields in rows {
+        let cells = fields
+            .iter()
+            .map(|(_, value)| markdown_escape(&value.to_string()))
+            .collect::<Vec<_>>();
+        let _ = writeln!(markdown, "| {} |", cells.join(" | "));
+    }
+}
+
+/// Writes a value as nested Markdown bullet lists.
+fn markdown_value(markdown: &mut String, value: &ReportValue, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match value {
+        ReportValue::List(values) if values.is_empty() => {
+            let _ = writeln!(markdown, "{indent}- *none*");
+        }
+        ReportValue::List(values) => {
+            for value in values {
+                match value {
+                    ReportValue::Record(fields) => {
+                        let _ = writeln!(markdown, "{indent}- {}", markdown_escape(&inline_fields(fields)));
+                        for (key, value) in fields
+                            .iter()
+                            .filter(|(_, value)| value.is_nested())
+                        {
+                            let _ = writeln!(markdown, "{indent}  - **{}**:", markdown_escape(key));
+                            markdown_value(markdown, value, depth + 2);
+                        }
+                    }
+                    value if value.is_nested() => markdown_value(markdown, value, depth + 1),
+                    value => {
+                        let _ = writeln!(markdown, "{indent}- {}", markdown_escape(&value.to_string()));
+                    }
+                }
+            }
+        }
+        ReportValue::Record(fields) => {
+            for (key, value) in fields {
+                if value.is_nested() {
+                    let _ = writeln!(markdown, "{indent}- **{}**:", markdown_escape(key));
+                    markdown_value(markdown, value, depth + 1);
+                } else {
+                    let _ = writeln!(markdown, "{indent}- **{}**: {}", markdown_escape(key), markdown_escape(&value.to_string()));
+                }
+            }
+        }
+        value => {
+            let _ = writeln!(markdown, "{indent}{}", markdown_escape(&value.to_string()));
+        }
+    }
+}
+
+/// Writes a value as HTML: tables for records and flat lists of records, lists otherwise.
+fn html_value(html: &mut String, value: &ReportValue) {
+    match value {
+        ReportValue::List(values) if values.is_empty() => html.push_str("<p><em>none</em></p>\n"),
+        ReportValue::List(values) if is_table(values) => {
+            html.push_str("<table>\n<tr>");
+            if let Some(ReportValue::Record(header)) = values.first() {
+                for (key, _) in header {
+                    let _ = write!(html, "<th>{}</th>", html_escape(key));
+                }
+            }
+
+            html.push_str("</tr>\n");
+            for value in values {
+                if let ReportValue::Record(fields) = value {
+                    html.push_str("<tr>");
+                    for (_, value) in fields {
+                        let _ = write!(html, "<td>{}</td>", html_escape(&value.to_string()));
+                    }
+
+                    html.push_str("</tr>\n");
+                }
+            }
+
+            html.push_str("</table>\n");
+        }
+        ReportValue::List(values) => {
+            html.push_str("<ul>\n");
+            for value in values {
+                html.push_str("<li>");
+                html_value(html, value);
+                html.push_str("</li>\n");
+            }
+
+            html.push_str("</ul>\n");
+        }
+        ReportValue::Record(fields) => {
+            html.push_str("<table>\n");
+            for (key, value) in fields {
+                let _ = write!(html, "<tr><th>{}</th><td>", html_escape(key));
+                html_value(html, value);
+                html.push_str("</td></tr>\n");
+            }
+
+            html.push_str("</table>\n");
+        }
+        value => html.push_str(&html_escape(&value.to_string())),
+    }
+}
+
+/// Escapes the characters with a meaning in Markdown.
+fn markdown_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '|' | '<' | '>' | '[' | ']' | '#') {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Escapes the characters with a meaning in HTML.
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,800 @@
+use std::str::FromStr;
+use crate::budget::{Budget, Partial};
+use crate::codepage::CodePage;
+use crate::error::UserDmpError;
+use crate::parse::{Memory, MemoryOwner, Module, Result, UserDump};
+
+/// Number of positions scanned between two checks of a [`Budget`].
+const BUDGET_CHECK_INTERVAL: usize = 0x10000;
+
+/// Number of positions scanned by each task of the parallel searches.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_LEN: usize = 0x10_0000;
+
+/// Represents the encoding in which a string was found in memory.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+    /// Single-byte or double-byte encoding: ASCII, or the ANSI code page of the process
+    /// for non-ASCII text.
+    Ascii,
+
+    /// UTF-16 little-endian encoding, used by most Windows APIs.
+    Utf16Le,
+}
+
+/// Represents an occurrence found by one of the memory scanners, annotated with
+/// the region, module and thread it belongs to.
+#[derive(Copy, Debug, Clone)]
+pub struct Match<'d, 'a> {
+    /// The virtual address where the occurrence starts.
+    pub address: u64,
+
+    /// The length of the occurrence, in bytes.
+    pub len: usize,
+
+    /// The encoding of the occurrence, for string searches.
+    pub encoding: Option<StringEncoding>,
+
+    /// The memory region holding the occurrence.
+    pub region: &'d Memory<'a>,
+
+    /// The module whose image contains the occurrence, if any.
+    pub module: Option<&'d Module<'a>>,
+
+    /// The thread whose stack contains the occurrence, if any.
+    pub thread_id: Option<u32>,
+}
+
+impl<'d, 'a> Match<'d, 'a> {
+    /// Creates a [`Match`] for an occurrence inside `region`, resolving its owners.
+    pub(crate) fn new(dump: &'d UserDump<'a>, region: &'d Memory<'a>, offset: usize, len: usize, encoding: Option<StringEncoding>) -> Self {
+        let address = region.range.start + offset as u64;
+        Self {
+            address,
+            len,
+            encoding,
+            region,
+            module: dump.module_at(address),
+            thread_id: region
+                .owner()
+                .map(|MemoryOwner::ThreadStack(thread_id)| thread_id),
+        }
+    }
+
+    /// Returns the bytes of the occurrence.
+    pub fn bytes(&self) -> &'a [u8] {
+        let start = (self.address - self.region.range.start) as usize;
+        &self.region.data[start..start + self.len]
+    }
+
+    /// Returns the occurrence with up to `radius` bytes of surrounding memory on each side.
+    ///
+    /// The snippet never extends past the region holding the occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The number of bytes to include before and after the occurrence.
+    ///
+    /// # Returns
+    ///
+    /// * A tuple with the virtual address of the first byte of the snippet and the snippet itself.
+    pub fn snippet(&self, radius: usize) -> (u64, &'a [u8]) {
+        let data = self.region.data;
+        let start = (self.address - self.region.range.start) as usize;
+        let first = start.saturating_sub(radius);
+        let last = (start + self.len)
+            .saturating_add(radius)
+            .min(data.len());
+        (self.region.range.start + first as u64, &data[first..last])
+    }
+
+    /// Returns the offset of the occurrence from the base of its module.
+    pub fn module_offset(&self) -> Option<u64> {
+        self.module
+            .map(|module| self.address - module.start_addr())
+    }
+}
+
+/// A byte pattern where some positions match any byte.
+///
+/// Patterns are usually written as IDA-style signatures, with bytes in hexadecimal
+/// separated by spaces and `?` or `??` for wildcards.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::search::Pattern;
+///
+/// let pattern: Pattern = "48 8B ?? ?? 90".parse().unwrap();
+/// assert!(pattern.matches(&[0x48, 0x8B, 0x05, 0x10, 0x90]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    /// The bytes of the pattern, `None` for wildcards.
+    bytes: Vec<Option<u8>>,
+}
+
+impl Pattern {
+    /// Parses a signature such as `48 8B ?? ?? 90`.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Hexadecimal bytes separated by whitespace, with `?` or `??` as wildcards.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Pattern)` - If every token is a byte or a wildcard.
+    /// * `Err(UserDmpError::InvalidPattern)` - With the first invalid token.
+    pub fn parse(signature: &str) -> Result<Self> {
+        let bytes = signature
+            .split_whitespace()
+            .map(|token| match token {
+                "?" | "??" => Ok(None),
+                _ if token.len() == 2 => u8::from_str_radix(token, 16)
+                    .map(Some)
+                    .map_err(|_| UserDmpError::InvalidPattern(token.to_string())),
+                _ => Err(UserDmpError::InvalidPattern(token.to_string())),
+            })
+            .collect::<Result<Vec<Option<u8>>>>()?;
+
+        Ok(Self { bytes })
+    }
+
+    /// Builds a pattern from bytes and a mask in which `?` marks wildcards (e.g., `xx??x`).
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes of the pattern.
+    /// * `mask` - One character per byte: `?` for a wildcard, anything else for an exact match.
+    ///   Bytes beyond the end of the mask are matched exactly.
+    pub fn from_mask(bytes: &[u8], mask: &str) -> Self {
+        let mut mask = mask.chars();
+        let bytes = bytes
+            .iter()
+            .map(|&byte| if mask.next() == Some('?') { None } else { Some(byte) })
+            .collect();
+
+        Self { bytes }
+    }
+
+    /// Returns the length of the pattern, in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if the pattern has no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns true if `window` starts with bytes matching the pattern.
+    pub fn matches(&self, window: &[u8]) -> bool {
+        window.len() >= self.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(window)
+                .all(|(expected, byte)| expected.is_none_or(|expected| expected == *byte))
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = UserDmpError;
+
+    fn from_str(signature: &str) -> Result<Self> {
+        Self::parse(signature)
+    }
+}
+
+impl From<&[u8]> for Pattern {
+    fn from(bytes: &[u8]) -> Self {
+        Self {
+            bytes: bytes
+                .iter()
+                .copied()
+                .map(Some)
+                .collect(),
+        }
+    }
+}
+
+/// Returns true if `byte` is a printable ASCII character or a tab.
+fn is_printable(byte: u8) -> bool {
+    byte == b'\t' || (0x20..0x7F).contains(&byte)
+}
+
+/// Returns the length of the printable character at the start of `bytes`.
+///
+/// Without a code page only ASCII is printable; with one, the non-ASCII characters of
+/// the code page are too.
+fn printable_len(bytes: &[u8], code_page: Option<&CodePage>) -> Option<usize> {
+    let &byte = bytes.first()?;
+    if byte < 0x80 {
+        return is_printable(byte).then_some(1);
+    }
+
+    let (c, len) = code_page?.decode_char(bytes)?;
+    (!c.is_control() && c != char::REPLACEMENT_CHARACTER).then_some(len)
+}
+
+/// Extracts the printable strings of at least `min_len` characters from a region.
+///
+/// # Arguments
+///
+/// * `memory` - The region to scan.
+/// * `min_len` - The minimum number of characters of a string.
+/// * `code_page` - The code page of single-byte strings, `None` for ASCII only.
+///
+/// # Returns
+///
+/// * The strings found, ordered by address.
+fn region_strings(memory: &Memory, min_len: usize, code_page: Option<&CodePage>) -> Vec<(u64, StringEncoding, String)> {
+    let data = memory.data;
+    let min_len = min_len.max(1);
+    let mut strings = Vec::new();
+
+    // Single-byte runs, of characters taking one or two bytes.
+    let (mut start, mut chars, mut index) = (0, 0, 0);
+    while index <= data.len() {
+        if let Some(len) = printable_len(&data[index..], code_page) {
+            chars += 1;
+            index += len;
+            continue;
+        }
+
+        if chars >= min_len {
+            let text = match code_page {
+                Some(code_page) => code_page.decode(&data[start..index]),
+                None => String::from_utf8_lossy(&data[start..index]).into_owned(),
+            };
+            strings.push((memory.range.start + start as u64, StringEncoding::Ascii, text));
+        }
+
+        chars = 0;
+        index += 1;
+        start = index;
+    }
+
+    // UTF-16LE runs of ASCII characters, at both byte parities.
+    for parity in 0..2 {
+        let units = data
+            .get(parity..)
+            .unwrap_or_default()
+            .as_chunks::<2>()
+            .0;
+        let mut start = 0;
+        for (index, unit) in units
+            .iter()
+            .chain([&[0, 0]])
+            .enumerate()
+        {
+            if unit[1] != 0 || !is_printable(unit[0]) {
+                if index - start >= min_len {
+                    let text = units[start..index]
+                        .iter()
+                        .map(|unit| unit[0] as char)
+                        .collect();
+                    let address = memory.range.start + (parity + start * 2) as u64;
+                    strings.push((address, StringEncoding::Utf16Le, text));
+                }
+
+                start = index + 1;
+            }
+        }
+    }
+
+    strings.sort_by_key(|(address, encoding, _)| (*address, *encoding == StringEncoding::Utf16Le));
+    strings
+}
+
+/// Returns true if `window` matches `needle`, optionally ignoring ASCII case.
+///
+/// # Arguments
+///
+/// * `window` - The bytes being compared, with the same length as `needle`.
+/// * `needle` - The encoded bytes being searched for.
+/// * `encoding` - The encoding of `needle`, which defines the unit used for case folding.
+/// * `ignore_case` - Whether ASCII letters should be compared case-insensitively.
+fn matches(window: &[u8], needle: &[u8], encoding: StringEncoding, ignore_case: bool) -> bool {
+    if !ignore_case {
+        return window == needle;
+    }
+
+    match encoding {
+        StringEncoding::Ascii => window.eq_ignore_ascii_case(needle),
+        StringEncoding::Utf16Le => window
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .zip(needle.as_chunks::<2>().0)
+            .all(|(a, b)| {
+                let fold = |unit: &[u8; 2]| {
+                    let unit = u16::from_le_bytes(*unit);
+                    if unit < 0x80 { (unit as u8).to_ascii_lowercase() as u16 } else { unit }
+                };
+
+                fold(a) == fold(b)
+            }),
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Searches the captured memory for a string, in both its ASCII and UTF-16LE encodings.
+    ///
+    /// The comparison is case-sensitive. Text containing non-ASCII characters is
+    /// searched as UTF-8 instead of ASCII.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to search for.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over every [`Match`], ordered by region and then by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for found in dump.search_str("kernel32.dll") {
+    ///     let module = found.module.and_then(|module| module.name()).unwrap_or("-");
+    ///     println!("{:#x} ({:?}) in {module}", found.address, found.encoding);
+    /// }
+    /// ```
+    pub fn search_str<'s>(&'s self, text: &str) -> impl Iterator<Item = Match<'s, 'a>> + 's {
+        self.search_str_with(text, false)
+    }
+
+    /// Searches the captured memory for a string, in both its ASCII and UTF-16LE encodings,
+    /// ignoring the case of ASCII letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to search for.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over every [`Match`], ordered by region and then by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let hits = dump.search_str_ignore_case("PASSWORD").count();
+    /// println!("{hits} occurrences");
+    /// ```
+    pub fn search_str_ignore_case<'s>(&'s self, text: &str) -> impl Iterator<Item = Match<'s, 'a>> + 's {
+        self.search_str_with(text, true)
+    }
+
+    /// Searches the captured memory for an exact sequence of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The bytes to search for.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over every [`Match`], ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for found in dump.search_bytes(b"MZ\x90\x00") {
+    ///     let (start, bytes) = found.snippet(16);
+    ///     println!("{:#x}: {bytes:02x?} (from {start:#x})", found.address);
+    /// }
+    /// ```
+    pub fn search_bytes<'s>(&'s self, pattern: &[u8]) -> impl Iterator<Item = Match<'s, 'a>> + use<'s, 'a> {
+        self.search_pattern(&Pattern::from(pattern))
+    }
+
+    /// Searches the captured memory for an exact sequence of bytes.
+    ///
+    /// Every region captured in the dump is scanned, and matches never span two regions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The bytes to search for.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the virtual address of every match, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for address in dump.search(b"\xE8\x00\x00\x00\x00\x58") {
+    ///     println!("call/pop at {address:#x}");
+    /// }
+    /// ```
+    pub fn search<'s>(&'s self, pattern: &[u8]) -> impl Iterator<Item = u64> + use<'s, 'a> {
+        self.search_bytes(pattern)
+            .map(|found| found.address)
+    }
+
+    /// Searches the captured memory for a signature with wildcards, such as `48 8B ?? ?? 90`.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The signature to search for (see [`Pattern::parse`]).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator<Item = u64>)` - The virtual address of every match, in ascending order.
+    /// * `Err(UserDmpError::InvalidPattern)` - If the signature cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for address in dump.search_masked("48 8B 05 ?? ?? ?? ?? 48 85 C0").unwrap() {
+    ///     println!("{address:#x}");
+    /// }
+    /// ```
+    pub fn search_masked<'s>(&'s self, signature: &str) -> Result<impl Iterator<Item = u64> + use<'s, 'a>> {
+        let pattern = Pattern::parse(signature)?;
+        Ok(self
+            .search_pattern(&pattern)
+            .map(|found| found.address))
+    }
+
+    /// Searches the captured memory for a [`Pattern`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to search for.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over every [`Match`], ordered by address.
+    pub fn search_pattern<'s>(&'s self, pattern: &Pattern) -> impl Iterator<Item = Match<'s, 'a>> + use<'s, 'a> {
+        let pattern = pattern.clone();
+        let len = pattern.len();
+        self.memorys()
+            .values()
+            .filter(move |memory| len != 0 && memory.data.len() >= len)
+            .flat_map(move |memory| {
+                let pattern = pattern.clone();
+                memory
+                    .data
+                    .windows(len)
+                    .enumerate()
+                    .filter(move |(_, window)| pattern.matches(window))
+                    .map(move |(offset, _)| Match::new(self, memory, offset, len, None))
+            })
+    }
+
+    /// Searches the captured memory for a [`Pattern`], stopping when the budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to search for.
+    /// * `budget` - Limits how long the search may run.
+    ///
+    /// # Returns
+    ///
+    /// * The matches found, ordered by address, and whether the search stopped early.
+    pub fn search_pattern_within<'s>(&'s self, pattern: &Pattern, budget: &Budget) -> Partial<Vec<Match<'s, 'a>>> {
+        let len = pattern.len();
+        let mut found = Vec::new();
+        for memory in self
+            .memorys()
+            .values()
+            .filter(|memory| len != 0 && memory.data.len() >= len)
+        {
+            let positions = memory.data.len() - len + 1;
+            for start in (0..positions).step_by(BUDGET_CHECK_INTERVAL) {
+                if budget.is_exhausted() {
+                    return Partial {
+                        value: found,
+                        timed_out: true,
+                    };
+                }
+
+                let end = (start + BUDGET_CHECK_INTERVAL).min(positions);
+                found.extend(
+                    (start..end)
+                        .filter(|&offset| pattern.matches(&memory.data[offset..offset + len]))
+                        .map(|offset| Match::new(self, memory, offset, len, None)),
+                );
+            }
+        }
+
+        Partial {
+            value: found,
+            timed_out: false,
+        }
+    }
+
+    /// Extracts the printable single-byte and UTF-16LE strings from the captured memory,
+    /// stopping when the budget is exhausted.
+    ///
+    /// The budget is checked before each memory region is scanned.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_len` - The minimum number of characters of a string.
+    /// * `budget` - Limits how long the extraction may run.
+    ///
+    /// # Returns
+    ///
+    /// * The strings found, ordered by address, and whether the extraction stopped early.
+    pub fn strings_within(&self, min_len: usize, budget: &Budget) -> Partial<Vec<(u64, StringEncoding, String)>> {
+        let code_page = self.configured_code_page();
+        let mut strings = Vec::new();
+        for memory in self
+            .memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty())
+        {
+            if budget.is_exhausted() {
+                return Partial {
+                    value: strings,
+                    timed_out: true,
+                };
+            }
+
+            strings.extend(region_strings(memory, min_len, code_page.as_ref()));
+        }
+
+        Partial {
+            value: strings,
+            timed_out: false,
+        }
+    }
+
+    /// Extracts the printable single-byte and UTF-16LE strings from the captured memory,
+    /// similar to the `strings` utility but aware of virtual addresses.
+    ///
+    /// Single-byte strings are decoded with the code page set through
+    /// [`crate::options::ParseOptions::code_page`], else with the ANSI code page of the
+    /// process; when neither is known, only ASCII strings are extracted.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_len` - The minimum number of characters of a string.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the address, encoding and text of every string, ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (address, encoding, text) in dump.strings(8) {
+    ///     println!("{address:#x} {encoding:?} {text}");
+    /// }
+    /// ```
+    pub fn strings(&self, min_len: usize) -> impl Iterator<Item = (u64, StringEncoding, String)> + '_ {
+        self.strings_in(min_len, |_| true)
+    }
+
+    /// Extracts the printable single-byte and UTF-16LE strings from the regions accepted by `filter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_len` - The minimum number of characters of a string.
+    /// * `filter` - Selects the regions to scan (e.g., only private or only writable memory).
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the address, encoding and text of every string, ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (address, _, text) in dump.strings_in(6, |memory| memory.type_.is_private()) {
+    ///     println!("{address:#x} {text}");
+    /// }
+    /// ```
+    pub fn strings_in<'s, F>(&'s self, min_len: usize, filter: F) -> impl Iterator<Item = (u64, StringEncoding, String)> + 's
+    where
+        F: Fn(&Memory) -> bool + 's,
+    {
+        let code_page = self.configured_code_page();
+        self.memorys()
+            .values()
+            .filter(move |memory| !memory.data.is_empty() && filter(memory))
+            .flat_map(move |memory| region_strings(memory, min_len, code_page.as_ref()))
+    }
+
+    /// Searches the captured memory for the single-byte and UTF-16LE encodings of `text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to search for.
+    /// * `ignore_case` - Whether ASCII letters should be compared case-insensitively.
+    fn search_str_with<'s>(&'s self, text: &str, ignore_case: bool) -> impl Iterator<Item = Match<'s, 'a>> + 's {
+        let needles = Self::str_needles(text);
+
+        self.memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty())
+            .flat_map(move |memory| self.region_str_matches(memory, &needles, ignore_case))
+    }
+
+    /// Returns the single-byte and UTF-16LE encodings of a string searched in memory.
+    fn str_needles(text: &str) -> Vec<(StringEncoding, Vec<u8>)> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let wide = text
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<u8>>();
+        vec![(StringEncoding::Ascii, text.as_bytes().to_vec()), (StringEncoding::Utf16Le, wide)]
+    }
+
+    /// Searches a region for the encodings of a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - The region to scan.
+    /// * `needles` - The encodings of the string (see [`UserDump::str_needles`]).
+    /// * `ignore_case` - Whether ASCII letters should be compared case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// * The matches of every encoding, ordered by address.
+    fn region_str_matches<'s>(&'s self, memory: &'s Memory<'a>, needles: &[(StringEncoding, Vec<u8>)], ignore_case: bool) -> Vec<Match<'s, 'a>> {
+        let mut found = needles
+            .iter()
+            .flat_map(|(encoding, needle)| {
+                memory
+                    .data
+                    .windows(needle.len())
+                    .enumerate()
+                    .filter(|(_, window)| matches(window, needle, *encoding, ignore_case))
+                    .map(|(offset, _)| Match::new(self, memory, offset, needle.len(), Some(*encoding)))
+            })
+            .collect::<Vec<Match>>();
+
+        found.sort_by_key(|found| found.address);
+        found
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a> UserDump<'a> {
+    /// Searches the captured memory for a [`Pattern`] on all cores.
+    ///
+    /// Regions are split into chunks of 1 MiB scanned in parallel, so a single large
+    /// region still uses every core. The result is the same as [`UserDump::search_pattern`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to search for.
+    ///
+    /// # Returns
+    ///
+    /// * Every [`Match`], ordered by address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, search::Pattern};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let pattern = Pattern::parse("48 8B 05 ?? ?? ?? ?? 48 85 C0").unwrap();
+    /// println!("{} matches", dump.par_search_pattern(&pattern).len());
+    /// ```
+    pub fn par_search_pattern(&self, pattern: &Pattern) -> Vec<Match<'_, 'a>> {
+        use rayon::prelude::*;
+
+        let len = pattern.len();
+        let chunks = self
+            .memorys()
+            .values()
+            .filter(|memory| len != 0 && memory.data.len() >= len)
+            .flat_map(|memory| {
+                let positions = memory.data.len() - len + 1;
+                (0..positions)
+                    .step_by(PARALLEL_CHUNK_LEN)
+                    .map(move |start| (memory, start..(start + PARALLEL_CHUNK_LEN).min(positions)))
+            })
+            .collect::<Vec<_>>();
+
+        chunks
+            .into_par_iter()
+            .flat_map_iter(|(memory, positions)| {
+                positions
+                    .filter(|&offset| pattern.matches(&memory.data[offset..offset + len]))
+                    .map(move |offset| Match::new(self, memory, offset, len, None))
+            })
+            .collect()
+    }
+
+    /// Searches the captured memory for a string on all cores, in both its ASCII and
+    /// UTF-16LE encodings.
+    ///
+    /// The result is the same as [`UserDump::search_str`], with regions split into chunks
+    /// of 1 MiB scanned in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The string to search for.
+    ///
+    /// # Returns
+    ///
+    /// * Every [`Match`], ordered by address.
+    pub fn par_search_str(&self, text: &str) -> Vec<Match<'_, 'a>> {
+        use rayon::prelude::*;
+
+        let needles = Self::str_needles(text);
+        let chunks = self
+            .memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty())
+            .flat_map(|memory| {
+                (0..memory.data.len())
+                    .step_by(PARALLEL_CHUNK_LEN)
+                    .map(move |start| (memory, start..(start + PARALLEL_CHUNK_LEN).min(memory.data.len())))
+            })
+            .collect::<Vec<_>>();
+
+        chunks
+            .into_par_iter()
+            .flat_map_iter(|(memory, positions)| {
+                let mut found = needles
+                    .iter()
+                    .flat_map(|(encoding, needle)| {
+                        positions
+                            .clone()
+                            .filter(|&offset| {
+                                memory
+                                    .data
+                                    .get(offset..offset + needle.len())
+                                    .is_some_and(|window| matches(window, needle, *encoding, false))
+                            })
+                            .map(|offset| Match::new(self, memory, offset, needle.len(), Some(*encoding)))
+                    })
+                    .collect::<Vec<Match>>();
+
+                found.sort_by_key(|found| found.address);
+                found
+            })
+            .collect()
+    }
+
+    /// Extracts the printable single-byte and UTF-16LE strings from the captured memory on all cores.
+    ///
+    /// The result is the same as [`UserDump::strings`], with regions scanned in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_len` - The minimum number of characters of a string.
+    ///
+    /// # Returns
+    ///
+    /// * The address, encoding and text of every string, ordered by address.
+    pub fn par_strings(&self, min_len: usize) -> Vec<(u64, StringEncoding, String)> {
+        use rayon::prelude::*;
+
+        let code_page = self.configured_code_page();
+        self.memorys()
+            .par_iter()
+            .filter(|(_, memory)| !memory.data.is_empty())
+            .flat_map_iter(|(_, memory)| region_strings(memory, min_len, code_page.as_ref()))
+            .collect()
+    }
+}
@@ -0,0 +1,259 @@
+use core::fmt;
+use crate::budget::{Budget, Partial};
+use crate::parse::UserDump;
+use crate::search::Match;
+
+/// Number of positions scanned between two checks of a [`Budget`].
+const BUDGET_CHECK_INTERVAL: usize = 0x10000;
+
+/// Longest `-----BEGIN ... PRIVATE KEY-----` header line recognized, in bytes.
+const MAX_PEM_HEADER_LEN: usize = 64;
+
+/// Prefixes of AWS access key IDs (long-term, temporary, group, user, role, ...).
+const AWS_KEY_PREFIXES: [&[u8; 4]; 8] = [b"AKIA", b"ASIA", b"AGPA", b"AIDA", b"AROA", b"ANPA", b"ANVA", b"AIPA"];
+
+/// Length of an AWS access key ID.
+const AWS_KEY_LEN: usize = 20;
+
+/// Minimum length of the header and payload segments of a JWT.
+const MIN_JWT_SEGMENT_LEN: usize = 10;
+
+/// Signature of NTLMSSP messages.
+const NTLMSSP_SIGNATURE: &[u8; 8] = b"NTLMSSP\0";
+
+/// Message type of the NTLMSSP `AUTHENTICATE_MESSAGE`, which carries the challenge response.
+const NTLMSSP_AUTHENTICATE: u32 = 3;
+
+/// Length of a hexadecimal LM or NT hash.
+const HASH_HEX_LEN: usize = 32;
+
+/// Number of characters left readable at the start of a redacted preview.
+const PREVIEW_KEEP: usize = 4;
+
+/// Maximum number of masking characters in a redacted preview.
+const PREVIEW_MASK: usize = 16;
+
+/// Identifies the kind of secret found by [`UserDump::scan_secrets`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SecretKind {
+    /// A PEM private key (`-----BEGIN ... PRIVATE KEY-----`).
+    PemPrivateKey,
+
+    /// An AWS access key ID (e.g., `AKIA...`).
+    AwsAccessKey,
+
+    /// A JSON Web Token (`eyJ...`).
+    Jwt,
+
+    /// An NTLMSSP `AUTHENTICATE_MESSAGE`, holding a crackable challenge response.
+    NtlmAuthenticate,
+
+    /// An LM and NT hash pair in `pwdump` format (`LM:NT`).
+    NtlmHash,
+}
+
+impl fmt::Display for SecretKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretKind::PemPrivateKey => write!(f, "PEM private key"),
+            SecretKind::AwsAccessKey => write!(f, "AWS access key"),
+            SecretKind::Jwt => write!(f, "JSON Web Token"),
+            SecretKind::NtlmAuthenticate => write!(f, "NTLM authenticate message"),
+            SecretKind::NtlmHash => write!(f, "NTLM hash"),
+        }
+    }
+}
+
+/// Represents a secret found in the captured memory, see [`UserDump::scan_secrets`].
+#[derive(Debug, Clone)]
+pub struct Secret<'d, 'a> {
+    /// The kind of secret.
+    pub kind: SecretKind,
+
+    /// Where the secret was found.
+    pub occurrence: Match<'d, 'a>,
+
+    /// A preview of the secret with its sensitive part masked, safe to log.
+    pub preview: String,
+}
+
+impl fmt::Display for Secret<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:#x}: {}", self.kind, self.occurrence.address, self.preview)
+    }
+}
+
+/// Returns true if a byte may be part of a base64url segment.
+fn is_base64url(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_'
+}
+
+/// Masks all but the first characters of a secret.
+fn redact(secret: &[u8]) -> String {
+    let keep = secret.len().min(PREVIEW_KEEP);
+    let mask = (secret.len() - keep).min(PREVIEW_MASK);
+    format!("{}{}", String::from_utf8_lossy(&secret[..keep]), "*".repeat(mask))
+}
+
+/// Returns the length of the run of bytes at the start of `data` satisfying `predicate`.
+fn run_len(data: &[u8], predicate: impl Fn(u8) -> bool) -> usize {
+    data.iter()
+        .position(|byte| !predicate(*byte))
+        .unwrap_or(data.len())
+}
+
+/// Recognizes a secret starting at the first byte of `data`.
+///
+/// # Arguments
+///
+/// * `data` - The captured bytes from the candidate position to the end of the region.
+/// * `previous` - The byte preceding the candidate position, if any.
+///
+/// # Returns
+///
+/// * `Some((SecretKind, usize, String))` - The kind, the length and the redacted preview of the secret.
+/// * `None` - If no secret starts at this position.
+fn detect(data: &[u8], previous: Option<u8>) -> Option<(SecretKind, usize, String)> {
+    match data.first()? {
+        b'-' if data.starts_with(b"-----BEGIN ") => {
+            // The header names the key type and carries nothing sensitive.
+            let header = &data[..data.len().min(MAX_PEM_HEADER_LEN)];
+            let end = header
+                .windows(16)
+                .position(|window| window == b"PRIVATE KEY-----")?
+                + 16;
+            let header = &header[..end];
+            header
+                .iter()
+                .all(|byte| byte.is_ascii_uppercase() || *byte == b' ' || *byte == b'-')
+                .then(|| (SecretKind::PemPrivateKey, end, String::from_utf8_lossy(header).into_owned()))
+        }
+        b'A' if previous.is_none_or(|byte| !byte.is_ascii_alphanumeric()) => {
+            let key = data.get(..AWS_KEY_LEN)?;
+            let valid = AWS_KEY_PREFIXES
+                .iter()
+                .any(|prefix| key.starts_with(*prefix))
+                && key[4..]
+                    .iter()
+                    .all(|byte| byte.is_ascii_uppercase() || (b'2'..=b'7').contains(byte))
+                && data
+                    .get(AWS_KEY_LEN)
+                    .is_none_or(|byte| !byte.is_ascii_alphanumeric());
+            valid.then(|| (SecretKind::AwsAccessKey, AWS_KEY_LEN, redact(key)))
+        }
+        b'e' if data.starts_with(b"eyJ") && previous.is_none_or(|byte| !is_base64url(byte)) => {
+            // Both the header and the payload are base64url-encoded JSON objects.
+            let header = run_len(data, is_base64url);
+            let payload_start = header + 1;
+            if header < MIN_JWT_SEGMENT_LEN || data.get(header) != Some(&b'.') || !data[payload_start..].starts_with(b"eyJ") {
+                return None;
+            }
+
+            let payload = run_len(&data[payload_start..], is_base64url);
+            let signature_start = payload_start + payload + 1;
+            if payload < MIN_JWT_SEGMENT_LEN || data.get(signature_start - 1) != Some(&b'.') {
+                return None;
+            }
+
+            let len = signature_start + run_len(&data[signature_start..], is_base64url);
+
+            // The header is kept readable since it only names the algorithm.
+            let preview = format!("{}.{}", String::from_utf8_lossy(&data[..header]), redact(&data[payload_start..len]));
+            Some((SecretKind::Jwt, len, preview))
+        }
+        b'N' if data.starts_with(NTLMSSP_SIGNATURE) => {
+            let message_type = data.get(8..12)?;
+            (u32::from_le_bytes(message_type.try_into().ok()?) == NTLMSSP_AUTHENTICATE)
+                .then(|| (SecretKind::NtlmAuthenticate, 12, String::from("NTLMSSP AUTHENTICATE_MESSAGE")))
+        }
+        b':' => {
+            // `:LM:NT:` as written by pwdump and secretsdump.
+            let len = 1 + HASH_HEX_LEN + 1 + HASH_HEX_LEN + 1;
+            let line = data.get(..len)?;
+            let (lm, nt) = (&line[1..1 + HASH_HEX_LEN], &line[2 + HASH_HEX_LEN..len - 1]);
+            let valid = lm.iter().all(u8::is_ascii_hexdigit)
+                && line[1 + HASH_HEX_LEN] == b':'
+                && nt.iter().all(u8::is_ascii_hexdigit)
+                && line[len - 1] == b':';
+            valid.then(|| (SecretKind::NtlmHash, len, format!(":{}:{}:", redact(lm), redact(nt))))
+        }
+        _ => None,
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Scans the captured memory for high-value secrets: PEM private keys, AWS access key IDs,
+    /// JSON Web Tokens, NTLMSSP authenticate messages and `pwdump`-style NTLM hashes.
+    ///
+    /// The scan is opt-in and never runs as part of the analysis passes. It is meant to
+    /// assess the exposure of a dump before it is shared, so each secret is reported with
+    /// a redacted preview instead of its value; the value itself stays reachable through
+    /// [`Match::bytes`]. Only single-byte text is recognized, and regions that are neither
+    /// committed nor captured from the memory lists are skipped.
+    ///
+    /// The budget is checked every 64 KiB of scanned memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Limits how long the scan may run.
+    ///
+    /// # Returns
+    ///
+    /// * The secrets found, ordered by address, and whether the scan stopped early.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, budget::Budget};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for secret in dump.scan_secrets(&Budget::unlimited()).into_inner() {
+    ///     println!("{secret}");
+    /// }
+    /// ```
+    pub fn scan_secrets<'s>(&'s self, budget: &Budget) -> Partial<Vec<Secret<'s, 'a>>> {
+        let mut secrets = Vec::new();
+        for memory in self
+            .memorys()
+            .values()
+            .filter(|memory| !memory.data.is_empty() && (memory.state.is_committed() || memory.state.is_empty()))
+        {
+            let data = memory.data;
+            let mut offset = 0;
+            let mut checked = 0;
+            while offset < data.len() {
+                if offset >= checked {
+                    if budget.is_exhausted() {
+                        return Partial {
+                            value: secrets,
+                            timed_out: true,
+                        };
+                    }
+
+                    checked = offset + BUDGET_CHECK_INTERVAL;
+                }
+
+                let previous = offset
+                    .checked_sub(1)
+                    .map(|index| data[index]);
+                match detect(&data[offset..], previous) {
+                    Some((kind, len, preview)) => {
+                        secrets.push(Secret {
+                            kind,
+                            occurrence: Match::new(self, memory, offset, len, None),
+                            preview,
+                        });
+                        offset += len;
+                    }
+                    None => offset += 1,
+                }
+            }
+        }
+
+        Partial {
+            value: secrets,
+            timed_out: false,
+        }
+    }
+}
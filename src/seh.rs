@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+use crate::data::{IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG, IMAGE_DLLCHARACTERISTICS_NO_SEH};
+use crate::error::UserDmpError;
+use crate::parse::{Arch, Result, Thread, UserDump};
+use crate::reader::offset_va;
+
+/// Distance between the 64-bit TEB of a WOW64 thread and its 32-bit TEB.
+const WOW64_TEB32_OFFSET: u64 = 0x2000;
+
+/// The `Next` link ending an SEH chain.
+const SEH_CHAIN_END: u32 = 0xFFFF_FFFF;
+
+/// Upper bound on the number of records followed, to survive corrupted chains.
+const MAX_SEH_RECORDS: usize = 0x400;
+
+/// Offset of `SEHandlerTable` in the 32-bit `IMAGE_LOAD_CONFIG_DIRECTORY`.
+const LOAD_CONFIG_SE_HANDLER_TABLE: u64 = 0x40;
+
+/// Upper bound on the number of SafeSEH handlers read from a module.
+const MAX_SAFE_HANDLERS: usize = 0x1_0000;
+
+/// Represents a record of the SEH chain of a 32-bit thread (`EXCEPTION_REGISTRATION_RECORD`).
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SehRecord {
+    /// The address of the record.
+    pub address: u64,
+
+    /// The address of the next record, as stored in `Next`.
+    pub next: u64,
+
+    /// The address of the exception handler.
+    pub handler: u64,
+
+    /// The base address of the module containing the handler.
+    pub module_base: Option<u64>,
+
+    /// Indicates whether the record lies within the stack of the thread.
+    pub on_stack: bool,
+
+    /// Whether the handler is listed in the SafeSEH table of its module: `Some(false)`
+    /// also for modules built with `/NO_SEH`, `None` when the module has no table or
+    /// it was not captured.
+    pub safe_seh: Option<bool>,
+}
+
+impl SehRecord {
+    /// Returns true if the record shows the signs of an overwritten SEH chain: a record
+    /// outside the stack, or a handler outside any module or rejected by SafeSEH.
+    pub fn is_suspicious(&self) -> bool {
+        !self.on_stack || self.module_base.is_none() || self.safe_seh == Some(false)
+    }
+}
+
+impl UserDump<'_> {
+    /// Walks the SEH chain of a 32-bit thread, from `NtTib.ExceptionList` of its TEB.
+    ///
+    /// The 32-bit TEB of a WOW64 thread is used in x64 dumps of WOW64 processes. Vectored
+    /// handlers are kept in an unexported list of `ntdll` with encoded pointers, so they
+    /// are not enumerated.
+    ///
+    /// The walk stops at the end-of-chain marker, at a record missing from the dump and
+    /// at a record already visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread whose chain is walked.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<SehRecord>)` - The records, from the most recently registered.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the dump is neither an x86 dump
+    ///   nor a dump of a WOW64 process.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     for record in dump.seh_chain(thread).unwrap_or_default() {
+    ///         let flag = if record.is_suspicious() { " (suspicious)" } else { "" };
+    ///         println!("{tid}: {:#x} -> {:#x}{flag}", record.address, record.handler);
+    ///     }
+    /// }
+    /// ```
+    pub fn seh_chain(&self, thread: &Thread) -> Result<Vec<SehRecord>> {
+        let teb = match self.system.processor_architecture {
+            Arch::X86 => thread.teb,
+            Arch::X64 if self.is_wow64() => offset_va(thread.teb, WOW64_TEB32_OFFSET)?,
+            arch => return Err(UserDmpError::UnsupportedArchitecture(arch.into())),
+        };
+
+        // `ExceptionList`, `StackBase` and `StackLimit` open the `NT_TIB`.
+        let stack = match (
+            offset_va(teb, 4).and_then(|va| self.read_u32(va)),
+            offset_va(teb, 8).and_then(|va| self.read_u32(va)),
+        ) {
+            (Ok(base), Ok(limit)) => limit as u64..base as u64,
+            _ => 0..0,
+        };
+
+        let mut records = Vec::new();
+        let mut visited = HashSet::new();
+        let Ok(mut link) = self.read_u32(teb) else {
+            return Ok(records);
+        };
+
+        while link != SEH_CHAIN_END && link != 0 && visited.insert(link) && records.len() < MAX_SEH_RECORDS {
+            let address = link as u64;
+            let (Ok(next), Ok(handler)) = (self.read_u32(address), self.read_u32(address + 4)) else {
+                break;
+            };
+
+            let handler = handler as u64;
+            let module_base = self
+                .module_at(handler)
+                .map(|module| module.start_addr());
+            records.push(SehRecord {
+                address,
+                next: next as u64,
+                handler,
+                module_base,
+                on_stack: stack.contains(&address),
+                safe_seh: module_base.and_then(|base| self.safe_seh(base, handler)),
+            });
+
+            link = next;
+        }
+
+        Ok(records)
+    }
+
+    /// Checks a handler against the SafeSEH table of the 32-bit module loaded at `base`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(bool)` - Whether the handler is registered, `false` for `/NO_SEH` images.
+    /// * `None` - If the module is not a 32-bit image, has no SafeSEH table or its table
+    ///   was not captured.
+    fn safe_seh(&self, base: u64, handler: u64) -> Option<bool> {
+        let headers = self.pe_headers(base).ok()?;
+        if headers.is_64bit {
+            return None;
+        }
+
+        if headers.dll_characteristics & IMAGE_DLLCHARACTERISTICS_NO_SEH != 0 {
+            return Some(false);
+        }
+
+        // `SEHandlerTable` and `SEHandlerCount` are only present in large enough directories.
+        let directory = headers.data_directory(IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG)?;
+        let config = base.checked_add(directory.VirtualAddress as u64)?;
+        let size = self
+            .read_u32(config)
+            .ok()?
+            .min(directory.Size) as u64;
+        if directory.VirtualAddress == 0 || size < LOAD_CONFIG_SE_HANDLER_TABLE + 8 {
+            return None;
+        }
+
+        let table = self
+            .read_u32(config.checked_add(LOAD_CONFIG_SE_HANDLER_TABLE)?)
+            .ok()? as u64;
+        let count = self
+            .read_u32(config.checked_add(LOAD_CONFIG_SE_HANDLER_TABLE + 4)?)
+            .ok()? as usize;
+        if table == 0 {
+            return None;
+        }
+
+        let rva = u32::try_from(handler.checked_sub(base)?).ok()?;
+        let bytes = self
+            .read_bytes(table, count.min(MAX_SAFE_HANDLERS) * 4)
+            .ok()?;
+        Some(
+            bytes
+                .as_chunks::<4>()
+                .0
+                .iter()
+                .any(|entry| u32::from_le_bytes(*entry) == rva),
+        )
+    }
+}
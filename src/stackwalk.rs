@@ -0,0 +1,985 @@
+use std::{collections::HashMap, io::Cursor, ops::Range};
+use binrw::BinRead;
+use crate::budget::{Budget, Partial};
+use crate::error::UserDmpError;
+use crate::options::UnmappedReadPolicy;
+use crate::reader::RangeRead;
+use crate::parse::{Arch, MinidumpStream, Module, Result, Thread, ThreadContext, UserDump};
+use crate::data::*;
+
+/// Maximum number of frames produced for a single thread.
+const MAX_FRAMES: usize = 1024;
+
+/// Maximum size of the stack memory attributed to a single frame.
+const MAX_FRAME_STACK: u64 = 0x10000;
+
+/// Maximum number of stack slots searched for a return address when scanning the stack.
+const MAX_SCAN_SLOTS: u64 = 0x200;
+
+/// Describes how the caller of a frame was recovered.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+pub enum FrameTrust {
+    /// The frame comes directly from the thread context.
+    Context,
+
+    /// The frame was recovered using the unwind information of the module.
+    CallFrameInfo,
+
+    /// The frame was recovered using the rule of a code range registered with
+    /// [`UserDump::register_code_range`].
+    CodeRange,
+
+    /// The frame was recovered using a dynamic function table from the `FunctionTableStream`.
+    FunctionTable,
+
+    /// The frame was recovered by following the frame pointer (`RBP`) of the callee, for
+    /// code without unwind information. The result is heuristic.
+    FramePointer,
+
+    /// The frame was recovered by scanning the stack for a plausible return address, for
+    /// code without unwind information. The result is heuristic.
+    Scan,
+}
+
+/// Describes how to recover the caller of a function in runtime-generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnwindRule {
+    /// The functions have x64 unwind information, as registered with `RtlAddFunctionTable`.
+    ///
+    /// The entries are relative to `base`, and the `UNWIND_INFO` structures they point
+    /// to are read from the dump.
+    FunctionTable {
+        /// The address the entries are relative to.
+        base: u64,
+
+        /// The function table.
+        functions: Vec<RUNTIME_FUNCTION>,
+    },
+
+    /// The functions set up a `push rbp; mov rbp, rsp` frame, so the caller is found through `RBP`.
+    ///
+    /// The rule does not hold while the innermost frame is still in its prolog.
+    FramePointer,
+
+    /// The functions do not touch the stack: the return address is at `[RSP]`.
+    Leaf,
+
+    /// The functions allocate a frame of a fixed size below the return address.
+    FixedFrame {
+        /// The distance from `RSP` to the return address, in bytes.
+        size: u64,
+    },
+}
+
+/// A range of runtime-generated code with the rule used to unwind through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeRange {
+    /// The addresses of the code.
+    pub range: Range<u64>,
+
+    /// How the callers of the functions in the range are recovered.
+    pub rule: UnwindRule,
+}
+
+/// A dynamic function table registered by the process, read from the `FunctionTableStream`.
+///
+/// JIT compilers (CLR, V8) register the unwind information of the code they emit with
+/// `RtlAddFunctionTable` or `RtlInstallFunctionTableCallback`, and the dump writer saves
+/// those tables so the generated code can be unwound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionTable {
+    /// The lowest address covered by the table.
+    pub minimum_address: u64,
+
+    /// The highest address covered by the table.
+    pub maximum_address: u64,
+
+    /// The address the entries are relative to.
+    pub base_address: u64,
+
+    /// The entries of the table, sorted by `BeginAddress`.
+    functions: Vec<RUNTIME_FUNCTION>,
+}
+
+impl FunctionTable {
+    /// Returns the addresses covered by the table.
+    pub fn range(&self) -> Range<u64> {
+        self.minimum_address..self.maximum_address
+    }
+
+    /// Returns the entries of the table, sorted by `BeginAddress`.
+    ///
+    /// Only x64 entries are decoded; tables of other architectures are listed without entries.
+    pub fn functions(&self) -> &[RUNTIME_FUNCTION] {
+        &self.functions
+    }
+}
+
+impl MinidumpStream<'_> for FunctionTable {
+    type Output = Vec<FunctionTable>;
+
+    /// Parses the dynamic function tables from the `FunctionTableStream`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the function table stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<FunctionTable>)` - If the tables are parsed successfully.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the function table stream header.
+        let start = cursor.position();
+        let header = MINIDUMP_FUNCTION_TABLE_STREAM::read(cursor)?;
+
+        // Entries of other sizes are FPO_DATA (x86) or packed ARM64 entries.
+        let x64 = header.SizeOfFunctionEntry as usize == size_of::<RUNTIME_FUNCTION>();
+
+        let mut tables = Vec::new();
+        let mut position = start + header.SizeOfHeader as u64 + header.SizeOfAlignPad as u64;
+        for _ in 0..header.NumberOfDescriptors {
+            cursor.set_position(position);
+            let descriptor = MINIDUMP_FUNCTION_TABLE_DESCRIPTOR::read(cursor)?;
+
+            // The entries follow the descriptor and the native descriptor of the table.
+            let entries = position + header.SizeOfDescriptor as u64 + header.SizeOfNativeDescriptor as u64;
+            cursor.set_position(entries);
+
+            let mut functions = Vec::new();
+            if x64 {
+                for _ in 0..descriptor.EntryCount {
+                    functions.push(RUNTIME_FUNCTION::read(cursor)?);
+                }
+
+                functions.sort_by_key(|function| function.BeginAddress);
+            }
+
+            tables.push(FunctionTable {
+                minimum_address: descriptor.MinimumAddress,
+                maximum_address: descriptor.MaximumAddress,
+                base_address: descriptor.BaseAddress,
+                functions,
+            });
+
+            position = entries + descriptor.EntryCount as u64 * header.SizeOfFunctionEntry as u64 + descriptor.SizeOfAlignPad as u64;
+        }
+
+        Ok(tables)
+    }
+}
+
+/// Represents a single frame of a reconstructed call stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// The instruction pointer of the frame.
+    ///
+    /// For the first frame this is the address of the current instruction;
+    /// for the others it is the return address into the function.
+    pub instruction_pointer: u64,
+
+    /// The stack pointer of the frame.
+    pub stack_pointer: u64,
+
+    /// The frame pointer (`RBP`) of the frame.
+    pub frame_pointer: u64,
+
+    /// The address this frame returns to, or `None` for the outermost frame.
+    pub return_address: Option<u64>,
+
+    /// The base address of the module containing the instruction pointer.
+    pub module_base: Option<u64>,
+
+    /// How the frame was recovered.
+    pub trust: FrameTrust,
+
+    /// The stack memory belonging to the frame, from its stack pointer up to the stack
+    /// pointer of its caller, where its arguments and locals live.
+    ///
+    /// The range is bounded to 64 KiB. For the outermost frame it extends to the end
+    /// of the thread's stack.
+    pub stack: Range<u64>,
+}
+
+impl Frame {
+    /// Returns whether the frame was recovered heuristically, by following the frame
+    /// pointer or scanning the stack, so it may be spurious.
+    pub fn is_heuristic(&self) -> bool {
+        matches!(self.trust, FrameTrust::FramePointer | FrameTrust::Scan)
+    }
+
+    /// Returns the module containing the instruction pointer of the frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the frame was produced from.
+    pub fn module<'d, 'a>(&self, dump: &'d UserDump<'a>) -> Option<&'d Module<'a>> {
+        dump.modules().get(&self.module_base?)
+    }
+
+    /// Returns the registered label covering the instruction pointer of the frame, if any.
+    ///
+    /// Useful to name frames in runtime-generated code, which belongs to no module.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the frame was produced from.
+    pub fn label<'d>(&self, dump: &'d UserDump) -> Option<&'d str> {
+        dump.label_at(self.instruction_pointer)
+    }
+
+    /// Reads the stack memory belonging to the frame (see [`Frame::stack`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the frame was produced from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RangeRead)` - The bytes of the frame. Bytes missing from the dump are zero-filled
+    ///   and reported in [`RangeRead::gaps`].
+    /// * `Err(UserDmpError)` - If the range cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let thread = dump.threads().values().next().unwrap();
+    /// for frame in dump.stack_trace(thread).unwrap() {
+    ///     let memory = frame.stack_memory(&dump).unwrap();
+    ///     for (index, slot) in memory.data.as_chunks::<8>().0.iter().enumerate() {
+    ///         println!("  [rsp+{:#x}] {:#018x}", index * 8, u64::from_le_bytes(*slot));
+    ///     }
+    /// }
+    /// ```
+    pub fn stack_memory(&self, dump: &UserDump) -> Result<RangeRead> {
+        let len = self
+            .stack
+            .end
+            .saturating_sub(self.stack.start) as usize;
+        dump.read_range_with(self.stack.start, len, UnmappedReadPolicy::ZeroFill)
+    }
+}
+
+/// Tells whether the bytes before a return address end with an x64 `call` instruction:
+/// `call rel32`, or `call r/m64` in any of its register and memory forms.
+///
+/// # Arguments
+///
+/// * `bytes` - The 7 bytes preceding the return address.
+fn ends_with_call(bytes: &[u8]) -> bool {
+    if bytes.len() < 7 {
+        return false;
+    }
+
+    if bytes[bytes.len() - 5] == 0xE8 {
+        return true;
+    }
+
+    // `FF /2`, whose length depends on the ModRM byte, its SIB byte and the displacement.
+    (2..=7).any(|len| {
+        let start = bytes.len() - len;
+        let modrm = bytes[start + 1];
+        let (mode, reg, rm) = (modrm >> 6, (modrm >> 3) & 7, modrm & 7);
+        if bytes[start] != 0xFF || reg != 2 {
+            return false;
+        }
+
+        let sib = mode != 3 && rm == 4;
+        let base_disp32 = sib
+            && mode == 0
+            && bytes
+                .get(start + 2)
+                .is_some_and(|sib| sib & 7 == 5);
+        let displacement = match mode {
+            1 => 1,
+            2 => 4,
+            0 if rm == 5 || base_disp32 => 4,
+            _ => 0,
+        };
+
+        2 + sib as usize + displacement == len
+    })
+}
+
+/// Returns the number of slots used by an unwind code, including its operands.
+///
+/// # Arguments
+///
+/// * `operation` - The unwind operation code.
+/// * `info` - The operation info of the unwind code.
+fn slot_count(operation: u8, info: usize) -> usize {
+    match operation {
+        UWOP_ALLOC_LARGE if info == 0 => 2,
+        UWOP_ALLOC_LARGE => 3,
+        UWOP_SAVE_NONVOL | UWOP_EPILOG | UWOP_SAVE_XMM128 => 2,
+        UWOP_SAVE_NONVOL_FAR | UWOP_SPARE_CODE | UWOP_SAVE_XMM128_FAR => 3,
+        _ => 1,
+    }
+}
+
+/// Finds the entry covering a relative address in a function table sorted by `BeginAddress`.
+fn find_function(table: &[RUNTIME_FUNCTION], rva: u32) -> Option<RUNTIME_FUNCTION> {
+    let index = table
+        .partition_point(|function| function.BeginAddress <= rva)
+        .checked_sub(1)?;
+    table
+        .get(index)
+        .copied()
+        .filter(|function| rva < function.EndAddress)
+}
+
+/// The integer registers of an x64 thread, indexed by their unwind register number
+/// (`RAX`, `RCX`, `RDX`, `RBX`, `RSP`, `RBP`, `RSI`, `RDI`, `R8`-`R15`).
+#[derive(Copy, Clone, Debug)]
+struct Registers {
+    /// The general purpose registers.
+    gpr: [u64; 16],
+
+    /// The instruction pointer.
+    rip: u64,
+}
+
+impl Registers {
+    /// Index of `RBP` in the register file.
+    const RBP: usize = 5;
+    /// Index of `RSP` in the register file.
+    const RSP: usize = 4;
+
+    /// Returns the stack pointer.
+    fn rsp(&self) -> u64 {
+        self.gpr[Self::RSP]
+    }
+}
+
+/// Reconstructs the call stacks of the threads in a dump.
+///
+/// The walker caches the exception directory of every module it visits,
+/// so reusing it across threads avoids re-reading `.pdata`.
+pub struct StackWalker<'d, 'a> {
+    /// The dump being walked.
+    dump: &'d UserDump<'a>,
+
+    /// The sorted `.pdata` entries of the modules visited so far, by module base.
+    function_tables: HashMap<u64, Vec<RUNTIME_FUNCTION>>,
+}
+
+impl<'d, 'a> StackWalker<'d, 'a> {
+    /// Creates a new [`StackWalker`] for the given dump.
+    pub fn new(dump: &'d UserDump<'a>) -> Self {
+        Self {
+            dump,
+            function_tables: HashMap::new(),
+        }
+    }
+
+    /// Reconstructs the call stack of a thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread to walk.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Frame>)` - The frames, from the innermost to the outermost.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the thread is not an x64 thread.
+    pub fn walk(&mut self, thread: &Thread) -> Result<Vec<Frame>> {
+        self.walk_within(thread, &Budget::unlimited())
+            .map(Partial::into_inner)
+    }
+
+    /// Reconstructs the call stack of a thread, stopping when the budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread to walk.
+    /// * `budget` - Limits how long the walk may run. It is checked before each frame.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Partial<Vec<Frame>>)` - The frames recovered, from the innermost, and whether the walk stopped early.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the thread is not an x64 thread.
+    pub fn walk_within(&mut self, thread: &Thread, budget: &Budget) -> Result<Partial<Vec<Frame>>> {
+        let context = match thread.context() {
+            ThreadContext::X64(context) => context,
+            ThreadContext::X86(_) => return Err(UserDmpError::UnsupportedArchitecture(ARCH_X86)),
+            ThreadContext::Unsupported(arch) => return Err(UserDmpError::UnsupportedArchitecture((*arch).into())),
+        };
+
+        let mut registers = Registers {
+            gpr: [
+                context.Rax,
+                context.Rcx,
+                context.Rdx,
+                context.Rbx,
+                context.Rsp,
+                context.Rbp,
+                context.Rsi,
+                context.Rdi,
+                context.R8,
+                context.R9,
+                context.R10,
+                context.R11,
+                context.R12,
+                context.R13,
+                context.R14,
+                context.R15,
+            ],
+            rip: context.Rip,
+        };
+
+        let stack_end = self.dump.stack_bounds(thread).end;
+        let mut frames = Vec::<Frame>::new();
+        let mut trust = FrameTrust::Context;
+        while frames.len() < MAX_FRAMES && registers.rip != 0 {
+            if budget.is_exhausted() {
+                return Ok(Partial {
+                    value: frames,
+                    timed_out: true,
+                });
+            }
+
+            let module_base = self
+                .dump
+                .module_at(registers.rip)
+                .map(Module::start_addr);
+            let stack_pointer = registers.rsp();
+            frames.push(Frame {
+                instruction_pointer: registers.rip,
+                stack_pointer,
+                frame_pointer: registers.gpr[Registers::RBP],
+                return_address: None,
+                module_base,
+                trust,
+                stack: stack_pointer..stack_end.clamp(stack_pointer, stack_pointer.saturating_add(MAX_FRAME_STACK)),
+            });
+
+            // Stops when the caller cannot be recovered or the stack does not progress.
+            let Some((caller, caller_trust)) = self.unwind(&registers, module_base, trust == FrameTrust::Context, stack_end) else {
+                break;
+            };
+
+            if caller.rip == 0 || caller.rsp() <= registers.rsp() {
+                break;
+            }
+
+            if let Some(frame) = frames.last_mut() {
+                frame.return_address = Some(caller.rip);
+                frame.stack.end = caller.rsp().min(
+                    frame
+                        .stack
+                        .start
+                        .saturating_add(MAX_FRAME_STACK),
+                );
+            }
+
+            registers = caller;
+            trust = caller_trust;
+        }
+
+        Ok(Partial {
+            value: frames,
+            timed_out: false,
+        })
+    }
+
+    /// Computes the register state of the caller of the current frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The register state of the current frame.
+    /// * `module_base` - The base of the module containing the instruction pointer.
+    /// * `innermost` - Whether the frame comes from the thread context rather than from a call.
+    /// * `stack_end` - The upper bound of the thread's stack, 0 if unknown.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((Registers, FrameTrust))` - The registers of the caller and how they were recovered.
+    /// * `None` - If the caller cannot be recovered.
+    fn unwind(&mut self, registers: &Registers, module_base: Option<u64>, innermost: bool, stack_end: u64) -> Option<(Registers, FrameTrust)> {
+        let mut caller = *registers;
+
+        // Return addresses may point just past the end of the calling function.
+        let rip = if innermost { registers.rip } else { registers.rip - 1 };
+
+        // Registered code ranges take precedence, since runtime code is not part of any module.
+        let dump = self.dump;
+        if let Some(code_range) = dump
+            .code_ranges
+            .iter()
+            .find(|code_range| code_range.range.contains(&rip))
+        {
+            return self
+                .apply_rule(registers, &code_range.rule, rip, innermost)
+                .map(|caller| (caller, FrameTrust::CodeRange));
+        }
+
+        // Dynamic function tables saved by the dump writer cover the remaining runtime code.
+        if let Some(table) = dump
+            .function_tables()
+            .iter()
+            .find(|table| table.range().contains(&rip))
+        {
+            return self
+                .apply_function_table(registers, table.base_address, &table.functions, rip, innermost)
+                .map(|caller| (caller, FrameTrust::FunctionTable));
+        }
+
+        if let Some(base) = module_base
+            && self.has_function_table(base)
+        {
+            // Functions without unwind information are leaf functions: the return address is at [RSP].
+            if let Some(function) = self.lookup(base, rip) {
+                let offset = (rip - base).checked_sub(function.BeginAddress as u64)?;
+                if self.apply_unwind_info(&mut caller, base, function, innermost.then_some(offset))? {
+                    return Some((caller, FrameTrust::CallFrameInfo));
+                }
+            }
+
+            caller.rip = self.dump.read_u64(caller.rsp()).ok()?;
+            caller.gpr[Registers::RSP] = caller.rsp().checked_add(8)?;
+            return Some((caller, FrameTrust::CallFrameInfo));
+        }
+
+        // Stripped modules and unregistered runtime code have no unwind information at all.
+        self.frame_pointer_unwind(registers, stack_end)
+            .map(|caller| (caller, FrameTrust::FramePointer))
+            .or_else(|| {
+                self.scan_unwind(registers, stack_end)
+                    .map(|caller| (caller, FrameTrust::Scan))
+            })
+    }
+
+    /// Recovers the caller through a `push rbp; mov rbp, rsp` frame, if `RBP` points to one.
+    ///
+    /// The frame is only accepted if `RBP` lies on the stack above `RSP` and the saved
+    /// return address passes [`StackWalker::is_return_address`].
+    fn frame_pointer_unwind(&self, registers: &Registers, stack_end: u64) -> Option<Registers> {
+        let rbp = registers.gpr[Registers::RBP];
+        let end = if stack_end > registers.rsp() { stack_end } else { u64::MAX };
+        if rbp < registers.rsp() || !rbp.is_multiple_of(8) || rbp.checked_add(16)? > end {
+            return None;
+        }
+
+        let rip = self.dump.read_u64(rbp + 8).ok()?;
+        if !self.is_return_address(rip) {
+            return None;
+        }
+
+        let mut caller = *registers;
+        caller.rip = rip;
+        caller.gpr[Registers::RBP] = self.dump.read_u64(rbp).ok()?;
+        caller.gpr[Registers::RSP] = rbp + 16;
+        Some(caller)
+    }
+
+    /// Recovers the caller by searching the stack upward from `RSP` for the first value
+    /// passing [`StackWalker::is_return_address`].
+    ///
+    /// At most [`MAX_SCAN_SLOTS`] slots are searched. The non-volatile registers of the
+    /// caller cannot be recovered and are left unchanged.
+    fn scan_unwind(&self, registers: &Registers, stack_end: u64) -> Option<Registers> {
+        let rsp = registers.rsp();
+        let end = if stack_end > rsp { stack_end } else { u64::MAX };
+        let (slot, rip) = (0..MAX_SCAN_SLOTS)
+            .map_while(|index| rsp.checked_add(index * 8))
+            .take_while(|slot| slot.saturating_add(8) <= end)
+            .map_while(|slot| Some((slot, self.dump.read_u64(slot).ok()?)))
+            .find(|(_, value)| self.is_return_address(*value))?;
+
+        let mut caller = *registers;
+        caller.rip = rip;
+        caller.gpr[Registers::RSP] = slot.checked_add(8)?;
+        Some(caller)
+    }
+
+    /// Tells whether a value is a plausible return address.
+    ///
+    /// The value must point into a module, a registered code range or a dynamic function
+    /// table, in pages that are executable when their protection is known. When the bytes
+    /// before it were captured, they must also end with a `call` instruction.
+    fn is_return_address(&self, address: u64) -> bool {
+        let dump = self.dump;
+        let in_code = dump.module_at(address).is_some()
+            || dump
+                .code_ranges
+                .iter()
+                .any(|code_range| code_range.range.contains(&address))
+            || dump
+                .function_tables()
+                .iter()
+                .any(|table| table.range().contains(&address));
+        if !in_code
+            || dump
+                .memory_at(address)
+                .is_some_and(|memory| !memory.protect.is_executable())
+        {
+            return false;
+        }
+
+        match address
+            .checked_sub(7)
+            .and_then(|start| dump.read_bytes(start, 7).ok())
+        {
+            Some(bytes) => ends_with_call(&bytes),
+            None => true,
+        }
+    }
+
+    /// Tells whether the module loaded at `base` has an exception directory in the dump.
+    fn has_function_table(&mut self, base: u64) -> bool {
+        let dump = self.dump;
+        !self
+            .function_tables
+            .entry(base)
+            .or_insert_with(|| Self::read_function_table(dump, base))
+            .is_empty()
+    }
+
+    /// Computes the register state of the caller of a function in a registered code range.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The register state of the current frame.
+    /// * `rule` - The rule registered for the code range.
+    /// * `rip` - The instruction pointer, adjusted to fall inside the calling function.
+    /// * `innermost` - Whether the frame comes from the thread context rather than from a call.
+    fn apply_rule(&self, registers: &Registers, rule: &UnwindRule, rip: u64, innermost: bool) -> Option<Registers> {
+        let mut caller = *registers;
+        match rule {
+            UnwindRule::FunctionTable { base, functions } => {
+                return self.apply_function_table(registers, *base, functions, rip, innermost);
+            }
+            UnwindRule::FramePointer => {
+                let rbp = registers.gpr[Registers::RBP];
+                caller.gpr[Registers::RBP] = self.dump.read_u64(rbp).ok()?;
+                caller.gpr[Registers::RSP] = rbp.checked_add(8)?;
+            }
+            UnwindRule::Leaf => {}
+            UnwindRule::FixedFrame { size } => caller.gpr[Registers::RSP] = caller.rsp().checked_add(*size)?,
+        }
+
+        caller.rip = self.dump.read_u64(caller.rsp()).ok()?;
+        caller.gpr[Registers::RSP] = caller.rsp().checked_add(8)?;
+        Some(caller)
+    }
+
+    /// Computes the register state of the caller of a function covered by a function table.
+    ///
+    /// Functions missing from the table are treated as leaf functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The register state of the current frame.
+    /// * `base` - The address the entries of the table are relative to.
+    /// * `functions` - The function table, sorted by `BeginAddress`.
+    /// * `rip` - The instruction pointer, adjusted to fall inside the calling function.
+    /// * `innermost` - Whether the frame comes from the thread context rather than from a call.
+    fn apply_function_table(&self, registers: &Registers, base: u64, functions: &[RUNTIME_FUNCTION], rip: u64, innermost: bool) -> Option<Registers> {
+        let mut caller = *registers;
+        let rva = u32::try_from(rip.checked_sub(base)?).ok()?;
+        if let Some(function) = find_function(functions, rva) {
+            let offset = (rva - function.BeginAddress) as u64;
+            if self.apply_unwind_info(&mut caller, base, function, innermost.then_some(offset))? {
+                return Some(caller);
+            }
+        }
+
+        caller.rip = self.dump.read_u64(caller.rsp()).ok()?;
+        caller.gpr[Registers::RSP] = caller.rsp().checked_add(8)?;
+        Some(caller)
+    }
+
+    /// Applies the unwind codes of a function (and of its chained entries) to the register state.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The register state being unwound.
+    /// * `base` - The base address of the module containing the function.
+    /// * `function` - The `.pdata` entry of the function.
+    /// * `prolog_offset` - The offset of the instruction pointer from the start of the function,
+    ///   used to skip prolog operations that have not executed yet.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` - If a machine frame was popped, so the caller is already complete.
+    /// * `Some(false)` - If the return address still needs to be popped.
+    /// * `None` - If the unwind information could not be read.
+    fn apply_unwind_info(&self, registers: &mut Registers, base: u64, function: RUNTIME_FUNCTION, prolog_offset: Option<u64>) -> Option<bool> {
+        let mut function = function;
+        let mut prolog_offset = prolog_offset;
+
+        // Chains are short in practice; the bound protects against cyclic entries.
+        for _ in 0..32 {
+            let info = base.checked_add(function.UnwindInfoAddress as u64)?;
+            let header = self.dump.read_bytes(info, 4).ok()?;
+            let flags = header[0] >> 3;
+            let size_of_prolog = header[1] as u64;
+            let count = header[2] as usize;
+            let frame_register = (header[3] & 0x0F) as usize;
+            let frame_offset = (header[3] >> 4) as u64;
+
+            let codes = self
+                .dump
+                .read_bytes(info.checked_add(4)?, count * 2)
+                .ok()?;
+            let code = |index: usize| {
+                codes
+                    .get(index * 2..index * 2 + 2)
+                    .map(|slot| u16::from_le_bytes([slot[0], slot[1]]))
+            };
+
+            // Operations after the current prolog position have not executed yet.
+            let executed = |code_offset: u64| match prolog_offset {
+                Some(offset) if offset < size_of_prolog => code_offset <= offset,
+                _ => true,
+            };
+
+            // The frame base used by the save operations.
+            let mut frame = registers.rsp();
+            if frame_register != 0 {
+                let mut fpreg_executed = true;
+                let mut index = 0;
+                while let Some(slot) = code(index) {
+                    if (slot >> 8) as u8 & 0x0F == UWOP_SET_FPREG {
+                        fpreg_executed = executed((slot & 0xFF) as u64);
+                        break;
+                    }
+
+                    index += slot_count((slot >> 8) as u8 & 0x0F, (slot >> 12) as usize);
+                }
+
+                if fpreg_executed {
+                    frame = registers.gpr[frame_register].wrapping_sub(frame_offset * 16);
+                }
+            }
+
+            let mut index = 0;
+            while index < count {
+                let slot = code(index)?;
+                let code_offset = (slot & 0xFF) as u64;
+                let operation = (slot >> 8) as u8 & 0x0F;
+                let info = (slot >> 12) as usize;
+                let apply = executed(code_offset);
+
+                if apply {
+                    match operation {
+                        UWOP_PUSH_NONVOL => {
+                            registers.gpr[info] = self
+                                .dump
+                                .read_u64(registers.rsp())
+                                .ok()?;
+                            registers.gpr[Registers::RSP] = registers.rsp().checked_add(8)?;
+                        }
+                        UWOP_ALLOC_LARGE | UWOP_ALLOC_SMALL => {
+                            let size = match (operation, info) {
+                                (UWOP_ALLOC_LARGE, 0) => code(index + 1)? as u64 * 8,
+                                (UWOP_ALLOC_LARGE, _) => code(index + 1)? as u64 | (code(index + 2)? as u64) << 16,
+                                _ => info as u64 * 8 + 8,
+                            };
+
+                            registers.gpr[Registers::RSP] = registers.rsp().checked_add(size)?;
+                        }
+                        UWOP_SET_FPREG => registers.gpr[Registers::RSP] = frame,
+                        UWOP_SAVE_NONVOL => {
+                            let address = frame.checked_add(code(index + 1)? as u64 * 8)?;
+                            registers.gpr[info] = self.dump.read_u64(address).ok()?;
+                        }
+                        UWOP_SAVE_NONVOL_FAR => {
+                            let address = frame.checked_add(code(index + 1)? as u64 | (code(index + 2)? as u64) << 16)?;
+                            registers.gpr[info] = self.dump.read_u64(address).ok()?;
+                        }
+                        UWOP_PUSH_MACHFRAME => {
+                            // An error code is pushed on top of the machine frame when `info` is 1.
+                            let rsp = registers
+                                .rsp()
+                                .checked_add(if info == 1 { 8 } else { 0 })?;
+                            registers.rip = self.dump.read_u64(rsp).ok()?;
+                            registers.gpr[Registers::RSP] = self
+                                .dump
+                                .read_u64(rsp.checked_add(24)?)
+                                .ok()?;
+                            return Some(true);
+                        }
+                        UWOP_EPILOG | UWOP_SPARE_CODE | UWOP_SAVE_XMM128 | UWOP_SAVE_XMM128_FAR => {}
+                        _ => return None,
+                    }
+                }
+
+                index += slot_count(operation, info);
+            }
+
+            if flags & UNW_FLAG_CHAININFO == 0 {
+                return Some(false);
+            }
+
+            // The chained entry follows the codes, aligned to an even number of slots.
+            let chained = info.checked_add(4 + (count + (count & 1)) as u64 * 2)?;
+            let bytes = self
+                .dump
+                .read_bytes(chained, size_of::<RUNTIME_FUNCTION>())
+                .ok()?;
+            function = RUNTIME_FUNCTION::read(&mut Cursor::new(bytes)).ok()?;
+            prolog_offset = None;
+        }
+
+        None
+    }
+
+    /// Finds the `.pdata` entry covering an instruction pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base address of the module containing `rip`.
+    /// * `rip` - The instruction pointer to look up.
+    fn lookup(&mut self, base: u64, rip: u64) -> Option<RUNTIME_FUNCTION> {
+        let dump = self.dump;
+        let table = self
+            .function_tables
+            .entry(base)
+            .or_insert_with(|| Self::read_function_table(dump, base));
+
+        find_function(table, u32::try_from(rip - base).ok()?)
+    }
+
+    /// Reads and sorts the exception directory of the module loaded at `base`.
+    ///
+    /// Modules whose headers or `.pdata` were not captured yield an empty table.
+    fn read_function_table(dump: &UserDump, base: u64) -> Vec<RUNTIME_FUNCTION> {
+        let Some(directory) = dump
+            .pe_headers(base)
+            .ok()
+            .and_then(|headers| headers.data_directory(IMAGE_DIRECTORY_ENTRY_EXCEPTION))
+        else {
+            return Vec::new();
+        };
+
+        let Some(Ok(bytes)) = base
+            .checked_add(directory.VirtualAddress as u64)
+            .map(|va| dump.read_bytes(va, directory.Size as usize))
+        else {
+            return Vec::new();
+        };
+
+        let mut cursor = Cursor::new(bytes);
+        let mut table = (0..directory.Size as usize / size_of::<RUNTIME_FUNCTION>())
+            .map_while(|_| RUNTIME_FUNCTION::read(&mut cursor).ok())
+            .collect::<Vec<RUNTIME_FUNCTION>>();
+
+        table.sort_by_key(|function| function.BeginAddress);
+        table
+    }
+}
+
+impl UserDump<'_> {
+    /// Reconstructs the call stack of a thread.
+    ///
+    /// Frames are recovered with the x64 unwind information (`.pdata` and `UNWIND_INFO`)
+    /// read from the module images captured in the dump. Functions without unwind
+    /// information are treated as leaf functions.
+    ///
+    /// Code with no unwind information at all (modules whose exception directory was
+    /// stripped or not captured, and runtime code not covered by a registered code range)
+    /// falls back to following the frame pointer, then to scanning the stack for a return
+    /// address. Such frames are flagged by [`Frame::is_heuristic`].
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread to walk.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Frame>)` - The frames, from the innermost to the outermost.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the dump is not an x64 dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     println!("Thread {tid}");
+    ///     for frame in dump.stack_trace(thread).unwrap_or_default() {
+    ///         let module = frame.module(&dump).and_then(|module| module.name()).unwrap_or("???");
+    ///         println!("  {:#018x} {module}", frame.instruction_pointer);
+    ///     }
+    /// }
+    /// ```
+    pub fn stack_trace(&self, thread: &Thread) -> Result<Vec<Frame>> {
+        if self.system.processor_architecture != Arch::X64 {
+            return Err(UserDmpError::UnsupportedArchitecture(
+                self.system
+                    .processor_architecture
+                    .into(),
+            ));
+        }
+
+        StackWalker::new(self).walk(thread)
+    }
+
+    /// Reconstructs the call stack of a thread, stopping when the budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The thread to walk.
+    /// * `budget` - Limits how long the walk may run.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Partial<Vec<Frame>>)` - The frames recovered, from the innermost, and whether the walk stopped early.
+    /// * `Err(UserDmpError::UnsupportedArchitecture)` - If the dump is not an x64 dump.
+    pub fn stack_trace_within(&self, thread: &Thread, budget: &Budget) -> Result<Partial<Vec<Frame>>> {
+        if self.system.processor_architecture != Arch::X64 {
+            return Err(UserDmpError::UnsupportedArchitecture(
+                self.system
+                    .processor_architecture
+                    .into(),
+            ));
+        }
+
+        StackWalker::new(self).walk_within(thread, budget)
+    }
+
+    /// Registers a range of runtime-generated code with the rule used to unwind through it.
+    ///
+    /// JIT compilers emit code outside of any module, so the stack walker has no unwind
+    /// information for it and would otherwise stop or misread the stack there. Ranges
+    /// registered later take precedence when they overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The addresses of the code.
+    /// * `rule` - How the callers of the functions in the range are recovered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, stackwalk::UnwindRule};
+    ///
+    /// let mut dump = UserDump::new("example.dmp").unwrap();
+    /// dump.register_code_range(0x1f_0000_0000..0x1f_0010_0000, UnwindRule::FramePointer);
+    ///
+    /// let thread = dump.threads().values().next().unwrap();
+    /// let frames = dump.stack_trace(thread).unwrap();
+    /// ```
+    pub fn register_code_range(&mut self, range: Range<u64>, rule: UnwindRule) {
+        let rule = match rule {
+            UnwindRule::FunctionTable { base, mut functions } => {
+                functions.sort_by_key(|function| function.BeginAddress);
+                UnwindRule::FunctionTable { base, functions }
+            }
+            rule => rule,
+        };
+
+        self.code_ranges
+            .insert(0, CodeRange { range, rule });
+    }
+
+    /// Returns the code ranges registered with [`UserDump::register_code_range`], the most recent first.
+    pub fn code_ranges(&self) -> &[CodeRange] {
+        &self.code_ranges
+    }
+}
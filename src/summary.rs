@@ -0,0 +1,343 @@
+use std::{collections::BTreeMap, fmt, fmt::Write};
+use binrw::BinRead;
+use crate::data::MINIDUMP_HEADER;
+use crate::memory::MemoryType;
+use crate::os::OsInfo;
+use crate::parse::{ModuleOrder, UserDump};
+use crate::report::{json_option, json_string};
+
+/// Number of allocations listed in [`Summary::top_consumers`].
+const TOP_CONSUMERS: usize = 5;
+
+/// An allocation of the process, with the memory it commits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryConsumer {
+    /// The base address of the allocation.
+    pub allocation_base: u64,
+
+    /// The committed size of the allocation, in bytes.
+    pub committed: u64,
+
+    /// What the allocation holds (e.g., a registered label, a module name, a thread stack or `heap`).
+    pub label: String,
+}
+
+/// A compact, one-screen overview of a dump.
+///
+/// The summary renders as a short block of text through [`fmt::Display`], and as a
+/// single JSON object through [`Summary::to_json`] for dashboards.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::UserDump;
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let summary = dump.summary();
+/// println!("{summary}");
+/// println!("{}", summary.to_json());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// The identifier of the captured process, if recorded.
+    pub process_id: Option<u32>,
+
+    /// The path of the main executable, the first module in load order.
+    pub image: Option<String>,
+
+    /// The command line of the process, read from its parameters.
+    pub command_line: Option<String>,
+
+    /// The operating system and processor architecture.
+    pub os: OsInfo,
+
+    /// The time the dump was written, as a 32-bit UNIX time value.
+    pub captured_at: Option<u32>,
+
+    /// A one-line description of the exception, if the dump holds one.
+    pub exception: Option<String>,
+
+    /// The number of threads.
+    pub threads: usize,
+
+    /// The number of modules.
+    pub modules: usize,
+
+    /// The number of handles.
+    pub handles: usize,
+
+    /// The number of memory regions.
+    pub regions: usize,
+
+    /// The committed memory of the process, in bytes.
+    pub committed_memory: u64,
+
+    /// The allocations committing the most memory, the largest first.
+    pub top_consumers: Vec<MemoryConsumer>,
+}
+
+impl Summary {
+    /// Renders the summary as a single JSON object.
+    ///
+    /// Missing values are written as `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        let _ = write!(json, "\"process_id\":{},", json_option(self.process_id));
+        let _ = write!(json, "\"image\":{},", json_option(self.image.as_deref().map(json_string)));
+        let _ = write!(
+            json,
+            "\"command_line\":{},",
+            json_option(
+                self.command_line
+                    .as_deref()
+                    .map(json_string)
+            )
+        );
+        let _ = write!(json, "\"os\":{},", json_string(&self.os.name));
+        let _ = write!(json, "\"os_version\":{},", json_string(&self.os.version()));
+        let _ = write!(json, "\"arch\":{},", json_string(&format!("{:?}", self.os.arch)));
+        let _ = write!(json, "\"captured_at\":{},", json_option(self.captured_at));
+        let _ = write!(
+            json,
+            "\"exception\":{},",
+            json_option(
+                self.exception
+                    .as_deref()
+                    .map(json_string)
+            )
+        );
+        let _ = write!(json, "\"threads\":{},", self.threads);
+        let _ = write!(json, "\"modules\":{},", self.modules);
+        let _ = write!(json, "\"handles\":{},", self.handles);
+        let _ = write!(json, "\"regions\":{},", self.regions);
+        let _ = write!(json, "\"committed_memory\":{},", self.committed_memory);
+
+        json.push_str("\"top_consumers\":[");
+        for (index, consumer) in self.top_consumers.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                "{{\"allocation_base\":{},\"committed\":{},\"label\":{}}}",
+                consumer.allocation_base,
+                consumer.committed,
+                json_string(&consumer.label)
+            );
+        }
+
+        json.push_str("]}");
+        json
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unknown = || "<unknown>".to_string();
+
+        writeln!(
+            f,
+            "process:   {} (pid {})",
+            self.image
+                .clone()
+                .unwrap_or_else(unknown),
+            self.process_id
+                .map_or_else(unknown, |pid| pid.to_string())
+        )?;
+
+        if let Some(command_line) = &self.command_line {
+            writeln!(f, "command:   {command_line}")?;
+        }
+
+        writeln!(f, "os:        {}", self.os)?;
+        writeln!(
+            f,
+            "captured:  {}",
+            self.captured_at
+                .map_or_else(unknown, format_unix_time)
+        )?;
+        writeln!(
+            f,
+            "exception: {}",
+            self.exception
+                .as_deref()
+                .unwrap_or("none")
+        )?;
+        writeln!(
+            f,
+            "counts:    {} threads, {} modules, {} handles, {} regions",
+            self.threads, self.modules, self.handles, self.regions
+        )?;
+
+        writeln!(f, "committed: {:#x} bytes", self.committed_memory)?;
+        for consumer in &self.top_consumers {
+            writeln!(f, "  {:#018x} {:>#12x}  {}", consumer.allocation_base, consumer.committed, consumer.label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl UserDump<'_> {
+    /// Summarizes the dump in a single [`Summary`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// print!("{}", dump.summary());
+    /// ```
+    pub fn summary(&self) -> Summary {
+        let process = self.process();
+        let image = self
+            .modules_sorted(ModuleOrder::LoadOrder)
+            .first()
+            .and_then(|module| module.path.to_str())
+            .map(str::to_string)
+            .or_else(|| {
+                process
+                    .as_ref()
+                    .and_then(|process| process.image_path())
+            });
+
+        let captured_at = MINIDUMP_HEADER::read(&mut self.mapped_file.cursor())
+            .ok()
+            .map(|header| header.TimeDateStamp)
+            .filter(|&time| time != 0);
+
+        let committed_memory = self
+            .memorys()
+            .values()
+            .filter(|memory| memory.state.is_committed())
+            .map(|memory| memory.range.end - memory.range.start)
+            .sum();
+
+        Summary {
+            process_id: self
+                .misc_info()
+                .and_then(|misc| misc.process_id),
+            image,
+            command_line: process.and_then(|process| process.command_line()),
+            os: self.os_info(),
+            captured_at,
+            exception: self.exception_line(),
+            threads: self.threads().len(),
+            modules: self.modules().len(),
+            handles: self.handles().len(),
+            regions: self.memorys().len(),
+            committed_memory,
+            top_consumers: self.top_consumers(),
+        }
+    }
+
+    /// Describes the exception in one line, with its code, its address and the faulting thread.
+    fn exception_line(&self) -> Option<String> {
+        let exception = self.exception()?;
+        let mut line = format!("{:#010x}", exception.code);
+        if let Some(description) = exception.exception_code().description() {
+            match exception.access_violation() {
+                Some(violation) => {
+                    let _ = write!(line, " ({description}, {violation})");
+                }
+                None => {
+                    let _ = write!(line, " ({description})");
+                }
+            }
+        }
+
+        let _ = write!(line, " at {:#x}", exception.address);
+        if let Some(module) = self.module_at(exception.address) {
+            let _ = write!(line, " ({}+{:#x})", module.name().unwrap_or("<unknown>"), exception.address - module.start_addr());
+        } else if let Some(label) = self.label_at(exception.address) {
+            let _ = write!(line, " ({label})");
+        }
+
+        let _ = write!(line, " on thread {}", exception.thread_id);
+        Some(line)
+    }
+
+    /// Groups the committed regions by allocation and returns the largest allocations.
+    fn top_consumers(&self) -> Vec<MemoryConsumer> {
+        let mut allocations = BTreeMap::<u64, (u64, MemoryType, Option<String>)>::new();
+        for memory in self
+            .memorys()
+            .values()
+            .filter(|memory| memory.state.is_committed())
+        {
+            let entry = allocations
+                .entry(memory.allocation_base)
+                .or_insert((0, memory.type_, None));
+            entry.0 += memory.range.end - memory.range.start;
+            if let Some(owner) = memory.owner() {
+                entry
+                    .2
+                    .get_or_insert_with(|| owner.to_string());
+            }
+        }
+
+        let heaps = self
+            .heaps()
+            .into_iter()
+            .map(|heap| heap.base)
+            .collect::<Vec<_>>();
+        let mut consumers = allocations
+            .into_iter()
+            .map(|(allocation_base, (committed, type_, owner))| {
+                let label = self
+                    .label_at(allocation_base)
+                    .or_else(|| {
+                        self.module_at(allocation_base)
+                            .and_then(|module| module.name())
+                    })
+                    .map(str::to_string)
+                    .or(owner)
+                    .unwrap_or_else(|| {
+                        match type_ {
+                            _ if heaps.contains(&allocation_base) => "heap",
+                            _ if type_.is_image() => "image",
+                            _ if type_.is_private() => "private",
+                            _ => "mapped",
+                        }
+                        .to_string()
+                    });
+
+                MemoryConsumer {
+                    allocation_base,
+                    committed,
+                    label,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        consumers.sort_by_key(|consumer| std::cmp::Reverse(consumer.committed));
+        consumers.truncate(TOP_CONSUMERS);
+        consumers
+    }
+}
+
+/// Formats a UNIX time value as `YYYY-MM-DD HH:MM:SS UTC`.
+fn format_unix_time(time: u32) -> String {
+    let days = (time / 86_400) as i64;
+    let seconds = time % 86_400;
+
+    // Converts days since 1970-01-01 to a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
@@ -0,0 +1,458 @@
+use std::fmt;
+use crate::parse::{Module, UserDump};
+use crate::reader::decode_utf16;
+
+/// Signature of `ACTIVATION_CONTEXT_DATA` (`xtcA`).
+const ACTIVATION_CONTEXT_DATA_MAGIC: u32 = 0x4163_7478;
+
+/// The roster entry does not describe an assembly.
+const ROSTER_ENTRY_INVALID: u32 = 0x1;
+
+/// Size of `ACTIVATION_CONTEXT_DATA_ASSEMBLY_ROSTER_ENTRY`.
+const ROSTER_ENTRY_SIZE: u32 = 0x18;
+
+/// Upper bound on the size of an activation context, to survive corrupted headers.
+const MAX_CONTEXT_SIZE: u32 = 0x100_0000;
+
+/// Upper bound on the number of assemblies read from an activation context.
+const MAX_ASSEMBLIES: u32 = 0x1000;
+
+/// Path component under which side-by-side assemblies are installed.
+const WINSXS_DIRECTORY: &str = "\\winsxs\\";
+
+/// The DLLs mapped from the `\KnownDlls` object directory on Windows 10 and 11.
+///
+/// The loader maps these from pre-built sections instead of searching for them, so
+/// they always come from the system directory whatever the search order says.
+const KNOWN_DLLS: [&str; 41] = [
+    "advapi32.dll",
+    "bcryptprimitives.dll",
+    "cfgmgr32.dll",
+    "clbcatq.dll",
+    "combase.dll",
+    "comdlg32.dll",
+    "coml2.dll",
+    "difxapi.dll",
+    "gdi32.dll",
+    "gdi32full.dll",
+    "gdiplus.dll",
+    "imagehlp.dll",
+    "imm32.dll",
+    "kernel.appcore.dll",
+    "kernel32.dll",
+    "kernelbase.dll",
+    "msctf.dll",
+    "msvcp_win.dll",
+    "msvcrt.dll",
+    "normaliz.dll",
+    "nsi.dll",
+    "ntdll.dll",
+    "ole32.dll",
+    "oleaut32.dll",
+    "psapi.dll",
+    "rpcrt4.dll",
+    "sechost.dll",
+    "setupapi.dll",
+    "shcore.dll",
+    "shell32.dll",
+    "shlwapi.dll",
+    "ucrtbase.dll",
+    "user32.dll",
+    "win32u.dll",
+    "wintrust.dll",
+    "wldap32.dll",
+    "wow64.dll",
+    "wow64cpu.dll",
+    "wow64win.dll",
+    "ws2_32.dll",
+    "wsock32.dll",
+];
+
+/// The identity of a side-by-side assembly: its name and attributes such as its version.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyIdentity {
+    /// The name of the assembly (e.g., `Microsoft.Windows.Common-Controls`).
+    pub name: String,
+
+    /// The attributes of the identity, in the order they were encoded.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl AssemblyIdentity {
+    /// Returns the value of an attribute, matched case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the attribute (e.g., `publicKeyToken`).
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the version of the assembly (e.g., `6.0.22621.2506`).
+    pub fn version(&self) -> Option<&str> {
+        self.attribute("version")
+    }
+
+    /// Returns the processor architecture of the assembly (e.g., `amd64`).
+    pub fn processor_architecture(&self) -> Option<&str> {
+        self.attribute("processorArchitecture")
+    }
+
+    /// Returns the public key token of the assembly publisher.
+    pub fn public_key_token(&self) -> Option<&str> {
+        self.attribute("publicKeyToken")
+    }
+
+    /// Returns the language of the assembly, `*` for language-neutral assemblies.
+    pub fn language(&self) -> Option<&str> {
+        self.attribute("language")
+    }
+
+    /// Decodes a textual identity, as stored in activation contexts
+    /// (`name,attribute="value",...`).
+    fn parse(text: &str) -> Option<Self> {
+        let mut fields = split_identity(text).into_iter();
+        let name = fields
+            .next()
+            .filter(|name| !name.is_empty() && !name.contains('='))?;
+
+        let attributes = fields
+            .filter_map(|field| {
+                let (attribute, value) = field.split_once('=')?;
+                Some((
+                    attribute.trim().to_string(),
+                    value
+                        .trim()
+                        .trim_matches('"')
+                        .to_string(),
+                ))
+            })
+            .collect();
+
+        Some(Self { name, attributes })
+    }
+
+    /// Recovers the identity of an assembly from the name of its `WinSxS` directory
+    /// (`<architecture>_<name>_<token>_<version>_<language>_<hash>`).
+    fn from_directory(directory: &str) -> Option<Self> {
+        let mut fields = directory.rsplitn(5, '_');
+        let (_hash, language, version, token, rest) = (fields.next()?, fields.next()?, fields.next()?, fields.next()?, fields.next()?);
+        let (architecture, name) = rest.split_once('_')?;
+        if name.is_empty() || !version.contains('.') {
+            return None;
+        }
+
+        let language = if language == "none" { "*" } else { language };
+        Some(Self {
+            name: name.to_string(),
+            attributes: vec![
+                ("processorArchitecture".to_string(), architecture.to_string()),
+                ("publicKeyToken".to_string(), token.to_string()),
+                ("version".to_string(), version.to_string()),
+                ("language".to_string(), language.to_string()),
+            ],
+        })
+    }
+}
+
+impl fmt::Display for AssemblyIdentity {
+    /// Writes the identity in its textual form (`name,attribute="value",...`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        for (attribute, value) in &self.attributes {
+            write!(f, ",{attribute}=\"{value}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Identifies the activation context an assembly was found in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ActivationContextKind {
+    /// `PEB.ActivationContextData`, built from the manifest of the executable.
+    Process,
+
+    /// `PEB.SystemDefaultActivationContextData`, shared by every process of the system.
+    SystemDefault,
+}
+
+impl fmt::Display for ActivationContextKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActivationContextKind::Process => write!(f, "process"),
+            ActivationContextKind::SystemDefault => write!(f, "system default"),
+        }
+    }
+}
+
+/// A side-by-side assembly bound by an activation context of the process.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assembly {
+    /// The identity of the assembly.
+    pub identity: AssemblyIdentity,
+
+    /// The activation context the assembly was found in.
+    pub context: ActivationContextKind,
+
+    /// The path of the manifest the assembly was bound from.
+    pub manifest_path: Option<String>,
+
+    /// The path of the publisher policy that redirected the version, if any.
+    pub policy_path: Option<String>,
+
+    /// The name of the directory of the assembly under `WinSxS`, for system assemblies.
+    pub directory: Option<String>,
+}
+
+/// Describes how the loader resolved a module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleResolution {
+    /// The module is one of the `KnownDLLs`, mapped from the system directory.
+    KnownDll,
+
+    /// The module was redirected to a side-by-side assembly.
+    SideBySide(AssemblyIdentity),
+
+    /// The module was found through the DLL search order.
+    SearchOrder,
+}
+
+impl fmt::Display for ModuleResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModuleResolution::KnownDll => write!(f, "KnownDLL"),
+            ModuleResolution::SideBySide(identity) => write!(f, "side-by-side {identity}"),
+            ModuleResolution::SearchOrder => write!(f, "search order"),
+        }
+    }
+}
+
+impl<'a> UserDump<'a> {
+    /// Returns the side-by-side assemblies bound by the activation contexts of the process.
+    ///
+    /// The assemblies of the context built from the manifest of the executable come
+    /// first, followed by those of the system default context. Contexts whose pages
+    /// were not captured are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for assembly in dump.assemblies() {
+    ///     println!("{} {}", assembly.identity.name, assembly.identity.version().unwrap_or("?"));
+    /// }
+    /// ```
+    pub fn assemblies(&self) -> Vec<Assembly> {
+        let Some(process) = self.process() else {
+            return Vec::new();
+        };
+
+        [
+            (process.activation_context_data(), ActivationContextKind::Process),
+            (process.system_default_activation_context_data(), ActivationContextKind::SystemDefault),
+        ]
+        .into_iter()
+        .filter_map(|(base, context)| Some((base?, context)))
+        .flat_map(|(base, context)| {
+            ActivationContext::new(self, base)
+                .map(|data| data.assemblies(context))
+                .unwrap_or_default()
+        })
+        .collect()
+    }
+
+    /// Tells how the loader resolved a module: from the `KnownDLLs`, through a
+    /// side-by-side assembly, or through the DLL search order.
+    ///
+    /// Modules loaded from `WinSxS` are matched against the assemblies of the activation
+    /// contexts; when the contexts were not captured, the identity is recovered from the
+    /// name of the assembly directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - A module of the dump.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, sxs::ModuleResolution};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     if let ModuleResolution::SideBySide(identity) = dump.module_resolution(module) {
+    ///         println!("{} from {identity}", module.name().unwrap_or("?"));
+    ///     }
+    /// }
+    /// ```
+    pub fn module_resolution(&self, module: &Module) -> ModuleResolution {
+        let path = module
+            .path
+            .to_string_lossy()
+            .to_lowercase()
+            .replace('/', "\\");
+
+        if let Some(index) = path.find(WINSXS_DIRECTORY) {
+            let rest = &path[index + WINSXS_DIRECTORY.len()..];
+            if let Some((directory, _)) = rest.split_once('\\') {
+                let identity = self
+                    .assemblies()
+                    .into_iter()
+                    .find(|assembly| {
+                        assembly
+                            .directory
+                            .as_deref()
+                            .is_some_and(|name| name.eq_ignore_ascii_case(directory))
+                    })
+                    .map(|assembly| assembly.identity)
+                    .or_else(|| AssemblyIdentity::from_directory(directory));
+
+                if let Some(identity) = identity {
+                    return ModuleResolution::SideBySide(identity);
+                }
+            }
+        }
+
+        let known = module
+            .name()
+            .is_some_and(|name| KNOWN_DLLS.contains(&name.to_lowercase().as_str()));
+        let system = ["\\system32\\", "\\syswow64\\"]
+            .iter()
+            .any(|directory| path.contains(directory));
+
+        if known && system {
+            ModuleResolution::KnownDll
+        } else {
+            ModuleResolution::SearchOrder
+        }
+    }
+
+    /// Returns the modules loaded from side-by-side assemblies, with the identity of their assembly.
+    pub fn side_by_side_modules(&self) -> Vec<(&Module<'a>, AssemblyIdentity)> {
+        self.modules()
+            .values()
+            .filter_map(|module| match self.module_resolution(module) {
+                ModuleResolution::SideBySide(identity) => Some((module, identity)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Reads the fields of an `ACTIVATION_CONTEXT_DATA` blob, within its `TotalSize`.
+struct ActivationContext<'d, 'a> {
+    /// The dump holding the context.
+    dump: &'d UserDump<'a>,
+
+    /// The address of the context.
+    base: u64,
+
+    /// The size of the context, in bytes.
+    size: u32,
+}
+
+impl<'d, 'a> ActivationContext<'d, 'a> {
+    /// Checks the header of the context at `base`.
+    fn new(dump: &'d UserDump<'a>, base: u64) -> Option<Self> {
+        let magic = dump.read_u32(base).ok()?;
+        let size = dump.read_u32(base + 0xC).ok()?;
+        (magic == ACTIVATION_CONTEXT_DATA_MAGIC && size <= MAX_CONTEXT_SIZE).then_some(Self { dump, base, size })
+    }
+
+    /// Reads a `ULONG` at an offset of the context.
+    fn u32(&self, offset: u32) -> Option<u32> {
+        if offset.checked_add(4)? > self.size {
+            return None;
+        }
+
+        self.dump
+            .read_u32(self.base + offset as u64)
+            .ok()
+    }
+
+    /// Reads a UTF-16 string of `length` bytes at an offset of the context.
+    fn string(&self, offset: u32, length: u32) -> Option<String> {
+        if length == 0 || offset.checked_add(length)? > self.size {
+            return None;
+        }
+
+        let bytes = self
+            .dump
+            .read_bytes(self.base + offset as u64, length as usize)
+            .ok()?;
+        Some(
+            decode_utf16(&bytes)
+                .trim_end_matches('\0')
+                .to_string(),
+        )
+    }
+
+    /// Walks the assembly roster of the context.
+    fn assemblies(&self, context: ActivationContextKind) -> Vec<Assembly> {
+        let Some(roster) = self.u32(0x18) else {
+            return Vec::new();
+        };
+
+        let (Some(count), Some(first_entry), Some(section)) = (self.u32(roster + 0x8), self.u32(roster + 0xC), self.u32(roster + 0x10)) else {
+            return Vec::new();
+        };
+
+        // The first entry is reserved.
+        (1..count.min(MAX_ASSEMBLIES))
+            .filter_map(|index| {
+                let entry = first_entry.checked_add(index * ROSTER_ENTRY_SIZE)?;
+                if self.u32(entry)? & ROSTER_ENTRY_INVALID != 0 {
+                    return None;
+                }
+
+                self.assembly(self.u32(entry + 0x10)?, section, context)
+            })
+            .collect()
+    }
+
+    /// Decodes an `ACTIVATION_CONTEXT_DATA_ASSEMBLY_INFORMATION` structure, whose strings
+    /// are relative to the assembly information section.
+    fn assembly(&self, info: u32, section: u32, context: ActivationContextKind) -> Option<Assembly> {
+        let string =
+            |length: u32, offset: u32| self.string(section.checked_add(self.u32(info.checked_add(offset)?)?)?, self.u32(info.checked_add(length)?)?);
+
+        Some(Assembly {
+            identity: AssemblyIdentity::parse(&string(0x08, 0x0C)?)?,
+            context,
+            manifest_path: string(0x14, 0x18),
+            policy_path: string(0x2C, 0x30),
+            directory: string(0x58, 0x5C),
+        })
+    }
+}
+
+/// Splits a textual identity on the commas outside quoted values.
+fn split_identity(text: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                fields.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some(field) = fields.last_mut() {
+            field.push(c);
+        }
+    }
+
+    fields
+}
@@ -0,0 +1,486 @@
+use core::fmt;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::PathBuf,
+    time::Duration,
+};
+use pdb::FallibleIterator;
+use crate::codeview::DebugInfo;
+use crate::error::UserDmpError;
+use crate::parse::{Module, Result, UserDump};
+use crate::stackwalk::{Frame, FrameTrust};
+
+/// The public Microsoft symbol server.
+pub const MICROSOFT_SYMBOL_SERVER: &str = "https://msdl.microsoft.com/download/symbols";
+
+/// Timeout of a single symbol server request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Downloads PDBs and binaries from symbol servers into a local cache.
+///
+/// Files are looked up with the symbol server keys: the PDB GUID and age for PDBs, and
+/// the link timestamp and image size for binaries. The cache uses the same
+/// `<name>/<key>/<name>` layout as the servers, so it can be shared with debuggers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, symsrv::SymbolServer};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let server = SymbolServer::microsoft("C:\\symbols");
+/// for module in dump.modules().values() {
+///     if let Some(info) = module.debug_info() {
+///         println!("{:?}", server.fetch_pdb(&info));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SymbolServer {
+    /// The base URLs of the servers, queried in order.
+    servers: Vec<String>,
+
+    /// The directory downloaded files are stored in.
+    cache: PathBuf,
+
+    /// The HTTP client.
+    agent: ureq::Agent,
+}
+
+impl SymbolServer {
+    /// Creates a new [`SymbolServer`] with no server, only serving files already in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The directory downloaded files are stored in.
+    pub fn new(cache: impl Into<PathBuf>) -> Self {
+        Self {
+            servers: Vec::new(),
+            cache: cache.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(REQUEST_TIMEOUT)
+                .build(),
+        }
+    }
+
+    /// Creates a new [`SymbolServer`] querying the public Microsoft symbol server.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The directory downloaded files are stored in.
+    pub fn microsoft(cache: impl Into<PathBuf>) -> Self {
+        Self::new(cache).server(MICROSOFT_SYMBOL_SERVER)
+    }
+
+    /// Adds a server, queried after the ones already added.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base URL of the server (e.g., `https://symbols.example.com`).
+    pub fn server(mut self, url: impl Into<String>) -> Self {
+        let url = url.into();
+        self.servers
+            .push(url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Fetches the PDB described by a CodeView record.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The debug information of the module (see [`Module::debug_info`]).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathBuf)` - The path of the PDB in the cache.
+    /// * `Err(UserDmpError::SymbolNotFound)` - If no server holds the PDB.
+    /// * `Err(UserDmpError)` - If a request or writing to the cache failed.
+    pub fn fetch_pdb(&self, info: &DebugInfo) -> Result<PathBuf> {
+        self.fetch(info.pdb_name(), &info.debug_id())
+    }
+
+    /// Fetches the binary of a module, for modules whose code was not captured in the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module to fetch.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathBuf)` - The path of the binary in the cache.
+    /// * `Err(UserDmpError::SymbolNotFound)` - If no server holds the binary.
+    /// * `Err(UserDmpError)` - If the module has no name, a request or writing to the cache failed.
+    pub fn fetch_binary(&self, module: &Module) -> Result<PathBuf> {
+        let name = module
+            .name()
+            .ok_or(UserDmpError::ModuleNotFound(module.start_addr()))?;
+        self.fetch(name, &format!("{:08X}{:x}", module.time_date_stamp, module.len()))
+    }
+
+    /// Returns the cached copy of a file, downloading it first if needed.
+    fn fetch(&self, name: &str, key: &str) -> Result<PathBuf> {
+        // Names come from the dump, so they must not escape the cache directory.
+        if name.is_empty() || name == "." || name == ".." || name.contains(['\\', '/']) {
+            return Err(UserDmpError::SymbolNotFound(name.to_string()));
+        }
+
+        let relative = format!("{name}/{key}/{name}");
+        let path = self
+            .cache
+            .join(name)
+            .join(key)
+            .join(name);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        for server in &self.servers {
+            let response = match self
+                .agent
+                .get(&format!("{server}/{relative}"))
+                .call()
+            {
+                Ok(response) => response,
+                Err(ureq::Error::Status(404, _)) => continue,
+                Err(error) => return Err(UserDmpError::SymbolServer(error.to_string())),
+            };
+
+            // Downloads next to the final path, so an interrupted transfer never looks cached.
+            fs::create_dir_all(path.parent().unwrap_or(&self.cache))?;
+            let partial = path.with_extension("partial");
+            io::copy(&mut response.into_reader(), &mut fs::File::create(&partial)?)?;
+            fs::rename(&partial, &path)?;
+            return Ok(path);
+        }
+
+        Err(UserDmpError::SymbolNotFound(relative))
+    }
+}
+
+/// Represents the source file and line an address was compiled from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SourceLocation {
+    /// The path of the source file, as recorded by the compiler.
+    pub file: String,
+
+    /// The line number, starting at 1.
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+/// A range of code compiled from a single source line.
+#[derive(Debug, Clone, Copy)]
+struct LineRecord {
+    /// The length of the code, or `None` if it extends to the next record.
+    len: Option<u32>,
+
+    /// The index of the source file in [`SymbolTable::files`].
+    file: usize,
+
+    /// The line number.
+    line: u32,
+}
+
+/// The public function symbols and line information of a PDB, indexed by address relative
+/// to the image base.
+///
+/// Public PDBs, such as the ones of Windows components, carry no line information.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    /// The symbol names, indexed by relative virtual address.
+    symbols: BTreeMap<u32, String>,
+
+    /// The line records, indexed by relative virtual address.
+    lines: BTreeMap<u32, LineRecord>,
+
+    /// The paths of the source files referenced by the line records.
+    files: Vec<String>,
+}
+
+impl SymbolTable {
+    /// Reads the public function symbols and the line information of a PDB file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the PDB.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SymbolTable)` - The symbols of the PDB.
+    /// * `Err(UserDmpError::InvalidPdb)` - If the file is not a valid PDB.
+    pub fn from_pdb(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let invalid = |error: pdb::Error| UserDmpError::InvalidPdb(error.to_string());
+        let mut pdb = pdb::PDB::open(fs::File::open(path)?).map_err(invalid)?;
+        let globals = pdb.global_symbols().map_err(invalid)?;
+        let address_map = pdb.address_map().map_err(invalid)?;
+
+        let mut symbols = BTreeMap::new();
+        let mut iter = globals.iter();
+        while let Some(symbol) = iter.next().map_err(invalid)? {
+            if let Ok(pdb::SymbolData::Public(public)) = symbol.parse()
+                && public.function
+                && let Some(rva) = public.offset.to_rva(&address_map)
+            {
+                symbols.insert(rva.0, public.name.to_string().into_owned());
+            }
+        }
+
+        let mut table = Self { symbols, ..Self::default() };
+
+        // Line information lives in the module streams, and needs the names from the string table.
+        let Ok(strings) = pdb.string_table() else {
+            return Ok(table);
+        };
+
+        let debug_information = pdb
+            .debug_information()
+            .map_err(invalid)?;
+        let mut modules = debug_information
+            .modules()
+            .map_err(invalid)?;
+        let mut files = HashMap::new();
+        while let Some(module) = modules.next().map_err(invalid)? {
+            // A damaged module stream only loses the lines of that module.
+            let Ok(Some(info)) = pdb.module_info(&module) else {
+                continue;
+            };
+
+            let Ok(program) = info.line_program() else {
+                continue;
+            };
+
+            let mut lines = program.lines();
+            while let Ok(Some(line)) = lines.next() {
+                let Some(rva) = line.offset.to_rva(&address_map) else {
+                    continue;
+                };
+
+                let Ok(file) = program.get_file_info(line.file_index) else {
+                    continue;
+                };
+
+                let Ok(name) = strings.get(file.name) else {
+                    continue;
+                };
+
+                let name = name.to_string().into_owned();
+                let file = *files
+                    .entry(name)
+                    .or_insert_with_key(|name| {
+                        table.files.push(name.clone());
+                        table.files.len() - 1
+                    });
+
+                table.lines.insert(
+                    rva.0,
+                    LineRecord {
+                        len: line.length,
+                        file,
+                        line: line.line_start,
+                    },
+                );
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Returns the symbol containing an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The address, relative to the image base.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&str, u32))` - The name of the closest preceding symbol and the offset from it.
+    /// * `None` - If no symbol precedes the address.
+    pub fn lookup(&self, rva: u32) -> Option<(&str, u32)> {
+        self.symbols
+            .range(..=rva)
+            .next_back()
+            .map(|(start, name)| (name.as_str(), rva - start))
+    }
+
+    /// Returns the source line an address was compiled from.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The address, relative to the image base.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SourceLocation)` - The file and line of the address.
+    /// * `None` - If the PDB has no line information covering the address.
+    pub fn source_location(&self, rva: u32) -> Option<SourceLocation> {
+        let (start, record) = self.lines.range(..=rva).next_back()?;
+        if record
+            .len
+            .is_some_and(|len| rva - start >= len)
+        {
+            return None;
+        }
+
+        Some(SourceLocation {
+            file: self.files[record.file].clone(),
+            line: record.line,
+        })
+    }
+
+    /// Returns the number of symbols in the table.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns true if the table holds no symbol.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// Names addresses after the PDB symbols of their module, downloading the PDBs on first use.
+///
+/// Modules whose PDB cannot be fetched fall back to [`UserDump::symbolize`], which only
+/// knows the exports. Each PDB is fetched at most once, even when it is missing.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, symsrv::{SymbolServer, Symbolizer}};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let mut symbolizer = Symbolizer::new(SymbolServer::microsoft("C:\\symbols"));
+/// for (tid, thread) in dump.threads() {
+///     if let Some(ip) = thread.context().instruction_pointer() {
+///         println!("{tid}: {}", symbolizer.symbolize(&dump, ip).unwrap_or_default());
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Symbolizer {
+    /// The server PDBs are fetched from.
+    server: SymbolServer,
+
+    /// The symbol tables loaded so far, indexed by module base, `None` when unavailable.
+    tables: BTreeMap<u64, Option<SymbolTable>>,
+}
+
+impl Symbolizer {
+    /// Creates a new [`Symbolizer`] fetching PDBs from a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - The server PDBs are fetched from.
+    pub fn new(server: SymbolServer) -> Self {
+        Self {
+            server,
+            tables: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the symbol table of a module, fetching and reading its PDB on first use.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module whose symbols are requested.
+    pub fn table(&mut self, module: &Module) -> Option<&SymbolTable> {
+        let server = &self.server;
+        self.tables
+            .entry(module.start_addr())
+            .or_insert_with(|| {
+                let path = server
+                    .fetch_pdb(&module.debug_info()?)
+                    .ok()?;
+                SymbolTable::from_pdb(path).ok()
+            })
+            .as_ref()
+    }
+
+    /// Names an address after the module and the closest PDB symbol preceding it
+    /// (e.g., `ntdll!RtlUserThreadStart+0x21`).
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the address belongs to.
+    /// * `va` - The virtual address to name.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The name of the address.
+    /// * `None` - If the address does not belong to any loaded module.
+    pub fn symbolize(&mut self, dump: &UserDump, va: u64) -> Option<String> {
+        let module = dump.module_at(va)?;
+        let rva = u32::try_from(va - module.start_addr()).ok()?;
+        let Some((symbol, offset)) = self
+            .table(module)
+            .and_then(|table| table.lookup(rva))
+        else {
+            return dump.symbolize(va);
+        };
+
+        let name = module.name().unwrap_or("<unknown>");
+        let stem = name
+            .rsplit_once('.')
+            .map_or(name, |(stem, _)| stem);
+        Some(match offset {
+            0 => format!("{stem}!{symbol}"),
+            offset => format!("{stem}!{symbol}+{offset:#x}"),
+        })
+    }
+}
+
+impl Frame {
+    /// Returns the source file and line of the frame, from the PDB of its module.
+    ///
+    /// The return address of a caller frame points after the call instruction, which may
+    /// belong to the next line, so the line is looked up one byte before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the frame was produced from.
+    /// * `symbolizer` - Provides the PDB of the module, downloading it on first use.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SourceLocation)` - The file and line the frame is executing.
+    /// * `None` - If the frame belongs to no module, or its PDB is unavailable or has no
+    ///   line information.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, symsrv::{SymbolServer, Symbolizer}};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// let mut symbolizer = Symbolizer::new(SymbolServer::microsoft("C:\\symbols").server("https://symbols.example.com"));
+    /// let thread = dump.threads().values().next().unwrap();
+    /// for frame in dump.stack_trace(thread).unwrap() {
+    ///     let name = symbolizer.symbolize(&dump, frame.instruction_pointer).unwrap_or_default();
+    ///     match frame.source_location(&dump, &mut symbolizer) {
+    ///         Some(location) => println!("{name} ({location})"),
+    ///         None => println!("{name}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn source_location(&self, dump: &UserDump, symbolizer: &mut Symbolizer) -> Option<SourceLocation> {
+        let module = self.module(dump)?;
+        let address = match self.trust {
+            FrameTrust::Context => self.instruction_pointer,
+            _ => self
+                .instruction_pointer
+                .checked_sub(1)?,
+        };
+
+        let rva = u32::try_from(address.checked_sub(module.start_addr())?).ok()?;
+        symbolizer
+            .table(module)?
+            .source_location(rva)
+    }
+}
@@ -0,0 +1,567 @@
+use std::{
+    fmt,
+    io::{self, Cursor, Seek},
+};
+use binrw::BinRead;
+use crate::data::{MINIDUMP_TOKEN_INFO_HEADER, MINIDUMP_TOKEN_INFO_LIST};
+use crate::error::UserDmpError;
+use crate::parse::{Arch, MinidumpStream, Result, UserDump};
+
+/// Group attribute marking a group enabled for access checks.
+pub const SE_GROUP_ENABLED: u32 = 0x0000_0004;
+
+/// Group attribute marking a group only used to deny access.
+pub const SE_GROUP_USE_FOR_DENY_ONLY: u32 = 0x0000_0010;
+
+/// Group attribute marking the mandatory integrity SID of the token.
+pub const SE_GROUP_INTEGRITY: u32 = 0x0000_0020;
+
+/// Group attribute marking the logon session SID of the token.
+pub const SE_GROUP_LOGON_ID: u32 = 0xC000_0000;
+
+/// Privilege attribute marking a privilege enabled by default.
+pub const SE_PRIVILEGE_ENABLED_BY_DEFAULT: u32 = 0x0000_0001;
+
+/// Privilege attribute marking an enabled privilege.
+pub const SE_PRIVILEGE_ENABLED: u32 = 0x0000_0002;
+
+/// Privilege attribute marking a removed privilege.
+pub const SE_PRIVILEGE_REMOVED: u32 = 0x0000_0004;
+
+/// Identifier authority of the mandatory integrity label SIDs (`S-1-16-*`).
+const SECURITY_MANDATORY_LABEL_AUTHORITY: u64 = 16;
+
+/// Maximum number of sub-authorities of a SID.
+const SID_MAX_SUB_AUTHORITIES: usize = 15;
+
+/// Names of the well-known privileges, indexed by the low part of their LUID.
+const PRIVILEGE_NAMES: [&str; 35] = [
+    "SeCreateTokenPrivilege",
+    "SeAssignPrimaryTokenPrivilege",
+    "SeLockMemoryPrivilege",
+    "SeIncreaseQuotaPrivilege",
+    "SeMachineAccountPrivilege",
+    "SeTcbPrivilege",
+    "SeSecurityPrivilege",
+    "SeTakeOwnershipPrivilege",
+    "SeLoadDriverPrivilege",
+    "SeSystemProfilePrivilege",
+    "SeSystemtimePrivilege",
+    "SeProfileSingleProcessPrivilege",
+    "SeIncreaseBasePriorityPrivilege",
+    "SeCreatePagefilePrivilege",
+    "SeCreatePermanentPrivilege",
+    "SeBackupPrivilege",
+    "SeRestorePrivilege",
+    "SeShutdownPrivilege",
+    "SeDebugPrivilege",
+    "SeAuditPrivilege",
+    "SeSystemEnvironmentPrivilege",
+    "SeChangeNotifyPrivilege",
+    "SeRemoteShutdownPrivilege",
+    "SeUndockPrivilege",
+    "SeSyncAgentPrivilege",
+    "SeEnableDelegationPrivilege",
+    "SeManageVolumePrivilege",
+    "SeImpersonatePrivilege",
+    "SeCreateGlobalPrivilege",
+    "SeTrustedCredManAccessPrivilege",
+    "SeRelabelPrivilege",
+    "SeIncreaseWorkingSetPrivilege",
+    "SeTimeZonePrivilege",
+    "SeCreateSymbolicLinkPrivilege",
+    "SeDelegateSessionUserImpersonatePrivilege",
+];
+
+/// LUID of the first entry of [`PRIVILEGE_NAMES`] (`SE_CREATE_TOKEN_PRIVILEGE`).
+const FIRST_PRIVILEGE_LUID: u64 = 2;
+
+/// Represents a security identifier.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Sid {
+    /// The revision of the SID structure, always 1.
+    pub revision: u8,
+
+    /// The identifier authority (e.g., 5 for `SECURITY_NT_AUTHORITY`).
+    pub authority: u64,
+
+    /// The sub-authorities, the last one being the relative identifier (RID).
+    pub sub_authorities: Vec<u32>,
+}
+
+impl Sid {
+    /// Decodes a SID from its binary form.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The buffer starting with the SID.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((Sid, usize))` - The SID and its length in bytes.
+    /// * `None` - If the buffer does not start with a valid SID.
+    pub fn parse(bytes: &[u8]) -> Option<(Sid, usize)> {
+        let (&revision, rest) = bytes.split_first()?;
+        let (&count, rest) = rest.split_first()?;
+        if revision != 1 || count as usize > SID_MAX_SUB_AUTHORITIES {
+            return None;
+        }
+
+        // The identifier authority is stored big-endian.
+        let authority = rest
+            .get(..6)?
+            .iter()
+            .fold(0u64, |authority, &byte| authority << 8 | byte as u64);
+
+        let len = 8 + count as usize * 4;
+        let sub_authorities = bytes
+            .get(8..len)?
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|chunk| u32::from_le_bytes(*chunk))
+            .collect();
+
+        Some((
+            Sid {
+                revision,
+                authority,
+                sub_authorities,
+            },
+            len,
+        ))
+    }
+
+    /// Returns the relative identifier (RID), the last sub-authority of the SID.
+    pub fn rid(&self) -> Option<u32> {
+        self.sub_authorities.last().copied()
+    }
+
+    /// Returns true if the SID is a mandatory integrity label (`S-1-16-*`).
+    pub fn is_integrity_label(&self) -> bool {
+        self.authority == SECURITY_MANDATORY_LABEL_AUTHORITY
+    }
+}
+
+impl fmt::Display for Sid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Authorities that do not fit in 32 bits are written in hexadecimal, as ConvertSidToStringSid does.
+        if self.authority >> 32 == 0 {
+            write!(f, "S-{}-{}", self.revision, self.authority)?;
+        } else {
+            write!(f, "S-{}-{:#014x}", self.revision, self.authority)?;
+        }
+
+        for sub_authority in &self.sub_authorities {
+            write!(f, "-{sub_authority}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents a group of a token, with its attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenGroup {
+    /// The SID of the group.
+    pub sid: Sid,
+
+    /// The attributes of the group (`SE_GROUP_*`).
+    pub attributes: u32,
+}
+
+impl TokenGroup {
+    /// Returns true if the group is enabled for access checks.
+    pub fn is_enabled(&self) -> bool {
+        self.attributes & SE_GROUP_ENABLED != 0
+    }
+
+    /// Returns true if the group is only used to deny access.
+    pub fn is_deny_only(&self) -> bool {
+        self.attributes & SE_GROUP_USE_FOR_DENY_ONLY != 0
+    }
+
+    /// Returns true if the group is the logon session of the token.
+    pub fn is_logon_id(&self) -> bool {
+        self.attributes & SE_GROUP_LOGON_ID == SE_GROUP_LOGON_ID
+    }
+}
+
+/// Represents a privilege held by a token, with its attributes.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Privilege {
+    /// The locally unique identifier of the privilege.
+    pub luid: u64,
+
+    /// The attributes of the privilege (`SE_PRIVILEGE_*`).
+    pub attributes: u32,
+}
+
+impl Privilege {
+    /// Returns the name of the privilege (e.g., `SeDebugPrivilege`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - The name of a well-known privilege.
+    /// * `None` - If the LUID is not one of the well-known privileges.
+    pub fn name(&self) -> Option<&'static str> {
+        let index = self
+            .luid
+            .checked_sub(FIRST_PRIVILEGE_LUID)?;
+        PRIVILEGE_NAMES
+            .get(usize::try_from(index).ok()?)
+            .copied()
+    }
+
+    /// Returns true if the privilege is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.attributes & SE_PRIVILEGE_ENABLED != 0
+    }
+
+    /// Returns true if the privilege is enabled by default.
+    pub fn is_enabled_by_default(&self) -> bool {
+        self.attributes & SE_PRIVILEGE_ENABLED_BY_DEFAULT != 0
+    }
+}
+
+/// Identifies the mandatory integrity level of a token.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum IntegrityLevel {
+    /// `SECURITY_MANDATORY_UNTRUSTED_RID` (`S-1-16-0`).
+    Untrusted,
+
+    /// `SECURITY_MANDATORY_LOW_RID` (`S-1-16-4096`), e.g. sandboxed browser renderers.
+    Low,
+
+    /// `SECURITY_MANDATORY_MEDIUM_RID` (`S-1-16-8192`), standard user processes.
+    Medium,
+
+    /// `SECURITY_MANDATORY_MEDIUM_PLUS_RID` (`S-1-16-8448`).
+    MediumPlus,
+
+    /// `SECURITY_MANDATORY_HIGH_RID` (`S-1-16-12288`), elevated processes.
+    High,
+
+    /// `SECURITY_MANDATORY_SYSTEM_RID` (`S-1-16-16384`), services running as SYSTEM.
+    System,
+
+    /// `SECURITY_MANDATORY_PROTECTED_PROCESS_RID` (`S-1-16-20480`).
+    ProtectedProcess,
+
+    /// A level between the well-known ones, with its RID.
+    Other(u32),
+}
+
+impl From<u32> for IntegrityLevel {
+    fn from(rid: u32) -> Self {
+        match rid {
+            0x0000 => IntegrityLevel::Untrusted,
+            0x1000 => IntegrityLevel::Low,
+            0x2000 => IntegrityLevel::Medium,
+            0x2100 => IntegrityLevel::MediumPlus,
+            0x3000 => IntegrityLevel::High,
+            0x4000 => IntegrityLevel::System,
+            0x5000 => IntegrityLevel::ProtectedProcess,
+            rid => IntegrityLevel::Other(rid),
+        }
+    }
+}
+
+impl fmt::Display for IntegrityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityLevel::Untrusted => f.write_str("Untrusted"),
+            IntegrityLevel::Low => f.write_str("Low"),
+            IntegrityLevel::Medium => f.write_str("Medium"),
+            IntegrityLevel::MediumPlus => f.write_str("Medium Plus"),
+            IntegrityLevel::High => f.write_str("High"),
+            IntegrityLevel::System => f.write_str("System"),
+            IntegrityLevel::ProtectedProcess => f.write_str("Protected Process"),
+            IntegrityLevel::Other(rid) => write!(f, "{rid:#x}"),
+        }
+    }
+}
+
+/// Represents an access token captured in the `TokenStream`.
+///
+/// Dumps written with `MiniDumpWithTokenInformation` record the tokens of the process
+/// and of its impersonating threads. The information of each token is the output of
+/// `GetTokenInformation` for the `TokenUser`, `TokenGroups` and `TokenPrivileges`
+/// classes, written back to back. The SID pointers of those buffers refer to the
+/// address space of the dump writer, so the SIDs are decoded from their position
+/// instead, and the raw information is kept for anything not decoded here.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Token {
+    /// The identifier of the token.
+    pub token_id: u32,
+
+    /// The handle of the token in the captured process.
+    pub token_handle: u64,
+
+    /// The user the token belongs to.
+    user: Option<Sid>,
+
+    /// The groups of the token.
+    groups: Vec<TokenGroup>,
+
+    /// The privileges of the token.
+    privileges: Vec<Privilege>,
+
+    /// The raw token information, following the entry header.
+    data: Vec<u8>,
+}
+
+impl Token {
+    /// Returns the SID of the user the token belongs to.
+    pub fn user(&self) -> Option<&Sid> {
+        self.user.as_ref()
+    }
+
+    /// Returns the groups of the token, including the integrity label and the logon session.
+    pub fn groups(&self) -> &[TokenGroup] {
+        &self.groups
+    }
+
+    /// Returns the privileges of the token, enabled or not.
+    pub fn privileges(&self) -> &[Privilege] {
+        &self.privileges
+    }
+
+    /// Returns the privileges of the token that are enabled.
+    pub fn enabled_privileges(&self) -> impl Iterator<Item = &Privilege> {
+        self.privileges
+            .iter()
+            .filter(|privilege| privilege.is_enabled())
+    }
+
+    /// Returns the mandatory integrity level of the token, taken from its integrity group.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IntegrityLevel)` - The integrity level of the token.
+    /// * `None` - If the groups of the token could not be decoded.
+    pub fn integrity_level(&self) -> Option<IntegrityLevel> {
+        self.groups
+            .iter()
+            .find(|group| group.attributes & SE_GROUP_INTEGRITY != 0 || group.sid.is_integrity_label())
+            .and_then(|group| group.sid.rid())
+            .map(IntegrityLevel::from)
+    }
+
+    /// Returns the raw token information, following the entry header.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Decodes the user, groups and privileges from the raw token information.
+    ///
+    /// Decoding stops at the first structure that does not fit, leaving the
+    /// remaining fields empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `arch` - The architecture of the dump, which sets the size of the pointers.
+    pub(crate) fn decode(&mut self, arch: Arch) {
+        let pointer = match arch {
+            Arch::X86 | Arch::Arm => 4,
+            _ => 8,
+        };
+
+        // SID_AND_ATTRIBUTES: a pointer and a 32-bit attribute, padded to the pointer alignment.
+        let sid_and_attributes = pointer * 2;
+        let mut reader = TokenReader { data: &self.data, offset: 0 };
+
+        // TOKEN_USER
+        if reader
+            .sid_and_attributes(pointer)
+            .is_none()
+        {
+            return;
+        }
+
+        let Some(user) = reader.sid() else {
+            return;
+        };
+        self.user = Some(user);
+
+        // TOKEN_GROUPS
+        reader.align(pointer);
+        let Some(count) = reader.u32() else {
+            return;
+        };
+        reader.align(pointer);
+        if count as usize > reader.remaining() / sid_and_attributes {
+            return;
+        }
+
+        let attributes = (0..count)
+            .map_while(|_| reader.sid_and_attributes(pointer))
+            .collect::<Vec<u32>>();
+        for attributes in attributes {
+            let Some(sid) = reader.sid() else {
+                return;
+            };
+            self.groups
+                .push(TokenGroup { sid, attributes });
+        }
+
+        // TOKEN_PRIVILEGES
+        reader.align(pointer);
+        let Some(count) = reader.u32() else {
+            return;
+        };
+        if count as usize > reader.remaining() / 12 {
+            return;
+        }
+
+        self.privileges = (0..count)
+            .map_while(|_| {
+                let low = reader.u32()?;
+                let high = reader.u32()?;
+                let attributes = reader.u32()?;
+                Some(Privilege {
+                    luid: (high as u64) << 32 | low as u64,
+                    attributes,
+                })
+            })
+            .collect();
+    }
+}
+
+/// Reads the structures of the token information sequentially.
+struct TokenReader<'d> {
+    /// The raw token information.
+    data: &'d [u8],
+
+    /// The offset of the next structure.
+    offset: usize,
+}
+
+impl TokenReader<'_> {
+    /// Returns the number of bytes left.
+    fn remaining(&self) -> usize {
+        self.data
+            .len()
+            .saturating_sub(self.offset)
+    }
+
+    /// Moves to the next multiple of `alignment`.
+    fn align(&mut self, alignment: usize) {
+        self.offset = self.offset.next_multiple_of(alignment);
+    }
+
+    /// Reads a 32-bit value.
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + 4)?;
+        self.offset += 4;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a `SID_AND_ATTRIBUTES` structure and returns its attributes.
+    fn sid_and_attributes(&mut self, pointer: usize) -> Option<u32> {
+        self.data
+            .get(self.offset..self.offset + pointer * 2)?;
+        self.offset += pointer;
+        let attributes = self.u32()?;
+        self.offset += pointer - 4;
+        Some(attributes)
+    }
+
+    /// Reads a SID.
+    fn sid(&mut self) -> Option<Sid> {
+        let (sid, len) = Sid::parse(self.data.get(self.offset..)?)?;
+        self.offset += len;
+        Some(sid)
+    }
+}
+
+impl MinidumpStream<'_> for Token {
+    type Output = Vec<Token>;
+
+    /// Parses the tokens from the `TokenStream`.
+    ///
+    /// Only the entry headers and the raw information are read here; the information
+    /// is decoded once the architecture of the dump is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor positioned at the token stream.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Token>)` - The tokens, in stream order.
+    /// * `Err(UserDmpError)` - If an error occurs during parsing.
+    fn parse(cursor: &mut Cursor<&'_ [u8]>) -> Result<Self::Output> {
+        // Reads the token list header.
+        let start = cursor.position();
+        let token_list = MINIDUMP_TOKEN_INFO_LIST::read(cursor)?;
+
+        let mut tokens = Vec::new();
+        let mut offset = start + token_list.ListHeaderSize as u64;
+        for _ in 0..token_list.TokenListEntries {
+            cursor.seek(io::SeekFrom::Start(offset))?;
+            let header = MINIDUMP_TOKEN_INFO_HEADER::read(cursor)?;
+
+            // The information follows the entry header and fills the rest of the entry.
+            let data_start = offset + token_list.ElementHeaderSize as u64;
+            let data_size = (header.TokenSize as u64)
+                .checked_sub(token_list.ElementHeaderSize.into())
+                .ok_or(UserDmpError::TruncatedStream {
+                    rva: offset,
+                    size: header.TokenSize.into(),
+                })?;
+            let data = UserDump::file_slice(cursor, data_start, data_size)?;
+
+            tokens.push(Token {
+                token_id: header.TokenId,
+                token_handle: header.TokenHandle,
+                data: data.to_vec(),
+                ..Default::default()
+            });
+
+            // An empty entry would never advance, so the list ends there.
+            if header.TokenSize == 0 {
+                break;
+            }
+
+            offset += header.TokenSize as u64;
+        }
+
+        Ok(tokens)
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns the integrity level of the process.
+    ///
+    /// The level is taken from the first token of the `TokenStream`, and otherwise from
+    /// the integrity level recorded in the `MiscInfoStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(level) = dump.integrity_level() {
+    ///     println!("integrity: {level}");
+    /// }
+    /// ```
+    pub fn integrity_level(&self) -> Option<IntegrityLevel> {
+        self.tokens()
+            .iter()
+            .find_map(Token::integrity_level)
+            .or_else(|| {
+                self.misc_info()
+                    .and_then(|misc| misc.process_integrity_level)
+                    .map(IntegrityLevel::from)
+            })
+    }
+}
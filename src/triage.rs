@@ -0,0 +1,434 @@
+use std::{fmt, path::Path};
+use crate::analysis::passes::{AnalysisRegistry, Finding, Severity};
+use crate::exception::{AccessKind, ExceptionCode};
+use crate::parse::UserDump;
+#[cfg(feature = "iced-x86")]
+use crate::disasm::Instruction;
+use crate::pointer::is_canonical_x64;
+
+/// Exception codes reported for unresponsive applications.
+const HANG_CODES: [u32; 2] = [
+    0xCFFF_FFFF, // Application hang, used by Windows Error Reporting.
+    0xC000_0194, // STATUS_POSSIBLE_DEADLOCK
+];
+
+/// Exception codes reported when memory is exhausted.
+const OOM_CODES: [u32; 2] = [
+    0xC000_0017, // STATUS_NO_MEMORY
+    0xC000_012D, // STATUS_COMMITMENT_LIMIT
+];
+
+/// Addresses below this bound are never mapped, so faults on them come from `NULL` pointers plus an offset.
+const NEAR_NULL_LIMIT: u64 = 0x1_0000;
+
+/// A coarse, machine-readable classification of a dump.
+///
+/// The variants and their numeric [`Verdict::code`] are stable, so build pipelines
+/// can gate on them without parsing text reports.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::triage::Verdict;
+///
+/// let verdict = Verdict::from_file("example.dmp");
+/// println!("{verdict}");
+/// std::process::exit(verdict.code());
+/// ```
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The dump was written without an exception (e.g., a manual snapshot). Code `0`.
+    NoException,
+
+    /// The process crashed on an exception. Code `1`.
+    Crash,
+
+    /// The process was unresponsive or deadlocked. Code `2`.
+    Hang,
+
+    /// The process ran out of memory. Code `3`.
+    OutOfMemory,
+
+    /// The dump could not be parsed or is missing essential data. Code `4`.
+    CorruptDump,
+}
+
+impl Verdict {
+    /// Parses a minidump file and classifies it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the minidump file.
+    ///
+    /// # Returns
+    ///
+    /// * The [`Verdict`] of the dump, [`Verdict::CorruptDump`] if it cannot be parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        UserDump::new(path).map_or(Verdict::CorruptDump, |dump| dump.verdict())
+    }
+
+    /// Returns the numeric code of the verdict, suitable as a process exit code.
+    pub fn code(&self) -> i32 {
+        match self {
+            Verdict::NoException => 0,
+            Verdict::Crash => 1,
+            Verdict::Hang => 2,
+            Verdict::OutOfMemory => 3,
+            Verdict::CorruptDump => 4,
+        }
+    }
+
+    /// Returns the stable, lowercase name of the verdict (e.g., `no-exception`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::NoException => "no-exception",
+            Verdict::Crash => "crash",
+            Verdict::Hang => "hang",
+            Verdict::OutOfMemory => "oom",
+            Verdict::CorruptDump => "corrupt-dump",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How likely a crash is to be exploitable, in the spirit of the `!exploitable` debugger extension.
+///
+/// The variants are ordered from the least to the most concerning.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Exploitability {
+    /// The crash is unlikely to be exploitable (e.g., a `NULL` read or a stack exhaustion).
+    ProbablyNotExploitable,
+
+    /// The heuristics cannot tell (e.g., a read from a wild pointer).
+    Unknown,
+
+    /// The crash shows signs of exploitability (e.g., a write near `NULL`).
+    ProbablyExploitable,
+
+    /// The crash is likely exploitable (e.g., a write to a wild pointer or a DEP violation).
+    Exploitable,
+}
+
+impl Exploitability {
+    /// Returns the stable, lowercase name of the rating (e.g., `probably-exploitable`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Exploitability::ProbablyNotExploitable => "probably-not-exploitable",
+            Exploitability::Unknown => "unknown",
+            Exploitability::ProbablyExploitable => "probably-exploitable",
+            Exploitability::Exploitable => "exploitable",
+        }
+    }
+}
+
+impl fmt::Display for Exploitability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The exploitability rating of a crash, with the evidence it rests on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExploitabilityAssessment {
+    /// The rating, the most concerning of the exception and the stack heuristics.
+    pub rating: Exploitability,
+
+    /// A short name for the kind of crash (e.g., `WriteAV`, `ReadAVNearNull`).
+    pub classification: &'static str,
+
+    /// The observations behind the rating.
+    pub reasons: Vec<String>,
+}
+
+impl ExploitabilityAssessment {
+    /// Raises the rating to `rating` if it is more concerning, recording the reason.
+    fn raise(&mut self, rating: Exploitability, reason: String) {
+        self.rating = self.rating.max(rating);
+        self.reasons.push(reason);
+    }
+}
+
+impl fmt::Display for ExploitabilityAssessment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.rating, self.classification)?;
+        for reason in &self.reasons {
+            write!(f, "\n  {reason}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The verdict of a dump together with the findings of the analysis passes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use userdmp::{UserDump, analysis::passes::AnalysisRegistry};
+///
+/// let dump = UserDump::new("example.dmp").unwrap();
+/// let report = dump.triage(&AnalysisRegistry::builtin());
+/// println!("{report}");
+/// std::process::exit(report.verdict.code());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriageReport {
+    /// The classification of the dump.
+    pub verdict: Verdict,
+
+    /// The exploitability of the crash, for dumps holding an exception.
+    pub exploitability: Option<ExploitabilityAssessment>,
+
+    /// The findings of the analysis passes, the most severe first.
+    pub findings: Vec<Finding>,
+
+    /// The code around the address of the exception, empty if it was not captured.
+    #[cfg(feature = "iced-x86")]
+    pub faulting_instructions: Vec<Instruction>,
+}
+
+impl TriageReport {
+    /// Returns the severity of the most severe finding, if any.
+    pub fn highest_severity(&self) -> Option<Severity> {
+        self.findings
+            .iter()
+            .map(|finding| finding.severity)
+            .max()
+    }
+}
+
+impl fmt::Display for TriageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "verdict: {}", self.verdict)?;
+        if let Some(exploitability) = &self.exploitability {
+            writeln!(f, "exploitability: {exploitability}")?;
+        }
+
+        for finding in &self.findings {
+            writeln!(f, "{finding}")?;
+        }
+
+        #[cfg(feature = "iced-x86")]
+        if !self.faulting_instructions.is_empty() {
+            writeln!(f, "faulting code:")?;
+            for instruction in &self.faulting_instructions {
+                writeln!(f, "  {instruction}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl UserDump<'_> {
+    /// Classifies the dump and runs the passes of a registry over it.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The passes to run (e.g., [`AnalysisRegistry::builtin`]).
+    pub fn triage(&self, registry: &AnalysisRegistry) -> TriageReport {
+        TriageReport {
+            verdict: self.verdict(),
+            exploitability: self.exploitability(),
+            findings: self.analyze(registry),
+            #[cfg(feature = "iced-x86")]
+            faulting_instructions: self
+                .exception()
+                .and_then(|exception| {
+                    exception
+                        .faulting_instructions(self)
+                        .ok()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Reduces the dump to a [`Verdict`].
+    ///
+    /// The dump is considered corrupt when parsing reported anomalies, when it holds
+    /// no thread or no memory, or when the exception names a thread missing from the
+    /// thread list. Otherwise the exception code decides between a hang, an out of
+    /// memory condition and a crash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, triage::Verdict};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if dump.verdict() == Verdict::Crash {
+    ///     eprintln!("crash detected");
+    /// }
+    /// ```
+    pub fn verdict(&self) -> Verdict {
+        let missing_thread = self
+            .exception()
+            .is_some_and(|exception| {
+                !self
+                    .threads()
+                    .contains_key(&exception.thread_id)
+            });
+
+        if !self.diagnostics().is_empty() || self.threads().is_empty() || self.memorys().is_empty() || missing_thread {
+            return Verdict::CorruptDump;
+        }
+
+        match self.exception() {
+            None => Verdict::NoException,
+            Some(exception) if HANG_CODES.contains(&exception.code) => Verdict::Hang,
+            Some(exception) if OOM_CODES.contains(&exception.code) => Verdict::OutOfMemory,
+            Some(_) => Verdict::Crash,
+        }
+    }
+
+    /// Rates how likely the crash is to be exploitable, in the spirit of `!exploitable`.
+    ///
+    /// The exception gives the base rating: writes and DEP violations rate higher than
+    /// reads, and faults near `NULL` lower than faults on wild pointers. Stack corruption
+    /// heuristics on the faulting thread may then raise it: a stack pointer outside the
+    /// stack, return addresses outside images, code running outside images, or an SEH
+    /// record off the stack or with a handler outside images or rejected by SafeSEH.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ExploitabilityAssessment)` - The rating and the evidence behind it.
+    /// * `None` - If the dump holds no exception.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::{UserDump, triage::Exploitability};
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// if let Some(assessment) = dump.exploitability() {
+    ///     if assessment.rating >= Exploitability::ProbablyExploitable {
+    ///         println!("{assessment}");
+    ///     }
+    /// }
+    /// ```
+    pub fn exploitability(&self) -> Option<ExploitabilityAssessment> {
+        let exception = self.exception()?;
+        let code = exception.exception_code();
+        let (rating, classification) = match code {
+            ExceptionCode::AccessViolation | ExceptionCode::InPageError => match exception.access_violation() {
+                Some(violation) => {
+                    let near_null = violation.address < NEAR_NULL_LIMIT;
+                    match (violation.kind, near_null) {
+                        (AccessKind::Execute, true) => (Exploitability::ProbablyNotExploitable, "ExecuteNearNull"),
+                        (AccessKind::Execute, false) => (Exploitability::Exploitable, "DEPViolation"),
+                        (AccessKind::Write, true) => (Exploitability::ProbablyExploitable, "WriteAVNearNull"),
+                        (AccessKind::Write, false) => (Exploitability::Exploitable, "WriteAV"),
+                        (AccessKind::Read, true) => (Exploitability::ProbablyNotExploitable, "ReadAVNearNull"),
+                        (AccessKind::Read, false) => (Exploitability::Unknown, "ReadAV"),
+                        (AccessKind::Unknown(_), _) => (Exploitability::Unknown, "AccessViolation"),
+                    }
+                }
+                None => (Exploitability::Unknown, "AccessViolation"),
+            },
+            ExceptionCode::StackBufferOverrun => (Exploitability::Exploitable, "GSViolation"),
+            ExceptionCode::HeapCorruption => (Exploitability::Exploitable, "HeapCorruption"),
+            ExceptionCode::IllegalInstruction | ExceptionCode::PrivilegedInstruction => (Exploitability::ProbablyExploitable, "IllegalInstruction"),
+            ExceptionCode::StackOverflow => (Exploitability::ProbablyNotExploitable, "StackExhaustion"),
+            ExceptionCode::IntegerDivideByZero | ExceptionCode::FloatDivideByZero => (Exploitability::ProbablyNotExploitable, "DivideByZero"),
+            ExceptionCode::NoMemory | ExceptionCode::ApplicationHang | ExceptionCode::PossibleDeadlock => {
+                (Exploitability::ProbablyNotExploitable, "ResourceExhaustion")
+            }
+            ExceptionCode::Breakpoint | ExceptionCode::SingleStep | ExceptionCode::Wow64Breakpoint => (Exploitability::Unknown, "Breakpoint"),
+            _ => (Exploitability::Unknown, "Unclassified"),
+        };
+
+        let mut assessment = ExploitabilityAssessment {
+            rating,
+            classification,
+            reasons: vec![exception.to_string()],
+        };
+
+        if let Some(violation) = exception.access_violation() {
+            let wild = (self.pointer_size() == 8 && !is_canonical_x64(violation.address))
+                || self
+                    .memory_at(violation.address)
+                    .is_none();
+            if violation.address >= NEAR_NULL_LIMIT && wild {
+                assessment
+                    .reasons
+                    .push(format!("{:#x} is a wild pointer, outside any region of the process", violation.address));
+            }
+        }
+
+        // Faults raised by the processor report the faulting instruction; software
+        // exceptions are raised from `RaiseException`, inside an image.
+        let hardware = !matches!(
+            code,
+            ExceptionCode::CppException | ExceptionCode::DotNet | ExceptionCode::DotNetLegacy | ExceptionCode::Unknown(_)
+        );
+        if hardware && !self.is_image_address(exception.address) {
+            assessment.raise(
+                Exploitability::ProbablyExploitable,
+                format!("instruction pointer {:#x} is outside any image", exception.address),
+            );
+        }
+
+        if let Some(thread) = self.threads().get(&exception.thread_id) {
+            let bounds = self.stack_bounds(thread);
+            if let Some(sp) = thread.context().stack_pointer()
+                && !bounds.is_empty()
+                && !bounds.contains(&sp)
+            {
+                assessment.raise(
+                    Exploitability::Exploitable,
+                    format!("stack pointer {sp:#x} is outside the stack {:#x}-{:#x}", bounds.start, bounds.end),
+                );
+            }
+
+            if let Ok(frames) = self.stack_trace(thread) {
+                if let Some(frame) = frames
+                    .iter()
+                    .skip(1)
+                    .find(|frame| !self.is_image_address(frame.instruction_pointer))
+                {
+                    assessment.raise(
+                        Exploitability::ProbablyExploitable,
+                        format!("return address {:#x} is outside any image", frame.instruction_pointer),
+                    );
+                }
+
+                if !bounds.is_empty()
+                    && let Some(frame) = frames
+                        .iter()
+                        .find(|frame| !bounds.contains(&frame.stack_pointer))
+                {
+                    assessment.raise(
+                        Exploitability::ProbablyExploitable,
+                        format!("frame at {:#x} lies outside the stack", frame.stack_pointer),
+                    );
+                }
+            }
+
+            if let Ok(records) = self.seh_chain(thread)
+                && let Some(record) = records
+                    .iter()
+                    .find(|record| record.is_suspicious())
+            {
+                assessment.raise(
+                    Exploitability::ProbablyExploitable,
+                    format!("SEH record at {:#x} has a suspicious handler {:#x}", record.address, record.handler),
+                );
+            }
+        }
+
+        Some(assessment)
+    }
+
+    /// Returns true if the address lies in an image mapping or a module of the dump.
+    fn is_image_address(&self, va: u64) -> bool {
+        self.module_at(va).is_some()
+            || self
+                .memory_at(va)
+                .is_some_and(|memory| memory.type_.is_image())
+    }
+}
@@ -0,0 +1,195 @@
+use core::fmt;
+use crate::data::VS_FIXEDFILEINFO;
+use crate::memory::write_flags;
+use crate::parse::Module;
+
+/// Signature of a valid `VS_FIXEDFILEINFO`.
+const VS_FFI_SIGNATURE: u32 = 0xFEEF_04BD;
+
+/// The Boolean attributes of a file (`VS_FF_*` flags of `dwFileFlags`).
+#[derive(Copy, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FileFlags(u32);
+
+impl FileFlags {
+    /// `VS_FF_DEBUG`: the file contains debugging information or was compiled with debugging features.
+    pub const DEBUG: Self = Self(0x01);
+    /// `VS_FF_INFOINFERRED`: the version structure was created dynamically.
+    pub const INFOINFERRED: Self = Self(0x10);
+    /// The names of the flags, in display order.
+    const NAMES: [(u32, &str); 6] = [
+        (0x01, "VS_FF_DEBUG"),
+        (0x02, "VS_FF_PRERELEASE"),
+        (0x04, "VS_FF_PATCHED"),
+        (0x08, "VS_FF_PRIVATEBUILD"),
+        (0x10, "VS_FF_INFOINFERRED"),
+        (0x20, "VS_FF_SPECIALBUILD"),
+    ];
+    /// `VS_FF_PATCHED`: the file was modified and is not identical to the original shipping file.
+    pub const PATCHED: Self = Self(0x04);
+    /// `VS_FF_PRERELEASE`: the file is a development version, not a commercially released product.
+    pub const PRERELEASE: Self = Self(0x02);
+    /// `VS_FF_PRIVATEBUILD`: the file was not built using standard release procedures.
+    pub const PRIVATEBUILD: Self = Self(0x08);
+    /// `VS_FF_SPECIALBUILD`: the file is a variation of the standard file of the same version.
+    pub const SPECIALBUILD: Self = Self(0x20);
+
+    /// Returns the raw `VS_FF_*` value.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether every flag of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether no flag is set, as for regular release builds.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether the file is a debug build.
+    pub const fn is_debug(self) -> bool {
+        self.contains(Self::DEBUG)
+    }
+
+    /// Returns whether the file is a prerelease build.
+    pub const fn is_prerelease(self) -> bool {
+        self.contains(Self::PRERELEASE)
+    }
+
+    /// Returns whether the file was patched.
+    pub const fn is_patched(self) -> bool {
+        self.contains(Self::PATCHED)
+    }
+}
+
+impl From<u32> for FileFlags {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl fmt::Display for FileFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flags(f, self.0, &Self::NAMES)
+    }
+}
+
+/// The fixed version information of a module, as recorded in `MINIDUMP_MODULE.VersionInfo`.
+#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VersionInfo {
+    /// The version of the file, as `(major, minor, build, revision)`.
+    pub file_version: (u16, u16, u16, u16),
+
+    /// The version of the product the file ships with, as `(major, minor, build, revision)`.
+    pub product_version: (u16, u16, u16, u16),
+
+    /// The attributes of the file, limited to the bits declared valid by `dwFileFlagsMask`.
+    pub file_flags: FileFlags,
+
+    /// The operating system the file was designed for (`VOS_*`).
+    pub file_os: u32,
+
+    /// The general type of the file (`VFT_*`, e.g., `VFT_DLL`).
+    pub file_type: u32,
+
+    /// The function of the file for drivers and fonts (`VFT2_*`).
+    pub file_subtype: u32,
+}
+
+/// Splits the two halves of a `VS_FIXEDFILEINFO` version into its four parts.
+fn version_parts(most: u32, least: u32) -> (u16, u16, u16, u16) {
+    ((most >> 16) as u16, most as u16, (least >> 16) as u16, least as u16)
+}
+
+/// Formats a version as `major.minor.build.revision`.
+fn version_string((major, minor, build, revision): (u16, u16, u16, u16)) -> String {
+    format!("{major}.{minor}.{build}.{revision}")
+}
+
+impl VersionInfo {
+    /// Decodes a `VS_FIXEDFILEINFO`, which is all zeros when the producer recorded none.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(VersionInfo)` - If the structure carries the `VS_FIXEDFILEINFO` signature.
+    /// * `None` - Otherwise.
+    pub(crate) fn from_fixed_file_info(info: &VS_FIXEDFILEINFO) -> Option<Self> {
+        (info.dwSignature == VS_FFI_SIGNATURE).then(|| Self {
+            file_version: version_parts(info.dwFileVersionMS, info.dwFileVersionLS),
+            product_version: version_parts(info.dwProductVersionMS, info.dwProductVersionLS),
+            file_flags: FileFlags(info.dwFileFlags.0 & info.dwFileFlagsMask),
+            file_os: info.dwFileOS.0,
+            file_type: info.dwFileType,
+            file_subtype: info.dwFileSubtype,
+        })
+    }
+
+    /// Returns the file version formatted as `major.minor.build.revision`.
+    pub fn file_version_string(&self) -> String {
+        version_string(self.file_version)
+    }
+
+    /// Returns the product version formatted as `major.minor.build.revision`.
+    pub fn product_version_string(&self) -> String {
+        version_string(self.product_version)
+    }
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.file_version_string())?;
+        if !self.file_flags.is_empty() {
+            write!(f, " ({})", self.file_flags)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Module<'_> {
+    /// Returns the file version of the module, as `(major, minor, build, revision)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for module in dump.modules().values() {
+    ///     let version = module.file_version_string().unwrap_or_default();
+    ///     println!("{:<32} {version}", module.name().unwrap_or("???"));
+    /// }
+    /// ```
+    pub fn file_version(&self) -> Option<(u16, u16, u16, u16)> {
+        self.version_info()
+            .map(|info| info.file_version)
+    }
+
+    /// Returns the product version of the module, as `(major, minor, build, revision)`.
+    pub fn product_version(&self) -> Option<(u16, u16, u16, u16)> {
+        self.version_info()
+            .map(|info| info.product_version)
+    }
+
+    /// Returns the file version of the module formatted as `major.minor.build.revision`.
+    pub fn file_version_string(&self) -> Option<String> {
+        self.version_info()
+            .map(VersionInfo::file_version_string)
+    }
+
+    /// Returns the product version of the module formatted as `major.minor.build.revision`.
+    pub fn product_version_string(&self) -> Option<String> {
+        self.version_info()
+            .map(VersionInfo::product_version_string)
+    }
+
+    /// Returns the attributes of the module file (debug, prerelease, patched, ...).
+    pub fn file_flags(&self) -> Option<FileFlags> {
+        self.version_info()
+            .map(|info| info.file_flags)
+    }
+}
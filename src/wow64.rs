@@ -0,0 +1,86 @@
+use std::ptr;
+use crate::data::CONTEXT_X86;
+use crate::parse::{Module, Thread, UserDump};
+use crate::reader::offset_va;
+
+/// Offset of `TlsSlots[WOW64_TLS_CPURESERVED]` in the 64-bit TEB.
+const TEB64_WOW64_CPURESERVED: u64 = 0x1480 + 8;
+
+/// Offset of the 32-bit context in the `WOW64_CPURESERVED` area, after its `Flags` and `Machine` fields.
+const CPURESERVED_CONTEXT_OFFSET: u64 = 4;
+
+impl Thread {
+    /// Reads the 32-bit context of a thread of a WOW64 process.
+    ///
+    /// The context in the `ThreadListStream` of a WOW64 process is the native x64
+    /// context, which usually sits in the WOW64 layer. The registers of the 32-bit
+    /// code are saved by WOW64 in the area pointed to by the `WOW64_TLS_CPURESERVED`
+    /// slot of the 64-bit TEB.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the thread belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Box<CONTEXT_X86>)` - The 32-bit context of the thread.
+    /// * `None` - If the process is not a WOW64 process or the context was not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use userdmp::UserDump;
+    ///
+    /// let dump = UserDump::new("example.dmp").unwrap();
+    /// for (tid, thread) in dump.threads() {
+    ///     if let Some(context) = thread.wow64_context(&dump) {
+    ///         println!("Thread {tid}: EIP {:#x}", context.Eip);
+    ///     }
+    /// }
+    /// ```
+    pub fn wow64_context(&self, dump: &UserDump) -> Option<Box<CONTEXT_X86>> {
+        if !dump.is_wow64() {
+            return None;
+        }
+
+        let reserved = offset_va(self.teb, TEB64_WOW64_CPURESERVED)
+            .and_then(|va| dump.read_u64(va))
+            .ok()
+            .filter(|&reserved| reserved != 0)?;
+        let bytes = dump
+            .read_bytes(reserved.checked_add(CPURESERVED_CONTEXT_OFFSET)?, size_of::<CONTEXT_X86>())
+            .ok()?;
+
+        // SAFETY: `bytes` holds exactly `size_of::<CONTEXT_X86>()` bytes, and every bit
+        // pattern is a valid `CONTEXT_X86`.
+        Some(Box::new(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const CONTEXT_X86) }))
+    }
+}
+
+impl Module<'_> {
+    /// Returns true if the module is a 32-bit image, as in the 32-bit half of a WOW64 process.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The dump the module belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(bool)` - Whether the optional header of the image is a PE32 header.
+    /// * `None` - If the headers of the module were not captured.
+    pub fn is_32bit(&self, dump: &UserDump) -> Option<bool> {
+        dump.pe_headers(self.start_addr())
+            .ok()
+            .map(|headers| !headers.is_64bit)
+    }
+}
+
+impl UserDump<'_> {
+    /// Returns true if the dump is an x64 dump of a 32-bit process running under WOW64.
+    ///
+    /// The process is recognized by the 32-bit TEB that WOW64 places after the 64-bit
+    /// TEB of every thread.
+    pub fn is_wow64(&self) -> bool {
+        self.peb32().is_some()
+    }
+}